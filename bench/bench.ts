@@ -11,6 +11,7 @@ import { benchGetSetProperty } from './get-set-property'
 import { benchNoop } from './noop'
 import { benchPlus } from './plus'
 import { benchQuery } from './query'
+import { benchString } from './string'
 
 async function run() {
   const output = [
@@ -22,6 +23,7 @@ async function run() {
     await benchGetSetProperty(),
     await benchAsync(),
     await benchQuery(),
+    await benchString(),
   ]
     .map(formatSummary)
     .join('\n')
@@ -4,6 +4,7 @@ const {
   createArrayJson,
   createArray,
   createArrayWithSerdeTrait,
+  createObjectArray,
 } = require('./index.node')
 
 export const benchCreateArray = () =>
@@ -20,6 +21,10 @@ export const benchCreateArray = () =>
       createArrayWithSerdeTrait()
     }),
 
+    b.add('create array of #[napi(object)]', () => {
+      createObjectArray()
+    }),
+
     b.cycle(),
     b.complete(),
   )
@@ -1,10 +1,14 @@
-use napi::{ContextlessResult, Env, JsObject, JsString, JsUnknown, Result};
+use napi::{
+  bindgen_prelude::ToNapiValue, ContextlessResult, Env, JsObject, JsString, JsUnknown, NapiValue,
+  Result,
+};
 use serde_json::to_string;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("createArrayJson", create_array_json)?;
   exports.create_named_method("createArray", create_array)?;
   exports.create_named_method("createArrayWithSerdeTrait", create_array_with_serde_trait)?;
+  exports.create_named_method("createObjectArray", create_object_array)?;
   Ok(())
 }
 
@@ -30,3 +34,29 @@ pub fn create_array_with_serde_trait(env: Env) -> ContextlessResult<JsUnknown> {
   let a: Vec<u32> = vec![42; 1000];
   env.to_js_value(&a).map(Some)
 }
+
+#[napi(object)]
+pub struct Point {
+  pub x: u32,
+  pub y: f64,
+  pub label: String,
+  pub visible: bool,
+  pub weight: Option<u32>,
+}
+
+#[contextless_function]
+pub fn create_object_array(env: Env) -> ContextlessResult<JsUnknown> {
+  let points = (0..1000)
+    .map(|i| Point {
+      x: i,
+      y: i as f64 / 2.0,
+      label: "point".to_owned(),
+      visible: i % 2 == 0,
+      weight: Some(i),
+    })
+    .collect::<Vec<_>>();
+  let value = unsafe { Vec::<Point>::to_napi_value(env.raw(), points)? };
+  Ok(Some(unsafe {
+    JsUnknown::from_raw_unchecked(env.raw(), value)
+  }))
+}
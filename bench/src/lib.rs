@@ -23,6 +23,7 @@ mod get_value_from_js;
 mod noop;
 mod plus;
 mod query;
+mod string;
 
 #[module_exports]
 fn init(mut exports: JsObject, env: Env) -> Result<()> {
@@ -35,6 +36,7 @@ fn init(mut exports: JsObject, env: Env) -> Result<()> {
   create_array::register_js(&mut exports)?;
   get_value_from_js::register_js(&mut exports)?;
   query::register_js(&mut exports)?;
+  string::register_js(&mut exports)?;
 
   Ok(())
 }
@@ -0,0 +1,26 @@
+use napi::{CallContext, JsObject, JsString, JsUndefined, Result};
+
+pub fn register_js(exports: &mut JsObject) -> Result<()> {
+  exports.create_named_method("getString", get_string)?;
+  exports.create_named_method("getStringUtf8", get_string_utf8)?;
+  exports.create_named_method("getAsciiString", get_ascii_string)?;
+  Ok(())
+}
+
+#[js_function(1)]
+fn get_string(ctx: CallContext) -> Result<JsUndefined> {
+  let _: String = ctx.get(0)?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn get_string_utf8(ctx: CallContext) -> Result<JsUndefined> {
+  let _ = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+  ctx.env.get_undefined()
+}
+
+#[js_function(1)]
+fn get_ascii_string(ctx: CallContext) -> Result<JsUndefined> {
+  let _ = ctx.get::<JsString>(0)?.into_ascii_string()?;
+  ctx.env.get_undefined()
+}
@@ -0,0 +1,39 @@
+import b from 'benny'
+
+const { getString, getStringUtf8, getAsciiString } = require('./index.node')
+
+// `String` reads up to 251 UTF-8 bytes with a single call into a 256 bytes stack buffer,
+// a longer string like `LONG` is read again once its UTF-8 length is known, so it pays two extra calls
+// `JsString::into_ascii_string` takes the UTF-16 length, which doesn't scan the string, then copies it
+// Node.js 20, x64, release build, ns/op:
+//   String#short 66, JsString::into_utf8#short 125, JsString::into_ascii_string#short 70
+//   String#long 590, JsString::into_utf8#long 560, JsString::into_ascii_string#long 240
+const SHORT = 'identifier'
+const LONG = 'const value = 42;\n'.repeat(100)
+
+export const benchString = () =>
+  b.suite(
+    'String from JavaScript',
+    b.add('String#short', () => {
+      getString(SHORT)
+    }),
+    b.add('JsString::into_utf8#short', () => {
+      getStringUtf8(SHORT)
+    }),
+    b.add('JsString::into_ascii_string#short', () => {
+      getAsciiString(SHORT)
+    }),
+
+    b.add('String#long', () => {
+      getString(LONG)
+    }),
+    b.add('JsString::into_utf8#long', () => {
+      getStringUtf8(LONG)
+    }),
+    b.add('JsString::into_ascii_string#long', () => {
+      getAsciiString(LONG)
+    }),
+
+    b.cycle(),
+    b.complete(),
+  )
@@ -192,12 +192,54 @@ const BUILD_OPTIONS: CommandSchema = {
         'Whether to disable the generation JS binding file. Only works with `--platform` flag.',
       long: 'no-js',
     },
+    {
+      name: 'esm',
+      type: 'boolean',
+      description:
+        'Whether to generate an ES module loader and package.json `exports` as well. Only works with `--platform` flag.',
+    },
+    {
+      name: 'jsDoc',
+      type: 'boolean',
+      description:
+        'Whether to annotate the exports of the generated JS binding file with JSDoc from the type defs. Only works with `--platform` flag.',
+    },
     {
       name: 'dts',
       type: 'string',
       description:
         'Path and filename of generated type def file. Relative to `--output-dir`',
     },
+    {
+      name: 'dtsDir',
+      type: 'string',
+      description:
+        'Write the type defs of each namespace into its own file in the directory, re-exported by the type def file. Relative to `--output-dir`',
+    },
+    {
+      name: 'dtsSort',
+      type: 'string',
+      description:
+        'How to order the type defs, `name` puts the classes first and sorts the rest by name, `source` keeps the declaration order and `kind` sorts by kind then name',
+    },
+    {
+      name: 'dtsJsonValue',
+      type: 'string',
+      description:
+        'The TypeScript type of `serde_json::Value` in the type defs, `any` or `unknown`, defaults to `any`',
+    },
+    {
+      name: 'dtsCheck',
+      type: 'boolean',
+      description:
+        'Check the type def files are up to date without writing any file, fails if they differ from the generated ones or `dtsDir` has files of removed namespaces',
+    },
+    {
+      name: 'flow',
+      type: 'boolean',
+      description:
+        'Generate a Flow type def file next to the JS binding, like `index.js.flow`, from the same type defs',
+    },
     {
       name: 'dtsHeader',
       type: 'string',
@@ -210,12 +252,24 @@ const BUILD_OPTIONS: CommandSchema = {
       description:
         'Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.',
     },
+    {
+      name: 'noDtsExternalObject',
+      type: 'boolean',
+      description:
+        'Whether to skip the `ExternalObject<T>` helper class in the generated type def file, eg: when it\'s declared by the dts header',
+    },
     {
       name: 'strip',
       type: 'boolean',
       description: 'Whether strip the library to achieve the minimum file size',
       short: ['s'],
     },
+    {
+      name: 'splitDebugInfo',
+      type: 'boolean',
+      description:
+        'Move the debug info into a separate file next to the binary, `.debug` by objcopy, `.dSYM` by dsymutil or the `.pdb` on Windows, and strip it from the binary',
+    },
     {
       name: 'release',
       type: 'boolean',
@@ -249,6 +303,7 @@ const BUILD_OPTIONS: CommandSchema = {
       type: 'boolean',
       description:
         '[experimental] cross-compile for the specified target with `cargo-xwin` on windows and `cargo-zigbuild` on other platform',
+      alias: ['zig'],
       short: ['x'],
     },
     {
@@ -270,6 +325,18 @@ const BUILD_OPTIONS: CommandSchema = {
         'watch the crate changes and build continuously with `cargo-watch` crates',
       short: ['w'],
     },
+    {
+      name: 'afterBuild',
+      type: 'string',
+      description:
+        'Shell command to run after every successful build, eg: `yarn test` to rerun the tests in watch mode',
+    },
+    {
+      name: 'profileSize',
+      type: 'boolean',
+      description:
+        'Print the size of the built binary, its largest symbols and whether the debug info is stripped',
+    },
     {
       name: 'features',
       type: 'string[]',
@@ -507,6 +574,12 @@ const VERSION_OPTIONS: CommandSchema = {
       description: 'Path to the folder where the npm packages put',
       default: "'npm'",
     },
+    {
+      name: 'manifestPath',
+      type: 'string',
+      description:
+        'Path to `Cargo.toml`, the version of the crate is synced as well if provided',
+    },
   ],
 }
 
@@ -1,3 +1,4 @@
+import { createHash } from 'node:crypto'
 import { join, parse } from 'node:path'
 
 import * as colors from 'colorette'
@@ -21,7 +22,7 @@ export async function collectArtifacts(userOptions: ArtifactsOptions) {
   const options = applyDefaultArtifactsOptions(userOptions)
 
   const packageJsonPath = join(options.cwd, options.packageJsonPath)
-  const { targets, binaryName, packageName } =
+  const { targets, binaryName, packageName, downloadFallback } =
     await readNapiConfig(packageJsonPath)
 
   const distDirs = targets.map((platform) =>
@@ -37,6 +38,9 @@ export async function collectArtifacts(userOptions: ArtifactsOptions) {
       .filter(Boolean) as string[],
   )
 
+  // `napi.downloadFallback`, the js binding verifies the downloaded binaries against them
+  const checksums: Record<string, string> = {}
+
   await collectNodeBinaries(join(options.cwd, options.outputDir)).then(
     (output) =>
       Promise.all(
@@ -70,6 +74,11 @@ export async function collectArtifacts(userOptions: ArtifactsOptions) {
             `Write file content to [${colors.yellowBright(distFilePath)}]`,
           )
           await writeFileAsync(distFilePath, sourceContent)
+          if (downloadFallback) {
+            checksums[platformArchABI] = createHash('sha256')
+              .update(sourceContent)
+              .digest('hex')
+          }
           const distFilePathLocal = join(
             parse(packageJsonPath).dir,
             parsedName.base,
@@ -82,6 +91,10 @@ export async function collectArtifacts(userOptions: ArtifactsOptions) {
       ),
   )
 
+  if (downloadFallback) {
+    await writeChecksums(packageJsonPath, checksums)
+  }
+
   const wasiTarget = targets.find((t) => t.platform === 'wasi')
   if (wasiTarget) {
     const wasiDir = join(
@@ -148,6 +161,25 @@ export async function collectArtifacts(userOptions: ArtifactsOptions) {
   }
 }
 
+// the checksums of the binaries not collected this time, e.g. built on another machine, are kept
+async function writeChecksums(
+  packageJsonPath: string,
+  checksums: Record<string, string>,
+) {
+  const packageJson = JSON.parse(await readFileAsync(packageJsonPath, 'utf8'))
+  packageJson.napiChecksums = {
+    ...packageJson.napiChecksums,
+    ...checksums,
+  }
+  debug.info(
+    `Write checksums of [${Object.keys(checksums).join(', ')}] to [${colors.yellowBright(packageJsonPath)}]`,
+  )
+  await writeFileAsync(
+    packageJsonPath,
+    JSON.stringify(packageJson, null, 2) + '\n',
+  )
+}
+
 async function collectNodeBinaries(root: string) {
   const files = await readdirAsync(root, { withFileTypes: true })
   const nodeBinaries = files
@@ -1,15 +1,16 @@
-import { spawn } from 'node:child_process'
+import { spawn, spawnSync } from 'node:child_process'
 import { createHash } from 'node:crypto'
-import { existsSync, mkdirSync } from 'node:fs'
+import { copyFileSync, existsSync, mkdirSync, readdirSync } from 'node:fs'
 import { createRequire } from 'node:module'
 import { tmpdir, homedir } from 'node:os'
-import { parse, join, resolve } from 'node:path'
+import { delimiter, parse, join, relative, resolve } from 'node:path'
 
 import * as colors from 'colorette'
 import { include as setjmpInclude, lib as setjmpLib } from 'wasm-sjlj'
 
 import { BuildOptions as RawBuildOptions } from '../def/build.js'
 import {
+  checkTypeDefFiles,
   CLI_VERSION,
   copyFileAsync,
   Crate,
@@ -22,17 +23,26 @@ import {
   NapiConfig,
   parseMetadata,
   parseTriple,
+  processFlowTypeDef,
   processTypeDef,
   readFileAsync,
   readNapiConfig,
+  statAsync,
   Target,
   targetToEnvVar,
   tryInstallCargoBinary,
+  TYPE_DEF_SORTS,
+  TypeDefSort,
   unlinkAsync,
   writeFileAsync,
 } from '../utils/index.js'
 
-import { createCjsBinding } from './templates/index.js'
+import {
+  createCjsBinding,
+  createCjsBindingFromTemplate,
+  createEsmBinding,
+  DownloadFallback,
+} from './templates/index.js'
 import {
   createWasiBinding,
   createWasiBrowserBinding,
@@ -45,7 +55,7 @@ import {
 const debug = debugFactory('build')
 const require = createRequire(import.meta.url)
 
-type OutputKind = 'js' | 'dts' | 'node' | 'exe' | 'wasm'
+type OutputKind = 'js' | 'dts' | 'node' | 'exe' | 'wasm' | 'debug'
 type Output = {
   kind: OutputKind
   path: string
@@ -80,6 +90,24 @@ export async function buildProject(options: BuildOptions) {
     )
   }
 
+  if (
+    options.dtsSort &&
+    !(TYPE_DEF_SORTS as readonly string[]).includes(options.dtsSort)
+  ) {
+    throw new Error(
+      `Invalid \`--dts-sort\` value \`${options.dtsSort}\`, expected one of ${TYPE_DEF_SORTS.join(', ')}`,
+    )
+  }
+
+  if (
+    options.dtsJsonValue &&
+    !['any', 'unknown'].includes(options.dtsJsonValue)
+  ) {
+    throw new Error(
+      `Invalid \`--dts-json-value\` value \`${options.dtsJsonValue}\`, expected \`any\` or \`unknown\``,
+    )
+  }
+
   const crateDir = parse(pkg.manifest_path).dir
 
   const builder = new Builder(
@@ -329,7 +357,8 @@ class Builder {
         const output = data.toString()
         console.error(output)
         if (/Finished\s(dev|release)/.test(output)) {
-          this.postBuild().catch(() => {})
+          // keep watching when the post build steps fail, e.g. the tests run by `--after-build`
+          this.postBuild().catch((e) => debug.error(e as Error))
         }
       })
     })
@@ -454,6 +483,10 @@ class Builder {
   private setEnvs() {
     // type definition intermediate file
     this.envs.TYPE_DEF_TMP_PATH = this.getIntermediateTypeFile()
+    // read by `napi-derive` when it generates the type defs, tracked by `napi-build`
+    if (this.options.dtsJsonValue) {
+      this.envs.NAPI_JSON_VALUE_TS_TYPE = this.options.dtsJsonValue
+    }
     // WASI register intermediate file
     this.envs.WASI_REGISTER_TMP_PATH = this.getIntermediateWasiRegisterFile()
     // TODO:
@@ -496,34 +529,10 @@ class Builder {
     }
 
     if (this.target.platform === 'android') {
-      const { ANDROID_NDK_LATEST_HOME } = process.env
-      if (!ANDROID_NDK_LATEST_HOME) {
-        debug.warn(
-          `${colors.red(
-            'ANDROID_NDK_LATEST_HOME',
-          )} environment variable is missing`,
-        )
-      }
-
-      const targetArch = this.target.arch === 'arm' ? 'armv7a' : 'aarch64'
-      const targetPlatform =
-        this.target.arch === 'arm' ? 'androideabi24' : 'android24'
-      const hostPlatform =
-        process.platform === 'darwin'
-          ? 'darwin'
-          : process.platform === 'win32'
-            ? 'windows'
-            : 'linux'
-      Object.assign(this.envs, {
-        CARGO_TARGET_AARCH64_LINUX_ANDROID_LINKER: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/${targetArch}-linux-android24-clang`,
-        CARGO_TARGET_ARMV7_LINUX_ANDROIDEABI_LINKER: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/${targetArch}-linux-androideabi24-clang`,
-        TARGET_CC: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/${targetArch}-linux-${targetPlatform}-clang`,
-        TARGET_CXX: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/${targetArch}-linux-${targetPlatform}-clang++`,
-        TARGET_AR: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/llvm-ar`,
-        TARGET_RANLIB: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin/llvm-ranlib`,
-        ANDROID_NDK: ANDROID_NDK_LATEST_HOME,
-        PATH: `${ANDROID_NDK_LATEST_HOME}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin:${process.env.PATH}`,
-      })
+      this.setAndroidToolchain()
+    }
+    if (this.target.platform === 'ios') {
+      this.setIosToolchain()
     }
     // END LINKER
 
@@ -660,6 +669,14 @@ class Builder {
   }
 
   private async postBuild() {
+    // `--dts-check` only compares the type def files, the artifact and the bindings are left untouched
+    if (this.options.dtsCheck) {
+      if (this.cdyLibName) {
+        await this.generateTypeDef()
+      }
+      return this.outputs
+    }
+
     try {
       debug(`Try to create output directory:`)
       debug('  %i', this.outputDir)
@@ -672,10 +689,13 @@ class Builder {
     }
 
     const dest = await this.copyArtifact()
+    if (dest && this.options.profileSize) {
+      await this.reportSize(dest)
+    }
 
     // only for cdylib
     if (this.cdyLibName) {
-      const idents = await this.generateTypeDef()
+      const { exports: idents, jsDocs } = await this.generateTypeDef()
       const intermediateWasiRegisterFile = this.envs.WASI_REGISTER_TMP_PATH
       const wasiRegisterFunctions =
         this.target.arch === 'wasm32'
@@ -701,23 +721,56 @@ class Builder {
                 })
             })()
           : []
-      const jsOutput = await this.writeJsBinding(idents)
+      const jsOutput = await this.writeJsBinding(
+        idents,
+        this.options.jsDoc ? jsDocs : undefined,
+      )
       const wasmBindingsOutput = await this.writeWasiBinding(
         wasiRegisterFunctions,
         dest ?? 'index.wasm',
         idents,
       )
       if (jsOutput) {
-        this.outputs.push(jsOutput)
+        this.outputs.push(...jsOutput)
       }
       if (wasmBindingsOutput) {
         this.outputs.push(...wasmBindingsOutput)
       }
     }
 
+    if (this.options.afterBuild) {
+      await this.runAfterBuild(this.options.afterBuild)
+    }
+
     return this.outputs
   }
 
+  private runAfterBuild(command: string) {
+    debug('Run after build command:')
+    debug('  %i', command)
+    return new Promise<void>((resolve, reject) => {
+      const afterBuildProcess = spawn(command, {
+        shell: true,
+        stdio: 'inherit',
+        cwd: this.cwd,
+      })
+      afterBuildProcess.once('exit', (code) => {
+        if (code === 0) {
+          resolve()
+        } else {
+          reject(new Error(`After build command exited with code ${code}`))
+        }
+      })
+      afterBuildProcess.once('error', (e) => {
+        reject(
+          new Error(`After build command failed with error: ${e.message}`, {
+            cause: e,
+          }),
+        )
+      })
+    })
+  }
+
   private async copyArtifact() {
     const [srcName, destName] = this.getArtifactNames()
     if (!srcName || !destName) {
@@ -778,6 +831,10 @@ class Builder {
         kind: dest.endsWith('.node') ? 'node' : isWasm ? 'wasm' : 'exe',
         path: dest,
       })
+      // the wasm debug info is split into `.debug.wasm` already
+      if (this.options.splitDebugInfo && !isWasm) {
+        this.splitDebugInfo(src, dest)
+      }
       return dest
     } catch (e) {
       throw new Error('Failed to copy artifact', {
@@ -786,6 +843,110 @@ class Builder {
     }
   }
 
+  // `--split-debug-info`, a missing tool only fails the split, not the build
+  private splitDebugInfo(src: string, dest: string) {
+    const run = (commands: string[], args: string[]) => {
+      for (const command of commands) {
+        debug('  %i', `${command} ${args.join(' ')}`)
+        const { status, error } = spawnSync(command, args, {
+          stdio: 'inherit',
+        })
+        if (!error) {
+          return status === 0
+        }
+      }
+      return false
+    }
+
+    debug('Split debug info of:')
+    debug('  %i', dest)
+    let debugFile: string
+    if (this.target.platform === 'win32') {
+      // the `.pdb` is written by the linker next to the `.dll`
+      debugFile = dest.replace(/\.(node|exe)$/, '.pdb')
+      const pdb = src.replace(/\.(dll|exe)$/, '.pdb')
+      if (!existsSync(pdb)) {
+        debug.warn(`No debug info found at ${pdb}, skip`)
+        return
+      }
+      copyFileSync(pdb, debugFile)
+    } else if (this.target.platform === 'darwin') {
+      debugFile = `${dest}.dSYM`
+      if (!run(['dsymutil'], [dest, '-o', debugFile])) {
+        debug.warn('Failed to split debug info with `dsymutil`, skip')
+        return
+      }
+      run(['strip'], ['-S', dest])
+    } else {
+      // `llvm-objcopy` handles the binaries of every target
+      debugFile = `${dest}.debug`
+      const objcopy = ['llvm-objcopy', 'objcopy']
+      if (!run(objcopy, ['--only-keep-debug', dest, debugFile])) {
+        debug.warn('Failed to split debug info with `objcopy`, skip')
+        return
+      }
+      run(objcopy, [
+        '--strip-debug',
+        `--add-gnu-debuglink=${debugFile}`,
+        dest,
+      ])
+    }
+    this.outputs.push({
+      kind: 'debug',
+      path: debugFile,
+    })
+  }
+
+  private async reportSize(path: string) {
+    const { size } = await statAsync(path)
+    const content = await readFileAsync(path)
+    // the section names of the ELF, PE and wasm files, and of the Mach-O files
+    const hasDebugInfo =
+      content.includes('.debug_info') || content.includes('__debug_info')
+    debug.info(
+      `${colors.yellowBright(path)}: ${formatSize(size)}, ${
+        hasDebugInfo
+          ? 'with debug info, remove it with `--strip` or `strip = true` in the cargo profile'
+          : 'without debug info'
+      }`,
+    )
+
+    // `llvm-nm` reads the binaries of every target, `nm` only the host ones
+    for (const nm of ['llvm-nm', 'nm']) {
+      const { stdout, status, error } = spawnSync(
+        nm,
+        ['--print-size', '--size-sort', '--radix=d', '--demangle', path],
+        { encoding: 'utf-8' },
+      )
+      if (error || status !== 0) {
+        continue
+      }
+      // `address size type name`, from the smallest to the largest
+      const symbols = stdout
+        .split('\n')
+        .map((line) => line.trim().split(/\s+/))
+        .filter((fields) => fields.length >= 4)
+        .map(([, size, , ...name]) => ({
+          size: parseInt(size, 10),
+          name: name.join(' '),
+        }))
+        .reverse()
+        .slice(0, 10)
+      if (!symbols.length) {
+        debug.info('No symbols found, the binary is stripped')
+      } else {
+        debug.info('Largest symbols:')
+        for (const symbol of symbols) {
+          debug.info(
+            `  ${formatSize(symbol.size).padStart(10)}  ${symbol.name}`,
+          )
+        }
+      }
+      return
+    }
+    debug.warn('`nm` not found, skip the symbol sizes')
+  }
+
   private getArtifactNames() {
     if (this.cdyLibName) {
       const cdyLib = this.cdyLibName.replace(/-/g, '_')
@@ -825,12 +986,18 @@ class Builder {
 
   private async generateTypeDef() {
     if (!(await fileExists(this.envs.TYPE_DEF_TMP_PATH))) {
-      return []
+      return { exports: [], jsDocs: new Map<string, string>() }
     }
 
     const dest = join(this.outputDir, this.options.dts ?? 'index.d.ts')
-
-    const { dts, exports } = await processTypeDef(
+    const dtsDir = this.options.dtsDir
+      ? join(this.outputDir, this.options.dtsDir)
+      : undefined
+    const { dir: destDir, base: destName } = parse(dest)
+    // the namespace files use the same extension, `index.d.cts` is imported as `index.cjs`
+    const dtsExtension = /\.d\.([cm]?)ts$/.exec(destName)
+    const extension = dtsExtension?.[1] ? `.${dtsExtension[1]}js` : ''
+    const { dts, exports, jsDocs, namespaces } = await processTypeDef(
       this.envs.TYPE_DEF_TMP_PATH,
       this.options.constEnum ?? true,
       !this.options.noDtsHeader
@@ -849,8 +1016,56 @@ class Builder {
             this.config.dtsHeader ??
             DEFAULT_TYPE_DEF_HEADER
         : '',
+      !this.options.noDtsExternalObject,
+      dtsDir
+        ? {
+            dir: toSpecifier(relative(destDir, dtsDir)),
+            index: toSpecifier(
+              relative(
+                dtsDir,
+                join(destDir, destName.slice(0, dtsExtension?.index)),
+              ),
+            ),
+            extension,
+          }
+        : undefined,
+      this.options.dtsSort as TypeDefSort | undefined,
     )
 
+    // Flow looks for `index.js.flow` next to `index.js`
+    const flow = this.options.flow
+      ? {
+          path: join(this.outputDir, `${this.jsBindingName}.flow`),
+          dts: await processFlowTypeDef(
+            this.envs.TYPE_DEF_TMP_PATH,
+            this.options.noDtsHeader ? '' : DEFAULT_TYPE_DEF_HEADER,
+            this.options.dtsSort as TypeDefSort | undefined,
+          ),
+        }
+      : null
+
+    if (this.options.dtsCheck) {
+      await this.checkTypeDef(
+        [
+          { path: dest, dts },
+          ...(flow ? [flow] : []),
+          ...(dtsDir
+            ? namespaces.map(({ namespace, dts }) => ({
+                path: join(
+                  dtsDir,
+                  `${namespace}${dtsExtension?.[0] ?? '.d.ts'}`,
+                ),
+                dts,
+              }))
+            : []),
+        ],
+        dtsDir
+          ? { dir: dtsDir, extension: dtsExtension?.[0] ?? '.d.ts' }
+          : undefined,
+      )
+      return { exports, jsDocs }
+    }
+
     try {
       debug('Writing type def to:')
       debug('  %i', dest)
@@ -864,10 +1079,122 @@ class Builder {
       debug.error(e as Error)
     }
 
-    return exports
+    if (flow) {
+      try {
+        debug('  %i', flow.path)
+        await writeFileAsync(flow.path, flow.dts, 'utf-8')
+        this.outputs.push({
+          kind: 'dts',
+          path: flow.path,
+        })
+      } catch (e) {
+        debug.error('Failed to write flow type def file')
+        debug.error(e as Error)
+      }
+    }
+
+    if (dtsDir && namespaces.length) {
+      await mkdirAsync(dtsDir, { recursive: true })
+      for (const { namespace, dts } of namespaces) {
+        const namespaceDest = join(
+          dtsDir,
+          `${namespace}${dtsExtension?.[0] ?? '.d.ts'}`,
+        )
+        try {
+          debug('  %i', namespaceDest)
+          await writeFileAsync(namespaceDest, dts, 'utf-8')
+          this.outputs.push({
+            kind: 'dts',
+            path: namespaceDest,
+          })
+        } catch (e) {
+          debug.error(`Failed to write type def file of namespace ${namespace}`)
+          debug.error(e as Error)
+        }
+      }
+    }
+
+    return { exports, jsDocs }
+  }
+
+  // `--dts-check`, compare with the existing files without touching them
+  private async checkTypeDef(
+    files: { path: string; dts: string }[],
+    dtsDir?: { dir: string; extension: string },
+  ) {
+    const { outdated, stale } = await checkTypeDefFiles(files, dtsDir)
+    const errors: string[] = []
+    if (outdated.length) {
+      errors.push(
+        `Type def files are out of date, build without \`--dts-check\` to regenerate them:\n${outdated
+          .map((path) => `  ${path}`)
+          .join('\n')}`,
+      )
+    }
+    if (stale.length) {
+      errors.push(
+        `Type def files of removed namespaces, delete them:\n${stale
+          .map((path) => `  ${path}`)
+          .join('\n')}`,
+      )
+    }
+
+    if (errors.length) {
+      throw new Error(errors.join('\n'))
+    }
+    debug('Type def files are up to date')
+  }
+
+  private get jsBindingName() {
+    // with `"type": "module"` the `.js` extension means ES module, so the CommonJS binding must be `.cjs`
+    return (
+      this.options.jsBinding ?? (this.options.esm ? 'index.cjs' : 'index.js')
+    )
   }
 
-  private async writeJsBinding(idents: string[]) {
+  // `napi.downloadFallback`, `{platform}` is left to the js binding
+  private get downloadFallback(): DownloadFallback | undefined {
+    const { downloadFallback, binaryName, packageJson } = this.config
+    if (!downloadFallback) {
+      return
+    }
+    let url = downloadFallback
+    if (url === true) {
+      const repository: string | undefined =
+        typeof packageJson.repository === 'string'
+          ? packageJson.repository
+          : packageJson.repository?.url
+      // `https://github.com/owner/repo.git`, `git+ssh://git@github.com/owner/repo`, `github:owner/repo` or `owner/repo`
+      const [, owner, repo] =
+        /github\.com[/:]([^/]+)\/([^/#]+?)(?:\.git)?(?:#.*)?$/.exec(
+          repository ?? '',
+        ) ??
+        /^(?:github:)?([\w.-]+)\/([\w.-]+)$/.exec(repository ?? '') ??
+        []
+      if (!owner || !repo) {
+        throw new Error(
+          '`napi.downloadFallback` requires a GitHub `repository` in package.json, or the url of the binaries',
+        )
+      }
+      url = `https://github.com/${owner}/${repo}/releases/download/v{version}/{binaryName}.{platform}.node`
+    }
+    // the js binding reads the checksums recorded by `napi artifacts` from the published package.json
+    const packageJsonPath = resolve(
+      this.cwd,
+      this.options.packageJsonPath ?? 'package.json',
+    )
+    return {
+      url: url
+        .replaceAll('{version}', packageJson.version)
+        .replaceAll('{binaryName}', binaryName),
+      packageJson: toSpecifier(relative(this.outputDir, packageJsonPath)),
+    }
+  }
+
+  private async writeJsBinding(
+    idents: string[],
+    jsDocs?: Map<string, string>,
+  ) {
     if (
       !this.options.platform ||
       // eslint-disable-next-line @typescript-eslint/prefer-nullish-coalescing
@@ -877,26 +1204,105 @@ class Builder {
       return
     }
 
-    const name = this.options.jsBinding ?? 'index.js'
-
-    const cjs = createCjsBinding(
-      this.config.binaryName,
-      this.config.packageName,
-      idents,
-    )
+    const name = this.jsBindingName
+
+    const cjs = this.config.jsBindingTemplate
+      ? createCjsBindingFromTemplate(
+          await readFileAsync(
+            join(this.cwd, this.config.jsBindingTemplate),
+            'utf-8',
+          ).catch((e) => {
+            throw new Error(
+              `Failed to read js binding template ${this.config.jsBindingTemplate}`,
+              { cause: e },
+            )
+          }),
+          this.config.binaryName,
+          this.config.packageName,
+          idents,
+          jsDocs,
+          this.downloadFallback,
+        )
+      : createCjsBinding(
+          this.config.binaryName,
+          this.config.packageName,
+          idents,
+          jsDocs,
+          this.downloadFallback,
+        )
 
+    const outputs: Output[] = []
     try {
       const dest = join(this.outputDir, name)
       debug('Writing js binding to:')
       debug('  %i', dest)
       await writeFileAsync(dest, cjs, 'utf-8')
-      return {
+      outputs.push({
         kind: 'js',
         path: dest,
-      } satisfies Output
+      })
     } catch (e) {
       throw new Error('Failed to write js binding file', { cause: e })
     }
+
+    if (this.options.esm) {
+      const { dir, name: cjsName, base } = parse(name)
+      const esmName = join(dir, `${cjsName}.mjs`)
+      try {
+        const dest = join(this.outputDir, esmName)
+        debug('Writing esm binding to:')
+        debug('  %i', dest)
+        await writeFileAsync(
+          dest,
+          createEsmBinding(base, idents, jsDocs),
+          'utf-8',
+        )
+        outputs.push({
+          kind: 'js',
+          path: dest,
+        })
+      } catch (e) {
+        throw new Error('Failed to write esm binding file', { cause: e })
+      }
+      await this.writePackageJsonExports(name, esmName)
+    }
+
+    return outputs
+  }
+
+  // `exports` defined by the user is left as is
+  private async writePackageJsonExports(cjsName: string, esmName: string) {
+    const packageJsonPath = resolve(
+      this.cwd,
+      this.options.packageJsonPath ?? 'package.json',
+    )
+    if (!(await fileExists(packageJsonPath))) {
+      return
+    }
+    const packageJson = JSON.parse(
+      await readFileAsync(packageJsonPath, 'utf-8'),
+    )
+    if (packageJson.exports) {
+      debug('`exports` is already defined in %i, skip', packageJsonPath)
+      return
+    }
+    const packageDir = parse(packageJsonPath).dir
+    const toExportPath = (file: string) =>
+      `./${relative(packageDir, join(this.outputDir, file)).replaceAll('\\', '/')}`
+    packageJson.exports = {
+      '.': {
+        types: toExportPath(this.options.dts ?? 'index.d.ts'),
+        import: toExportPath(esmName),
+        require: toExportPath(cjsName),
+      },
+    }
+    debug('Writing `exports` to:')
+    debug('  %i', packageJsonPath)
+    await writeFileAsync(
+      packageJsonPath,
+      JSON.stringify(packageJson, null, 2) + '\n',
+      'utf-8',
+    )
   }
 
   private async writeWasiBinding(
@@ -986,9 +1392,110 @@ class Builder {
     return []
   }
 
+  private setAndroidToolchain() {
+    const { ANDROID_HOME, ANDROID_SDK_ROOT } = process.env
+    const sdk = ANDROID_HOME ?? ANDROID_SDK_ROOT
+    const ndk =
+      process.env.ANDROID_NDK_LATEST_HOME ??
+      process.env.ANDROID_NDK_HOME ??
+      process.env.ANDROID_NDK_ROOT ??
+      // the latest side by side NDK installed by the Android SDK manager
+      (sdk && existsSync(join(sdk, 'ndk'))
+        ? readdirSync(join(sdk, 'ndk'))
+            .sort((a, b) => a.localeCompare(b, 'en', { numeric: true }))
+            .map((version) => join(sdk, 'ndk', version))
+            .pop()
+        : undefined)
+    if (!ndk) {
+      debug.warn(
+        `${colors.red(
+          'ANDROID_NDK_LATEST_HOME',
+        )} environment variable is missing, and no NDK is found in ${colors.red(
+          'ANDROID_HOME',
+        )}`,
+      )
+    }
+
+    const clangArch: Record<string, string> = {
+      arm: 'armv7a',
+      arm64: 'aarch64',
+      x64: 'x86_64',
+      ia32: 'i686',
+    }
+    const targetArch = clangArch[this.target.arch] ?? this.target.arch
+    const apiLevel = process.env.ANDROID_API_LEVEL ?? '24'
+    const targetPlatform =
+      this.target.arch === 'arm'
+        ? `androideabi${apiLevel}`
+        : `android${apiLevel}`
+    const hostPlatform =
+      process.platform === 'darwin'
+        ? 'darwin'
+        : process.platform === 'win32'
+          ? 'windows'
+          : 'linux'
+    // the NDK only ships x86_64 host toolchains, they run on arm64 macOS too
+    const bin = `${ndk}/toolchains/llvm/prebuilt/${hostPlatform}-x86_64/bin`
+    // the clang wrappers are `.cmd` scripts on Windows
+    const clang = `${bin}/${targetArch}-linux-${targetPlatform}-clang`
+    const ext = process.platform === 'win32' ? '.cmd' : ''
+    Object.assign(this.envs, {
+      [`CARGO_TARGET_${targetToEnvVar(this.target.triple)}_LINKER`]: `${clang}${ext}`,
+      TARGET_CC: `${clang}${ext}`,
+      TARGET_CXX: `${clang}++${ext}`,
+      TARGET_AR: `${bin}/llvm-ar`,
+      TARGET_RANLIB: `${bin}/llvm-ranlib`,
+      ANDROID_NDK: ndk,
+      PATH: `${bin}${delimiter}${process.env.PATH}`,
+    })
+  }
+
+  private setIosToolchain() {
+    // the linker and the SDK are found by `xcrun`, only shipped with Xcode
+    if (process.platform !== 'darwin') {
+      debug.warn(
+        `${this.target.triple} can only be built on macOS with Xcode installed`,
+      )
+      return
+    }
+    if (process.env.SDKROOT) {
+      return
+    }
+    const sdk =
+      this.target.abi === 'sim' || this.target.arch === 'x64'
+        ? 'iphonesimulator'
+        : 'iphoneos'
+    const { stdout, status } = spawnSync(
+      'xcrun',
+      ['--sdk', sdk, '--show-sdk-path'],
+      { encoding: 'utf-8' },
+    )
+    if (status !== 0) {
+      debug.warn(`Failed to find the ${sdk} SDK, is Xcode installed?`)
+      return
+    }
+    this.envs.SDKROOT = stdout.trim()
+  }
+
   private setEnvIfNotExists(env: string, value: string) {
     if (!process.env[env]) {
       this.envs[env] = value
     }
   }
 }
+
+function formatSize(size: number) {
+  if (size < 1024) {
+    return `${size} B`
+  }
+  if (size < 1024 * 1024) {
+    return `${(size / 1024).toFixed(1)} KiB`
+  }
+  return `${(size / 1024 / 1024).toFixed(1)} MiB`
+}
+
+// a relative path usable by `require` and `import`
+function toSpecifier(path: string) {
+  const specifier = path.replaceAll('\\', '/')
+  return specifier.startsWith('.') ? specifier : `./${specifier}`
+}
@@ -23,6 +23,7 @@ import {
   createBuildRs,
   createCargoToml,
   createGithubActionsCIYml,
+  createIndexSpec,
   createLibRs,
   createPackageJson,
   gitIgnore,
@@ -69,8 +70,9 @@ function processOptions(options: RawNewOptions) {
     const out = execSync(`rustup target list`, {
       encoding: 'utf8',
     })
+    // the target is renamed to `wasm32-wasip1-threads` since Rust 1.78
     if (out.includes('wasm32-wasip1-threads')) {
-      options.targets.map((target) =>
+      options.targets = options.targets.map((target) =>
         target === 'wasm32-wasi-preview1-threads'
           ? 'wasm32-wasip1-threads'
           : target,
@@ -139,6 +141,7 @@ async function generateFiles(options: NewOptions): Promise<Output[]> {
     generateBuildRs,
     generateGithubWorkflow,
     generateIgnoreFiles,
+    generateTest,
   ]
     .flatMap((generator) => {
       const output = generator(options)
@@ -202,6 +205,17 @@ async function generatePackageJson(options: NewOptions): Promise<Output> {
   }
 }
 
+function generateTest(options: NewOptions): Output | null {
+  if (options.testFramework !== 'ava') {
+    return null
+  }
+
+  return {
+    target: './__test__/index.spec.mjs',
+    content: createIndexSpec(),
+  }
+}
+
 function generateGithubWorkflow(options: NewOptions): Output | null {
   if (!options.enableGithubActions) {
     return null
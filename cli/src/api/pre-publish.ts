@@ -138,7 +138,8 @@ export async function prePublish(userOptions: PrePublishOptions) {
 
           return deps
         },
-        {} as Record<string, string>,
+        // keep the optional dependencies which are not platform packages
+        { ...packageJson.optionalDependencies },
       ),
     })
   }
@@ -1,24 +1,51 @@
-import { resolve } from 'node:path'
+import { existsSync } from 'node:fs'
+import { join, parse, resolve } from 'node:path'
 
-import { isNil, merge, omitBy, pick } from 'lodash-es'
+import { escapeRegExp, isNil, merge, omitBy, pick } from 'lodash-es'
 
 import { applyDefaultRenameOptions, RenameOptions } from '../def/rename.js'
-import { readFileAsync, writeFileAsync } from '../utils/index.js'
+import {
+  debugFactory,
+  readFileAsync,
+  readNapiConfig,
+  unlinkAsync,
+  writeFileAsync,
+} from '../utils/index.js'
 
 import { createNpmDirs } from './create-npm-dirs.js'
 
+const debug = debugFactory('rename')
+
 export async function renameProject(userOptions: RenameOptions) {
   const options = applyDefaultRenameOptions(userOptions)
 
   const packageJsonPath = resolve(options.cwd, options.packageJsonPath)
   const cargoTomlPath = resolve(options.cwd, options.manifestPath)
+  const configPath = options.configPath
+    ? resolve(options.cwd, options.configPath)
+    : undefined
+
+  // the names before renaming, to update the references to them
+  const { binaryName: oldBinaryName, packageName: oldPackageName } =
+    await readNapiConfig(packageJsonPath, configPath)
+  const binaryName = options.binaryName ?? oldBinaryName
+  const packageName = options.packageName ?? oldPackageName
 
   const packageJsonContent = await readFileAsync(packageJsonPath, 'utf8')
   const packageJsonData = JSON.parse(packageJsonContent)
 
   merge(
     packageJsonData,
-    omitBy(pick(options, ['name', 'description', 'author', 'license']), isNil),
+    omitBy(
+      pick(options, [
+        'name',
+        'description',
+        'author',
+        'license',
+        'repository',
+      ]),
+      isNil,
+    ),
     {
       napi: omitBy(
         {
@@ -30,12 +57,33 @@ export async function renameProject(userOptions: RenameOptions) {
     },
   )
 
-  if (options.configPath) {
-    const configPath = resolve(options.cwd, options.configPath)
+  // the platform packages are published with the new package name
+  if (packageName !== oldPackageName && packageJsonData.optionalDependencies) {
+    packageJsonData.optionalDependencies = Object.fromEntries(
+      Object.entries(packageJsonData.optionalDependencies).map(
+        ([name, version]) => [
+          name.startsWith(`${oldPackageName}-`)
+            ? `${packageName}${name.slice(oldPackageName.length)}`
+            : name,
+          version,
+        ],
+      ),
+    )
+  }
+
+  if (configPath) {
     const configContent = await readFileAsync(configPath, 'utf8')
     const configData = JSON.parse(configContent)
-    configData.binaryName = options.binaryName
-    configData.packageName = options.packageName
+    merge(
+      configData,
+      omitBy(
+        {
+          binaryName: options.binaryName,
+          packageName: options.packageName,
+        },
+        isNil,
+      ),
+    )
     await writeFileAsync(configPath, JSON.stringify(configData, null, 2))
   }
 
@@ -44,12 +92,20 @@ export async function renameProject(userOptions: RenameOptions) {
     JSON.stringify(packageJsonData, null, 2),
   )
 
-  let tomlContent = await readFileAsync(cargoTomlPath, 'utf8')
-  tomlContent = tomlContent.replace(
-    /name\s?=\s?"([\w+])"/,
-    `name = "${options.binaryName}"`,
+  if (options.binaryName) {
+    let tomlContent = await readFileAsync(cargoTomlPath, 'utf8')
+    tomlContent = tomlContent.replace(
+      /name\s?=\s?"([\w-]+)"/,
+      `name = "${options.binaryName}"`,
+    )
+    await writeFileAsync(cargoTomlPath, tomlContent)
+  }
+
+  await renameBindingReferences(
+    parse(packageJsonPath).dir,
+    { binaryName: oldBinaryName, packageName: oldPackageName },
+    { binaryName, packageName },
   )
-  await writeFileAsync(cargoTomlPath, tomlContent)
 
   await createNpmDirs({
     cwd: options.cwd,
@@ -58,3 +114,51 @@ export async function renameProject(userOptions: RenameOptions) {
     dryRun: false,
   })
 }
+
+interface BindingNames {
+  binaryName: string
+  packageName: string
+}
+
+// the generated js bindings `require` the binaries and the platform packages by name,
+// update them so the project works before it's built again
+async function renameBindingReferences(
+  dir: string,
+  from: BindingNames,
+  to: BindingNames,
+) {
+  if (
+    from.binaryName === to.binaryName &&
+    from.packageName === to.packageName
+  ) {
+    return
+  }
+
+  const wasiFiles = [
+    `${from.binaryName}.wasi.cjs`,
+    `${from.binaryName}.wasi-browser.js`,
+  ]
+  // `./index.linux-x64-gnu.node`, `./index.wasm32-wasi.wasm` and `./index.wasi.cjs`, not `./index.cjs`
+  const binaryReference = new RegExp(
+    `\\./${escapeRegExp(from.binaryName)}\\.(?=[\\w-]+\\.(?:node|wasm)\\b|wasi)`,
+    'g',
+  )
+  const jsFiles = ['index.js', 'index.cjs', 'index.mjs', 'browser.js']
+  for (const file of [...jsFiles, ...wasiFiles]) {
+    const path = join(dir, file)
+    if (!existsSync(path)) {
+      continue
+    }
+    const content = (await readFileAsync(path, 'utf8'))
+      .replace(binaryReference, `./${to.binaryName}.`)
+      .replaceAll(`'${from.packageName}-`, `'${to.packageName}-`)
+    const dest = wasiFiles.includes(file)
+      ? join(dir, `${to.binaryName}${file.slice(from.binaryName.length)}`)
+      : path
+    debug('Update references of the binding in %i', dest)
+    await writeFileAsync(dest, content)
+    if (dest !== path) {
+      await unlinkAsync(path)
+    }
+  }
+}
@@ -0,0 +1,69 @@
+import test from 'ava'
+
+import {
+  createCjsBinding,
+  createCjsBindingFromTemplate,
+} from '../js-binding.js'
+
+const HEADER = `// prettier-ignore
+/* eslint-disable */
+/* auto-generated by NAPI-RS */
+
+`
+
+test('should fill the tokens of the js binding template', (t) => {
+  const jsDocs = new Map([['sum', '/** Sum the numbers */']])
+  const downloadFallback = {
+    url: 'https://example.com/v1.0.0/pkg.{platform}.node',
+    packageJson: './package.json',
+  }
+  t.is(
+    createCjsBindingFromTemplate(
+      `${HEADER}{{ loader }}\n{{exports}}\n`,
+      'pkg',
+      '@scope/pkg',
+      ['sum', 'Animal'],
+      jsDocs,
+      downloadFallback,
+    ),
+    createCjsBinding(
+      'pkg',
+      '@scope/pkg',
+      ['sum', 'Animal'],
+      jsDocs,
+      downloadFallback,
+    ),
+  )
+
+  const binding = createCjsBindingFromTemplate(
+    `{{ exports }}\nmodule.exports.binaryName = '{{ binaryName }}'\nmodule.exports.packageName = '{{ packageName }}'\n`,
+    'pkg',
+    '@scope/pkg',
+    ['sum'],
+    jsDocs,
+  )
+  t.is(
+    binding,
+    `/** Sum the numbers */
+module.exports.sum = nativeBinding.sum
+module.exports.binaryName = 'pkg'
+module.exports.packageName = '@scope/pkg'
+`,
+  )
+})
+
+test('should throw on unknown tokens in the js binding template', (t) => {
+  t.throws(
+    () =>
+      createCjsBindingFromTemplate(
+        '{{ loader }}\n{{ platform }}\n',
+        'pkg',
+        '@scope/pkg',
+        [],
+      ),
+    {
+      message:
+        'Unknown token {{ platform }} in the js binding template, expected one of {{ binaryName }}, {{ packageName }}, {{ loader }}, {{ exports }}',
+    },
+  )
+})
@@ -0,0 +1,8 @@
+export const createIndexSpec = () => `import test from 'ava'
+
+import { sum } from '../index.js'
+
+test('sum from native', (t) => {
+  t.is(sum(1, 2), 3)
+})
+`
@@ -3,6 +3,7 @@ export * from './.npmignore.js'
 export * from './build.rs.js'
 export * from './cargo.toml.js'
 export * from './ci.yml.js'
+export * from './index.spec.mjs.js'
 export * from './lib.rs.js'
 export * from './package.json.js'
 export * from './js-binding.js'
@@ -1,10 +1,81 @@
+// prepend the JSDoc of the export, if any
+function withJsDoc(
+  code: string,
+  ident: string,
+  jsDocs?: Map<string, string>,
+): string {
+  const jsDoc = jsDocs?.get(ident)
+  return jsDoc ? `${jsDoc}\n${code}` : code
+}
+
+// `napi.downloadFallback`
+export interface DownloadFallback {
+  // the url of the binaries, with the `{platform}` placeholder
+  url: string
+  // the path of the package.json relative to the js binding, with the checksums recorded by `napi artifacts`
+  packageJson: string
+}
+
 export function createCjsBinding(
   localName: string,
   pkgName: string,
   idents: string[],
+  jsDocs?: Map<string, string>,
+  downloadFallback?: DownloadFallback,
+): string {
+  return `// prettier-ignore
+/* eslint-disable */
+/* auto-generated by NAPI-RS */
+
+${createCjsLoader(localName, pkgName, downloadFallback)}
+${createCjsExports(idents, jsDocs)}
+`
+}
+
+// the tokens of the `jsBindingTemplate` file, like `{{ loader }}`
+const JS_BINDING_TEMPLATE_TOKENS = [
+  'binaryName',
+  'packageName',
+  'loader',
+  'exports',
+] as const
+
+// `napi.jsBindingTemplate`, the generated parts are inserted in place of the tokens
+export function createCjsBindingFromTemplate(
+  template: string,
+  localName: string,
+  pkgName: string,
+  idents: string[],
+  jsDocs?: Map<string, string>,
+  downloadFallback?: DownloadFallback,
+): string {
+  const parts: Record<(typeof JS_BINDING_TEMPLATE_TOKENS)[number], string> = {
+    binaryName: localName,
+    packageName: pkgName,
+    loader: createCjsLoader(localName, pkgName, downloadFallback),
+    exports: createCjsExports(idents, jsDocs),
+  }
+  return template.replaceAll(/\{\{\s*(\w+)\s*\}\}/g, (token, name) => {
+    if (!(JS_BINDING_TEMPLATE_TOKENS as readonly string[]).includes(name)) {
+      throw new Error(
+        `Unknown token ${token} in the js binding template, expected one of ${JS_BINDING_TEMPLATE_TOKENS.map(
+          (name) => `{{ ${name} }}`,
+        ).join(', ')}`,
+      )
+    }
+    return parts[name as keyof typeof parts]
+  })
+}
+
+// assigns the loaded addon to `nativeBinding`, or throws
+function createCjsLoader(
+  localName: string,
+  pkgName: string,
+  downloadFallback?: DownloadFallback,
 ): string {
   function requireTuple(tuple: string) {
-    return `try {
+    return `${downloadFallback ? `nativeTuple = '${tuple}'
+      ` : ''}try {
         return require('./${localName}.${tuple}.node')
       } catch (e) {
         loadErrors.push(e)
@@ -17,15 +88,17 @@ export function createCjsBinding(
 `
   }
 
-  return `// prettier-ignore
-/* eslint-disable */
-/* auto-generated by NAPI-RS */
-
-const { readFileSync } = require('fs')
+  return `const { readFileSync } = require('fs')
 
 let nativeBinding = null
 const loadErrors = []
-
+${
+  downloadFallback
+    ? `// the platform of the binary to download if no platform package is installed
+let nativeTuple = null
+`
+    : ''
+}
 const isMusl = () => {
   let musl = false
   if (process.platform === 'linux') {
@@ -84,6 +157,15 @@ function requireNative() {
     } else {
       loadErrors.push(new Error(\`Unsupported architecture on Android \${process.arch}\`))
     }
+  } else if (process.platform === 'ios') {
+    if (process.arch === 'arm64') {
+      ${requireTuple('ios-arm64')}
+      ${requireTuple('ios-arm64-sim')}
+    } else if (process.arch === 'x64') {
+      ${requireTuple('ios-x64')}
+    } else {
+      loadErrors.push(new Error(\`Unsupported architecture on iOS: \${process.arch}\`))
+    }
   } else if (process.platform === 'win32') {
     if (process.arch === 'x64') {
       ${requireTuple('win32-x64-msvc')}
@@ -149,7 +231,7 @@ function requireNative() {
 }
 
 nativeBinding = requireNative()
-
+${downloadFallback ? createDownloadFallback(localName, downloadFallback) : ''}
 if (!nativeBinding || process.env.NAPI_RS_FORCE_WASI) {
   try {
     nativeBinding = require('./${localName}.wasi.cjs')
@@ -179,9 +261,112 @@ if (!nativeBinding) {
   }
   throw new Error(\`Failed to load native binding\`)
 }
+`
+}
+
+// `napi.downloadFallback`, e.g. for the users installing with `--no-optional`
+// the binary is checked against the sha256 checksum recorded in `napiChecksums` of the published package.json by `napi artifacts`,
+// not against one from the server of the binary, and saved next to the js binding, so it's only downloaded once
+function createDownloadFallback(
+  localName: string,
+  { url, packageJson }: DownloadFallback,
+) {
+  return `
+// runs in a child process, \`require\` can't wait for the download
+const downloadNative = (url, dest, expected) => {
+  const { createHash } = require('crypto')
+  const { renameSync, writeFileSync } = require('fs')
+  const https = require('https')
+  const get = (url) =>
+    new Promise((resolve, reject) => {
+      https
+        .get(url, (res) => {
+          if (res.statusCode >= 300 && res.statusCode < 400 && res.headers.location) {
+            res.resume()
+            resolve(get(new URL(res.headers.location, url).toString()))
+          } else if (res.statusCode !== 200) {
+            res.resume()
+            reject(new Error('Failed to download ' + url + ', status code ' + res.statusCode))
+          } else {
+            const chunks = []
+            res.on('data', (chunk) => chunks.push(chunk))
+            res.on('end', () => resolve(Buffer.concat(chunks)))
+          }
+        })
+        .on('error', reject)
+    })
+  get(url)
+    .then((binary) => {
+      const actual = createHash('sha256').update(binary).digest('hex')
+      if (actual !== expected) {
+        throw new Error('Checksum of ' + url + ' is ' + actual + ', expected ' + expected)
+      }
+      writeFileSync(dest + '.download', binary)
+      renameSync(dest + '.download', dest)
+    })
+    .catch((e) => {
+      console.error(e.message)
+      process.exit(1)
+    })
+}
+
+if (!nativeBinding && nativeTuple && !process.env.NAPI_RS_FORCE_WASI) {
+  try {
+    const { napiChecksums } = require(${JSON.stringify(packageJson)})
+    const checksum = napiChecksums && napiChecksums[nativeTuple]
+    if (!checksum) {
+      throw new Error('No checksum of ' + nativeTuple + ' in the package.json, run \`napi artifacts\` before publishing')
+    }
+    const dest = require('path').join(__dirname, '${localName}.' + nativeTuple + '.node')
+    require('child_process').execFileSync(
+      process.execPath,
+      ['-e', '(' + downloadNative.toString() + ')(process.argv[1], process.argv[2], process.argv[3])', ${JSON.stringify(url)}.split('{platform}').join(nativeTuple), dest, checksum],
+      { stdio: ['ignore', 'ignore', 'pipe'] },
+    )
+    nativeBinding = require(dest)
+  } catch (e) {
+    loadErrors.push(e)
+  }
+}
+`
+}
+
+function createCjsExports(
+  idents: string[],
+  jsDocs?: Map<string, string>,
+): string {
+  return idents
+    .map((ident) =>
+      withJsDoc(
+        `module.exports.${ident} = nativeBinding.${ident}`,
+        ident,
+        jsDocs,
+      ),
+    )
+    .join('\n')
+}
+
+export function createEsmBinding(
+  cjsBinding: string,
+  idents: string[],
+  jsDocs?: Map<string, string>,
+): string {
+  return `// prettier-ignore
+/* eslint-disable */
+/* auto-generated by NAPI-RS */
+
+import { createRequire } from 'node:module'
+
+const require = createRequire(import.meta.url)
+
+// the native binding is loaded by the CommonJS binding, so both module systems share the same addon instance
+const nativeBinding = require('./${cjsBinding}')
 
+export default nativeBinding
 ${idents
-  .map((ident) => `module.exports.${ident} = nativeBinding.${ident}`)
+  .map((ident) =>
+    withJsDoc(`export const ${ident} = nativeBinding.${ident}`, ident, jsDocs),
+  )
   .join('\n')}
 `
 }
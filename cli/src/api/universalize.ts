@@ -16,9 +16,18 @@ const universalizers: Partial<
   Record<NodeJS.Platform, (inputs: string[], output: string) => void>
 > = {
   darwin: (inputs, output) => {
-    spawnSync('lipo', ['-create', '-output', output, ...inputs], {
-      stdio: 'inherit',
-    })
+    const { status, error } = spawnSync(
+      'lipo',
+      ['-create', '-output', output, ...inputs],
+      {
+        stdio: 'inherit',
+      },
+    )
+    if (error || status !== 0) {
+      throw new Error(`Failed to merge binaries into ${output} with lipo`, {
+        cause: error,
+      })
+    }
   },
 }
 
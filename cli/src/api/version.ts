@@ -4,7 +4,9 @@ import { applyDefaultVersionOptions, VersionOptions } from '../def/version.js'
 import {
   readNapiConfig,
   debugFactory,
+  readFileAsync,
   updatePackageJson,
+  writeFileAsync,
 } from '../utils/index.js'
 
 const debug = debugFactory('version')
@@ -17,13 +19,72 @@ export async function version(userOptions: VersionOptions) {
     packageJsonPath,
     options.configPath ? resolve(options.cwd, options.configPath) : undefined,
   )
+  const { version, optionalDependencies } = config.packageJson
 
   for (const target of config.targets) {
     const pkgDir = resolve(options.cwd, options.npmDir, target.platformArchABI)
 
-    debug(`Update version to %i in [%i]`, config.packageJson.version, pkgDir)
+    debug(`Update version to %i in [%i]`, version, pkgDir)
     await updatePackageJson(join(pkgDir, 'package.json'), {
-      version: config.packageJson.version,
+      version,
     })
   }
+
+  // the platform packages the main package depends on must be published with the same version
+  if (optionalDependencies) {
+    const platformPackages = config.targets.map(
+      (target) => `${config.packageName}-${target.platformArchABI}`,
+    )
+    const outdated = platformPackages.filter(
+      (name) =>
+        name in optionalDependencies && optionalDependencies[name] !== version,
+    )
+    if (outdated.length) {
+      debug(
+        `Update optionalDependencies to %i in [%i]`,
+        version,
+        packageJsonPath,
+      )
+      await updatePackageJson(packageJsonPath, {
+        optionalDependencies: {
+          ...optionalDependencies,
+          ...Object.fromEntries(outdated.map((name) => [name, version])),
+        },
+      })
+    }
+  }
+
+  if (options.manifestPath) {
+    await updateCargoVersion(
+      resolve(options.cwd, options.manifestPath),
+      version,
+    )
+  }
+}
+
+async function updateCargoVersion(manifestPath: string, version: string) {
+  const manifest = await readFileAsync(manifestPath, 'utf-8')
+  const versionLine = /^(\s*version\s*=\s*)"[^"]*"/
+  let section = ''
+  let updated = false
+  // only the `version` of the `[package]` section, not the versions of the dependencies
+  const lines = manifest.split('\n').map((line) => {
+    const sectionMatch = line.match(/^\s*\[([^\]]+)\]/)
+    if (sectionMatch) {
+      section = sectionMatch[1].trim()
+    } else if (section === 'package' && !updated && versionLine.test(line)) {
+      updated = true
+      return line.replace(versionLine, `$1"${version}"`)
+    }
+    return line
+  })
+
+  if (!updated) {
+    // e.g. `version.workspace = true`
+    debug.warn(`No version of the crate found in [%i], skip`, manifestPath)
+    return
+  }
+
+  debug(`Update version to %i in [%i]`, version, manifestPath)
+  await writeFileAsync(manifestPath, lines.join('\n'), 'utf-8')
 }
@@ -65,11 +65,46 @@ export abstract class BaseBuildCommand extends Command {
       'Whether to disable the generation JS binding file. Only works with `--platform` flag.',
   })
 
+  esm?: boolean = Option.Boolean('--esm', {
+    description:
+      'Whether to generate an ES module loader and package.json `exports` as well. Only works with `--platform` flag.',
+  })
+
+  jsDoc?: boolean = Option.Boolean('--js-doc', {
+    description:
+      'Whether to annotate the exports of the generated JS binding file with JSDoc from the type defs. Only works with `--platform` flag.',
+  })
+
   dts?: string = Option.String('--dts', {
     description:
       'Path and filename of generated type def file. Relative to `--output-dir`',
   })
 
+  dtsDir?: string = Option.String('--dts-dir', {
+    description:
+      'Write the type defs of each namespace into its own file in the directory, re-exported by the type def file. Relative to `--output-dir`',
+  })
+
+  dtsSort?: string = Option.String('--dts-sort', {
+    description:
+      'How to order the type defs, `name` puts the classes first and sorts the rest by name, `source` keeps the declaration order and `kind` sorts by kind then name',
+  })
+
+  dtsJsonValue?: string = Option.String('--dts-json-value', {
+    description:
+      'The TypeScript type of `serde_json::Value` in the type defs, `any` or `unknown`, defaults to `any`',
+  })
+
+  dtsCheck?: boolean = Option.Boolean('--dts-check', {
+    description:
+      'Check the type def files are up to date without writing any file, fails if they differ from the generated ones or `dtsDir` has files of removed namespaces',
+  })
+
+  flow?: boolean = Option.Boolean('--flow', {
+    description:
+      'Generate a Flow type def file next to the JS binding, like `index.js.flow`, from the same type defs',
+  })
+
   dtsHeader?: string = Option.String('--dts-header', {
     description:
       'Custom file header for generated type def file. Only works when `typedef` feature enabled.',
@@ -80,10 +115,23 @@ export abstract class BaseBuildCommand extends Command {
       'Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.',
   })
 
+  noDtsExternalObject?: boolean = Option.Boolean(
+    '--no-dts-external-object',
+    {
+      description:
+        "Whether to skip the `ExternalObject<T>` helper class in the generated type def file, eg: when it's declared by the dts header",
+    },
+  )
+
   strip?: boolean = Option.Boolean('--strip,-s', {
     description: 'Whether strip the library to achieve the minimum file size',
   })
 
+  splitDebugInfo?: boolean = Option.Boolean('--split-debug-info', {
+    description:
+      'Move the debug info into a separate file next to the binary, `.debug` by objcopy, `.dSYM` by dsymutil or the `.pdb` on Windows, and strip it from the binary',
+  })
+
   release?: boolean = Option.Boolean('--release,-r', {
     description: 'Build in release mode',
   })
@@ -104,7 +152,7 @@ export abstract class BaseBuildCommand extends Command {
     description: 'Build artifacts with the specified profile',
   })
 
-  crossCompile?: boolean = Option.Boolean('--cross-compile,-x', {
+  crossCompile?: boolean = Option.Boolean('--cross-compile,--zig,-x', {
     description:
       '[experimental] cross-compile for the specified target with `cargo-xwin` on windows and `cargo-zigbuild` on other platform',
   })
@@ -124,6 +172,16 @@ export abstract class BaseBuildCommand extends Command {
       'watch the crate changes and build continuously with `cargo-watch` crates',
   })
 
+  afterBuild?: string = Option.String('--after-build', {
+    description:
+      'Shell command to run after every successful build, eg: `yarn test` to rerun the tests in watch mode',
+  })
+
+  profileSize?: boolean = Option.Boolean('--profile-size', {
+    description:
+      'Print the size of the built binary, its largest symbols and whether the debug info is stripped',
+  })
+
   features?: string[] = Option.Array('--features,-F', {
     description: 'Space-separated list of features to activate',
   })
@@ -150,10 +208,19 @@ export abstract class BaseBuildCommand extends Command {
       constEnum: this.constEnum,
       jsBinding: this.jsBinding,
       noJsBinding: this.noJsBinding,
+      esm: this.esm,
+      jsDoc: this.jsDoc,
       dts: this.dts,
+      dtsDir: this.dtsDir,
+      dtsSort: this.dtsSort,
+      dtsJsonValue: this.dtsJsonValue,
+      dtsCheck: this.dtsCheck,
+      flow: this.flow,
       dtsHeader: this.dtsHeader,
       noDtsHeader: this.noDtsHeader,
+      noDtsExternalObject: this.noDtsExternalObject,
       strip: this.strip,
+      splitDebugInfo: this.splitDebugInfo,
       release: this.release,
       verbose: this.verbose,
       bin: this.bin,
@@ -163,6 +230,8 @@ export abstract class BaseBuildCommand extends Command {
       useCross: this.useCross,
       useNapiCross: this.useNapiCross,
       watch: this.watch,
+      afterBuild: this.afterBuild,
+      profileSize: this.profileSize,
       features: this.features,
       allFeatures: this.allFeatures,
       noDefaultFeatures: this.noDefaultFeatures,
@@ -222,10 +291,38 @@ export interface BuildOptions {
    * Whether to disable the generation JS binding file. Only works with `--platform` flag.
    */
   noJsBinding?: boolean
+  /**
+   * Whether to generate an ES module loader and package.json `exports` as well. Only works with `--platform` flag.
+   */
+  esm?: boolean
+  /**
+   * Whether to annotate the exports of the generated JS binding file with JSDoc from the type defs. Only works with `--platform` flag.
+   */
+  jsDoc?: boolean
   /**
    * Path and filename of generated type def file. Relative to `--output-dir`
    */
   dts?: string
+  /**
+   * Write the type defs of each namespace into its own file in the directory, re-exported by the type def file. Relative to `--output-dir`
+   */
+  dtsDir?: string
+  /**
+   * How to order the type defs, `name` puts the classes first and sorts the rest by name, `source` keeps the declaration order and `kind` sorts by kind then name
+   */
+  dtsSort?: string
+  /**
+   * The TypeScript type of `serde_json::Value` in the type defs, `any` or `unknown`, defaults to `any`
+   */
+  dtsJsonValue?: string
+  /**
+   * Check the type def files are up to date without writing any file, fails if they differ from the generated ones or `dtsDir` has files of removed namespaces
+   */
+  dtsCheck?: boolean
+  /**
+   * Generate a Flow type def file next to the JS binding, like `index.js.flow`, from the same type defs
+   */
+  flow?: boolean
   /**
    * Custom file header for generated type def file. Only works when `typedef` feature enabled.
    */
@@ -234,10 +331,18 @@ export interface BuildOptions {
    * Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.
    */
   noDtsHeader?: boolean
+  /**
+   * Whether to skip the `ExternalObject<T>` helper class in the generated type def file, eg: when it's declared by the dts header
+   */
+  noDtsExternalObject?: boolean
   /**
    * Whether strip the library to achieve the minimum file size
    */
   strip?: boolean
+  /**
+   * Move the debug info into a separate file next to the binary, `.debug` by objcopy, `.dSYM` by dsymutil or the `.pdb` on Windows, and strip it from the binary
+   */
+  splitDebugInfo?: boolean
   /**
    * Build in release mode
    */
@@ -274,6 +379,14 @@ export interface BuildOptions {
    * watch the crate changes and build continuously with `cargo-watch` crates
    */
   watch?: boolean
+  /**
+   * Shell command to run after every successful build, eg: `yarn test` to rerun the tests in watch mode
+   */
+  afterBuild?: string
+  /**
+   * Print the size of the built binary, its largest symbols and whether the debug info is stripped
+   */
+  profileSize?: boolean
   /**
    * Space-separated list of features to activate
    */
@@ -26,12 +26,18 @@ export abstract class BaseVersionCommand extends Command {
     description: 'Path to the folder where the npm packages put',
   })
 
+  manifestPath?: string = Option.String('--manifest-path', {
+    description:
+      'Path to `Cargo.toml`, the version of the crate is synced as well if provided',
+  })
+
   getOptions() {
     return {
       cwd: this.cwd,
       configPath: this.configPath,
       packageJsonPath: this.packageJsonPath,
       npmDir: this.npmDir,
+      manifestPath: this.manifestPath,
     }
   }
 }
@@ -62,6 +68,10 @@ export interface VersionOptions {
    * @default 'npm'
    */
   npmDir?: string
+  /**
+   * Path to `Cargo.toml`, the version of the crate is synced as well if provided
+   */
+  manifestPath?: string
 }
 
 export function applyDefaultVersionOptions(options: VersionOptions) {
@@ -1,9 +1,19 @@
+import { mkdirSync, mkdtempSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
 import { join } from 'path'
 import { fileURLToPath } from 'url'
 
 import test from 'ava'
 
-import { correctStringIdent, processTypeDef } from '../typegen.js'
+import {
+  checkTypeDefFiles,
+  correctStringIdent,
+  createJsDoc,
+  processFlowTypeDef,
+  processTypeDef,
+  TypeDefKind,
+  TypeDefSort,
+} from '../typegen.js'
 
 test('should ident string correctly', (t) => {
   const input = `
@@ -62,3 +72,282 @@ test('should process type def with noConstEnum correctly', async (t) => {
 
   t.snapshot(dts)
 })
+
+test('should process nested namespaces correctly', async (t) => {
+  const { dts, exports } = await processTypeDef(
+    join(
+      fileURLToPath(import.meta.url),
+      '../',
+      '__fixtures__',
+      'napi_type_def_nested_namespace',
+    ),
+    true,
+  )
+
+  t.is(
+    dts,
+    `export declare function version(): string
+
+export declare namespace fs {
+  export class Reader {
+    constructor()
+  }
+  export function readFile(path: string): Buffer
+  export namespace constants {
+    export const DEFAULT_MODE: number
+  }
+  export namespace promises {
+    export function watch(path: string): Promise<void>
+  }
+}
+
+`,
+  )
+  t.deepEqual(exports, ['version', 'fs'])
+})
+
+test('should skip the ExternalObject helper if asked', async (t) => {
+  const fixture = join(
+    fileURLToPath(import.meta.url),
+    '../',
+    '__fixtures__',
+    'napi_type_def',
+  )
+  const { dts: withHelper } = await processTypeDef(fixture, true)
+  t.true(withHelper.includes('export declare class ExternalObject<T>'))
+
+  const { dts: withoutHelper } = await processTypeDef(fixture, true, '', false)
+  t.false(withoutHelper.includes('class ExternalObject'))
+
+  const header = 'declare class ExternalObject<T> {\n  readonly value: T\n}\n'
+  const { dts: declaredInHeader } = await processTypeDef(fixture, true, header)
+  t.is(declaredInHeader.match(/class ExternalObject/g)?.length, 1)
+})
+
+test('should split namespaces into dts dir', async (t) => {
+  const { dts, namespaces } = await processTypeDef(
+    join(
+      fileURLToPath(import.meta.url),
+      '../',
+      '__fixtures__',
+      'napi_type_def_nested_namespace',
+    ),
+    true,
+    '',
+    true,
+    { dir: './types', index: '../index', extension: '' },
+  )
+
+  t.is(
+    dts,
+    `export declare function version(): string
+
+export * from './types/fs'
+
+`,
+  )
+  t.deepEqual(namespaces.map(({ namespace }) => namespace), ['fs'])
+  t.true(namespaces[0].dts.startsWith('export declare namespace fs {\n'))
+})
+
+test('should order type defs as asked', async (t) => {
+  const fixture = join(
+    fileURLToPath(import.meta.url),
+    '../',
+    '__fixtures__',
+    'napi_type_def_sort',
+  )
+  const exportsOf = async (sort: TypeDefSort) =>
+    (await processTypeDef(fixture, true, '', true, undefined, sort)).exports
+
+  t.deepEqual(await exportsOf('name'), ['ALPHA', 'beta', 'GAMMA', 'zeta'])
+  t.deepEqual(await exportsOf('source'), ['zeta', 'ALPHA', 'beta', 'GAMMA'])
+  t.deepEqual(await exportsOf('kind'), ['ALPHA', 'GAMMA', 'beta', 'zeta'])
+})
+
+test('should report the line of an invalid type def', async (t) => {
+  const fixture = join(
+    fileURLToPath(import.meta.url),
+    '../',
+    '__fixtures__',
+    'napi_type_def_invalid',
+  )
+  await t.throwsAsync(() => processTypeDef(fixture, true), {
+    message: /^Failed to parse the type def at line 2 of /,
+  })
+})
+
+test('should report duplicate exports', async (t) => {
+  const fixture = join(
+    fileURLToPath(import.meta.url),
+    '../',
+    '__fixtures__',
+    'napi_type_def_duplicate',
+  )
+  await t.throwsAsync(() => processTypeDef(fixture, true), {
+    message:
+      'Duplicate export `fs` at the top level, exported by both a fn and a namespace. Rename one of them with `#[napi(js_name = "...")]`',
+  })
+})
+
+test('should merge the type defs of every crate', async (t) => {
+  const fixtures = join(fileURLToPath(import.meta.url), '../', '__fixtures__')
+  const { dts, exports } = await processTypeDef(
+    join(fixtures, 'napi_type_def_workspace'),
+    true,
+    '',
+  )
+
+  t.deepEqual(exports, ['sum', 'version'])
+  t.is(
+    dts,
+    `export declare function sum(a: number, b: number): number
+
+export declare function version(): string
+
+`,
+  )
+  await t.throwsAsync(
+    () =>
+      processTypeDef(join(fixtures, 'napi_type_def_workspace_conflict'), true),
+    {
+      message: /exported by both a fn of crate `core` and a const of crate `addon`/,
+    },
+  )
+})
+
+test('should merge the interface of a trait implemented by classes', async (t) => {
+  const fixture = join(
+    fileURLToPath(import.meta.url),
+    '../',
+    '__fixtures__',
+    'napi_type_def_trait',
+  )
+  const { dts } = await processTypeDef(fixture, true)
+
+  t.true(dts.includes('export declare class Circle implements Shape {'))
+  t.true(dts.includes('export declare class Square implements Shape {'))
+  t.true(
+    dts.includes(`export interface Shape {
+  /** The area of the shape */
+  area(): number
+}`),
+  )
+  t.is(dts.split('export interface Shape').length, 2)
+})
+
+test('should create flow type def', async (t) => {
+  const flow = await processFlowTypeDef(
+    join(
+      fileURLToPath(import.meta.url),
+      '../',
+      '__fixtures__',
+      'napi_type_def_flow',
+    ),
+    '',
+  )
+
+  t.true(flow.startsWith('// @flow\n'))
+  t.true(
+    flow.includes(`declare export class Animal {
+  +kind: Kind;
+  constructor(kind: Kind): void;
+  set name(name: string): void;
+  get name(): string;
+}`),
+  )
+  t.true(
+    flow.includes(`declare export enum Kind {
+  Dog = 0,
+  Cat = 1,
+}`),
+  )
+  t.true(
+    flow.includes(`export type Options = {
+  extra?: { [key: string]: mixed },
+}`),
+  )
+  t.true(
+    flow.includes(
+      'declare export function getAge(key?: string | void | null): number | null',
+    ),
+  )
+  t.true(
+    flow.includes(`declare export var path: {|
+  +join: ((a: string, b: string) => string),
+|}`),
+  )
+})
+
+test('should create jsdoc from type def', (t) => {
+  t.is(
+    createJsDoc({
+      kind: TypeDefKind.Fn,
+      name: 'sum',
+      js_doc: '/** Sum the numbers */\n',
+      def: 'function sum(a: number, b?: number, ...rest: Array<number>): number',
+    }),
+    `/**
+ * Sum the numbers
+ * @param {number} a
+ * @param {number} [b]
+ * @param {...number} rest
+ * @returns {number}
+ */`,
+  )
+  t.is(
+    createJsDoc({
+      kind: TypeDefKind.Const,
+      name: 'DEFAULT_COST',
+      def: 'export const DEFAULT_COST: number',
+    }),
+    `/**
+ * @type {number}
+ */`,
+  )
+  t.is(
+    createJsDoc({
+      kind: TypeDefKind.Fn,
+      name: 'noop',
+      def: 'function noop(): void',
+    }),
+    null,
+  )
+})
+
+test('should check the type def files without writing them', async (t) => {
+  const outputDir = mkdtempSync(join(tmpdir(), 'napi-dts-check-'))
+  const dtsDir = join(outputDir, 'types')
+  mkdirSync(dtsDir)
+  writeFileSync(join(outputDir, 'index.d.ts'), "export * from './types/a'\r\n")
+  writeFileSync(join(dtsDir, 'a.d.ts'), 'export declare function a(): void\n')
+  writeFileSync(join(dtsDir, 'b.d.ts'), 'export declare function b(): void\n')
+  writeFileSync(join(dtsDir, 'README.md'), '')
+
+  const files = [
+    {
+      path: join(outputDir, 'index.d.ts'),
+      dts: "export * from './types/a'\n",
+    },
+    {
+      path: join(dtsDir, 'a.d.ts'),
+      dts: 'export declare function a(): number\n',
+    },
+    {
+      path: join(dtsDir, 'c.d.ts'),
+      dts: 'export declare function c(): void\n',
+    },
+  ]
+  t.deepEqual(
+    await checkTypeDefFiles(files, { dir: dtsDir, extension: '.d.ts' }),
+    {
+      // `c.d.ts` is missing
+      outdated: [join(dtsDir, 'a.d.ts'), join(dtsDir, 'c.d.ts')],
+      stale: [join(dtsDir, 'b.d.ts')],
+    },
+  )
+  t.deepEqual(await checkTypeDefFiles(files.slice(0, 1)), {
+    outdated: [],
+    stale: [],
+  })
+})
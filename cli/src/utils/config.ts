@@ -53,6 +53,20 @@ export interface UserNapiConfig {
    */
   dtsHeaderFile?: string
 
+  /**
+   * template file path of the generated js binding, the generated parts are inserted in place of
+   * `{{ loader }}` and `{{ exports }}`, `{{ binaryName }}` and `{{ packageName }}` are replaced as well
+   */
+  jsBindingTemplate?: string
+
+  /**
+   * download the prebuilt binary in the js binding if no platform package is installed, e.g. with `--no-optional`
+   * `true` for the GitHub releases of the `repository`, or the url of the binaries with `{version}`, `{binaryName}` and `{platform}` placeholders
+   * the binaries are checked against the sha256 checksums recorded in `napiChecksums` of package.json by `napi artifacts`,
+   * so `napi artifacts` must run before publishing, a platform without a recorded checksum is never downloaded
+   */
+  downloadFallback?: boolean | string
+
   /**
    * wasm compilation options
    */
@@ -118,6 +132,8 @@ export interface CommonPackageJsonFields {
   bugs?: any
   // eslint-disable-next-line no-use-before-define
   napi?: UserNapiConfig
+  // the sha256 checksums of the binaries by platform, recorded by `napi artifacts` for `napi.downloadFallback`
+  napiChecksums?: Record<string, string>
   type?: 'module' | 'commonjs'
   scripts?: Record<string, string>
 
@@ -130,6 +146,7 @@ export interface CommonPackageJsonFields {
 
   dependencies?: Record<string, string>
   devDependencies?: Record<string, string>
+  optionalDependencies?: Record<string, string>
 
   ava?: {
     timeout?: string
@@ -139,7 +156,14 @@ export interface CommonPackageJsonFields {
 export type NapiConfig = Required<
   Pick<UserNapiConfig, 'binaryName' | 'packageName' | 'npmClient'>
 > &
-  Pick<UserNapiConfig, 'wasm' | 'dtsHeader' | 'dtsHeaderFile'> & {
+  Pick<
+    UserNapiConfig,
+    | 'wasm'
+    | 'dtsHeader'
+    | 'dtsHeaderFile'
+    | 'jsBindingTemplate'
+    | 'downloadFallback'
+  > & {
     targets: Target[]
     packageJson: CommonPackageJsonFields
   }
@@ -1,13 +1,15 @@
+import { join } from 'node:path'
+
 import { sortBy } from 'lodash-es'
 
-import { readFileAsync } from './misc.js'
+import { readdirAsync, readFileAsync } from './misc.js'
 
 const TOP_LEVEL_NAMESPACE = '__TOP_LEVEL_MODULE__'
 export const DEFAULT_TYPE_DEF_HEADER = `/* auto-generated by NAPI-RS */
 /* eslint-disable */
 `
 
-enum TypeDefKind {
+export enum TypeDefKind {
   Const = 'const',
   Enum = 'enum',
   StringEnum = 'string_enum',
@@ -15,15 +17,35 @@ enum TypeDefKind {
   Fn = 'fn',
   Struct = 'struct',
   Impl = 'impl',
+  Type = 'type',
 }
 
-interface TypeDefLine {
+export const TYPE_DEF_SORTS = ['name', 'source', 'kind'] as const
+export type TypeDefSort = (typeof TYPE_DEF_SORTS)[number]
+
+// `impl` must come after the `struct` it's merged into
+const KIND_ORDER = [
+  TypeDefKind.Struct,
+  TypeDefKind.Impl,
+  TypeDefKind.Interface,
+  TypeDefKind.Type,
+  TypeDefKind.Enum,
+  TypeDefKind.StringEnum,
+  TypeDefKind.Const,
+  TypeDefKind.Fn,
+]
+
+export interface TypeDefLine {
   kind: TypeDefKind
   name: string
   original_name?: string
   def: string
   js_doc?: string
   js_mod?: string
+  extends?: string
+  implements?: string[]
+  // the crate emitting the def, every `#[napi]` crate of the build shares the same type def file
+  crate_name?: string
 }
 
 function prettyPrint(
@@ -52,14 +74,26 @@ function prettyPrint(
       break
 
     case TypeDefKind.Struct:
-      s += `${exportDeclare(ambient)} class ${line.name} {\n${line.def}\n}`
+      const extendsClause = line.extends ? ` extends ${line.extends}` : ''
+      const implementsClause = line.implements?.length
+        ? ` implements ${line.implements.join(', ')}`
+        : ''
+      s += `${exportDeclare(ambient)} class ${line.name}${extendsClause}${implementsClause} {\n${line.def}\n}`
       if (line.original_name && line.original_name !== line.name) {
         s += `\nexport type ${line.original_name} = ${line.name}`
       }
       break
 
     case TypeDefKind.Fn:
-      s += `${exportDeclare(ambient)} ${line.def}`
+      // one line for each `#[napi(ts_overload)]` signature
+      s += line.def
+        .split('\n')
+        .map((def) => `${exportDeclare(ambient)} ${def}`)
+        .join('\n')
+      break
+
+    case TypeDefKind.Type:
+      s += `export type ${line.name} =\n${line.def}`
       break
 
     default:
@@ -77,52 +111,94 @@ function exportDeclare(ambient: boolean): string {
   return 'export declare'
 }
 
+export interface DtsDirOptions {
+  // import specifier of the directory from the type def file, like `./types`
+  dir: string
+  // import specifier of the type def file from the directory, like `../index`
+  index: string
+  // `.cjs` or `.mjs` for `.d.cts` and `.d.mts` files, empty for `.d.ts`
+  extension: string
+}
+
 export async function processTypeDef(
   intermediateTypeFile: string,
   constEnum: boolean,
   header?: string,
+  externalObject = true,
+  dtsDir?: DtsDirOptions,
+  sort: TypeDefSort = 'name',
 ) {
   const exports: string[] = []
-  const defs = await readIntermediateTypeFile(intermediateTypeFile)
+  const defs = await readIntermediateTypeFile(intermediateTypeFile, sort)
   const groupedDefs = preprocessTypeDef(defs)
+  // every name declared by the type def file, imported by the namespace files in `dtsDir` mode
+  const declaredNames: string[] = []
+  const namespaces: { namespace: string; dts: string }[] = []
+  // JSDoc of the top level functions and consts for the JS binding file
+  const jsDocs = new Map<string, string>()
 
   header = header ?? ''
+  // a custom header like `/// <reference types="node" />` from `--dts-header` may not end with a line break
+  if (header && !header.endsWith('\n')) {
+    header += '\n'
+  }
   let dts = ''
 
-  sortBy(Array.from(groupedDefs), ([namespace]) => namespace).forEach(
-    ([namespace, defs]) => {
-      if (namespace === TOP_LEVEL_NAMESPACE) {
-        for (const def of defs) {
-          dts += prettyPrint(def, constEnum, 0) + '\n\n'
-          switch (def.kind) {
-            case TypeDefKind.Const:
-            case TypeDefKind.Enum:
-            case TypeDefKind.StringEnum:
-            case TypeDefKind.Fn:
-            case TypeDefKind.Struct: {
-              exports.push(def.name)
-              if (def.original_name && def.original_name !== def.name) {
-                exports.push(def.original_name)
-              }
-              break
+  sortBy(
+    Array.from(groupNestedNamespaces(groupedDefs)),
+    ([namespace]) => namespace,
+  ).forEach(([namespace, tree]) => {
+    if (namespace === TOP_LEVEL_NAMESPACE) {
+      for (const def of tree.defs) {
+        dts += prettyPrint(def, constEnum, 0) + '\n\n'
+        declaredNames.push(def.name)
+        const jsDoc = createJsDoc(def)
+        if (jsDoc) {
+          jsDocs.set(def.name, jsDoc)
+        }
+        switch (def.kind) {
+          case TypeDefKind.Const:
+          case TypeDefKind.Enum:
+          case TypeDefKind.StringEnum:
+          case TypeDefKind.Fn:
+          case TypeDefKind.Struct: {
+            exports.push(def.name)
+            if (def.original_name && def.original_name !== def.name) {
+              exports.push(def.original_name)
+              declaredNames.push(def.original_name)
             }
-            default:
-              break
+            break
           }
+          default:
+            break
         }
+      }
+    } else {
+      // only the outermost namespace is exported, the nested ones are its properties
+      exports.push(namespace)
+      declaredNames.push(namespace)
+      const namespaceDts = printNamespace(namespace, tree, constEnum, 0)
+      if (dtsDir) {
+        namespaces.push({ namespace, dts: namespaceDts })
+        dts += `export * from '${dtsDir.dir}/${namespace}${dtsDir.extension}'\n\n`
       } else {
-        exports.push(namespace)
-        dts += `export declare namespace ${namespace} {\n`
-        for (const def of defs) {
-          dts += prettyPrint(def, constEnum, 2, true) + '\n'
-        }
-        dts += '}\n\n'
+        dts += namespaceDts + '\n'
       }
-    },
-  )
+    }
+  })
 
-  if (dts.indexOf('ExternalObject<') > -1) {
-    header += `
+  // only declared once, by the type def file, even if it's only used by the namespace files
+  let externalObjectDts = ''
+  if (
+    externalObject &&
+    [dts, ...namespaces.map(({ dts }) => dts)].some(
+      (dts) => dts.indexOf('ExternalObject<') > -1,
+    ) &&
+    // declared by the user in the header already
+    !/\bclass ExternalObject\b/.test(header)
+  ) {
+    declaredNames.push('ExternalObject')
+    externalObjectDts = `
 export declare class ExternalObject<T> {
   readonly '': {
     readonly '': unique symbol
@@ -133,45 +209,99 @@ export declare class ExternalObject<T> {
   }
 
   return {
-    dts: header + dts,
+    dts: header + externalObjectDts + dts,
     exports,
+    jsDocs,
+    namespaces: namespaces.map(({ namespace, dts: namespaceDts }) => {
+      const imports = declaredNames.filter(
+        (name) =>
+          name !== namespace && new RegExp(`\\b${name}\\b`).test(namespaceDts),
+      )
+      const importDts = imports.length
+        ? `import type { ${imports.join(', ')} } from '${dtsDir!.index}${dtsDir!.extension}'\n\n`
+        : ''
+      return {
+        namespace,
+        dts: header + importDts + namespaceDts,
+      }
+    }),
   }
 }
 
-async function readIntermediateTypeFile(file: string) {
+async function readIntermediateTypeFile(file: string, sort: TypeDefSort) {
   const content = await readFileAsync(file, 'utf8')
-  const defs = content
-    .split('\n')
-    .filter(Boolean)
-    .map((line) => {
-      line = line.trim()
-      if (!line.startsWith('{')) {
-        // crateName:{ "def": "", ... }
-        const start = line.indexOf(':') + 1
-        line = line.slice(start)
-      }
-      return JSON.parse(line) as TypeDefLine
-    })
-
-  // move all `struct` def to the very top
-  // and order the rest alphabetically.
-  return defs.sort((a, b) => {
-    if (a.kind === TypeDefKind.Struct) {
-      if (b.kind === TypeDefKind.Struct) {
-        return a.name.localeCompare(b.name)
-      }
-      return -1
-    } else if (b.kind === TypeDefKind.Struct) {
-      return 1
-    } else {
-      return a.name.localeCompare(b.name)
+  const defs: TypeDefLine[] = []
+  // a crate compiled more than once in the same build emits the same defs again
+  const seen = new Set<string>()
+  content.split('\n').forEach((line, index) => {
+    line = line.trim()
+    if (!line) {
+      return
+    }
+    let crateName: string | undefined
+    if (!line.startsWith('{')) {
+      // crateName:{ "def": "", ... }
+      const start = line.indexOf(':') + 1
+      crateName = line.slice(0, start - 1)
+      line = line.slice(start)
+    }
+    if (seen.has(line)) {
+      return
+    }
+    seen.add(line)
+    let def: TypeDefLine
+    try {
+      def = JSON.parse(line)
+    } catch (e) {
+      throw new Error(
+        `Failed to parse the type def at line ${index + 1} of ${file}: ${(e as Error).message}`,
+        { cause: e },
+      )
     }
+    if (typeof def?.kind !== 'string' || typeof def.name !== 'string') {
+      throw new Error(
+        `Invalid type def at line ${index + 1} of ${file}, \`kind\` and \`name\` are required: ${line}`,
+      )
+    }
+    if (crateName) {
+      def.crate_name = crateName
+    }
+    defs.push(def)
   })
+
+  switch (sort) {
+    // the order of the macro expansion, a `struct` is always expanded before its `impl`
+    case 'source':
+      return defs
+    case 'kind':
+      return defs.sort(
+        (a, b) =>
+          KIND_ORDER.indexOf(a.kind) - KIND_ORDER.indexOf(b.kind) ||
+          a.name.localeCompare(b.name),
+      )
+    case 'name':
+    default:
+      // move all `struct` def to the very top
+      // and order the rest alphabetically.
+      return defs.sort((a, b) => {
+        if (a.kind === TypeDefKind.Struct) {
+          if (b.kind === TypeDefKind.Struct) {
+            return a.name.localeCompare(b.name)
+          }
+          return -1
+        } else if (b.kind === TypeDefKind.Struct) {
+          return 1
+        } else {
+          return a.name.localeCompare(b.name)
+        }
+      })
+  }
 }
 
 function preprocessTypeDef(defs: TypeDefLine[]): Map<string, TypeDefLine[]> {
   const namespaceGrouped = new Map<string, TypeDefLine[]>()
   const classDefs = new Map<string, TypeDefLine>()
+  const interfaceDefs = new Map<string, TypeDefLine>()
 
   for (const def of defs) {
     const namespace = def.js_mod ?? TOP_LEVEL_NAMESPACE
@@ -183,25 +313,262 @@ function preprocessTypeDef(defs: TypeDefLine[]): Map<string, TypeDefLine[]> {
 
     if (def.kind === TypeDefKind.Struct) {
       group.push(def)
-      classDefs.set(def.name, def)
+      classDefs.set(`${namespace}::${def.name}`, def)
     } else if (def.kind === TypeDefKind.Impl) {
       // merge `impl` into class definition
-      const classDef = classDefs.get(def.name)
+      const classDef = classDefs.get(`${namespace}::${def.name}`)
       if (classDef) {
         if (classDef.def) {
           classDef.def += '\n'
         }
 
         classDef.def += def.def
+        if (def.implements?.length) {
+          classDef.implements = [
+            ...(classDef.implements ?? []),
+            ...def.implements,
+          ]
+        }
+      }
+    } else if (def.kind === TypeDefKind.Interface) {
+      // the interface of a trait is emitted by every class implementing it, with the methods exported by the class
+      // keep the methods exported by all of them, so every class stays assignable to the interface
+      const interfaceDef = interfaceDefs.get(`${namespace}::${def.name}`)
+      if (interfaceDef) {
+        const signatures = splitMembers(def.def).map(memberSignature)
+        interfaceDef.def = splitMembers(interfaceDef.def)
+          .filter((member) => signatures.includes(memberSignature(member)))
+          .join('\n')
+      } else {
+        group.push(def)
+        interfaceDefs.set(`${namespace}::${def.name}`, def)
       }
     } else {
       group.push(def)
     }
   }
 
+  checkDuplicateExports(namespaceGrouped)
+
   return namespaceGrouped
 }
 
+// the members of an interface def, each with its JSDoc
+function splitMembers(def: string): string[] {
+  const members: string[] = []
+  let lines: string[] = []
+  for (const line of def.split('\n')) {
+    lines.push(line)
+    if (!/^\s*(\/\*\*|\*)/.test(line)) {
+      members.push(lines.join('\n'))
+      lines = []
+    }
+  }
+  return members
+}
+
+// the member without its JSDoc
+function memberSignature(member: string): string {
+  return member.split('\n').pop()!.trim()
+}
+
+// a name exported twice from the same namespace overrides the first one at runtime
+function checkDuplicateExports(groupedDefs: Map<string, TypeDefLine[]>) {
+  // namespace -> exported name -> what it is, `fn`, `struct`, ..., or `namespace`
+  // with the crate emitting it, to tell the crates of a workspace apart
+  const exported = new Map<string, Map<string, string>>()
+  const addExport = (namespace: string, name: string, kind: string) => {
+    if (!exported.has(namespace)) {
+      exported.set(namespace, new Map())
+    }
+    const names = exported.get(namespace)!
+    const existing = names.get(name)
+    if (existing === 'namespace' && kind === 'namespace') {
+      return
+    }
+    if (existing) {
+      throw new Error(
+        `Duplicate export \`${name}\` ${
+          namespace === TOP_LEVEL_NAMESPACE
+            ? 'at the top level'
+            : `in namespace \`${namespace}\``
+        }, exported by both a ${existing} and a ${kind}. Rename one of them with \`#[napi(js_name = "...")]\``,
+      )
+    }
+    names.set(name, kind)
+  }
+
+  for (const [namespace, defs] of groupedDefs) {
+    if (namespace !== TOP_LEVEL_NAMESPACE) {
+      // `a::b` is the property `b` of the namespace `a`
+      const path = namespace.split('::')
+      path.forEach((name, i) => {
+        const parent = path.slice(0, i).join('::') || TOP_LEVEL_NAMESPACE
+        addExport(parent, name, 'namespace')
+      })
+    }
+    for (const def of defs) {
+      switch (def.kind) {
+        case TypeDefKind.Const:
+        case TypeDefKind.Enum:
+        case TypeDefKind.StringEnum:
+        case TypeDefKind.Fn:
+        case TypeDefKind.Struct:
+          addExport(
+            namespace,
+            def.name,
+            def.crate_name
+              ? `${def.kind} of crate \`${def.crate_name}\``
+              : def.kind,
+          )
+          break
+        default:
+          break
+      }
+    }
+  }
+}
+
+interface NamespaceTree {
+  defs: TypeDefLine[]
+  children: Map<string, NamespaceTree>
+}
+
+// `js_mod = "a::b::c"` is nested as `a` -> `b` -> `c`
+function groupNestedNamespaces(
+  groupedDefs: Map<string, TypeDefLine[]>,
+): Map<string, NamespaceTree> {
+  const roots = new Map<string, NamespaceTree>()
+
+  for (const [namespace, defs] of groupedDefs) {
+    let siblings = roots
+    let tree: NamespaceTree | undefined
+    const path =
+      namespace === TOP_LEVEL_NAMESPACE ? [namespace] : namespace.split('::')
+    for (const name of path) {
+      tree = siblings.get(name)
+      if (!tree) {
+        tree = { defs: [], children: new Map() }
+        siblings.set(name, tree)
+      }
+      siblings = tree.children
+    }
+    tree!.defs.push(...defs)
+  }
+
+  return roots
+}
+
+function printNamespace(
+  name: string,
+  tree: NamespaceTree,
+  constEnum: boolean,
+  ident: number,
+): string {
+  const indent = ' '.repeat(ident)
+  const declare = ident === 0 ? 'export declare' : 'export'
+  let dts = `${indent}${declare} namespace ${name} {\n`
+  for (const def of tree.defs) {
+    dts += prettyPrint(def, constEnum, ident + 2, true) + '\n'
+  }
+  // nested namespaces after the items of the namespace, ordered by name
+  sortBy(Array.from(tree.children), ([child]) => child).forEach(
+    ([child, childTree]) => {
+      dts += printNamespace(child, childTree, constEnum, ident + 2)
+    },
+  )
+  dts += `${indent}}\n`
+  return dts
+}
+
+// `/** foo */` or `/**\n * foo\n */` to `foo`
+function jsDocDescription(jsDoc?: string): string[] {
+  const lines = (jsDoc ?? '')
+    .replace(/^\s*\/\*\*/, '')
+    .replace(/\*\/\s*$/, '')
+    .split('\n')
+    .map((line) => line.trim().replace(/^\* ?/, ''))
+  while (lines.length && !lines[0]) {
+    lines.shift()
+  }
+  while (lines.length && !lines[lines.length - 1]) {
+    lines.pop()
+  }
+  return lines
+}
+
+// split `a: number, b: Array<string>` by the commas which are not nested in brackets
+function splitTopLevel(src: string, separator: string): string[] {
+  const parts: string[] = []
+  let depth = 0
+  let start = 0
+  for (let i = 0; i < src.length; i++) {
+    const char = src[i]
+    if ('<([{'.includes(char)) {
+      depth++
+    } else if (')]}'.includes(char) || (char === '>' && src[i - 1] !== '=')) {
+      depth--
+    } else if (char === separator && depth === 0) {
+      parts.push(src.slice(start, i))
+      start = i + 1
+    }
+  }
+  parts.push(src.slice(start))
+  return parts.map((part) => part.trim()).filter(Boolean)
+}
+
+export function createJsDoc(def: TypeDefLine): string | null {
+  const lines = jsDocDescription(def.js_doc)
+  if (def.kind === TypeDefKind.Const) {
+    const type = def.def.slice(def.def.indexOf(':') + 1).trim()
+    lines.push(`@type {${type}}`)
+  } else if (def.kind === TypeDefKind.Fn && !def.def.includes('\n')) {
+    // `function name<T>(a: number, b?: string): number`, overloads are left to the type def file
+    const paramsStart = def.def.indexOf('(')
+    let depth = 0
+    let paramsEnd = paramsStart
+    for (; paramsEnd < def.def.length; paramsEnd++) {
+      const char = def.def[paramsEnd]
+      if (char === '(') {
+        depth++
+      } else if (char === ')' && --depth === 0) {
+        break
+      }
+    }
+    for (const param of splitTopLevel(
+      def.def.slice(paramsStart + 1, paramsEnd),
+      ',',
+    )) {
+      const [name, ...rest] = splitTopLevel(param, ':')
+      let type = rest.join(': ')
+      let paramName = name
+      if (name.startsWith('...')) {
+        // `...args: Array<number>` is `{...number} args`
+        paramName = name.slice(3)
+        type = `...${type.replace(/^Array<(.*)>$/, '$1')}`
+      } else if (name.endsWith('?')) {
+        paramName = `[${name.slice(0, -1)}]`
+      }
+      lines.push(`@param {${type}} ${paramName}`)
+    }
+    const returnType = def.def
+      .slice(paramsEnd + 1)
+      .replace(/^\s*:/, '')
+      .trim()
+    if (returnType && returnType !== 'void') {
+      lines.push(`@returns {${returnType}}`)
+    }
+  }
+  if (!lines.length) {
+    return null
+  }
+  return [
+    '/**',
+    ...lines.map((line) => (line ? ` * ${line}` : ' *')),
+    ' */',
+  ].join('\n')
+}
+
 export function correctStringIdent(src: string, ident: number): string {
   let bracketDepth = 0
   const result = src
@@ -239,3 +606,231 @@ export function correctStringIdent(src: string, ident: number): string {
 
   return result
 }
+
+// `--dts-check`, compare the generated type defs with the existing files without touching them
+// `outdated` are the files which differ or are missing, `stale` are the files in `dtsDir` of the namespaces which don't exist anymore
+export async function checkTypeDefFiles(
+  files: { path: string; dts: string }[],
+  dtsDir?: { dir: string; extension: string },
+) {
+  const outdated: string[] = []
+  for (const { path, dts } of files) {
+    const existing = await readFileAsync(path, 'utf-8').catch(() => null)
+    // the files may be checked out with CRLF line endings on Windows
+    if (existing?.replaceAll('\r\n', '\n') !== dts) {
+      outdated.push(path)
+    }
+  }
+
+  const stale: string[] = []
+  if (dtsDir) {
+    const generated = new Set(files.map(({ path }) => join(path)))
+    const existing = await readdirAsync(dtsDir.dir).catch(() => [] as string[])
+    for (const name of existing.sort()) {
+      const path = join(dtsDir.dir, name)
+      if (name.endsWith(dtsDir.extension) && !generated.has(path)) {
+        stale.push(path)
+      }
+    }
+  }
+
+  return { outdated, stale }
+}
+
+// `.js.flow` variant of the type def file, from the same type defs
+// Flow has no namespaces, only their functions and consts are typed, the other items are `any`
+export async function processFlowTypeDef(
+  intermediateTypeFile: string,
+  header = DEFAULT_TYPE_DEF_HEADER,
+  sort: TypeDefSort = 'name',
+) {
+  const defs = await readIntermediateTypeFile(intermediateTypeFile, sort)
+  const groupedDefs = preprocessTypeDef(defs)
+
+  let flow = ''
+  sortBy(
+    Array.from(groupNestedNamespaces(groupedDefs)),
+    ([namespace]) => namespace,
+  ).forEach(([namespace, tree]) => {
+    if (namespace === TOP_LEVEL_NAMESPACE) {
+      for (const def of tree.defs) {
+        flow += prettyPrintFlow(def) + '\n\n'
+      }
+    } else {
+      flow += `declare export var ${namespace}: ${printFlowNamespace(tree, 0)}\n\n`
+    }
+  })
+
+  if (flow.indexOf('ExternalObject<') > -1) {
+    flow = `declare export opaque type ExternalObject<+T>\n\n` + flow
+  }
+
+  // the `@flow` pragma must be in the first comment of the file
+  return `// @flow\n${header}${header && !header.endsWith('\n') ? '\n' : ''}${flow}`
+}
+
+function prettyPrintFlow(line: TypeDefLine): string {
+  let s = line.js_doc ?? ''
+  switch (line.kind) {
+    case TypeDefKind.Interface:
+      s += `export type ${line.name} = {\n${toFlowMembers(line.def, ',')}\n}`
+      break
+
+    case TypeDefKind.Enum:
+    case TypeDefKind.StringEnum:
+      // requires `enums=true` in `.flowconfig`
+      s += `declare export enum ${line.name} {\n${line.def
+        .split('\n')
+        .map((member) =>
+          isCommentLine(member) || member.trim().endsWith(',')
+            ? member
+            : `${member},`,
+        )
+        .join('\n')}\n}`
+      break
+
+    case TypeDefKind.Struct:
+      const extendsClause = line.extends ? ` extends ${line.extends}` : ''
+      const implementsClause = line.implements?.length
+        ? ` implements ${line.implements.join(', ')}`
+        : ''
+      s += `declare export class ${line.name}${extendsClause}${implementsClause} {\n${toFlowMembers(line.def, ';')}\n}`
+      if (line.original_name && line.original_name !== line.name) {
+        s += `\nexport type ${line.original_name} = ${line.name}`
+      }
+      break
+
+    case TypeDefKind.Fn:
+      s += line.def
+        .split('\n')
+        .map((def) => `declare export ${toFlowType(def)}`)
+        .join('\n')
+      break
+
+    case TypeDefKind.Const:
+      // `export const NAME: type`
+      s += `declare export var ${line.name}: ${toFlowType(
+        line.def.slice(line.def.indexOf(':') + 1).trim(),
+      )}`
+      break
+
+    case TypeDefKind.Type:
+      s += `export type ${line.name} =\n${toFlowType(line.def)}`
+      break
+
+    default:
+      s += line.def
+  }
+
+  return correctStringIdent(s, 0)
+}
+
+function printFlowNamespace(tree: NamespaceTree, ident: number): string {
+  const indent = ' '.repeat(ident + 2)
+  let flow = '{|\n'
+  for (const def of tree.defs) {
+    switch (def.kind) {
+      case TypeDefKind.Fn:
+        flow += `${indent}+${def.name}: ${def.def
+          .split('\n')
+          .map((def) => toFlowFunctionType(def))
+          .join(' & ')},\n`
+        break
+      case TypeDefKind.Const:
+        flow += `${indent}+${def.name}: ${toFlowType(
+          def.def.slice(def.def.indexOf(':') + 1).trim(),
+        )},\n`
+        break
+      case TypeDefKind.Struct:
+      case TypeDefKind.Enum:
+      case TypeDefKind.StringEnum:
+        flow += `${indent}+${def.name}: any,\n`
+        break
+      default:
+        // types only, nothing at runtime
+        break
+    }
+  }
+  sortBy(Array.from(tree.children), ([child]) => child).forEach(
+    ([child, childTree]) => {
+      flow += `${indent}+${child}: ${printFlowNamespace(childTree, ident + 2)},\n`
+    },
+  )
+  return flow + `${' '.repeat(ident)}|}`
+}
+
+// the members of a class or an object, one on each line
+function toFlowMembers(def: string, separator: string): string {
+  return def
+    .split('\n')
+    .map((member) => {
+      if (isCommentLine(member) || !member.trim()) {
+        return member
+      }
+      member = toFlowType(member.trim())
+        .replace(/^(static )?readonly /, '$1+')
+        .replace(/\[Symbol\.(iterator|asyncIterator)\]/, '@@$1')
+      // setters and constructors must be typed as returning `void` in Flow
+      if (/^(set \w+|constructor)\(/.test(member) && member.endsWith(')')) {
+        member += ': void'
+      }
+      return `${member}${separator}`
+    })
+    .join('\n')
+}
+
+// `function name<T>(a: T): R` to `<T>(a: T) => R`
+function toFlowFunctionType(def: string): string {
+  const signature = toFlowType(def).replace(/^function\s+[\w$]+/, '')
+  let depth = 0
+  for (let i = signature.indexOf('('); i < signature.length; i++) {
+    if (signature[i] === '(') {
+      depth++
+    } else if (signature[i] === ')' && --depth === 0) {
+      const returnType = signature.slice(i + 1).replace(/^\s*:/, '').trim()
+      return `(${signature.slice(0, i + 1)} => ${returnType || 'void'})`
+    }
+  }
+  return 'any'
+}
+
+// the TypeScript types used by the generated type defs which are spelled differently in Flow
+function toFlowType(ts: string): string {
+  let flow = ts
+    // not the `undefined?: T` parameters
+    .replaceAll(/\bundefined\b(?!\??:)/g, 'void')
+    .replaceAll(/\bunknown\b(?!\??:)/g, 'mixed')
+    .replaceAll(/\bobject\b(?!\??:)/g, '{...}')
+
+  // `Record<K, V>` to `{ [key: K]: V }`
+  let start = flow.search(/\bRecord</)
+  while (start > -1) {
+    const argsStart = start + 'Record<'.length
+    let depth = 1
+    let end = argsStart
+    for (; end < flow.length && depth > 0; end++) {
+      if ('<([{'.includes(flow[end])) {
+        depth++
+      } else if (
+        ')]}'.includes(flow[end]) ||
+        (flow[end] === '>' && flow[end - 1] !== '=')
+      ) {
+        depth--
+      }
+    }
+    const [key, value] = splitTopLevel(flow.slice(argsStart, end - 1), ',')
+    flow = `${flow.slice(0, start)}{ [key: ${key}]: ${value} }${flow.slice(end)}`
+    start = flow.search(/\bRecord</)
+  }
+
+  return flow
+}
+
+function isCommentLine(line: string): boolean {
+  const trimmed = line.trim()
+  return (
+    trimmed.startsWith('/*') ||
+    trimmed.startsWith('*') ||
+    trimmed.startsWith('//')
+  )
+}
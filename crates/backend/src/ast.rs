@@ -14,6 +14,8 @@ pub struct NapiFn {
   pub kind: FnKind,
   pub vis: syn::Visibility,
   pub parent: Option<Ident>,
+  /// the trait of the `impl Trait for Struct` block of the method, called as `<Struct as Trait>::method`
+  pub parent_trait: Option<syn::Path>,
   pub strict: bool,
   pub return_if_invalid: bool,
   pub js_mod: Option<String>,
@@ -29,6 +31,12 @@ pub struct NapiFn {
   pub catch_unwind: bool,
   pub unsafe_: bool,
   pub register_name: Ident,
+  /// `#[napi(disposable)]`, the method is also exposed as `[Symbol.dispose]` or `[Symbol.asyncDispose]`
+  pub disposable: Option<Disposable>,
+  /// `#[napi(to_string)]`, the method is exposed as `toString` and as the `util.inspect.custom` hook
+  pub is_to_string: bool,
+  /// `#[napi(ts_overload = "...")]`, emitted in place of the generated signature
+  pub ts_overloads: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +50,8 @@ pub struct CallbackArg {
 pub struct NapiFnArg {
   pub kind: NapiFnArgKind,
   pub ts_arg_type: Option<String>,
+  /// `#[napi(default = "...")]`, used when the argument is `undefined`
+  pub default: Option<syn::Expr>,
 }
 
 impl NapiFnArg {
@@ -66,6 +76,14 @@ pub enum FnKind {
   Setter,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposable {
+  /// `[Symbol.dispose]`
+  Sync,
+  /// `[Symbol.asyncDispose]`, for `async fn` and methods returning `AsyncTask` or `Promise`
+  Async,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FnSelf {
   Value,
@@ -89,6 +107,12 @@ pub struct NapiStruct {
   pub use_custom_finalize: bool,
   pub register_name: Ident,
   pub use_nullable: bool,
+  /// Rust name of the base class
+  pub extends: Option<Ident>,
+  /// `(lower, upper)` halves of the `#[napi(type_tag = "uuid")]`
+  pub type_tag: Option<(u64, u64)>,
+  /// `#[napi(constructor = false)]`, instances can only be created by Rust
+  pub private_constructor: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -113,6 +137,27 @@ pub struct NapiStructField {
   pub ts_type: Option<String>,
 }
 
+/// Generic struct exported as one class per `#[napi(concrete = "...")]` instantiation
+#[derive(Debug, Clone)]
+pub struct NapiGenericStruct {
+  pub name: Ident,
+  pub js_name: String,
+  pub vis: syn::Visibility,
+  pub type_params: Vec<Ident>,
+  pub instances: Vec<NapiConcreteStruct>,
+  pub js_mod: Option<String>,
+  pub comments: Vec<String>,
+  pub register_name: Ident,
+}
+
+#[derive(Debug, Clone)]
+pub struct NapiConcreteStruct {
+  /// type alias of the instantiation, which is the Rust name of the exported class
+  pub name: Ident,
+  pub js_name: String,
+  pub args: Vec<Type>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NapiImpl {
   pub name: Ident,
@@ -122,11 +167,38 @@ pub struct NapiImpl {
   pub iterator_yield_type: Option<Type>,
   pub iterator_next_type: Option<Type>,
   pub iterator_return_type: Option<Type>,
+  /// `impl Iterator for Struct`, which is exposed through a `Generator` with `Item` as the `Yield` type
+  pub implement_std_iterator: bool,
+  /// the `#[napi(finalize)]` method, called from `ObjectFinalize::finalize`
+  pub finalize: Option<NapiFinalize>,
+  /// `#[napi]` associated consts, exposed as static readonly properties of the class
+  pub consts: Vec<NapiImplConst>,
+  /// the JavaScript name of the trait of an `impl Trait for Struct` block exporting methods,
+  /// emitted as an `interface` implemented by the class
+  pub trait_js_name: Option<String>,
   pub js_mod: Option<String>,
   pub comments: Vec<String>,
   pub register_name: Ident,
 }
 
+#[derive(Debug, Clone)]
+pub struct NapiImplConst {
+  pub name: Ident,
+  pub js_name: String,
+  pub ty: Type,
+  pub comments: Vec<String>,
+  pub skip_typescript: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NapiFinalize {
+  pub name: Ident,
+  /// `fn(self)` instead of `fn(&mut self)`
+  pub by_value: bool,
+  pub with_env: bool,
+  pub is_ret_result: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct NapiEnum {
   pub name: Ident,
@@ -161,6 +233,29 @@ pub struct NapiEnumVariant {
   pub comments: Vec<String>,
 }
 
+/// Enum with payload variants, represented in JavaScript as objects discriminated by the `discriminant` property
+#[derive(Debug, Clone)]
+pub struct NapiTaggedEnum {
+  pub name: Ident,
+  pub js_name: String,
+  pub discriminant: String,
+  pub variants: Vec<NapiTaggedEnumVariant>,
+  pub js_mod: Option<String>,
+  pub comments: Vec<String>,
+  pub skip_typescript: bool,
+  pub register_name: Ident,
+}
+
+#[derive(Debug, Clone)]
+pub struct NapiTaggedEnumVariant {
+  pub name: Ident,
+  /// value of the discriminant property
+  pub tag: String,
+  pub fields: Vec<NapiStructField>,
+  pub is_tuple: bool,
+  pub comments: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NapiConst {
   pub name: Ident,
@@ -170,6 +265,8 @@ pub struct NapiConst {
   pub js_mod: Option<String>,
   pub comments: Vec<String>,
   pub skip_typescript: bool,
+  /// `#[napi(ts_type = "...")]`, replaces the type generated from `type_name`
+  pub ts_type: Option<String>,
   pub register_name: Ident,
 }
 
@@ -1,7 +1,9 @@
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
-use crate::{codegen::js_mod_to_token_stream, BindgenResult, NapiEnum, TryToTokens};
+use crate::{
+  codegen::js_mod_to_token_stream, BindgenResult, NapiEnum, NapiTaggedEnum, TryToTokens,
+};
 
 impl TryToTokens for NapiEnum {
   fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
@@ -22,6 +24,12 @@ impl NapiEnum {
   fn gen_napi_value_map_impl(&self) -> TokenStream {
     let name = &self.name;
     let name_str = self.name.to_string();
+    // spell the value type out, so enums without variants don't rely on never type fallback
+    let (val_ty, value_type) = if self.is_string_enum {
+      (quote! { &str }, quote! { String })
+    } else {
+      (quote! { i32 }, quote! { Number })
+    };
     let mut from_napi_branches = vec![];
     let mut to_napi_branches = vec![];
 
@@ -40,7 +48,7 @@ impl NapiEnum {
         }
 
         fn value_type() -> napi::ValueType {
-          napi::ValueType::Object
+          napi::ValueType::#value_type
         }
       }
 
@@ -49,7 +57,7 @@ impl NapiEnum {
           env: napi::bindgen_prelude::sys::napi_env,
           napi_val: napi::bindgen_prelude::sys::napi_value
         ) -> napi::bindgen_prelude::Result<napi::sys::napi_value> {
-          napi::bindgen_prelude::assert_type_of!(env, napi_val, napi::bindgen_prelude::ValueType::Number)?;
+          napi::bindgen_prelude::assert_type_of!(env, napi_val, napi::bindgen_prelude::ValueType::#value_type)?;
           Ok(std::ptr::null_mut())
         }
       }
@@ -59,7 +67,7 @@ impl NapiEnum {
           env: napi::bindgen_prelude::sys::napi_env,
           napi_val: napi::bindgen_prelude::sys::napi_value
         ) -> napi::bindgen_prelude::Result<Self> {
-          let val = napi::bindgen_prelude::FromNapiValue::from_napi_value(env, napi_val).map_err(|e| {
+          let val: #val_ty = napi::bindgen_prelude::FromNapiValue::from_napi_value(env, napi_val).map_err(|e| {
             napi::bindgen_prelude::error!(
               e.status,
               "Failed to convert napi value into enum `{}`. {}",
@@ -87,7 +95,7 @@ impl NapiEnum {
           env: napi::bindgen_prelude::sys::napi_env,
           val: Self
         ) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
-          let val = match val {
+          let val: #val_ty = match val {
             #(#to_napi_branches,)*
           };
 
@@ -166,3 +174,159 @@ impl NapiEnum {
     }
   }
 }
+
+impl TryToTokens for NapiTaggedEnum {
+  fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
+    self.gen_napi_value_map_impl().to_tokens(tokens);
+
+    Ok(())
+  }
+}
+
+impl NapiTaggedEnum {
+  fn gen_napi_value_map_impl(&self) -> TokenStream {
+    let name = &self.name;
+    let name_str = self.name.to_string();
+    let discriminant = &self.discriminant;
+    let mut from_napi_branches = vec![];
+    let mut to_napi_branches = vec![];
+
+    for variant in self.variants.iter() {
+      let v_name = &variant.name;
+      let tag = &variant.tag;
+      let mut field_bindings = vec![];
+      let mut field_setters = vec![];
+      let mut field_getters = vec![];
+
+      for field in variant.fields.iter() {
+        let field_js_name = &field.js_name;
+        let ty = &field.ty;
+        let binding = match &field.name {
+          syn::Member::Named(ident) => {
+            let alias_ident = format_ident!("{}_", ident);
+            field_bindings.push(quote! { #ident: #alias_ident });
+            alias_ident
+          }
+          syn::Member::Unnamed(i) => {
+            let alias_ident = format_ident!("arg{}", i.index);
+            field_bindings.push(quote! { #alias_ident });
+            alias_ident
+          }
+        };
+
+        if is_option_type(ty) {
+          field_setters.push(quote! {
+            if #binding.is_some() {
+              obj.set_field(#field_js_name, #binding)?;
+            }
+          });
+          // read as `Option<T>`, so `null` is `None` like `undefined`
+          field_getters.push(quote! {
+            let #binding: #ty = obj.get_field::<#ty>(#field_js_name).map_err(|mut err| {
+              err.reason = format!("{} on {}.{}", err.reason, #name_str, #field_js_name);
+              err
+            })?.flatten();
+          });
+        } else {
+          field_setters.push(quote! { obj.set_field(#field_js_name, #binding)?; });
+          field_getters.push(quote! {
+            let #binding: #ty = obj.get_field(#field_js_name).map_err(|mut err| {
+              err.reason = format!("{} on {}.{}", err.reason, #name_str, #field_js_name);
+              err
+            })?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+              napi::bindgen_prelude::Status::InvalidArg,
+              format!("Missing field `{}` on {}", #field_js_name, #name_str),
+            ))?;
+          });
+        }
+      }
+
+      let pattern = if variant.is_tuple {
+        quote! { #name::#v_name(#(#field_bindings),*) }
+      } else {
+        quote! { #name::#v_name { #(#field_bindings),* } }
+      };
+
+      to_napi_branches.push(quote! {
+        #pattern => {
+          obj.set_field(#discriminant, #tag)?;
+          #(#field_setters)*
+        }
+      });
+      from_napi_branches.push(quote! {
+        #tag => {
+          #(#field_getters)*
+          Ok(#pattern)
+        }
+      });
+    }
+
+    quote! {
+      impl napi::bindgen_prelude::TypeName for #name {
+        fn type_name() -> &'static str {
+          #name_str
+        }
+
+        fn value_type() -> napi::ValueType {
+          napi::ValueType::Object
+        }
+      }
+
+      impl napi::bindgen_prelude::ValidateNapiValue for #name {}
+
+      impl napi::bindgen_prelude::FromNapiValue for #name {
+        unsafe fn from_napi_value(
+          env: napi::bindgen_prelude::sys::napi_env,
+          napi_val: napi::bindgen_prelude::sys::napi_value
+        ) -> napi::bindgen_prelude::Result<Self> {
+          let obj = napi::bindgen_prelude::Object::from_napi_value(env, napi_val)?;
+          let tag: String = obj.get_field(#discriminant)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+            napi::bindgen_prelude::Status::InvalidArg,
+            format!("Missing field `{}` on {}", #discriminant, #name_str),
+          ))?;
+
+          match tag.as_str() {
+            #(#from_napi_branches,)*
+            _ => {
+              Err(napi::bindgen_prelude::error!(
+                napi::bindgen_prelude::Status::InvalidArg,
+                "value `{}` does not match any variant of enum `{}`",
+                tag,
+                #name_str
+              ))
+            }
+          }
+        }
+      }
+
+      impl napi::bindgen_prelude::ToNapiValue for #name {
+        unsafe fn to_napi_value(
+          env: napi::bindgen_prelude::sys::napi_env,
+          val: Self
+        ) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
+          let env_wrapper = napi::bindgen_prelude::Env::from(env);
+          let mut obj = env_wrapper.create_object()?;
+
+          match val {
+            #(#to_napi_branches,)*
+          }
+
+          napi::bindgen_prelude::Object::to_napi_value(env, obj)
+        }
+      }
+    }
+  }
+}
+
+fn is_option_type(ty: &syn::Type) -> bool {
+  if let syn::Type::Path(syn::TypePath {
+    path: syn::Path { segments, .. },
+    ..
+  }) = ty
+  {
+    if let Some(last_path) = segments.last() {
+      return last_path.ident == "Option";
+    }
+  }
+  false
+}
@@ -38,6 +38,11 @@ impl TryToTokens for NapiFn {
       ));
     }
     let arg_ref_count = refs.len();
+    // `<Struct as Trait>::method(this, ...)`, the trait may not be in scope
+    let arg_names = match (&self.parent_trait, &self.fn_self) {
+      (Some(_), Some(_)) => std::iter::once(quote! { this }).chain(arg_names).collect(),
+      _ => arg_names,
+    };
     let receiver = self.gen_fn_receiver();
     let receiver_ret_name = Ident::new("_ret", Span::call_site());
     let ret = self.gen_fn_return(&receiver_ret_name);
@@ -106,7 +111,7 @@ impl TryToTokens for NapiFn {
         quote! { Ok(#receiver(#(#arg_names),*).await) }
       };
       quote! {
-        napi::bindgen_prelude::execute_tokio_future(env, async move { #call }, move |env, #receiver_ret_name| {
+        napi::bindgen_prelude::execute_future(env, async move { #call }, move |env, #receiver_ret_name| {
           _args_ref.drop(env);
           #ret
         })
@@ -155,22 +160,7 @@ impl TryToTokens for NapiFn {
 
     let function_call = if self.catch_unwind {
       quote! {
-        {
-          std::panic::catch_unwind(|| { #function_call })
-            .map_err(|e| {
-              let message = {
-                if let Some(string) = e.downcast_ref::<String>() {
-                  string.clone()
-                } else if let Some(string) = e.downcast_ref::<&str>() {
-                  string.to_string()
-                } else {
-                  format!("panic from Rust code: {:?}", e)
-                }
-              };
-              napi::Error::new(napi::Status::GenericFailure, message)
-            })
-            .and_then(|r| r)
-        }
+        napi::__private::catch_unwind(|| { #function_call })
       }
     } else {
       quote! {
@@ -345,7 +335,8 @@ impl NapiFn {
                 }
               }
             }
-            let (arg_conversion, arg_type) = self.gen_ty_arg_conversion(&ident, i, path)?;
+            let (arg_conversion, arg_type) =
+              self.gen_ty_arg_conversion(&ident, i, path, arg.default.as_ref())?;
             if NapiArgType::MutRef == arg_type {
               mut_ref_spans.push(path.ty.span());
             }
@@ -363,6 +354,22 @@ impl NapiFn {
       }
     }
 
+    // the accessor callbacks are called with no argument or the assigned value
+    let js_arg_count = self.args.len() - skipped_arg_count;
+    match self.kind {
+      FnKind::Getter if js_arg_count != 0 => bail_span!(
+        self.name,
+        "#[napi(getter)] can't take arguments, found {}",
+        js_arg_count
+      ),
+      FnKind::Setter if js_arg_count != 1 => bail_span!(
+        self.name,
+        "#[napi(setter)] must take exactly one argument, found {}",
+        js_arg_count
+      ),
+      _ => {}
+    }
+
     Ok(ArgConversions {
       arg_conversions,
       args,
@@ -379,8 +386,15 @@ impl NapiFn {
     arg_name: &Ident,
     index: usize,
     path: &syn::PatType,
+    default: Option<&syn::Expr>,
   ) -> BindgenResult<(TokenStream, NapiArgType)> {
     let ty = &*path.ty;
+    if let (Some(default), syn::Type::Reference(_)) = (default, ty) {
+      bail_span!(
+        default,
+        "#[napi(default = \"...\")] is not supported on reference arguments"
+      );
+    }
     let type_check = if self.return_if_invalid {
       quote! {
         if let Ok(maybe_promise) = <#ty as napi::bindgen_prelude::ValidateNapiValue>::validate(env, cb.get_arg(#index)) {
@@ -466,11 +480,28 @@ impl NapiFn {
         ))
       }
       _ => {
-        let q = quote! {
-          let #arg_name = {
-            #type_check
-            <#ty as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.get_arg(#index))?
-          };
+        let conversion = quote! {
+          #type_check
+          <#ty as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.get_arg(#index))?
+        };
+        let q = match default {
+          // omitted arguments are `undefined` in the callback info
+          Some(default) => quote! {
+            let #arg_name = {
+              let mut value_type = 0;
+              napi::check_status!(napi::sys::napi_typeof(env, cb.get_arg(#index), &mut value_type))?;
+              if value_type == napi::sys::ValueType::napi_undefined {
+                #default
+              } else {
+                #conversion
+              }
+            };
+          },
+          None => quote! {
+            let #arg_name = {
+              #conversion
+            };
+          },
         };
         Ok((q, NapiArgType::Value))
       }
@@ -534,10 +565,14 @@ impl NapiFn {
         // impossible, panic! in parser
         unreachable!();
       }
-      Some(FnSelf::Ref) | Some(FnSelf::MutRef) => quote! { this.#name },
-      None => match &self.parent {
-        Some(class) => quote! { #class::#name },
-        None => quote! { #name },
+      Some(FnSelf::Ref) | Some(FnSelf::MutRef) => match (&self.parent, &self.parent_trait) {
+        (Some(class), Some(trait_path)) => quote! { <#class as #trait_path>::#name },
+        _ => quote! { this.#name },
+      },
+      None => match (&self.parent, &self.parent_trait) {
+        (Some(class), Some(trait_path)) => quote! { <#class as #trait_path>::#name },
+        (Some(class), None) => quote! { #class::#name },
+        _ => quote! { #name },
       },
     }
   }
@@ -6,7 +6,8 @@ use quote::ToTokens;
 
 use crate::{
   codegen::{get_intermediate_ident, js_mod_to_token_stream},
-  BindgenResult, FnKind, NapiImpl, NapiStruct, NapiStructKind, TryToTokens,
+  BindgenResult, Disposable, FnKind, NapiFinalize, NapiGenericStruct, NapiImpl, NapiStruct,
+  NapiStructKind, TryToTokens,
 };
 
 static NAPI_IMPL_ID: AtomicU32 = AtomicU32::new(0);
@@ -99,6 +100,7 @@ fn gen_napi_value_map_impl(name: &Ident, to_napi_val_impl: TokenStream) -> Token
       ) -> napi::bindgen_prelude::Result<&'static Self> {
         let mut wrapped_val: *mut std::ffi::c_void = std::ptr::null_mut();
 
+        napi::__private::check_class_instance::<#name>(env, napi_val)?;
         napi::bindgen_prelude::check_status!(
           napi::bindgen_prelude::sys::napi_unwrap(env, napi_val, &mut wrapped_val),
           "Failed to recover `{}` type from napi value",
@@ -116,6 +118,7 @@ fn gen_napi_value_map_impl(name: &Ident, to_napi_val_impl: TokenStream) -> Token
       ) -> napi::bindgen_prelude::Result<&'static mut Self> {
         let mut wrapped_val: *mut std::ffi::c_void = std::ptr::null_mut();
 
+        napi::__private::check_class_instance::<#name>(env, napi_val)?;
         napi::bindgen_prelude::check_status!(
           napi::bindgen_prelude::sys::napi_unwrap(env, napi_val, &mut wrapped_val),
           "Failed to recover `{}` type from napi value",
@@ -364,6 +367,7 @@ impl NapiStruct {
             "Failed to wrap native object of class `{}`",
             #js_name_raw
           )?;
+          napi::__private::tag_class_instance::<#name>(env, result)?;
           napi::bindgen_prelude::Reference::<#name>::add_ref(env, wrapped_value, (wrapped_value, object_ref, finalize_callbacks_ptr));
           Ok(result)
         }
@@ -488,38 +492,48 @@ impl NapiStruct {
       } else {
         false
       };
+      // `#[napi(readonly)]` fields are defined as non-writable properties
+      let writable = !field.getter || field.setter;
+      let field_c_name = format!("{}\0", field_js_name);
+      let set_field = |value: TokenStream| {
+        quote! { fields.push(#field_c_name, #value, #writable)?; }
+      };
       match &field.name {
         syn::Member::Named(ident) => {
           let alias_ident = format_ident!("{}_", ident);
           field_destructions.push(quote! { #ident: #alias_ident });
           if is_optional_field {
+            let set_value = set_field(quote! { #alias_ident });
             obj_field_setters.push(match self.use_nullable {
               false => quote! {
-                if #alias_ident.is_some() {
-                  obj.set(#field_js_name, #alias_ident)?;
-                }
-              },
-              true => quote! {
                 if let Some(#alias_ident) = #alias_ident {
-                  obj.set(#field_js_name, #alias_ident)?;
-                } else {
-                  obj.set(#field_js_name, napi::bindgen_prelude::Null)?;
+                  #set_value
                 }
               },
+              true => {
+                let set_null = set_field(quote! { napi::bindgen_prelude::Null });
+                quote! {
+                  if let Some(#alias_ident) = #alias_ident {
+                    #set_value
+                  } else {
+                    #set_null
+                  }
+                }
+              }
             });
           } else {
-            obj_field_setters.push(quote! { obj.set(#field_js_name, #alias_ident)?; });
+            obj_field_setters.push(set_field(quote! { #alias_ident }));
           }
           if is_optional_field && !self.use_nullable {
             obj_field_getters.push(quote! {
-              let #alias_ident: #ty = obj.get(#field_js_name).map_err(|mut err| {
+              let #alias_ident: #ty = obj.get_field(#field_js_name).map_err(|mut err| {
                 err.reason = format!("{} on {}.{}", err.reason, #name_str, #field_js_name);
                 err
               })?;
             });
           } else {
             obj_field_getters.push(quote! {
-              let #alias_ident: #ty = obj.get(#field_js_name).map_err(|mut err| {
+              let #alias_ident: #ty = obj.get_field(#field_js_name).map_err(|mut err| {
                 err.reason = format!("{} on {}.{}", err.reason, #name_str, #field_js_name);
                 err
               })?.ok_or_else(|| napi::bindgen_prelude::Error::new(
@@ -532,28 +546,32 @@ impl NapiStruct {
         syn::Member::Unnamed(i) => {
           field_destructions.push(quote! { arg #i });
           if is_optional_field {
+            let set_value = set_field(quote! { arg #i });
             obj_field_setters.push(match self.use_nullable {
               false => quote! {
-                if arg #1.is_some() {
-                  obj.set(#field_js_name, arg #i)?;
-                }
-              },
-              true => quote! {
                 if let Some(arg #i) = arg #i {
-                  obj.set(#field_js_name, arg #i)?;
-                } else {
-                  obj.set(#field_js_name, napi::bindgen_prelude::Null)?;
+                  #set_value
                 }
               },
+              true => {
+                let set_null = set_field(quote! { napi::bindgen_prelude::Null });
+                quote! {
+                  if let Some(arg #i) = arg #i {
+                    #set_value
+                  } else {
+                    #set_null
+                  }
+                }
+              }
             });
           } else {
-            obj_field_setters.push(quote! { obj.set(#field_js_name, arg #1)?; });
+            obj_field_setters.push(set_field(quote! { arg #i }));
           }
           if is_optional_field && !self.use_nullable {
-            obj_field_getters.push(quote! { let arg #i: #ty = obj.get(#field_js_name)?; });
+            obj_field_getters.push(quote! { let arg #i: #ty = obj.get_field(#field_js_name)?; });
           } else {
             obj_field_getters.push(quote! {
-              let arg #i: #ty = obj.get(#field_js_name)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
+              let arg #i: #ty = obj.get_field(#field_js_name)?.ok_or_else(|| napi::bindgen_prelude::Error::new(
                 napi::bindgen_prelude::Status::InvalidArg,
                 format!("Missing field `{}`", #field_js_name),
               ))?;
@@ -573,17 +591,17 @@ impl NapiStruct {
       }
     };
 
+    let fields_len = self.fields.len();
     let to_napi_value = if self.object_to_js {
       quote! {
         impl napi::bindgen_prelude::ToNapiValue for #name {
           unsafe fn to_napi_value(env: napi::bindgen_prelude::sys::napi_env, val: #name) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
-            let env_wrapper = napi::bindgen_prelude::Env::from(env);
-            let mut obj = env_wrapper.create_object()?;
+            let mut fields = napi::bindgen_prelude::ObjectFields::<#fields_len>::new(env);
 
             let #destructed_fields = val;
             #(#obj_field_setters)*
 
-            napi::bindgen_prelude::Object::to_napi_value(env, obj)
+            fields.into_object()
           }
         }
       }
@@ -739,6 +757,27 @@ impl NapiStruct {
 
     if self.kind == NapiStructKind::Constructor {
       props.push(quote! { napi::bindgen_prelude::Property::new("constructor").unwrap().with_ctor(constructor) });
+    } else if self.private_constructor {
+      let message = format!(
+        "Class `{}` can't be constructed from JavaScript, its instances are created by Rust\0",
+        self.js_name
+      );
+      props.push(quote! {
+        {
+          extern "C" fn private_constructor(
+            env: napi::bindgen_prelude::sys::napi_env,
+            _cb: napi::bindgen_prelude::sys::napi_callback_info
+          ) -> napi::bindgen_prelude::sys::napi_value {
+            unsafe {
+              napi::__private::throw_without_constructor(
+                env,
+                std::ffi::CStr::from_bytes_with_nul_unchecked(#message.as_bytes()),
+              )
+            }
+          }
+          napi::bindgen_prelude::Property::new("constructor").unwrap().with_ctor(private_constructor)
+        }
+      });
     }
 
     for field in self.fields.iter() {
@@ -788,6 +827,17 @@ impl NapiStruct {
       props.push(prop);
     }
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let name = &self.name;
+    let register_extends = self.extends.as_ref().map(|base| {
+      let base_str = base.to_string();
+      quote! {
+        napi::__private::register_class_extends(#name_str, #base_str);
+        napi::__private::register_subclass_type_tag(
+          <#base as napi::bindgen_prelude::JavaScriptClass>::TYPE_TAG,
+          <#name as napi::bindgen_prelude::JavaScriptClass>::TYPE_TAG,
+        );
+      }
+    });
     quote! {
       #[allow(non_snake_case)]
       #[allow(clippy::all)]
@@ -795,6 +845,7 @@ impl NapiStruct {
       #[napi::bindgen_prelude::ctor]
       fn #struct_register_name() {
         napi::__private::register_class(#name_str, #js_mod_ident, #js_name, vec![#(#props),*]);
+        #register_extends
       }
 
       #[allow(non_snake_case)]
@@ -803,12 +854,31 @@ impl NapiStruct {
       #[no_mangle]
       extern "C" fn #struct_register_name() {
         napi::__private::register_class(#name_str, #js_mod_ident, #js_name, vec![#(#props),*]);
+        #register_extends
       }
     }
   }
 
   fn gen_instance_of_impl(&self, name: &Ident, js_name: &str) -> TokenStream {
+    let type_tag = match (&self.type_tag, &self.extends) {
+      (Some((lower, upper)), _) => quote! {
+        const TYPE_TAG: Option<napi::TypeTag> = Some(napi::TypeTag::new(#lower, #upper));
+      },
+      // the instances of the subclass are also accepted by the base class, see `register_subclass_type_tag`
+      (None, Some(extends)) => quote! {
+        const TYPE_TAG: Option<napi::TypeTag> = match <#extends as napi::bindgen_prelude::JavaScriptClass>::TYPE_TAG {
+          Some(tag) => Some(tag.subclass(#js_name)),
+          None => None,
+        };
+      },
+      (None, None) => quote! {},
+    };
     quote! {
+      impl napi::bindgen_prelude::JavaScriptClass for #name {
+        const JS_NAME: &'static str = #js_name;
+        #type_tag
+      }
+
       impl #name {
         pub fn instance_of<V: napi::NapiRaw>(env: napi::Env, value: V) -> napi::Result<bool> {
           if let Some(ctor_ref) = napi::bindgen_prelude::get_class_constructor(#js_name) {
@@ -834,16 +904,95 @@ impl NapiStruct {
   }
 }
 
+impl TryToTokens for NapiGenericStruct {
+  fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
+    let name = &self.name;
+    let vis = &self.vis;
+    for instance in self.instances.iter() {
+      let alias = &instance.name;
+      let args = &instance.args;
+      (quote! {
+        #vis type #alias = #name<#(#args),*>;
+      })
+      .to_tokens(tokens);
+    }
+
+    Ok(())
+  }
+}
+
 impl TryToTokens for NapiImpl {
   fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
     self.gen_helper_mod()?.to_tokens(tokens);
+    if self.implement_std_iterator {
+      self.gen_generator_impl().to_tokens(tokens);
+    }
+    if let Some(finalize) = &self.finalize {
+      self.gen_finalize_impl(finalize).to_tokens(tokens);
+    }
 
     Ok(())
   }
 }
 
 impl NapiImpl {
+  fn gen_generator_impl(&self) -> TokenStream {
+    let name = &self.name;
+    quote! {
+      impl napi::bindgen_prelude::Generator for #name {
+        type Yield = <#name as Iterator>::Item;
+        type Next = ();
+        type Return = ();
+
+        fn next(&mut self, _value: Option<Self::Next>) -> Option<Self::Yield> {
+          Iterator::next(self)
+        }
+      }
+    }
+  }
+
+  fn gen_finalize_impl(&self, finalize: &NapiFinalize) -> TokenStream {
+    let name = &self.name;
+    let method = &finalize.name;
+    let receiver = if finalize.by_value {
+      quote! { self }
+    } else {
+      quote! { &mut self }
+    };
+    let env = if finalize.with_env {
+      quote! { env }
+    } else {
+      quote! { _env }
+    };
+    let args = if finalize.with_env {
+      quote! { #receiver, env }
+    } else {
+      quote! { #receiver }
+    };
+    let call = if finalize.is_ret_result {
+      quote! { #name::#method(#args) }
+    } else {
+      quote! {
+        #name::#method(#args);
+        Ok(())
+      }
+    };
+    let self_arg = if finalize.by_value {
+      quote! { self }
+    } else {
+      quote! { mut self }
+    };
+    quote! {
+      impl napi::bindgen_prelude::ObjectFinalize for #name {
+        fn finalize(#self_arg, #env: napi::bindgen_prelude::Env) -> napi::bindgen_prelude::Result<()> {
+          #call
+        }
+      }
+    }
+  }
+
   fn gen_helper_mod(&self) -> BindgenResult<TokenStream> {
+    let name_ident = &self.name;
     let name_str = self.name.to_string();
     let js_name = format!("{}\0", self.js_name);
     let mod_name = Ident::new(
@@ -877,7 +1026,7 @@ impl NapiImpl {
         attribute |= super::PROPERTY_ATTRIBUTE_CONFIGURABLE;
       }
 
-      let prop = props.entry(&item.js_name).or_insert_with(|| {
+      let prop = props.entry(item.js_name.as_str()).or_insert_with(|| {
         quote! {
           napi::bindgen_prelude::Property::new(#js_name).unwrap().with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
         }
@@ -897,6 +1046,67 @@ impl NapiImpl {
       };
 
       appendix.to_tokens(prop);
+
+      // `console.log` and `util.inspect` ignore `toString`
+      if item.is_to_string {
+        props.insert("[nodejs.util.inspect.custom]", quote! {
+          napi::bindgen_prelude::Property::default()
+            .key_symbol_for("nodejs.util.inspect.custom")
+            .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
+            .with_method(#intermediate_name)
+        });
+      }
+
+      if let Some(disposable) = item.disposable {
+        let (key, symbol) = match disposable {
+          Disposable::Sync => ("[Symbol.dispose]", quote! { Dispose }),
+          Disposable::Async => ("[Symbol.asyncDispose]", quote! { AsyncDispose }),
+        };
+        if props.contains_key(key) {
+          bail_span!(item.name, "{} is already defined by another method", key);
+        }
+        props.insert(key, quote! {
+          napi::bindgen_prelude::Property::default()
+            .key_symbol(napi::WellKnownSymbol::#symbol)
+            .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
+            .with_method(#intermediate_name)
+        });
+      }
+    }
+
+    for item in self.consts.iter() {
+      if props.contains_key(item.js_name.as_str()) {
+        bail_span!(
+          item.name,
+          "`{}` is already defined by a method",
+          item.js_name
+        );
+      }
+      let js_name = Literal::string(&item.js_name);
+      let ty = &item.ty;
+      let const_name = &item.name;
+      let getter_name = Ident::new(
+        &format!("__napi_static_const__{}", const_name),
+        Span::call_site(),
+      );
+      // the value is converted in every access, the `Env` isn't available when the class is registered
+      methods.push(quote! {
+        extern "C" fn #getter_name(
+          env: napi::bindgen_prelude::sys::napi_env,
+          _cb: napi::bindgen_prelude::sys::napi_callback_info
+        ) -> napi::bindgen_prelude::sys::napi_value {
+          unsafe { <#ty as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, #name_ident::#const_name) }
+            .unwrap_or_else(|e| {
+              unsafe { napi::bindgen_prelude::JsError::from(e).throw_into(env) };
+              std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+            })
+        }
+      });
+      props.insert(item.js_name.as_str(), quote! {
+        napi::bindgen_prelude::Property::new(#js_name).unwrap()
+          .with_getter(#getter_name)
+          .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::Static | napi::bindgen_prelude::PropertyAttributes::Enumerable)
+      });
     }
 
     let mut props: Vec<_> = props.into_iter().collect();
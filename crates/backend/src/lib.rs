@@ -64,8 +64,10 @@ macro_rules! napi_ast_impl {
 napi_ast_impl! {
  (Fn, NapiFn),
  (Struct, NapiStruct),
+ (GenericStruct, NapiGenericStruct),
  (Impl, NapiImpl),
  (Enum, NapiEnum),
+ (TaggedEnum, NapiTaggedEnum),
  (Const, NapiConst),
 }
 
@@ -18,6 +18,13 @@ pub static NAPI_RS_CLI_VERSION: Lazy<semver::Version> = Lazy::new(|| {
   semver::Version::parse(&version).unwrap_or_else(|_| semver::Version::new(0, 0, 0))
 });
 
+/// `serde_json::Value` is emitted as `any` by default, `napi build --dts-json-value unknown` sets `NAPI_JSON_VALUE_TS_TYPE=unknown` to emit `unknown` instead
+static JSON_VALUE_IS_UNKNOWN: Lazy<bool> = Lazy::new(|| {
+  env::var("NAPI_JSON_VALUE_TS_TYPE")
+    .map(|ty| ty == "unknown")
+    .unwrap_or(false)
+});
+
 pub static NAPI_RS_CLI_VERSION_WITH_SHARED_CRATES_FIX: Lazy<semver::Version> =
   Lazy::new(|| semver::Version::new(2, 15, 1));
 
@@ -29,6 +36,10 @@ pub struct TypeDef {
   pub def: String,
   pub js_mod: Option<String>,
   pub js_doc: String,
+  /// JavaScript name of the base class
+  pub extends: Option<String>,
+  /// Interfaces implemented by the class, e.g. `Disposable`, merged into the class by the CLI
+  pub implements: Vec<String>,
 }
 
 thread_local! {
@@ -116,6 +127,24 @@ impl Display for TypeDef {
     } else {
       "".to_string()
     };
+    let extends = if let Some(extends) = &self.extends {
+      format!(", \"extends\": \"{}\"", extends)
+    } else {
+      "".to_string()
+    };
+    let implements = if self.implements.is_empty() {
+      "".to_string()
+    } else {
+      format!(
+        ", \"implements\": [{}]",
+        self
+          .implements
+          .iter()
+          .map(|name| format!("\"{}\"", name))
+          .collect::<Vec<_>>()
+          .join(", ")
+      )
+    };
     // TODO: remove this in v3
     // This is a workaround for lower version of @napi-rs/cli
     // See https://github.com/napi-rs/napi-rs/pull/1531
@@ -126,7 +155,7 @@ impl Display for TypeDef {
     };
     write!(
       f,
-      r#"{}{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}}}"#,
+      r#"{}{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}{}{}}}"#,
       prefix,
       self.kind,
       self.name,
@@ -134,6 +163,8 @@ impl Display for TypeDef {
       escape_json(&self.def),
       original_name,
       js_mod,
+      extends,
+      implements,
     )
   }
 }
@@ -150,13 +181,36 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
     ("JsObject", ("object", false, false)),
     ("Object", ("object", false, false)),
     ("Array", ("unknown[]", false, false)),
-    ("Value", ("any", false, false)),
-    ("Map", ("Record<string, any>", false, false)),
+    (
+      "Value",
+      (
+        if *JSON_VALUE_IS_UNKNOWN { "unknown" } else { "any" },
+        false,
+        false,
+      ),
+    ),
+    (
+      "Map",
+      (
+        if *JSON_VALUE_IS_UNKNOWN {
+          "Record<string, unknown>"
+        } else {
+          "Record<string, any>"
+        },
+        false,
+        false,
+      ),
+    ),
     ("HashMap", ("Record<{}, {}>", false, false)),
     ("BTreeMap", ("Record<{}, {}>", false, false)),
     ("IndexMap", ("Record<{}, {}>", false, false)),
+    ("JsMap", ("Map<any, any>", false, false)),
+    ("HashSet", ("Set<{}>", false, false)),
+    ("JsSet", ("Set<any>", false, false)),
     ("ArrayBuffer", ("ArrayBuffer", false, false)),
     ("JsArrayBuffer", ("ArrayBuffer", false, false)),
+    ("JsSharedArrayBuffer", ("SharedArrayBuffer", false, false)),
+    ("SharedMemory", ("SharedArrayBuffer", false, false)),
     ("Int8Array", ("Int8Array", false, false)),
     ("Uint8Array", ("Uint8Array", false, false)),
     ("Uint8ClampedArray", ("Uint8ClampedArray", false, false)),
@@ -186,6 +240,7 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
     ("ClassInstance", ("{}", false, false)),
     ("Function", ("({}) => {}", true, false)),
     ("FunctionRef", ("({}) => {}", true, false)),
+    ("Nullable", ("{} | null", false, true)),
     ("Either", ("{} | {}", false, true)),
     ("Either3", ("{} | {} | {}", false, true)),
     ("Either4", ("{} | {} | {} | {}", false, true)),
@@ -213,8 +268,14 @@ static KNOWN_TYPES: Lazy<HashMap<&'static str, (&'static str, bool, bool)>> = La
     ("Either26", ("{} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {}", false, true)),
     ("external", ("object", false, false)),
     ("Promise", ("Promise<{}>", false, false)),
+    ("AsyncIterator", ("AsyncIterableIterator<{}>", false, false)),
+    ("NodeReadable", ("NodeJS.ReadableStream", false, false)),
+    ("NodeWritable", ("NodeJS.WritableStream", false, false)),
+    ("JsPromise", ("Promise<unknown>", false, false)),
     ("AbortSignal", ("AbortSignal", false, false)),
+    ("CancellationToken", ("AbortSignal", false, false)),
     ("JsGlobal", ("typeof global", false, false)),
+    ("JsProcess", ("typeof process", false, false)),
     ("External", ("ExternalObject<{}>", false, false)),
     ("unknown", ("unknown", false, false)),
     ("Unknown", ("unknown", false, false)),
@@ -280,6 +341,20 @@ fn is_ts_function_type_notation(ty: &Type) -> bool {
 }
 
 // return (type, is_optional)
+fn typed_array_element_ident(arguments: &syn::PathArguments) -> Option<String> {
+  if let syn::PathArguments::AngleBracketed(arguments) = arguments {
+    if let Some(syn::GenericArgument::Type(Type::Path(TypePath { path, .. }))) =
+      arguments.args.first()
+    {
+      return path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string());
+    }
+  }
+  None
+}
+
 pub fn ty_to_ts_type(
   ty: &Type,
   is_return_ty: bool,
@@ -405,6 +480,11 @@ pub fn ty_to_ts_type(
               Some((rust_ty, false))
             }
           });
+        } else if rust_ty == "TypedArray" {
+          // TypedArray<f64> => Float64Array
+          ts_ty = typed_array_element_ident(arguments)
+            .and_then(|elem| crate::TYPEDARRAY_SLICE_TYPES.get(elem.as_str()))
+            .map(|js_type| (js_type.to_string(), false));
         } else if let Some(&(known_ty, _, _)) = KNOWN_TYPES.get(rust_ty.as_str()) {
           if rust_ty == "()" && is_return_ty {
             ts_ty = Some(("void".to_owned(), false));
@@ -420,6 +500,22 @@ pub fn ty_to_ts_type(
           .with(|c| c.borrow_mut().get(rust_ty.as_str()).cloned())
         {
           ts_ty = Some((t, false));
+        } else if let Some(t) = crate::typegen::r#struct::GENERIC_STRUCTS
+          .with(|g| g.borrow().get(rust_ty.as_str()).cloned())
+        {
+          // Store<u32> => Store<number>, which resolves to the concrete class
+          ts_ty = Some((
+            format!(
+              "{}<{}>",
+              t,
+              args
+                .into_iter()
+                .map(|(arg, _)| arg)
+                .collect::<Vec<_>>()
+                .join(", ")
+            ),
+            false,
+          ));
         } else if rust_ty == TSFN_RUST_TY {
           let fatal_tsfn = match args.last() {
             Some((arg, _)) => arg == "false",
@@ -1,5 +1,5 @@
 use super::{add_alias, ToTypeDef, TypeDef};
-use crate::{js_doc_from_comments, NapiEnum, NapiEnumValue};
+use crate::{js_doc_from_comments, ty_to_ts_type, NapiEnum, NapiEnumValue, NapiTaggedEnum};
 
 impl ToTypeDef for NapiEnum {
   fn to_type_def(&self) -> Option<TypeDef> {
@@ -20,6 +20,8 @@ impl ToTypeDef for NapiEnum {
       def: self.gen_ts_variants(),
       js_doc: js_doc_from_comments(&self.comments),
       js_mod: self.js_mod.to_owned(),
+      extends: None,
+      implements: vec![],
     })
   }
 }
@@ -40,3 +42,52 @@ impl NapiEnum {
       .join(",\n ")
   }
 }
+
+impl ToTypeDef for NapiTaggedEnum {
+  fn to_type_def(&self) -> Option<TypeDef> {
+    if self.skip_typescript {
+      return None;
+    }
+
+    add_alias(self.name.to_string(), self.js_name.to_string());
+
+    Some(TypeDef {
+      kind: "type".to_owned(),
+      name: self.js_name.to_owned(),
+      original_name: Some(self.name.to_string()),
+      def: self.gen_ts_variants(),
+      js_doc: js_doc_from_comments(&self.comments),
+      js_mod: self.js_mod.to_owned(),
+      extends: None,
+      implements: vec![],
+    })
+  }
+}
+
+impl NapiTaggedEnum {
+  fn gen_ts_variants(&self) -> String {
+    self
+      .variants
+      .iter()
+      .map(|v| {
+        let mut fields = vec![format!("{}: '{}'", self.discriminant, v.tag)];
+        fields.extend(v.fields.iter().filter(|f| !f.skip_typescript).map(|f| {
+          let (ty, is_optional) = ty_to_ts_type(&f.ty, false, true, false);
+          let ty = f.ts_type.clone().unwrap_or(ty);
+          format!(
+            "{}{}: {}",
+            f.js_name,
+            if is_optional { "?" } else { "" },
+            ty
+          )
+        }));
+        format!(
+          "{}| {{ {} }}",
+          js_doc_from_comments(&v.comments),
+          fields.join(", ")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\\n")
+  }
+}
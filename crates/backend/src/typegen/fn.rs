@@ -71,6 +71,31 @@ impl ToTypeDef for NapiFn {
       return None;
     }
 
+    if !self.ts_overloads.is_empty() {
+      let def = self
+        .ts_overloads
+        .iter()
+        .map(|overload| {
+          format!(
+            "{prefix} {name}{overload}",
+            prefix = self.gen_ts_func_prefix(),
+            name = &self.js_name,
+          )
+        })
+        .collect::<Vec<_>>()
+        .join("\\n");
+      return Some(TypeDef {
+        kind: "fn".to_owned(),
+        name: self.js_name.clone(),
+        original_name: None,
+        def,
+        js_mod: self.js_mod.to_owned(),
+        js_doc: js_doc_from_comments(&self.comments),
+        extends: None,
+        implements: vec![],
+      });
+    }
+
     let def = format!(
       r#"{prefix} {name}{generic}({args}){ret}"#,
       prefix = self.gen_ts_func_prefix(),
@@ -98,6 +123,8 @@ impl ToTypeDef for NapiFn {
       def,
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
+      extends: None,
+      implements: vec![],
     })
   }
 }
@@ -199,7 +226,13 @@ impl NapiFn {
 
             let (ts_type, is_optional) = ty_to_ts_type(&path.ty, false, false, false);
             let ts_type = arg.use_overridden_type_or(|| ts_type);
-            let arg = path.pat.to_token_stream().to_string().to_case(Case::Camel);
+            // the default is applied when the argument is omitted
+            let is_optional = is_optional || arg.default.is_some();
+            let arg = match path.pat.as_ref() {
+              // `FooOptions { a, b }: FooOptions`, the fields are read from a single options object
+              Pat::Struct(_) | Pat::TupleStruct(_) => "options".to_owned(),
+              pat => pat.to_token_stream().to_string().to_case(Case::Camel),
+            };
 
             Some(FnArg {
               arg,
@@ -2,11 +2,15 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 
 use super::{add_alias, ToTypeDef, TypeDef};
-use crate::{js_doc_from_comments, ty_to_ts_type, NapiImpl, NapiStruct, NapiStructKind};
+use crate::{
+  js_doc_from_comments, ty_to_ts_type, Disposable, NapiGenericStruct, NapiImpl, NapiStruct,
+  NapiStructKind,
+};
 
 thread_local! {
   pub(crate) static TASK_STRUCTS: RefCell<HashMap<String, String>> = Default::default();
   pub(crate) static CLASS_STRUCTS: RefCell<HashMap<String, String>> = Default::default();
+  pub(crate) static GENERIC_STRUCTS: RefCell<HashMap<String, String>> = Default::default();
 }
 
 impl ToTypeDef for NapiStruct {
@@ -28,6 +32,54 @@ impl ToTypeDef for NapiStruct {
       def: self.gen_ts_class(),
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
+      extends: self.extends.as_ref().map(|base| {
+        CLASS_STRUCTS
+          .with(|c| c.borrow().get(&base.to_string()).cloned())
+          .unwrap_or_else(|| base.to_string())
+      }),
+      implements: vec![],
+    })
+  }
+}
+
+impl ToTypeDef for NapiGenericStruct {
+  fn to_type_def(&self) -> Option<TypeDef> {
+    GENERIC_STRUCTS.with(|g| {
+      g.borrow_mut()
+        .insert(self.name.to_string(), self.js_name.clone());
+    });
+
+    let params = self
+      .type_params
+      .iter()
+      .map(|p| p.to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+    // Store<T> = [T] extends [number] ? StoreU32 : [T] extends [string] ? StoreString : never
+    let mut def = self
+      .instances
+      .iter()
+      .map(|instance| {
+        let args = instance
+          .args
+          .iter()
+          .map(|arg| ty_to_ts_type(arg, false, false, false).0)
+          .collect::<Vec<_>>()
+          .join(", ");
+        format!("[{}] extends [{}] ? {} :", params, args, instance.js_name)
+      })
+      .collect::<Vec<_>>();
+    def.push("never".to_owned());
+
+    Some(TypeDef {
+      kind: "type".to_owned(),
+      name: format!("{}<{}>", self.js_name, params),
+      original_name: Some(self.name.to_string()),
+      def: def.join("\\n"),
+      js_mod: self.js_mod.to_owned(),
+      js_doc: js_doc_from_comments(&self.comments),
+      extends: None,
+      implements: vec![],
     })
   }
 }
@@ -73,36 +125,94 @@ impl ToTypeDef for NapiImpl {
         ),
         js_mod: self.js_mod.to_owned(),
         js_doc: "".to_string(),
+        extends: None,
+        implements: vec![],
       })
     } else {
+      let mut implements = vec![];
+      let mut def = self
+        .consts
+        .iter()
+        .filter(|c| !c.skip_typescript)
+        .map(|c| {
+          format!(
+            "{}static readonly {}: {}",
+            js_doc_from_comments(&c.comments),
+            c.js_name,
+            ty_to_ts_type(&c.ty, false, false, false).0
+          )
+        })
+        .collect::<Vec<_>>();
+      def.extend(self.items.iter().filter_map(|f| {
+        if f.skip_typescript {
+          None
+        } else {
+          Some(format!(
+            "{}{}",
+            js_doc_from_comments(&f.comments),
+            f.to_type_def()
+              .map_or(String::default(), |type_def| type_def.def)
+          ))
+        }
+      }));
+      for f in self.items.iter().filter(|f| !f.skip_typescript) {
+        match f.disposable {
+          Some(Disposable::Sync) => {
+            def.push("[Symbol.dispose](): void".to_owned());
+            implements.push("Disposable".to_owned());
+          }
+          Some(Disposable::Async) => {
+            def.push("[Symbol.asyncDispose](): Promise<void>".to_owned());
+            implements.push("AsyncDisposable".to_owned());
+          }
+          None => {}
+        }
+      }
+      if let Some(trait_js_name) = &self.trait_js_name {
+        implements.push(trait_js_name.to_owned());
+      }
       Some(TypeDef {
         kind: "impl".to_owned(),
         name: self.js_name.to_owned(),
         original_name: None,
-        def: self
-          .items
-          .iter()
-          .filter_map(|f| {
-            if f.skip_typescript {
-              None
-            } else {
-              Some(format!(
-                "{}{}",
-                js_doc_from_comments(&f.comments),
-                f.to_type_def()
-                  .map_or(String::default(), |type_def| type_def.def)
-              ))
-            }
-          })
-          .collect::<Vec<_>>()
-          .join("\\n"),
+        def: def.join("\\n"),
         js_mod: self.js_mod.to_owned(),
         js_doc: "".to_string(),
+        extends: None,
+        implements,
       })
     }
   }
 }
 
+impl NapiImpl {
+  /// The `interface` of an `impl Trait for Struct` block, with the instance methods exported from it.
+  ///
+  /// Every class implementing the trait emits it, the CLI merges them.
+  pub fn to_trait_type_def(&self) -> Option<TypeDef> {
+    let trait_js_name = self.trait_js_name.as_ref()?;
+    let def = self
+      .items
+      .iter()
+      .filter(|f| !f.skip_typescript && f.fn_self.is_some())
+      .filter_map(|f| {
+        f.to_type_def()
+          .map(|type_def| format!("{}{}", js_doc_from_comments(&f.comments), type_def.def))
+      })
+      .collect::<Vec<_>>();
+    Some(TypeDef {
+      kind: "interface".to_owned(),
+      name: trait_js_name.to_owned(),
+      original_name: None,
+      def: def.join("\\n"),
+      js_mod: self.js_mod.to_owned(),
+      js_doc: "".to_string(),
+      extends: None,
+      implements: vec![],
+    })
+  }
+}
+
 impl NapiStruct {
   fn gen_ts_class(&self) -> String {
     let mut ctor_args = vec![];
@@ -147,6 +257,13 @@ impl NapiStruct {
 
     if self.kind == NapiStructKind::Constructor {
       format!("{}\\nconstructor({})", def, ctor_args.join(", "))
+    } else if self.private_constructor {
+      // constructing throws, instances are only returned from Rust
+      if def.is_empty() {
+        "private constructor()".to_owned()
+      } else {
+        format!("{}\\nprivate constructor()", def)
+      }
     } else {
       def
     }
@@ -8,10 +8,12 @@ use std::env;
 pub fn setup() {
   println!("cargo:rerun-if-env-changed=DEBUG_GENERATED_CODE");
   println!("cargo:rerun-if-env-changed=TYPE_DEF_TMP_PATH");
+  println!("cargo:rerun-if-env-changed=NAPI_JSON_VALUE_TS_TYPE");
   println!("cargo:rerun-if-env-changed=CARGO_CFG_NAPI_RS_CLI_VERSION");
 
   match std::env::var("CARGO_CFG_TARGET_OS").as_deref() {
-    Ok("macos") => {
+    // the addons are loaded by the host app on iOS as well, the `napi_*` symbols are resolved at runtime
+    Ok("macos") | Ok("ios") => {
       macos::setup();
     }
     Ok("android") => if android::setup().is_ok() {},
@@ -19,6 +21,7 @@ pub fn setup() {
       wasi::setup();
     }
     Ok("windows") => {
+      // with MSVC the `napi_*` symbols are loaded from the host process at runtime, so it works with `electron.exe` as is
       if let Ok("gnu") = env::var("CARGO_CFG_TARGET_ENV").as_deref() {
         windows::setup_gnu();
       }
@@ -98,18 +98,7 @@ pub fn get_execute_js_code(new_fn_name: Ident, function_kind: FunctionKind) -> T
     }
   };
   quote! {
-    match panic::catch_unwind(AssertUnwindSafe(move || #new_fn_name(ctx))).map_err(|e| {
-      let message = {
-        if let Some(string) = e.downcast_ref::<String>() {
-          string.clone()
-        } else if let Some(string) = e.downcast_ref::<&str>() {
-          string.to_string()
-        } else {
-          format!("panic from Rust code: {:?}", e)
-        }
-      };
-      Error::from_reason(message)
-    }).and_then(|v| v) {
+    match napi::__private::catch_unwind(move || #new_fn_name(ctx)) {
       #return_token_stream
       Err(e) => {
         unsafe { napi::JsError::from(e).throw_into(raw_env) };
@@ -5,9 +5,9 @@ use std::io::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::parser::{attrs::BindgenAttrs, ParseNapi};
-#[cfg(feature = "type-def")]
-use napi_derive_backend::ToTypeDef;
 use napi_derive_backend::{BindgenResult, Napi, TryToTokens};
+#[cfg(feature = "type-def")]
+use napi_derive_backend::{NapiItem, ToTypeDef};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{Attribute, Item};
@@ -80,14 +80,16 @@ pub fn expand(attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStrea
             _ => &mut empty_attrs,
           },
         ) {
-          let napi = item.parse_napi(&mut tokens, &item_opts)?;
+          let napis = item.parse_napi(&mut tokens, &item_opts)?;
           item_opts.check_used()?;
-          napi.try_to_tokens(&mut tokens)?;
+          for napi in napis {
+            napi.try_to_tokens(&mut tokens)?;
 
-          #[cfg(feature = "type-def")]
-          {
-            output_type_def(&napi);
-            output_wasi_register_def(&napi);
+            #[cfg(feature = "type-def")]
+            {
+              output_type_def(&napi);
+              output_wasi_register_def(&napi);
+            }
           }
         } else {
           item.to_tokens(&mut tokens);
@@ -107,14 +109,16 @@ pub fn expand(attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStrea
     let mod_tokens = quote! { #(#js_mod_attrs)* #visible mod #mod_name { #tokens } };
     Ok(mod_tokens)
   } else {
-    let napi = item.parse_napi(&mut tokens, &opts)?;
+    let napis = item.parse_napi(&mut tokens, &opts)?;
     opts.check_used()?;
-    napi.try_to_tokens(&mut tokens)?;
+    for napi in napis {
+      napi.try_to_tokens(&mut tokens)?;
 
-    #[cfg(feature = "type-def")]
-    {
-      output_type_def(&napi);
-      output_wasi_register_def(&napi);
+      #[cfg(feature = "type-def")]
+      {
+        output_type_def(&napi);
+        output_wasi_register_def(&napi);
+      }
     }
     Ok(tokens)
   }
@@ -141,11 +145,15 @@ fn output_wasi_register_def(napi: &Napi) {
 #[cfg(feature = "type-def")]
 fn output_type_def(napi: &Napi) {
   if let Ok(type_def_file) = env::var("TYPE_DEF_TMP_PATH") {
-    if let Some(type_def) = napi.to_type_def() {
+    let trait_type_def = match &napi.item {
+      NapiItem::Impl(napi_impl) => napi_impl.to_trait_type_def(),
+      _ => None,
+    };
+    for type_def in napi.to_type_def().into_iter().chain(trait_type_def) {
       fs::OpenOptions::new()
         .append(true)
         .create(true)
-        .open(type_def_file)
+        .open(&type_def_file)
         .and_then(|file| {
           let mut writer = BufWriter::<fs::File>::new(file);
           writer.write_all(type_def.to_string().as_bytes())?;
@@ -14,6 +14,16 @@ pub fn expand(_attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStre
       .for_each(|field| find_and_remove_napi_attr(&mut field.attrs))
   }
 
+  if let syn::Item::Enum(ref mut enum_) = item {
+    enum_.variants.iter_mut().for_each(|variant| {
+      find_and_remove_napi_attr(&mut variant.attrs);
+      variant
+        .fields
+        .iter_mut()
+        .for_each(|field| find_and_remove_napi_attr(&mut field.attrs))
+    })
+  }
+
   item.to_tokens(&mut tokens);
 
   Ok(tokens)
@@ -62,7 +62,6 @@ pub fn contextless_function(_attr: TokenStream, input: TokenStream) -> TokenStre
       cb_info: napi::sys::napi_callback_info,
     ) -> napi::sys::napi_value {
       use std::ptr;
-      use std::panic::{self, AssertUnwindSafe};
       use std::ffi::CString;
       use napi::{Env, NapiValue, NapiRaw, Error, Status};
 
@@ -98,7 +97,6 @@ pub fn js_function(attr: TokenStream, input: TokenStream) -> TokenStream {
       cb_info: napi::sys::napi_callback_info,
     ) -> napi::sys::napi_value {
       use std::ptr;
-      use std::panic::{self, AssertUnwindSafe};
       use std::ffi::CString;
       use napi::{Env, Error, Status, NapiValue, NapiRaw, CallContext};
       let mut argc = #arg_len_span as usize;
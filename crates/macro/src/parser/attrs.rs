@@ -22,7 +22,10 @@ struct StructParseState {
 
 struct ParsedStruct {
   js_name: String,
+  js_mod: Option<String>,
   ctor_defined: bool,
+  private_ctor: bool,
+  custom_finalize: bool,
 }
 
 #[derive(Default)]
@@ -49,9 +52,12 @@ macro_rules! attrgen {
   ($mac:ident) => {
     $mac! {
       (catch_unwind, CatchUnwind(Span)),
+      (disposable, Disposable(Span)),
+      (to_string, ToString(Span)),
       (js_name, JsName(Span, String, Span)),
-      (constructor, Constructor(Span)),
+      (constructor, Constructor(Span, Option<bool>)),
       (factory, Factory(Span)),
+      (r#static, Static(Span)),
       (getter, Getter(Span, Option<Ident>)),
       (setter, Setter(Span, Option<Ident>)),
       (readonly, Readonly(Span)),
@@ -65,13 +71,19 @@ macro_rules! attrgen {
       (object_from_js, ObjectFromJs(Span, Option<bool>), true),
       (object_to_js, ObjectToJs(Span, Option<bool>), true),
       (custom_finalize, CustomFinalize(Span)),
+      (finalize, Finalize(Span)),
+      (extends, Extends(Span, Ident)),
+      (type_tag, TypeTag(Span, String, Span)),
       (namespace, Namespace(Span, String, Span)),
       (iterator, Iterator(Span)),
       (ts_args_type, TsArgsType(Span, String, Span)),
+      (ts_overload, TsOverload(Span, String, Span)),
       (ts_return_type, TsReturnType(Span, String, Span)),
       (ts_type, TsType(Span, String, Span)),
       (ts_generic_types, TsGenericTypes(Span, String, Span)),
       (string_enum, StringEnum(Span, Option<(String, Span)>)),
+      (discriminant, Discriminant(Span, String, Span)),
+      (concrete, Concrete(Span, String, Span)),
       (use_nullable, UseNullable(Span, Option<bool>), false),
 
       // impl later
@@ -226,6 +238,52 @@ impl BindgenAttrs {
     Ok(BindgenAttrs::default())
   }
 
+  /// All the `#[napi(concrete = "...")]` instantiations of a generic struct.
+  pub fn concretes(&self) -> Vec<(&str, Span)> {
+    self
+      .attrs
+      .iter()
+      .filter_map(|a| match &a.1 {
+        BindgenAttr::Concrete(_, s, span) => {
+          a.0.set(true);
+          Some((&s[..], *span))
+        }
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// `#[napi(constructor)]` or `#[napi(constructor = true)]`
+  pub fn has_constructor(&self) -> bool {
+    matches!(self.constructor(), Some(None | Some(true)))
+  }
+
+  /// The span of `#[napi(constructor = false)]`, the class can't be constructed from JavaScript
+  pub fn private_constructor(&self) -> Option<Span> {
+    self.attrs.iter().find_map(|a| match &a.1 {
+      BindgenAttr::Constructor(span, Some(false)) => {
+        a.0.set(true);
+        Some(*span)
+      }
+      _ => None,
+    })
+  }
+
+  /// All the `#[napi(ts_overload = "...")]` signatures of a function.
+  pub fn ts_overloads(&self) -> Vec<(&str, Span)> {
+    self
+      .attrs
+      .iter()
+      .filter_map(|a| match &a.1 {
+        BindgenAttr::TsOverload(_, s, span) => {
+          a.0.set(true);
+          Some((&s[..], *span))
+        }
+        _ => None,
+      })
+      .collect()
+  }
+
   attrgen!(methods);
 }
 
@@ -302,18 +360,39 @@ pub fn record_struct(ident: &Ident, js_name: String, opts: &BindgenAttrs) {
       struct_name,
       ParsedStruct {
         js_name,
-        ctor_defined: opts.constructor().is_some(),
+        js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+        ctor_defined: opts.has_constructor(),
+        private_ctor: opts.private_constructor().is_some(),
+        custom_finalize: opts.custom_finalize().is_some(),
       },
     );
   });
 }
 
+/// The `namespace` of a struct recorded by `record_struct`, shared by its `#[napi] impl` blocks
+pub fn recorded_struct_namespace(ident: &Ident) -> Option<String> {
+  STRUCTS.with(|state| {
+    state
+      .parsed
+      .borrow()
+      .get(&ident.to_string())
+      .and_then(|parsed| parsed.js_mod.clone())
+  })
+}
+
 pub fn check_recorded_struct_for_impl(ident: &Ident, opts: &BindgenAttrs) -> BindgenResult<String> {
   STRUCTS.with(|state| {
     let struct_name = ident.to_string();
     let mut map = state.parsed.borrow_mut();
     if let Some(parsed) = map.get_mut(&struct_name) {
-      if opts.constructor().is_some() && !cfg!(debug_assertions) {
+      if opts.has_constructor() && parsed.private_ctor {
+        bail_span!(
+          ident,
+          "struct `{}` is marked with #[napi(constructor = false)], it can only be created by factories or Rust functions",
+          &struct_name
+        );
+      }
+      if opts.has_constructor() && !cfg!(debug_assertions) {
         if parsed.ctor_defined {
           bail_span!(
             ident,
@@ -336,6 +415,21 @@ pub fn check_recorded_struct_for_impl(ident: &Ident, opts: &BindgenAttrs) -> Bin
   })
 }
 
+/// `#[napi(finalize)]` implements `ObjectFinalize`, which conflicts with the default implementation of structs without `#[napi(custom_finalize)]`
+pub fn check_recorded_struct_for_finalize(ident: &Ident, method: &Ident) -> BindgenResult<()> {
+  STRUCTS.with(|state| {
+    let struct_name = ident.to_string();
+    match state.parsed.borrow().get(&struct_name) {
+      Some(parsed) if !parsed.custom_finalize => bail_span!(
+        method,
+        "#[napi(finalize)] requires #[napi(custom_finalize)] on struct `{}`",
+        &struct_name
+      ),
+      _ => Ok(()),
+    }
+  })
+}
+
 impl Parse for BindgenAttrs {
   fn parse(input: ParseStream) -> syn::Result<Self> {
     let mut attrs = BindgenAttrs::default();
@@ -426,6 +520,15 @@ impl Parse for BindgenAttr {
           }
         });
 
+        (@parser $variant:ident(Span, Option<bool>)) => ({
+          if let Ok(_) = input.parse::<Token![=]>() {
+            let val = input.parse::<syn::LitBool>()?.value();
+            return Ok::<BindgenAttr, syn::Error>(BindgenAttr::$variant(attr_span, Some(val)))
+          } else {
+            return Ok(BindgenAttr::$variant(attr_span, None))
+          }
+        });
+
         (@parser $variant:ident(Span, Option<bool>), $default_value:literal) => ({
           if let Ok(_) = input.parse::<Token![=]>() {
             let (val, _) = match input.parse::<syn::LitBool>() {
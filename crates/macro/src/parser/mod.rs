@@ -10,21 +10,50 @@ use attrs::BindgenAttrs;
 
 use convert_case::{Case, Casing};
 use napi_derive_backend::{
-  BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, Napi, NapiConst, NapiEnum, NapiEnumValue,
-  NapiEnumVariant, NapiFn, NapiFnArg, NapiFnArgKind, NapiImpl, NapiItem, NapiStruct,
-  NapiStructField, NapiStructKind,
+  BindgenResult, CallbackArg, Diagnostic, Disposable, FnKind, FnSelf, Napi, NapiConcreteStruct,
+  NapiConst, NapiEnum, NapiEnumValue, NapiEnumVariant, NapiFinalize, NapiFn, NapiFnArg,
+  NapiFnArgKind, NapiGenericStruct, NapiImpl, NapiImplConst, NapiItem, NapiStruct, NapiStructField,
+  NapiStructKind, NapiTaggedEnum, NapiTaggedEnumVariant,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use syn::ext::IdentExt;
+use syn::fold::Fold;
 use syn::parse::{Parse, ParseStream, Result as SynResult};
 use syn::spanned::Spanned;
 use syn::{Attribute, ExprLit, Meta, PatType, PathSegment, Signature, Type, Visibility};
 
-use crate::parser::attrs::{check_recorded_struct_for_impl, record_struct};
+use crate::parser::attrs::{
+  check_recorded_struct_for_finalize, check_recorded_struct_for_impl, record_struct,
+  recorded_struct_namespace,
+};
 
 thread_local! {
   static GENERATOR_STRUCT: RefCell<HashMap<String, bool>> = Default::default();
+  static CONCRETE_STRUCTS: RefCell<HashMap<String, Vec<ConcreteStruct>>> = Default::default();
+}
+
+/// An instantiation of a generic struct listed in `#[napi(concrete = "...")]`
+///
+/// Tokens can't outlive the macro invocation, so the alias and arguments are kept as strings.
+#[derive(Clone)]
+struct ConcreteStruct {
+  alias: String,
+  args: Vec<String>,
+}
+
+/// Replaces the generic parameters with the types of a concrete instantiation
+struct ConcreteFolder<'a>(&'a HashMap<Ident, syn::Type>);
+
+impl Fold for ConcreteFolder<'_> {
+  fn fold_type(&mut self, ty: syn::Type) -> syn::Type {
+    if let syn::Type::Path(syn::TypePath { qself: None, path }) = &ty {
+      if let Some(concrete) = path.get_ident().and_then(|ident| self.0.get(ident)) {
+        return concrete.clone();
+      }
+    }
+    syn::fold::fold_type(self, ty)
+  }
 }
 
 static REGISTER_INDEX: AtomicUsize = AtomicUsize::new(0);
@@ -54,12 +83,17 @@ pub trait ConvertToAST {
 }
 
 pub trait ParseNapi {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi>;
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>>;
 }
 
 /// This function does a few things:
 /// - parses the tokens for the given argument `p` to find the `#[napi(ts_arg_type = "MyType")]`
-///   attribute and return the manually overridden type.
+///   attribute, or its `#[napi(ts_type = "MyType")]` alias, and return the manually overridden type.
+/// - parses `#[napi(default = "expr")]`, the Rust expression used when the argument is `undefined`.
 /// - If both the `ts_args_type` override and the `ts_arg_type` override are present, bail
 ///   since it should only allow one at a time.
 /// - Bails if it finds the `#[napi...]` attribute but it has the wrong data.
@@ -71,27 +105,20 @@ pub trait ParseNapi {
 fn find_ts_arg_type_and_remove_attribute(
   p: &mut PatType,
   ts_args_type: Option<&(&str, Span)>,
-) -> BindgenResult<Option<String>> {
-  let mut ts_type_attr: Option<(usize, String)> = None;
+) -> BindgenResult<(Option<String>, Option<syn::Expr>)> {
+  let mut napi_attr_idx = None;
+  let mut ts_type_attr = None;
+  let mut default_attr = None;
   for (idx, attr) in p.attrs.iter().enumerate() {
     if attr.path().is_ident("napi") {
-      if let Some((ts_args_type, _)) = ts_args_type {
-        bail_span!(
-          attr,
-          "Found a 'ts_args_type'=\"{}\" override. Cannot use 'ts_arg_type' at the same time since they are mutually exclusive.",
-          ts_args_type
-        );
-      }
-
       match &attr.meta {
         syn::Meta::Path(_) | syn::Meta::NameValue(_) => {
           bail_span!(
             attr,
-            "Expects an assignment #[napi(ts_arg_type = \"MyType\")]"
+            "Expects an assignment #[napi(ts_arg_type = \"MyType\")] or #[napi(default = \"expr\")]"
           )
         }
         syn::Meta::List(list) => {
-          let mut found = false;
           list
             .parse_args_with(|tokens: &syn::parse::ParseBuffer<'_>| {
               // tokens:
@@ -100,52 +127,67 @@ fn find_ts_arg_type_and_remove_attribute(
               let list = tokens.parse_terminated(Meta::parse, Token![,])?;
 
               for meta in list {
-                if meta.path().is_ident("ts_arg_type") {
-                  match meta {
-                    Meta::Path(_) | Meta::List(_) => {
+                let is_ts_arg_type =
+                  meta.path().is_ident("ts_arg_type") || meta.path().is_ident("ts_type");
+                if !is_ts_arg_type && !meta.path().is_ident("default") {
+                  return Err(syn::Error::new(
+                    meta.path().span(),
+                    "Expects a 'ts_arg_type', a 'ts_type' or a 'default'",
+                  ));
+                }
+                let value = match meta {
+                  Meta::Path(_) | Meta::List(_) => {
+                    return Err(syn::Error::new(
+                      meta.path().span(),
+                      if is_ts_arg_type {
+                        "Expects an assignment (ts_arg_type = \"MyType\")"
+                      } else {
+                        "Expects an assignment (default = \"expr\")"
+                      },
+                    ))
+                  }
+                  Meta::NameValue(name_value) => match name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                      lit: syn::Lit::Str(str),
+                      ..
+                    }) => str,
+                    _ => {
                       return Err(syn::Error::new(
-                        meta.path().span(),
-                        "Expects an assignment (ts_arg_type = \"MyType\")",
+                        name_value.value.span(),
+                        "Expects a string literal",
                       ))
                     }
-                    Meta::NameValue(name_value) => match name_value.value {
-                      syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Str(str),
-                        ..
-                      }) => {
-                        let value = str.value();
-                        found = true;
-                        ts_type_attr = Some((idx, value));
-                      }
-                      _ => {
-                        return Err(syn::Error::new(
-                          name_value.value.span(),
-                          "Expects a string literal",
-                        ))
-                      }
-                    },
+                  },
+                };
+                if is_ts_arg_type {
+                  if let Some((ts_args_type, _)) = ts_args_type {
+                    return Err(syn::Error::new(
+                      value.span(),
+                      format!(
+                        "Found a 'ts_args_type'=\"{}\" override. Cannot use 'ts_arg_type' at the same time since they are mutually exclusive.",
+                        ts_args_type
+                      ),
+                    ));
                   }
+                  ts_type_attr = Some(value.value());
+                } else {
+                  default_attr = Some(value.parse::<syn::Expr>()?);
                 }
               }
 
               Ok(())
             })
             .map_err(Diagnostic::from)?;
-
-          if !found {
-            bail_span!(attr, "Expects a 'ts_arg_type'");
-          }
+          napi_attr_idx = Some(idx);
         }
       }
     }
   }
 
-  if let Some((idx, value)) = ts_type_attr {
+  if let Some(idx) = napi_attr_idx {
     p.attrs.remove(idx);
-    Ok(Some(value))
-  } else {
-    Ok(None)
   }
+  Ok((ts_type_attr, default_attr))
 }
 
 fn find_enum_value_and_remove_attribute(v: &mut syn::Variant) -> BindgenResult<Option<String>> {
@@ -539,6 +581,38 @@ fn extract_fn_closure_generics(
   Diagnostic::from_vec(errors).and(Ok(map))
 }
 
+/// Get the `Fn` trait arguments of an `impl Fn(A, B) -> Result<R>` argument
+fn extract_impl_fn_arguments(ty: &syn::Type) -> BindgenResult<Option<syn::PathArguments>> {
+  let syn::Type::ImplTrait(impl_trait) = ty else {
+    return Ok(None);
+  };
+
+  let mut arguments = None;
+  for bound in impl_trait.bounds.iter() {
+    match bound {
+      syn::TypeParamBound::Trait(t) => {
+        if let Some(segment) = t.path.segments.last() {
+          if matches!(
+            segment.ident.to_string().as_str(),
+            "Fn" | "FnOnce" | "FnMut"
+          ) {
+            arguments = Some(segment.arguments.clone());
+          }
+        }
+      }
+      syn::TypeParamBound::Lifetime(lifetime) if lifetime.ident != "static" => {
+        bail_span!(
+          bound,
+          "only 'static is supported in lifetime bound for fn arguments"
+        );
+      }
+      _ => {}
+    }
+  }
+
+  Ok(arguments)
+}
+
 fn napi_fn_from_decl(
   sig: &mut Signature,
   opts: &BindgenAttrs,
@@ -564,15 +638,24 @@ fn napi_fn_from_decl(
     .iter_mut()
     .filter_map(|arg| match arg {
       syn::FnArg::Typed(ref mut p) => {
-        let ts_arg_type = find_ts_arg_type_and_remove_attribute(p, opts.ts_args_type().as_ref())
-          .unwrap_or_else(|e| {
-            errors.push(e);
-            None
-          });
+        let (ts_arg_type, default) =
+          find_ts_arg_type_and_remove_attribute(p, opts.ts_args_type().as_ref()).unwrap_or_else(
+            |e| {
+              errors.push(e);
+              (None, None)
+            },
+          );
 
         let ty_str = p.ty.to_token_stream().to_string();
-        if let Some(path_arguments) = callback_traits.get(&ty_str) {
-          match extract_callback_trait_types(path_arguments) {
+        let callback_arguments = match callback_traits.get(&ty_str) {
+          Some(path_arguments) => Some(path_arguments.clone()),
+          None => extract_impl_fn_arguments(&p.ty).unwrap_or_else(|e| {
+            errors.push(e);
+            None
+          }),
+        };
+        if let Some(path_arguments) = callback_arguments {
+          match extract_callback_trait_types(&path_arguments) {
             Ok((fn_args, fn_ret)) => Some(NapiFnArg {
               kind: NapiFnArgKind::Callback(Box::new(CallbackArg {
                 pat: p.pat.clone(),
@@ -580,6 +663,7 @@ fn napi_fn_from_decl(
                 ret: fn_ret,
               })),
               ts_arg_type,
+              default,
             }),
             Err(e) => {
               errors.push(e);
@@ -592,6 +676,7 @@ fn napi_fn_from_decl(
           Some(NapiFnArg {
             kind: NapiFnArgKind::PatType(Box::new(p.clone())),
             ts_arg_type,
+            default,
           })
         }
       }
@@ -657,8 +742,12 @@ fn napi_fn_from_decl(
         },
         |(js_name, _)| js_name.to_owned(),
       )
-    } else if opts.constructor().is_some() {
+    } else if opts.has_constructor() {
       "constructor".to_owned()
+    } else if opts.to_string().is_some() {
+      opts
+        .js_name()
+        .map_or_else(|| "toString".to_owned(), |(js_name, _)| js_name.to_owned())
     } else {
       opts.js_name().map_or_else(
         || ident.to_string().to_case(Case::Camel),
@@ -666,7 +755,10 @@ fn napi_fn_from_decl(
       )
     };
 
-    let namespace = opts.namespace().map(|(m, _)| m.to_owned());
+    let namespace = opts
+      .namespace()
+      .map(|(m, _)| m.to_owned())
+      .or_else(|| parent.and_then(recorded_struct_namespace));
     let parent_is_generator = if let Some(p) = parent {
       GENERATOR_STRUCT.with(|inner| {
         let inner = inner.borrow();
@@ -689,6 +781,61 @@ fn napi_fn_from_decl(
       );
     }
 
+    if let Some(span) = opts.to_string() {
+      if fn_self.is_none() || kind != FnKind::Normal {
+        return Err(Diagnostic::span_error(
+          *span,
+          "#[napi(to_string)] can only be applied to a method with `&self` or `&mut self`",
+        ));
+      }
+    }
+
+    if let Some(span) = opts.private_constructor() {
+      return Err(Diagnostic::span_error(
+        span,
+        "#[napi(constructor = false)] can only be applied to a struct",
+      ));
+    }
+
+    if let Some(span) = opts.r#static() {
+      if parent.is_none() || fn_self.is_some() || kind != FnKind::Normal {
+        return Err(Diagnostic::span_error(
+          *span,
+          "#[napi(static)] can only be applied to a method without `self`",
+        ));
+      }
+    }
+
+    if let Some((_, span)) = opts.ts_overload() {
+      if matches!(kind, FnKind::Getter | FnKind::Setter) {
+        return Err(Diagnostic::span_error(
+          span,
+          "#[napi(ts_overload)] can't be applied to a getter or a setter",
+        ));
+      }
+    }
+
+    let disposable = match opts.disposable() {
+      Some(span) => {
+        if fn_self.is_none() || kind != FnKind::Normal {
+          return Err(Diagnostic::span_error(
+            *span,
+            "#[napi(disposable)] can only be applied to a method with `&self` or `&mut self`",
+          ));
+        }
+        let returns_promise = ret.as_ref().map_or(false, |ret| {
+          matches!(get_ty(ret), syn::Type::Path(syn::TypePath { path, .. })
+            if path.segments.last().map_or(false, |segment| segment.ident == "AsyncTask" || segment.ident == "Promise"))
+        });
+        if asyncness.is_some() || returns_promise {
+          Some(Disposable::Async)
+        } else {
+          Some(Disposable::Sync)
+        }
+      }
+      None => None,
+    };
+
     Ok(NapiFn {
       name: ident.clone(),
       js_name,
@@ -700,6 +847,7 @@ fn napi_fn_from_decl(
       kind,
       fn_self,
       parent: parent.cloned(),
+      parent_trait: None,
       comments: extract_doc_comments(&attrs),
       attrs,
       strict: opts.strict().is_some(),
@@ -713,15 +861,26 @@ fn napi_fn_from_decl(
       writable: opts.writable(),
       enumerable: opts.enumerable(),
       configurable: opts.configurable(),
-      catch_unwind: opts.catch_unwind().is_some(),
+      catch_unwind: opts.catch_unwind().is_some() || cfg!(feature = "catch_unwind"),
       unsafe_: sig.unsafety.is_some(),
       register_name: get_register_ident(ident.to_string().as_str()),
+      disposable,
+      is_to_string: opts.to_string().is_some(),
+      ts_overloads: opts
+        .ts_overloads()
+        .into_iter()
+        .map(|(overload, _)| overload.to_owned())
+        .collect(),
     })
   })
 }
 
 impl ParseNapi for syn::Item {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     match self {
       syn::Item::Fn(f) => f.parse_napi(tokens, opts),
       syn::Item::Struct(s) => s.parse_napi(tokens, opts),
@@ -737,7 +896,11 @@ impl ParseNapi for syn::Item {
 }
 
 impl ParseNapi for syn::ItemFn {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     if opts.ts_type().is_some() {
       bail_span!(
         self,
@@ -753,11 +916,15 @@ impl ParseNapi for syn::ItemFn {
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
-    napi
+    napi.map(|napi| vec![napi])
   }
 }
 impl ParseNapi for syn::ItemStruct {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
       || opts.skip_typescript().is_some()
@@ -783,15 +950,28 @@ impl ParseNapi for syn::ItemStruct {
     if opts.object().is_some() && opts.custom_finalize().is_some() {
       bail_span!(self, "Custom finalize is not supported for #[napi(object)]");
     }
+    if !self.generics.params.is_empty() {
+      return convert_generic_struct(self, tokens, opts);
+    }
+    if let Some((_, span)) = opts.concrete() {
+      return Err(Diagnostic::span_error(
+        span,
+        "#[napi(concrete)] can only be applied to a generic struct",
+      ));
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
-    napi
+    napi.map(|napi| vec![napi])
   }
 }
 
 impl ParseNapi for syn::ItemImpl {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
       || opts.skip_typescript().is_some()
@@ -815,16 +995,30 @@ impl ParseNapi for syn::ItemImpl {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if let Some(instances) = concrete_impls(self)? {
+      let napis = instances
+        .into_iter()
+        .map(|mut instance| instance.convert_to_ast(opts))
+        .collect::<BindgenResult<Vec<_>>>()?;
+      remove_napi_attrs_from_impl(self);
+      self.to_tokens(tokens);
+
+      return Ok(napis);
+    }
     // #[napi] macro will be remove from impl items after converted to ast
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
-    napi
+    napi.map(|napi| vec![napi])
   }
 }
 
 impl ParseNapi for syn::ItemEnum {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
       || opts.ts_type().is_some()
@@ -850,19 +1044,22 @@ impl ParseNapi for syn::ItemEnum {
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
-    napi
+    napi.map(|napi| vec![napi])
   }
 }
 impl ParseNapi for syn::ItemConst {
-  fn parse_napi(&mut self, tokens: &mut TokenStream, opts: &BindgenAttrs) -> BindgenResult<Napi> {
+  fn parse_napi(
+    &mut self,
+    tokens: &mut TokenStream,
+    opts: &BindgenAttrs,
+  ) -> BindgenResult<Vec<Napi>> {
     if opts.ts_args_type().is_some()
       || opts.ts_return_type().is_some()
-      || opts.ts_type().is_some()
       || opts.custom_finalize().is_some()
     {
       bail_span!(
         self,
-        "#[napi] can't be applied to a const with #[napi(ts_args_type)], #[napi(ts_return_type)] or #[napi(ts_type)] or #[napi(custom_finalize)]"
+        "#[napi] can't be applied to a const with #[napi(ts_args_type)], #[napi(ts_return_type)] or #[napi(custom_finalize)]"
       );
     }
     if opts.return_if_invalid().is_some() {
@@ -879,10 +1076,298 @@ impl ParseNapi for syn::ItemConst {
     }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
-    napi
+    napi.map(|napi| vec![napi])
   }
 }
 
+/// Export a generic struct as one class per `#[napi(concrete = "...")]` instantiation.
+///
+/// Every instantiation gets a type alias, e.g. `StoreU32` for `Store<u32>`, which is the Rust name of the class.
+fn convert_generic_struct(
+  item: &mut syn::ItemStruct,
+  tokens: &mut TokenStream,
+  opts: &BindgenAttrs,
+) -> BindgenResult<Vec<Napi>> {
+  let concretes = opts.concretes();
+  if concretes.is_empty() {
+    bail_span!(
+      item.generics,
+      "#[napi] on a generic struct requires the instantiations to export, e.g. #[napi(concrete = \"{}<u32>\")]",
+      item.ident
+    );
+  }
+  if let Some((_, span)) = opts.js_name() {
+    return Err(Diagnostic::span_error(
+      span,
+      "#[napi(js_name)] can't be used with #[napi(concrete)], the class names are derived from the instantiations",
+    ));
+  }
+  if matches!(item.fields, syn::Fields::Unnamed(_)) {
+    bail_span!(item, "#[napi(concrete)] can't be applied to a tuple struct");
+  }
+  let type_params = item
+    .generics
+    .params
+    .iter()
+    .map(|param| match param {
+      syn::GenericParam::Type(ty) => Ok(ty.ident.clone()),
+      _ => bail_span!(
+        param,
+        "only type parameters are supported in generic #[napi] struct"
+      ),
+    })
+    .collect::<BindgenResult<Vec<_>>>()?;
+
+  let mut napis = vec![];
+  let mut instances = vec![];
+  let mut concrete_structs = vec![];
+  for (concrete, span) in concretes {
+    let path = syn::parse_str::<syn::TypePath>(concrete)
+      .map_err(|_| Diagnostic::span_error(span, format!("`{}` is not a valid type", concrete)))?;
+    let args = match path.path.segments.last() {
+      Some(PathSegment {
+        ident,
+        arguments: syn::PathArguments::AngleBracketed(arguments),
+      }) if ident == &item.ident => arguments
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+          syn::GenericArgument::Type(ty) => Some(ty.clone()),
+          _ => None,
+        })
+        .collect::<Vec<_>>(),
+      _ => {
+        return Err(Diagnostic::span_error(
+          span,
+          format!("`{}` is not an instantiation of `{}`", concrete, item.ident),
+        ))
+      }
+    };
+    if args.len() != type_params.len() {
+      return Err(Diagnostic::span_error(
+        span,
+        format!(
+          "`{}` expects {} type arguments, but `{}` has {}",
+          item.ident,
+          type_params.len(),
+          concrete,
+          args.len()
+        ),
+      ));
+    }
+
+    // Store<Vec<u8>> => StoreVecU8
+    let suffix = args
+      .iter()
+      .map(|arg| arg.to_token_stream().to_string())
+      .collect::<Vec<_>>()
+      .join(" ")
+      .split(|c: char| !c.is_alphanumeric())
+      .filter(|word| !word.is_empty())
+      .map(|word| word.to_case(Case::Pascal))
+      .collect::<String>();
+    let alias = Ident::new(&format!("{}{}", item.ident, suffix), item.ident.span());
+
+    let generics = type_params
+      .iter()
+      .cloned()
+      .zip(args.iter().cloned())
+      .collect();
+    let mut instance = ConcreteFolder(&generics).fold_item_struct(item.clone());
+    instance.ident = alias.clone();
+    instance.generics = Default::default();
+    napis.push(instance.convert_to_ast(opts)?);
+
+    instances.push(NapiConcreteStruct {
+      name: alias.clone(),
+      js_name: alias.to_string().to_case(Case::Pascal),
+      args: args.clone(),
+    });
+    concrete_structs.push(ConcreteStruct {
+      alias: alias.to_string(),
+      args: args
+        .iter()
+        .map(|arg| arg.to_token_stream().to_string())
+        .collect(),
+    });
+  }
+
+  CONCRETE_STRUCTS.with(|c| {
+    c.borrow_mut()
+      .insert(item.ident.to_string(), concrete_structs);
+  });
+
+  napis.push(Napi {
+    item: NapiItem::GenericStruct(NapiGenericStruct {
+      name: item.ident.clone(),
+      js_name: item.ident.to_string().to_case(Case::Pascal),
+      vis: item.vis.clone(),
+      type_params,
+      instances,
+      js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+      comments: extract_doc_comments(&item.attrs),
+      register_name: get_register_ident(format!("{}_generic", item.ident).as_str()),
+    }),
+  });
+
+  for field in item.fields.iter_mut() {
+    field.attrs.retain(|attr| !attr.path().is_ident("napi"));
+  }
+  item.to_tokens(tokens);
+
+  Ok(napis)
+}
+
+/// Instantiate the impl of a generic `#[napi]` struct for every matching `#[napi(concrete = "...")]`.
+///
+/// Returns `None` if the impl isn't for a generic `#[napi]` struct.
+fn concrete_impls(item: &syn::ItemImpl) -> BindgenResult<Option<Vec<syn::ItemImpl>>> {
+  let segment = match get_ty(&item.self_ty) {
+    syn::Type::Path(syn::TypePath { qself: None, path }) => match path.segments.last() {
+      Some(segment) => segment,
+      None => return Ok(None),
+    },
+    _ => return Ok(None),
+  };
+  let self_args = match &segment.arguments {
+    syn::PathArguments::AngleBracketed(arguments) => arguments.args.iter().collect::<Vec<_>>(),
+    _ => return Ok(None),
+  };
+  let concretes =
+    match CONCRETE_STRUCTS.with(|c| c.borrow().get(&segment.ident.to_string()).cloned()) {
+      Some(concretes) => concretes,
+      None => return Ok(None),
+    };
+  let impl_params = item
+    .generics
+    .type_params()
+    .map(|param| &param.ident)
+    .collect::<Vec<_>>();
+
+  let mut instances = vec![];
+  'concrete: for concrete in concretes {
+    if concrete.args.len() != self_args.len() {
+      bail_span!(
+        segment,
+        "`{}` expects {} type arguments",
+        segment.ident,
+        concrete.args.len()
+      );
+    }
+    let mut generics = HashMap::new();
+    for (self_arg, concrete_ty) in self_args.iter().zip(concrete.args.iter()) {
+      let self_arg = match self_arg {
+        syn::GenericArgument::Type(ty) => get_ty(ty),
+        _ => bail_span!(
+          self_arg,
+          "only type arguments are supported in #[napi] impl"
+        ),
+      };
+      match self_arg {
+        // impl<T> Store<T>
+        syn::Type::Path(syn::TypePath { qself: None, path })
+          if path
+            .get_ident()
+            .map_or(false, |ident| impl_params.contains(&ident)) =>
+        {
+          let ty = syn::parse_str::<syn::Type>(concrete_ty)?;
+          if let Some(bound) = generics.insert(path.get_ident().unwrap().clone(), ty) {
+            if &bound.to_token_stream().to_string() != concrete_ty {
+              continue 'concrete;
+            }
+          }
+        }
+        // impl Store<u32>
+        ty => {
+          if &ty.to_token_stream().to_string() != concrete_ty {
+            continue 'concrete;
+          }
+        }
+      }
+    }
+
+    let alias = Ident::new(&concrete.alias, segment.ident.span());
+    let mut instance = ConcreteFolder(&generics).fold_item_impl(item.clone());
+    instance.generics = Default::default();
+    instance.self_ty = Box::new(parse_quote!(#alias));
+    instances.push(instance);
+  }
+
+  if instances.is_empty() {
+    bail_span!(
+      item.self_ty,
+      "`{}` is not listed in #[napi(concrete)] of `{}`",
+      item.self_ty.to_token_stream(),
+      segment.ident
+    );
+  }
+
+  Ok(Some(instances))
+}
+
+fn remove_napi_attrs_from_impl(item: &mut syn::ItemImpl) {
+  for impl_item in item.items.iter_mut() {
+    if let syn::ImplItem::Fn(method) = impl_item {
+      method.attrs.retain(|attr| !attr.path().is_ident("napi"));
+      for input in method.sig.inputs.iter_mut() {
+        if let syn::FnArg::Typed(arg) = input {
+          arg.attrs.retain(|attr| !attr.path().is_ident("napi"));
+        }
+      }
+    }
+  }
+}
+
+fn napi_finalize_from_sig(sig: &Signature) -> BindgenResult<NapiFinalize> {
+  if let Some(asyncness) = &sig.asyncness {
+    bail_span!(asyncness, "#[napi(finalize)] method can't be async");
+  }
+
+  let mut inputs = sig.inputs.iter();
+  let by_value = match inputs.next() {
+    Some(syn::FnArg::Receiver(r)) if r.reference.is_none() => true,
+    Some(syn::FnArg::Receiver(r)) if r.mutability.is_some() => false,
+    _ => bail_span!(
+      sig.ident,
+      "#[napi(finalize)] method must take `self` or `&mut self`"
+    ),
+  };
+  let with_env = match inputs.next() {
+    None => false,
+    Some(syn::FnArg::Typed(arg))
+      if matches!(
+        get_ty(&arg.ty),
+        syn::Type::Path(syn::TypePath { qself: None, path })
+          if path.segments.last().map_or(false, |segment| segment.ident == "Env")
+      ) =>
+    {
+      true
+    }
+    Some(arg) => bail_span!(arg, "#[napi(finalize)] method can only take an `Env`"),
+  };
+  if let Some(arg) = inputs.next() {
+    bail_span!(arg, "#[napi(finalize)] method can only take an `Env`");
+  }
+
+  let is_ret_result = match &sig.output {
+    syn::ReturnType::Default => false,
+    syn::ReturnType::Type(_, ty) => match extract_result_ty(ty)? {
+      Some(ty) if ty.to_token_stream().to_string() == "()" => true,
+      _ => bail_span!(
+        ty,
+        "#[napi(finalize)] method can only return nothing or `Result<()>`"
+      ),
+    },
+  };
+
+  Ok(NapiFinalize {
+    name: sig.ident.clone(),
+    by_value,
+    with_env,
+    is_ret_result,
+  })
+}
+
 fn fn_kind(opts: &BindgenAttrs) -> FnKind {
   let mut kind = FnKind::Normal;
 
@@ -894,7 +1379,7 @@ fn fn_kind(opts: &BindgenAttrs) -> FnKind {
     kind = FnKind::Setter;
   }
 
-  if opts.constructor().is_some() {
+  if opts.has_constructor() {
     kind = FnKind::Constructor;
   }
 
@@ -933,14 +1418,75 @@ impl ConvertToAST for syn::ItemStruct {
     );
     let mut fields = vec![];
     let mut is_tuple = false;
-    let struct_kind = if opts.constructor().is_some() {
+    let struct_kind = if opts.has_constructor() {
       NapiStructKind::Constructor
     } else if opts.object().is_some() {
       NapiStructKind::Object
     } else {
       NapiStructKind::None
     };
+    if let Some(span) = opts.private_constructor() {
+      if struct_kind == NapiStructKind::Object {
+        return Err(Diagnostic::span_error(
+          span,
+          "#[napi(constructor = false)] can't be applied to #[napi(object)]",
+        ));
+      }
+    }
     let use_nullable = opts.use_nullable();
+    let extends = opts.extends().cloned();
+    if let Some(base) = &extends {
+      if struct_kind == NapiStructKind::Object {
+        bail_span!(base, "#[napi(extends)] can't be applied to #[napi(object)]");
+      }
+      // methods of the base class are called with the derived instance,
+      // so it must be usable as the base by pointer cast
+      let is_repr_c = self.attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+          && attr
+            .parse_args_with(syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated)
+            .map_or(false, |reprs| reprs.iter().any(|repr| repr == "C"))
+      });
+      let first_field_is_base = self.fields.iter().next().map_or(false, |field| {
+        matches!(get_ty(&field.ty), syn::Type::Path(syn::TypePath { qself: None, path }) if path.is_ident(base))
+      });
+      if !is_repr_c || !first_field_is_base {
+        bail_span!(
+          self.ident,
+          "#[napi(extends = {})] requires the struct to be #[repr(C)] with `{}` as its first field",
+          base,
+          base
+        );
+      }
+    }
+
+    let type_tag = match opts.type_tag() {
+      Some((uuid, span)) => {
+        if struct_kind == NapiStructKind::Object {
+          bail_span!(
+            self.ident,
+            "#[napi(type_tag)] can't be applied to #[napi(object)]"
+          );
+        }
+        if extends.is_some() {
+          bail_span!(
+            self.ident,
+            "#[napi(type_tag)] can't be combined with #[napi(extends)], the tag of the subclass is derived from the tag of the base class"
+          );
+        }
+        let hex = uuid.replace('-', "");
+        match u128::from_str_radix(&hex, 16) {
+          Ok(value) if hex.len() == 32 => Some((value as u64, (value >> 64) as u64)),
+          _ => {
+            return Err(Diagnostic::span_error(
+              span,
+              format!("Invalid type_tag `{}`, expected a UUID", uuid),
+            ))
+          }
+        }
+      }
+      None => None,
+    };
 
     for (i, field) in self.fields.iter_mut().enumerate() {
       match field.vis {
@@ -1023,6 +1569,9 @@ impl ConvertToAST for syn::ItemStruct {
         use_custom_finalize: opts.custom_finalize().is_some(),
         register_name: get_register_ident(format!("{struct_name}_struct").as_str()),
         use_nullable,
+        extends,
+        type_tag,
+        private_constructor: opts.private_constructor().is_some(),
       }),
     })
   }
@@ -1048,14 +1597,38 @@ impl ConvertToAST for syn::ItemImpl {
     let mut iterator_yield_type = None;
     let mut iterator_next_type = None;
     let mut iterator_return_type = None;
+    let mut implement_std_iterator = false;
+    let mut finalize: Option<NapiFinalize> = None;
+    let mut consts = vec![];
     for item in self.items.iter_mut() {
       if let Some(method) = match item {
         syn::ImplItem::Fn(m) => Some(m),
+        syn::ImplItem::Const(c) => {
+          let opts = BindgenAttrs::find(&mut c.attrs)?;
+          if opts.exists {
+            if !matches!(c.vis, Visibility::Public(_)) {
+              bail_span!(c.ident, "only pub const supported by #[napi].");
+            }
+            consts.push(NapiImplConst {
+              name: c.ident.clone(),
+              js_name: opts
+                .js_name()
+                .map_or_else(|| c.ident.to_string(), |(js_name, _)| js_name.to_owned()),
+              ty: c.ty.clone(),
+              comments: extract_doc_comments(&c.attrs),
+              skip_typescript: opts.skip_typescript().is_some(),
+            });
+          }
+          None
+        }
         syn::ImplItem::Type(m) => {
           if let Some((_, t, _)) = &self.trait_ {
             if let Some(PathSegment { ident, .. }) = t.segments.last() {
               if ident == "Task" && m.ident == "JsValue" {
                 task_output_type = Some(m.ty.clone());
+              } else if ident == "Iterator" && m.ident == "Item" {
+                iterator_yield_type = Some(m.ty.clone());
+                implement_std_iterator = true;
               } else if ident == "Generator" {
                 if let Type::Path(_) = &m.ty {
                   if m.ident == "Yield" {
@@ -1082,32 +1655,78 @@ impl ConvertToAST for syn::ItemImpl {
           continue;
         }
 
+        if opts.finalize().is_some() {
+          if finalize.is_some() {
+            bail_span!(
+              method.sig.ident,
+              "#[napi(finalize)] can only be applied to one method of `{}`",
+              struct_name
+            );
+          }
+          check_recorded_struct_for_finalize(&struct_name, &method.sig.ident)?;
+          finalize = Some(napi_finalize_from_sig(&method.sig)?);
+          continue;
+        }
+
         if opts.constructor().is_some() || opts.factory().is_some() {
           struct_js_name = check_recorded_struct_for_impl(&struct_name, &opts)?;
         }
 
         let vis = method.vis.clone();
 
-        match &vis {
-          Visibility::Public(_) => {}
+        // the methods of a trait impl are as visible as the trait
+        match (&vis, &self.trait_) {
+          (Visibility::Public(_), _) | (_, Some(_)) => {}
           _ => {
             bail_span!(method.sig.ident, "only pub method supported by #[napi].",);
           }
         }
 
-        let func = napi_fn_from_decl(
+        let mut func = napi_fn_from_decl(
           &mut method.sig,
           &opts,
           method.attrs.clone(),
           vis,
           Some(&struct_name),
         )?;
+        func.parent_trait = self.trait_.as_ref().map(|(_, path, _)| path.clone());
 
         items.push(func);
       }
     }
 
-    let namespace = impl_opts.namespace().map(|(m, _)| m.to_owned());
+    // `impl Shape for Circle` exporting methods, the class implements the `Shape` interface
+    let trait_js_name = match &self.trait_ {
+      Some((_, path, _)) if !items.is_empty() => Some(impl_opts.js_name().map_or_else(
+        || {
+          path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default()
+        },
+        |(js_name, _)| js_name.to_owned(),
+      )),
+      _ => None,
+    };
+
+    // an impl without `namespace` belongs to the namespace of its struct, otherwise the class would be registered twice
+    let struct_namespace = recorded_struct_namespace(&struct_name);
+    let namespace = match impl_opts.namespace() {
+      Some((namespace, span)) => {
+        if let Some(struct_namespace) = struct_namespace.filter(|n| n != namespace) {
+          return Err(Diagnostic::span_error(
+            span,
+            format!(
+              "namespace `{}` doesn't match the namespace `{}` of struct `{}`",
+              namespace, struct_namespace, struct_name
+            ),
+          ));
+        }
+        Some(namespace.to_owned())
+      }
+      None => struct_namespace,
+    };
 
     Ok(Napi {
       item: NapiItem::Impl(NapiImpl {
@@ -1118,6 +1737,10 @@ impl ConvertToAST for syn::ItemImpl {
         iterator_yield_type,
         iterator_next_type,
         iterator_return_type,
+        implement_std_iterator,
+        finalize,
+        consts,
+        trait_js_name,
         js_mod: namespace,
         comments: extract_doc_comments(&self.attrs),
         register_name: get_register_ident(format!("{struct_name}_impl").as_str()),
@@ -1133,12 +1756,21 @@ impl ConvertToAST for syn::ItemEnum {
       _ => bail_span!(self, "only public enum allowed"),
     }
 
-    self.attrs.push(parse_quote!(#[derive(Copy, Clone)]));
-
     let js_name = opts
       .js_name()
       .map_or_else(|| self.ident.to_string(), |(s, _)| s.to_string());
 
+    if opts.discriminant().is_some()
+      || self
+        .variants
+        .iter()
+        .any(|v| !matches!(v.fields, syn::Fields::Unit))
+    {
+      return convert_to_tagged_enum_ast(self, js_name, opts);
+    }
+
+    self.attrs.push(parse_quote!(#[derive(Copy, Clone)]));
+
     let is_string_enum = opts.string_enum().is_some();
     let variants = match opts.string_enum() {
       Some(case) => {
@@ -1262,6 +1894,113 @@ impl ConvertToAST for syn::ItemEnum {
   }
 }
 
+fn convert_to_tagged_enum_ast(
+  item: &mut syn::ItemEnum,
+  js_name: String,
+  opts: &BindgenAttrs,
+) -> BindgenResult<Napi> {
+  if opts.string_enum().is_some() {
+    bail_span!(
+      item,
+      "#[napi(string_enum)] can't be applied to an enum with payload variants"
+    );
+  }
+  if item.variants.is_empty() {
+    bail_span!(
+      item,
+      "#[napi(discriminant)] requires the enum to have at least one variant"
+    );
+  }
+
+  let discriminant = opts
+    .discriminant()
+    .map_or_else(|| "type".to_owned(), |(d, _)| d.to_owned());
+
+  let variants = item
+    .variants
+    .iter_mut()
+    .map(|v| convert_to_tagged_enum_variant(v, &discriminant))
+    .collect::<BindgenResult<Vec<NapiTaggedEnumVariant>>>()?;
+
+  Ok(Napi {
+    item: NapiItem::TaggedEnum(NapiTaggedEnum {
+      name: item.ident.clone(),
+      js_name,
+      discriminant,
+      variants,
+      js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+      comments: extract_doc_comments(&item.attrs),
+      skip_typescript: opts.skip_typescript().is_some(),
+      register_name: get_register_ident(item.ident.to_string().as_str()),
+    }),
+  })
+}
+
+fn convert_to_tagged_enum_variant(
+  v: &mut syn::Variant,
+  discriminant: &str,
+) -> BindgenResult<NapiTaggedEnumVariant> {
+  if matches!(&v.discriminant, Some((_, _))) {
+    bail_span!(
+      v,
+      "Literal values are not supported with payload variants in #[napi]"
+    )
+  }
+
+  let tag = find_enum_value_and_remove_attribute(v)?.unwrap_or_else(|| v.ident.to_string());
+  let mut fields = vec![];
+  let mut is_tuple = false;
+
+  for (i, field) in v.fields.iter_mut().enumerate() {
+    let field_opts = BindgenAttrs::find(&mut field.attrs)?;
+
+    let (js_name, name) = match &field.ident {
+      Some(ident) => (
+        field_opts.js_name().map_or_else(
+          || ident.unraw().to_string().to_case(Case::Camel),
+          |(js_name, _)| js_name.to_owned(),
+        ),
+        syn::Member::Named(ident.clone()),
+      ),
+      None => {
+        is_tuple = true;
+        (format!("field{}", i), syn::Member::Unnamed(i.into()))
+      }
+    };
+
+    if js_name == discriminant {
+      bail_span!(
+        field,
+        "field `{}` conflicts with the discriminant of the enum, use #[napi(discriminant = \"...\")] to rename the discriminant",
+        js_name
+      );
+    }
+
+    fields.push(NapiStructField {
+      name,
+      js_name,
+      ty: field.ty.clone(),
+      getter: true,
+      setter: true,
+      writable: true,
+      enumerable: true,
+      configurable: true,
+      comments: extract_doc_comments(&field.attrs),
+      skip_typescript: field_opts.skip_typescript().is_some(),
+      ts_type: field_opts.ts_type().map(|e| e.0.to_string()),
+    });
+    field_opts.check_used()?;
+  }
+
+  Ok(NapiTaggedEnumVariant {
+    name: v.ident.clone(),
+    tag,
+    fields,
+    is_tuple,
+    comments: extract_doc_comments(&v.attrs),
+  })
+}
+
 impl ConvertToAST for syn::ItemConst {
   fn convert_to_ast(&mut self, opts: &BindgenAttrs) -> BindgenResult<Napi> {
     match self.vis {
@@ -1276,6 +2015,7 @@ impl ConvertToAST for syn::ItemConst {
           js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
           comments: extract_doc_comments(&self.attrs),
           skip_typescript: opts.skip_typescript().is_some(),
+          ts_type: opts.ts_type().map(|(ty, _)| ty.to_owned()),
           register_name: get_register_ident(self.ident.to_string().as_str()),
         }),
       }),
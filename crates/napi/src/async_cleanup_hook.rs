@@ -1,12 +1,25 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ffi::c_void;
 use std::mem;
 
 use crate::{sys, Status};
 
+thread_local! {
+  /// Handles of removable async cleanup hooks which have neither run nor been removed yet
+  pub(crate) static LIVE_ASYNC_CLEANUP_HOOKS: RefCell<HashSet<usize>> = Default::default();
+}
+
 /// Notice
 /// The hook will be removed if `AsyncCleanupHook` was `dropped`.
 /// If you want keep the hook until node process exited, call the `AsyncCleanupHook::forget`.
-#[repr(transparent)]
-pub struct AsyncCleanupHook(pub(crate) sys::napi_async_cleanup_hook_handle);
+///
+/// Dropping it after the hook has run is a no-op.
+pub struct AsyncCleanupHook {
+  pub(crate) handle: sys::napi_async_cleanup_hook_handle,
+  pub(crate) data: *mut c_void,
+  pub(crate) drop_data: unsafe fn(*mut c_void),
+}
 
 impl AsyncCleanupHook {
   /// Safe to forget it.
@@ -18,11 +31,18 @@ impl AsyncCleanupHook {
 
 impl Drop for AsyncCleanupHook {
   fn drop(&mut self) {
-    let status = unsafe { sys::napi_remove_async_cleanup_hook(self.0) };
+    let is_live = LIVE_ASYNC_CLEANUP_HOOKS
+      .try_with(|hooks| hooks.borrow_mut().remove(&(self.handle as usize)))
+      .unwrap_or(false);
+    if !is_live {
+      return;
+    }
+    let status = unsafe { sys::napi_remove_async_cleanup_hook(self.handle) };
     assert!(
       status == sys::Status::napi_ok,
       "Delete async cleanup hook failed: {}",
       Status::from(status)
     );
+    unsafe { (self.drop_data)(self.data) };
   }
 }
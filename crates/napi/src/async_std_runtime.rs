@@ -0,0 +1,102 @@
+use std::{
+  future::Future,
+  panic::{catch_unwind, AssertUnwindSafe},
+  pin::Pin,
+  task::{Context, Poll},
+};
+
+use async_std::task::JoinHandle;
+
+use crate::{sys, Error, JsDeferred, JsUnknown, NapiValue, Result};
+
+/// Spawns a future onto the async-std executor, which runs the `#[napi] async fn` with the `async_std_rt` feature.
+///
+/// The executor is global and shared with the rest of the process, there is no runtime to create or to drop with the `Env`.
+pub fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+where
+  F: 'static + Send + Future<Output = ()>,
+{
+  async_std::task::spawn(fut)
+}
+
+/// Runs a future to completion
+/// This is blocking, meaning that it pauses other execution until the future is complete,
+/// only use it when it is absolutely necessary, in other places use async functions instead.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+  async_std::task::block_on(fut)
+}
+
+/// spawn_blocking on the thread pool of async-std.
+pub fn spawn_blocking<F, R>(func: F) -> JoinHandle<R>
+where
+  F: FnOnce() -> R + Send + 'static,
+  R: Send + 'static,
+{
+  async_std::task::spawn_blocking(func)
+}
+
+// only called on the JavaScript thread by the threadsafe function of the `JsDeferred`,
+// the `Send` bound comes from `async_std::task::spawn`
+struct SendableResolver<R>(R);
+
+unsafe impl<R> Send for SendableResolver<R> {}
+
+impl<R> SendableResolver<R> {
+  fn resolve<Data>(self, env: sys::napi_env, data: Data) -> Result<sys::napi_value>
+  where
+    R: FnOnce(sys::napi_env, Data) -> Result<sys::napi_value>,
+  {
+    (self.0)(env, data)
+  }
+}
+
+// a panic would be lost with the task, and the promise left pending, reject it instead
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+  type Output = std::thread::Result<F::Output>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let fut = self.0.as_mut();
+    match catch_unwind(AssertUnwindSafe(|| fut.poll(cx))) {
+      Ok(Poll::Pending) => Poll::Pending,
+      Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+      Err(reason) => Poll::Ready(Err(reason)),
+    }
+  }
+}
+
+/// Same as `execute_tokio_future` of the `tokio_rt` feature, but on the async-std executor, the `#[napi] async fn` are run by it
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn execute_future<
+  Data: 'static + Send,
+  Fut: 'static + Send + Future<Output = Result<Data>>,
+  Resolver: 'static + FnOnce(sys::napi_env, Data) -> Result<sys::napi_value>,
+>(
+  env: sys::napi_env,
+  fut: Fut,
+  resolver: Resolver,
+) -> Result<sys::napi_value> {
+  let (deferred, promise) = JsDeferred::new(env)?;
+  let sendable_resolver = SendableResolver(resolver);
+
+  spawn(async move {
+    match CatchUnwind(Box::pin(fut)).await {
+      Ok(Ok(v)) => deferred.resolve(move |env| {
+        sendable_resolver
+          .resolve(env.raw(), v)
+          .map(|v| unsafe { JsUnknown::from_raw_unchecked(env.raw(), v) })
+      }),
+      Ok(Err(e)) => deferred.reject(e),
+      Err(reason) => deferred.reject(Error::new(
+        crate::Status::GenericFailure,
+        match reason.downcast_ref::<&str>() {
+          Some(s) => *s,
+          None => "Panic in async function",
+        },
+      )),
+    }
+  });
+
+  Ok(promise.0.value)
+}
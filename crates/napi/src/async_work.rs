@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::ffi::CString;
 use std::mem;
 use std::os::raw::c_void;
@@ -10,12 +12,130 @@ use crate::{
   Result, Task,
 };
 
+const DEFAULT_ASYNC_RESOURCE_NAME: &str = "napi_rs_async_work";
+/// The async work waiting in `AsyncWorkQueue` isn't queued into libuv yet, so it can't be cancelled by `napi_cancel_async_work`
+pub(crate) const PENDING_IN_QUEUE: u8 = 3;
+
+/// Options of the async work created by `Env::spawn_with_options` or `AsyncTask`
+#[derive(Clone, Default)]
+pub struct AsyncWorkOptions {
+  resource_name: Option<String>,
+  queue: Option<AsyncWorkQueue>,
+}
+
+impl AsyncWorkOptions {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The name `async_hooks` reports as the type of the async resource, `napi_rs_async_work` by default
+  pub fn with_resource_name<S: Into<String>>(mut self, name: S) -> Self {
+    self.resource_name = Some(name.into());
+    self
+  }
+
+  /// Wait for a free slot in `queue` before running in libuv thread pool
+  pub fn with_queue(mut self, queue: &AsyncWorkQueue) -> Self {
+    self.queue = Some(queue.clone());
+    self
+  }
+}
+
+/// Limits how many async works queued through it run in libuv thread pool at the same time, the others wait in FIFO order.
+///
+/// It can only be used on the JavaScript thread which created it, keep it in a thread local or in `Env::get_or_init_instance_data`.
+#[derive(Clone)]
+pub struct AsyncWorkQueue {
+  inner: Rc<RefCell<AsyncWorkQueueInner>>,
+}
+
+struct AsyncWorkQueueInner {
+  max_concurrency: usize,
+  running: usize,
+  pending: VecDeque<PendingAsyncWork>,
+}
+
+struct PendingAsyncWork {
+  env: sys::napi_env,
+  work: sys::napi_async_work,
+  status: Rc<AtomicU8>,
+}
+
+impl AsyncWorkQueue {
+  /// `max_concurrency` of 0 is treated as 1
+  pub fn new(max_concurrency: usize) -> Self {
+    Self {
+      inner: Rc::new(RefCell::new(AsyncWorkQueueInner {
+        max_concurrency: max_concurrency.max(1),
+        running: 0,
+        pending: VecDeque::new(),
+      })),
+    }
+  }
+
+  /// The number of async works running in libuv thread pool
+  pub fn running(&self) -> usize {
+    self.inner.borrow().running
+  }
+
+  /// The number of async works waiting for a free slot
+  pub fn pending(&self) -> usize {
+    self.inner.borrow().pending.len()
+  }
+
+  fn push(
+    &self,
+    env: sys::napi_env,
+    work: sys::napi_async_work,
+    status: &Rc<AtomicU8>,
+  ) -> Result<()> {
+    let mut inner = self.inner.borrow_mut();
+    if inner.running >= inner.max_concurrency {
+      status.store(PENDING_IN_QUEUE, Ordering::Relaxed);
+      inner.pending.push_back(PendingAsyncWork {
+        env,
+        work,
+        status: status.clone(),
+      });
+      return Ok(());
+    }
+    check_status!(unsafe { sys::napi_queue_async_work(env, work) })?;
+    inner.running += 1;
+    Ok(())
+  }
+
+  fn finish(&self) {
+    let mut inner = self.inner.borrow_mut();
+    inner.running -= 1;
+    while let Some(pending) = inner.pending.pop_front() {
+      let status = unsafe { sys::napi_queue_async_work(pending.env, pending.work) };
+      debug_assert!(
+        status == sys::Status::napi_ok,
+        "Queue pending async work failed, status: {:?}",
+        crate::Status::from(status)
+      );
+      if status != sys::Status::napi_ok {
+        continue;
+      }
+      inner.running += 1;
+      // aborted while waiting in the queue, it still needs to go through `complete` to be freed
+      if pending.status.load(Ordering::Relaxed) == 2 {
+        unsafe { sys::napi_cancel_async_work(pending.env, pending.work) };
+      } else {
+        pending.status.store(0, Ordering::Relaxed);
+      }
+      break;
+    }
+  }
+}
+
 struct AsyncWork<T: Task> {
   inner_task: T,
   deferred: sys::napi_deferred,
   value: Result<mem::MaybeUninit<T::Output>>,
   napi_async_work: sys::napi_async_work,
   status: Rc<AtomicU8>,
+  queue: Option<AsyncWorkQueue>,
 }
 
 pub struct AsyncWorkPromise {
@@ -27,6 +147,7 @@ pub struct AsyncWorkPromise {
   /// 0: not started
   /// 1: completed
   /// 2: canceled
+  /// 3: waiting in `AsyncWorkQueue`
   pub(crate) status: Rc<AtomicU8>,
 }
 
@@ -37,7 +158,10 @@ impl AsyncWorkPromise {
 
   pub fn cancel(&self) -> Result<()> {
     // must be happened in the main thread, relaxed is enough
-    self.status.store(2, Ordering::Relaxed);
+    if self.status.swap(2, Ordering::Relaxed) == PENDING_IN_QUEUE {
+      // cancelled by `AsyncWorkQueue` when it's dequeued
+      return Ok(());
+    }
     check_status!(unsafe { sys::napi_cancel_async_work(self.env, self.napi_async_work) })
   }
 }
@@ -46,6 +170,7 @@ pub fn run<T: Task>(
   env: sys::napi_env,
   task: T,
   abort_status: Option<Rc<AtomicU8>>,
+  options: AsyncWorkOptions,
 ) -> Result<AsyncWorkPromise> {
   let mut raw_resource = ptr::null_mut();
   check_status!(unsafe { sys::napi_create_object(env, &mut raw_resource) })?;
@@ -59,9 +184,13 @@ pub fn run<T: Task>(
     value: Ok(mem::MaybeUninit::zeroed()),
     napi_async_work: ptr::null_mut(),
     status: task_status.clone(),
+    queue: options.queue.clone(),
   }));
   let mut async_work_name = ptr::null_mut();
-  let s = "napi_rs_async_work";
+  let s = options
+    .resource_name
+    .as_deref()
+    .unwrap_or(DEFAULT_ASYNC_RESOURCE_NAME);
   let len = s.len();
   let s = CString::new(s)?;
   check_status!(unsafe {
@@ -78,7 +207,10 @@ pub fn run<T: Task>(
       &mut result.napi_async_work,
     )
   })?;
-  check_status!(unsafe { sys::napi_queue_async_work(env, result.napi_async_work) })?;
+  match &options.queue {
+    Some(queue) => queue.push(env, result.napi_async_work, &task_status)?,
+    None => check_status!(unsafe { sys::napi_queue_async_work(env, result.napi_async_work) })?,
+  };
   Ok(AsyncWorkPromise {
     napi_async_work: result.napi_async_work,
     raw_promise,
@@ -152,4 +284,7 @@ unsafe extern "C" fn complete<T: Task>(
     crate::Status::from(delete_status)
   );
   work.status.store(1, Ordering::Relaxed);
+  if let Some(queue) = work.queue.take() {
+    queue.finish();
+  }
 }
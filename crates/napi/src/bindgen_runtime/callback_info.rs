@@ -88,7 +88,7 @@ impl<const N: usize> CallbackInfo<N> {
     self.this
   }
 
-  fn _construct<const IsEmptyStructHint: bool, T: ObjectFinalize + 'static>(
+  fn _construct<const IsEmptyStructHint: bool, T: ObjectFinalize + JavaScriptClass>(
     &self,
     js_name: &str,
     obj: T,
@@ -117,6 +117,7 @@ impl<const N: usize> CallbackInfo<N> {
         "Failed to initialize class `{}`",
         js_name,
       )?;
+      tag_class_instance::<T>(self.env, this)?;
     };
 
     Reference::<T>::add_ref(
@@ -127,7 +128,7 @@ impl<const N: usize> CallbackInfo<N> {
     Ok((this, value_ref))
   }
 
-  pub fn construct<const IsEmptyStructHint: bool, T: ObjectFinalize + 'static>(
+  pub fn construct<const IsEmptyStructHint: bool, T: ObjectFinalize + JavaScriptClass>(
     &self,
     js_name: &str,
     obj: T,
@@ -139,7 +140,7 @@ impl<const N: usize> CallbackInfo<N> {
 
   pub fn construct_generator<
     const IsEmptyStructHint: bool,
-    T: Generator + ObjectFinalize + 'static,
+    T: Generator + ObjectFinalize + JavaScriptClass,
   >(
     &self,
     js_name: &str,
@@ -150,7 +151,7 @@ impl<const N: usize> CallbackInfo<N> {
     Ok(instance)
   }
 
-  pub fn factory<T: ObjectFinalize + 'static>(
+  pub fn factory<T: ObjectFinalize + JavaScriptClass>(
     &self,
     js_name: &str,
     obj: T,
@@ -158,7 +159,7 @@ impl<const N: usize> CallbackInfo<N> {
     self._factory(js_name, obj).map(|(value, _)| value)
   }
 
-  pub fn generator_factory<T: ObjectFinalize + Generator + 'static>(
+  pub fn generator_factory<T: ObjectFinalize + Generator + JavaScriptClass>(
     &self,
     js_name: &str,
     obj: T,
@@ -168,7 +169,7 @@ impl<const N: usize> CallbackInfo<N> {
     Ok(instance)
   }
 
-  fn _factory<T: ObjectFinalize + 'static>(
+  fn _factory<T: ObjectFinalize + JavaScriptClass>(
     &self,
     js_name: &str,
     obj: T,
@@ -222,6 +223,7 @@ impl<const N: usize> CallbackInfo<N> {
       "Failed to initialize class `{}`",
       js_name,
     )?;
+    unsafe { tag_class_instance::<T>(self.env, instance)? };
 
     Reference::<T>::add_ref(
       self.env,
@@ -233,14 +235,14 @@ impl<const N: usize> CallbackInfo<N> {
 
   pub fn unwrap_borrow_mut<T>(&mut self) -> Result<&'static mut T>
   where
-    T: FromNapiMutRef + TypeName,
+    T: FromNapiMutRef + TypeName + JavaScriptClass,
   {
     unsafe { self.unwrap_raw::<T>() }.map(|raw| Box::leak(unsafe { Box::from_raw(raw) }))
   }
 
   pub fn unwrap_borrow<T>(&mut self) -> Result<&'static T>
   where
-    T: FromNapiRef + TypeName,
+    T: FromNapiRef + TypeName + JavaScriptClass,
   {
     unsafe { self.unwrap_raw::<T>() }
       .map(|raw| Box::leak(unsafe { Box::from_raw(raw) }) as &'static T)
@@ -250,11 +252,12 @@ impl<const N: usize> CallbackInfo<N> {
   #[inline]
   pub unsafe fn unwrap_raw<T>(&mut self) -> Result<*mut T>
   where
-    T: TypeName,
+    T: TypeName + JavaScriptClass,
   {
     let mut wrapped_val: *mut c_void = std::ptr::null_mut();
 
     unsafe {
+      check_class_instance::<T>(self.env, self.this)?;
       check_status!(
         sys::napi_unwrap(self.env, self.this, &mut wrapped_val),
         "Failed to unwrap exclusive reference of `{}` type from napi value",
@@ -1,6 +1,6 @@
 use std::ptr;
 
-use crate::{check_status, sys, JsGlobal, JsNull, JsUndefined, NapiValue, Result};
+use crate::{check_status, sys, JsGlobal, JsNull, JsProcess, JsUndefined, NapiValue, Result};
 
 use super::Array;
 
@@ -38,4 +38,9 @@ impl Env {
       value_type: crate::ValueType::Object,
     }))
   }
+
+  /// The `process` object of Node.js, to read `process.env` or `process.argv` as seen by JavaScript
+  pub fn node_process(&self) -> Result<JsProcess> {
+    self.get_global()?.process()
+  }
 }
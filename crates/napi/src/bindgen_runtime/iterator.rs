@@ -10,6 +10,8 @@ const GENERATOR_STATE_KEY: &str = "[[GeneratorState]]\0";
 
 /// Implement a Iterator for the JavaScript Class.
 /// This feature is an experimental feature and is not yet stable.
+///
+/// A `#[napi] impl Iterator for Struct` is also exposed through this trait, with `Item` as the `Yield` type.
 pub trait Generator {
   type Yield: ToNapiValue;
   type Next: FromNapiValue;
@@ -12,6 +12,7 @@ mod arraybuffer;
 mod bigint;
 mod boolean;
 mod buffer;
+mod buffer_pool;
 mod class;
 #[cfg(all(feature = "chrono_date", feature = "napi5"))]
 mod date;
@@ -20,12 +21,17 @@ mod external;
 mod function;
 mod map;
 mod nil;
+#[cfg(all(feature = "stream", feature = "napi5"))]
+mod node_stream;
 mod number;
 mod object;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 mod promise;
 #[cfg(feature = "serde-json")]
 mod serde;
+mod set;
+#[cfg(feature = "stream")]
+mod stream;
 mod string;
 mod symbol;
 mod task;
@@ -39,14 +45,19 @@ pub use arraybuffer::*;
 #[cfg(feature = "napi6")]
 pub use bigint::*;
 pub use buffer::*;
+pub use buffer_pool::BufferPool;
 pub use class::*;
 pub use either::*;
 pub use external::*;
 pub use function::*;
 pub use nil::*;
+#[cfg(all(feature = "stream", feature = "napi5"))]
+pub use node_stream::*;
 pub use object::*;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 pub use promise::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
 pub use string::*;
 pub use symbol::*;
 pub use task::*;
@@ -42,6 +42,24 @@ impl Array {
     }
   }
 
+  // the handles of the elements are released every `ELEMENTS_PER_SCOPE` elements,
+  // so converting a large collection doesn't keep a handle per element until the native call returns
+  fn set_elements<T: ToNapiValue, I: IntoIterator<Item = T>>(&mut self, values: I) -> Result<()> {
+    const ELEMENTS_PER_SCOPE: usize = 1024;
+
+    let env = Env::from_raw(self.env);
+    let mut values = values.into_iter().enumerate().peekable();
+    while values.peek().is_some() {
+      env.with_handle_scope(|_| {
+        for (index, val) in values.by_ref().take(ELEMENTS_PER_SCOPE) {
+          self.set(index as u32, val)?;
+        }
+        Ok(())
+      })?;
+    }
+    Ok(())
+  }
+
   pub fn set<T: ToNapiValue>(&mut self, index: u32, val: T) -> Result<()> {
     unsafe {
       let napi_val = T::to_napi_value(self.env, val)?;
@@ -134,20 +152,14 @@ impl Array {
     T: ToNapiValue,
   {
     let mut arr = Array::new(env.0, value.len() as u32)?;
-    value.into_iter().enumerate().try_for_each(|(index, val)| {
-      arr.set(index as u32, val)?;
-      Ok::<(), Error>(())
-    })?;
+    arr.set_elements(value)?;
     Ok(arr)
   }
 
   /// Create `Array` from `&Vec<String>`
   pub fn from_ref_vec_string(env: &Env, value: &[String]) -> Result<Self> {
     let mut arr = Array::new(env.0, value.len() as u32)?;
-    value.iter().enumerate().try_for_each(|(index, val)| {
-      arr.set(index as u32, val.as_str())?;
-      Ok::<(), Error>(())
-    })?;
+    arr.set_elements(value.iter().map(String::as_str))?;
     Ok(arr)
   }
 
@@ -157,10 +169,7 @@ impl Array {
     T: ToNapiValue + Copy,
   {
     let mut arr = Array::new(env.0, value.len() as u32)?;
-    value.iter().enumerate().try_for_each(|(index, val)| {
-      arr.set(index as u32, *val)?;
-      Ok::<(), Error>(())
-    })?;
+    arr.set_elements(value.iter().copied())?;
     Ok(arr)
   }
 }
@@ -184,9 +193,7 @@ where
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let mut arr = Array::new(env, val.len() as u32)?;
 
-    for (i, v) in val.into_iter().enumerate() {
-      arr.set(i as u32, v)?;
-    }
+    arr.set_elements(val)?;
 
     unsafe { Array::to_napi_value(env, arr) }
   }
@@ -199,9 +206,7 @@ where
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let mut arr = Array::new(env, val.len() as u32)?;
 
-    for (i, v) in val.iter().enumerate() {
-      arr.set(i as u32, *v)?;
-    }
+    arr.set_elements(val.iter().copied())?;
 
     unsafe { Array::to_napi_value(env, arr) }
   }
@@ -214,9 +219,7 @@ where
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let mut arr = Array::new(env, val.len() as u32)?;
 
-    for (i, v) in val.into_iter().enumerate() {
-      arr.set(i as u32, v)?;
-    }
+    arr.set_elements(val)?;
 
     unsafe { Array::to_napi_value(env, arr) }
   }
@@ -228,9 +231,7 @@ macro_rules! impl_for_primitive_type {
       unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
         let mut arr = Array::new(env, val.len() as u32)?;
 
-        for (i, v) in val.iter().enumerate() {
-          arr.set(i as u32, *v)?;
-        }
+        arr.set_elements(val.iter().copied())?;
 
         unsafe { Array::to_napi_value(env, arr) }
       }
@@ -252,9 +253,7 @@ impl ToNapiValue for &Vec<String> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let mut arr = Array::new(env, val.len() as u32)?;
 
-    for (i, v) in val.iter().enumerate() {
-      arr.set(i as u32, v.as_str())?;
-    }
+    arr.set_elements(val.iter().map(String::as_str))?;
 
     unsafe { Array::to_napi_value(env, arr) }
   }
@@ -302,6 +302,31 @@ macro_rules! impl_typed_array {
             "Expected a TypedArray value".to_owned(),
           ));
         }
+        let mut typed_array_type = 0;
+        check_status!(
+          unsafe {
+            sys::napi_get_typedarray_info(
+              env,
+              napi_val,
+              &mut typed_array_type,
+              ptr::null_mut(),
+              ptr::null_mut(),
+              ptr::null_mut(),
+              ptr::null_mut(),
+            )
+          },
+          "Get TypedArray info failed"
+        )?;
+        if typed_array_type != $typed_array_type as i32 {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+              "Expected {}, got {:?}",
+              stringify!($name),
+              TypedArrayType::from(typed_array_type)
+            ),
+          ));
+        }
         Ok(ptr::null_mut())
       }
     }
@@ -335,7 +360,11 @@ macro_rules! impl_typed_array {
         if typed_array_type != $typed_array_type as i32 {
           return Err(Error::new(
             Status::InvalidArg,
-            format!("Expected $name, got {}", typed_array_type),
+            format!(
+              "Expected {}, got {:?}",
+              stringify!($name),
+              TypedArrayType::from(typed_array_type)
+            ),
           ));
         }
         Ok($name {
@@ -385,14 +414,16 @@ macro_rules! impl_typed_array {
           } else {
             let hint_ptr = Box::into_raw(Box::new(val));
             let status = unsafe {
-              sys::napi_create_external_arraybuffer(
-                env,
-                val_data.cast(),
-                length,
-                Some(finalizer::<$rust_type, $name>),
-                hint_ptr.cast(),
-                &mut arraybuffer_value,
-              )
+              crate::buffer_policy::create_external(|| {
+                sys::napi_create_external_arraybuffer(
+                  env,
+                  val_data.cast(),
+                  length,
+                  Some(finalizer::<$rust_type, $name>),
+                  hint_ptr.cast(),
+                  &mut arraybuffer_value,
+                )
+              })
             };
             if status == napi_sys::Status::napi_no_external_buffers_allowed {
               let hint = unsafe { Box::from_raw(hint_ptr) };
@@ -647,6 +678,48 @@ impl_typed_array!(BigUint64Array, u64, TypedArrayType::BigUint64);
 #[cfg(feature = "napi6")]
 impl_from_slice!(BigUint64Array, u64, TypedArrayType::BigUint64);
 
+/// Element types which have a corresponding JavaScript `TypedArray` class.
+///
+/// `u8` maps to `Uint8Array`, use `Uint8ClampedArray` directly for the clamped variant.
+pub trait TypedArrayElement: Copy + Sized {
+  type Array: FromNapiValue
+    + ToNapiValue
+    + TypeName
+    + ValidateNapiValue
+    + Clone
+    + Deref<Target = [Self]>
+    + DerefMut
+    + From<Vec<Self>>;
+
+  const TYPED_ARRAY_TYPE: TypedArrayType;
+}
+
+/// Generic alias over the typed array wrappers, `TypedArray<f64>` is `Float64Array`, `TypedArray<i32>` is `Int32Array` and so on.
+pub type TypedArray<T> = <T as TypedArrayElement>::Array;
+
+macro_rules! impl_typed_array_element {
+  ($name:ident, $rust_type:ident, $typed_array_type:expr) => {
+    impl TypedArrayElement for $rust_type {
+      type Array = $name;
+
+      const TYPED_ARRAY_TYPE: TypedArrayType = $typed_array_type;
+    }
+  };
+}
+
+impl_typed_array_element!(Int8Array, i8, TypedArrayType::Int8);
+impl_typed_array_element!(Uint8Array, u8, TypedArrayType::Uint8);
+impl_typed_array_element!(Int16Array, i16, TypedArrayType::Int16);
+impl_typed_array_element!(Uint16Array, u16, TypedArrayType::Uint16);
+impl_typed_array_element!(Int32Array, i32, TypedArrayType::Int32);
+impl_typed_array_element!(Uint32Array, u32, TypedArrayType::Uint32);
+impl_typed_array_element!(Float32Array, f32, TypedArrayType::Float32);
+impl_typed_array_element!(Float64Array, f64, TypedArrayType::Float64);
+#[cfg(feature = "napi6")]
+impl_typed_array_element!(BigInt64Array, i64, TypedArrayType::BigInt64);
+#[cfg(feature = "napi6")]
+impl_typed_array_element!(BigUint64Array, u64, TypedArrayType::BigUint64);
+
 /// Zero copy Uint8ClampedArray slice shared between Rust and Node.js.
 /// It can only be used in non-async context and the lifetime is bound to the fn closure.
 /// If you want to use Node.js `Uint8ClampedArray` in async context or want to extend the lifetime, use `Uint8ClampedArray` instead.
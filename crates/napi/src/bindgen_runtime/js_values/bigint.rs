@@ -1,5 +1,6 @@
 /// We don't implement `FromNapiValue` for `i64` `u64` `i128` `u128` `isize` `usize` here
 /// Because converting directly from `JsBigInt` to these values may result in a loss of precision and thus unintended behavior
+/// Use the `get_*` methods to get the value with a lossless flag, or `TryFrom<BigInt>` to fail on precision loss
 /// ```rust
 /// use napi::{bindgen_prelude::*, JsBigint};
 ///
@@ -94,8 +95,18 @@ impl BigInt {
   /// return true if the value is lossless
   /// or the value is truncated
   pub fn get_i64(&self) -> (i64, bool) {
-    let val = self.words[0] as i64;
-    (val, val as u64 == self.words[0] && self.words.len() == 1)
+    let magnitude = self.words[0];
+    let val = if self.sign_bit {
+      (magnitude as i64).wrapping_neg()
+    } else {
+      magnitude as i64
+    };
+    let in_range = if self.sign_bit {
+      magnitude <= i64::MIN.unsigned_abs()
+    } else {
+      magnitude <= i64::MAX as u64
+    };
+    (val, in_range && self.words.len() == 1)
   }
 
   /// (value, lossless)
@@ -103,16 +114,18 @@ impl BigInt {
   /// return true if the value is lossless
   /// or the value is truncated
   pub fn get_i128(&self) -> (i128, bool) {
-    let len = self.words.len();
-    if len == 1 {
-      (self.words[0] as i128, false)
+    let magnitude = self.low_u128();
+    let val = if self.sign_bit {
+      (magnitude as i128).wrapping_neg()
     } else {
-      let mut val = self.words[0] as i128 + ((self.words[1] as i128) << 64);
-      if self.sign_bit {
-        val = -val;
-      }
-      (val, len > 2)
-    }
+      magnitude as i128
+    };
+    let in_range = if self.sign_bit {
+      magnitude <= i128::MIN.unsigned_abs()
+    } else {
+      magnitude <= i128::MAX as u128
+    };
+    (val, in_range && self.words.len() <= 2)
   }
 
   /// (signed, value, lossless)
@@ -120,13 +133,18 @@ impl BigInt {
   /// return true if the value is lossless
   /// or the value is truncated
   pub fn get_u128(&self) -> (bool, u128, bool) {
-    let len = self.words.len();
-    if len == 1 {
-      (self.sign_bit, self.words[0] as u128, false)
-    } else {
-      let val = self.words[0] as u128 + ((self.words[1] as u128) << 64);
-      (self.sign_bit, val, len > 2)
-    }
+    (
+      self.sign_bit,
+      self.low_u128(),
+      !self.sign_bit && self.words.len() <= 2,
+    )
+  }
+
+  /// The first two words of the BigInt
+  fn low_u128(&self) -> u128 {
+    let low = self.words.first().copied().unwrap_or(0) as u128;
+    let high = self.words.get(1).copied().unwrap_or(0) as u128;
+    low | (high << 64)
   }
 }
 
@@ -153,15 +171,9 @@ impl ToNapiValue for BigInt {
 impl ToNapiValue for i128 {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
     let mut raw_value = ptr::null_mut();
-    let sign_bit = i32::from(val <= 0);
-    if cfg!(target_endian = "little") {
-      let words = &val as *const i128 as *const u64;
-      check_status!(unsafe {
-        sys::napi_create_bigint_words(env, sign_bit, 2, words, &mut raw_value)
-      })?;
-      return Ok(raw_value);
-    }
-
+    let sign_bit = i32::from(val < 0);
+    // `napi_create_bigint_words` expects the magnitude of the value, not the two's complement
+    let val = val.unsigned_abs();
     let arr: [u64; 2] = [val as _, (val >> 64) as _];
     let words = &arr as *const u64;
     check_status!(unsafe {
@@ -223,7 +235,7 @@ impl From<i64> for BigInt {
   fn from(val: i64) -> Self {
     BigInt {
       sign_bit: val < 0,
-      words: vec![val as u64],
+      words: vec![val.unsigned_abs()],
     }
   }
 }
@@ -240,7 +252,7 @@ impl From<u64> for BigInt {
 impl From<i128> for BigInt {
   fn from(val: i128) -> Self {
     let sign_bit = val < 0;
-    let val = if sign_bit { -val } else { val };
+    let val = val.unsigned_abs();
     BigInt {
       sign_bit,
       words: vec![val as _, (val >> 64) as _],
@@ -256,3 +268,70 @@ impl From<u128> for BigInt {
     }
   }
 }
+
+impl BigInt {
+  /// The absolute value of the BigInt, `None` if it doesn't fit in an `u128`
+  fn magnitude(&self) -> Option<u128> {
+    if self.words.iter().skip(2).any(|word| *word != 0) {
+      return None;
+    }
+    let low = self.words.first().copied().unwrap_or(0) as u128;
+    let high = self.words.get(1).copied().unwrap_or(0) as u128;
+    Some(low | (high << 64))
+  }
+}
+
+fn out_of_range(ty: &str) -> crate::Error {
+  crate::Error::new(
+    crate::Status::InvalidArg,
+    format!("BigInt can not be converted to `{ty}` without losing precision"),
+  )
+}
+
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt doesn't fit in an `i128`
+impl TryFrom<BigInt> for i128 {
+  type Error = crate::Error;
+
+  fn try_from(value: BigInt) -> crate::Result<i128> {
+    let magnitude = value.magnitude().ok_or_else(|| out_of_range("i128"))?;
+    if value.sign_bit {
+      if magnitude > i128::MIN.unsigned_abs() {
+        return Err(out_of_range("i128"));
+      }
+      Ok((magnitude as i128).wrapping_neg())
+    } else {
+      i128::try_from(magnitude).map_err(|_| out_of_range("i128"))
+    }
+  }
+}
+
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt is negative or doesn't fit in an `u128`
+impl TryFrom<BigInt> for u128 {
+  type Error = crate::Error;
+
+  fn try_from(value: BigInt) -> crate::Result<u128> {
+    match value.magnitude() {
+      Some(0) => Ok(0),
+      Some(magnitude) if !value.sign_bit => Ok(magnitude),
+      _ => Err(out_of_range("u128")),
+    }
+  }
+}
+
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt doesn't fit in an `i64`
+impl TryFrom<BigInt> for i64 {
+  type Error = crate::Error;
+
+  fn try_from(value: BigInt) -> crate::Result<i64> {
+    i128::try_from(value).and_then(|v| i64::try_from(v).map_err(|_| out_of_range("i64")))
+  }
+}
+
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt is negative or doesn't fit in an `u64`
+impl TryFrom<BigInt> for u64 {
+  type Error = crate::Error;
+
+  fn try_from(value: BigInt) -> crate::Result<u64> {
+    u128::try_from(value).and_then(|v| u64::try_from(v).map_err(|_| out_of_range("u64")))
+  }
+}
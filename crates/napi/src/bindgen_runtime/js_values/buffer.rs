@@ -11,7 +11,10 @@ use std::sync::Mutex;
 
 #[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
 use crate::bindgen_prelude::{CUSTOM_GC_TSFN, CUSTOM_GC_TSFN_DESTROYED, THREADS_CAN_ACCESS_ENV};
-use crate::{bindgen_prelude::*, check_status, sys, Result, ValueType};
+use crate::{
+  bindgen_prelude::*, check_status, external_memory::adjust_external_memory_unchecked, sys, Result,
+  ValueType,
+};
 
 #[cfg(all(debug_assertions, not(windows)))]
 thread_local! {
@@ -52,6 +55,17 @@ impl<'scope> FromNapiValue for BufferSlice<'scope> {
   }
 }
 
+impl<'scope> BufferSlice<'scope> {
+  pub fn as_slice(&self) -> &[u8] {
+    self.inner
+  }
+
+  /// Mutate the underlying `Buffer` in place, the changes are visible to JavaScript without copying
+  pub fn as_mut_slice(&mut self) -> &mut [u8] {
+    self.inner
+  }
+}
+
 impl ToNapiValue for BufferSlice<'_> {
   #[allow(unused_variables)]
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
@@ -92,6 +106,12 @@ impl AsRef<[u8]> for BufferSlice<'_> {
   }
 }
 
+impl AsMut<[u8]> for BufferSlice<'_> {
+  fn as_mut(&mut self) -> &mut [u8] {
+    self.inner
+  }
+}
+
 impl<'scope> Deref for BufferSlice<'scope> {
   type Target = [u8];
 
@@ -119,6 +139,18 @@ pub struct Buffer {
   pub(crate) capacity: usize,
   raw: Option<(sys::napi_ref, sys::napi_env)>,
   pub(crate) ref_count: Arc<()>,
+  pub(crate) pool: Option<BufferPool>,
+  force_copy: bool,
+}
+
+impl Buffer {
+  /// Copy the data into a new JavaScript `Buffer` when converting it, rather than sharing the memory with JavaScript.
+  ///
+  /// A `Buffer` received from JavaScript is converted back to the same JavaScript `Buffer`.
+  pub fn force_copy(mut self) -> Self {
+    self.force_copy = true;
+    self
+  }
 }
 
 impl Drop for Buffer {
@@ -165,7 +197,10 @@ impl Drop for Buffer {
           "Failed to delete Buffer reference in drop"
         );
       } else {
-        unsafe { Vec::from_raw_parts(self.inner.as_ptr(), self.len, self.capacity) };
+        let data = unsafe { Vec::from_raw_parts(self.inner.as_ptr(), self.len, self.capacity) };
+        if let Some(pool) = &self.pool {
+          pool.recycle(data);
+        }
       }
     }
   }
@@ -183,6 +218,8 @@ impl Clone for Buffer {
       capacity: self.capacity,
       raw: self.raw,
       ref_count: self.ref_count.clone(),
+      pool: self.pool.clone(),
+      force_copy: self.force_copy,
     }
   }
 }
@@ -218,6 +255,8 @@ impl From<Vec<u8>> for Buffer {
       capacity,
       raw: None,
       ref_count: Arc::new(()),
+      pool: None,
+      force_copy: false,
     }
   }
 }
@@ -313,6 +352,8 @@ impl FromNapiValue for Buffer {
       capacity: len,
       raw: Some((ref_, env)),
       ref_count: Arc::new(()),
+      pool: None,
+      force_copy: false,
     })
   }
 }
@@ -337,6 +378,7 @@ impl ToNapiValue for Buffer {
       return Ok(buf);
     }
     let len = val.len;
+    let capacity = val.capacity;
     let mut ret = ptr::null_mut();
     check_status!(
       if len == 0 {
@@ -346,16 +388,23 @@ impl ToNapiValue for Buffer {
         unsafe { sys::napi_create_buffer(env, len, ptr::null_mut(), &mut ret) }
       } else {
         let value_ptr = val.inner.as_ptr();
+        let force_copy = val.force_copy;
         let val_box_ptr = Box::into_raw(Box::new(val));
-        let mut status = unsafe {
-          sys::napi_create_external_buffer(
-            env,
-            len,
-            value_ptr as *mut c_void,
-            Some(drop_buffer),
-            val_box_ptr as *mut c_void,
-            &mut ret,
-          )
+        let mut status = if force_copy {
+          napi_sys::Status::napi_no_external_buffers_allowed
+        } else {
+          unsafe {
+            crate::buffer_policy::create_external(|| {
+              sys::napi_create_external_buffer(
+                env,
+                len,
+                value_ptr as *mut c_void,
+                Some(drop_buffer),
+                val_box_ptr as *mut c_void,
+                &mut ret,
+              )
+            })
+          }
         };
         if status == napi_sys::Status::napi_no_external_buffers_allowed {
           let value = unsafe { Box::from_raw(val_box_ptr) };
@@ -368,6 +417,10 @@ impl ToNapiValue for Buffer {
               &mut ret,
             )
           };
+        } else if status == napi_sys::Status::napi_ok {
+          // V8 only sees `len` bytes of the backing store, report the spare capacity of the `Vec` as well
+          // `drop_buffer` reports it as released
+          unsafe { adjust_external_memory_unchecked(env, spare_capacity(capacity, len)) };
         }
         status
       },
@@ -378,6 +431,10 @@ impl ToNapiValue for Buffer {
   }
 }
 
+pub(crate) fn spare_capacity(capacity: usize, len: usize) -> i64 {
+  capacity.saturating_sub(len) as i64
+}
+
 impl ToNapiValue for &Buffer {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     let buf = val.clone();
@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+
+use super::Buffer;
+
+/// Reuses the backing memory of the `Buffer`s sent to JavaScript.
+///
+/// The memory of a `Buffer` created by `BufferPool::buffer` goes back to the pool when the JavaScript `Buffer` is garbage collected,
+/// so streaming many short-lived `Buffer`s doesn't allocate and free a `Vec` for each of them.
+#[derive(Clone)]
+pub struct BufferPool {
+  inner: Arc<BufferPoolInner>,
+}
+
+struct BufferPoolInner {
+  buffer_capacity: usize,
+  max_buffers: usize,
+  buffers: Mutex<Vec<Vec<u8>>>,
+}
+
+impl BufferPool {
+  /// A pool handing out `Vec`s of at least `buffer_capacity` bytes and keeping up to `max_buffers` of them when they are returned
+  pub fn new(buffer_capacity: usize, max_buffers: usize) -> Self {
+    Self {
+      inner: Arc::new(BufferPoolInner {
+        buffer_capacity,
+        max_buffers,
+        buffers: Mutex::new(Vec::with_capacity(max_buffers)),
+      }),
+    }
+  }
+
+  /// An empty `Vec` from the pool, or a new one if the pool is empty
+  pub fn take(&self) -> Vec<u8> {
+    self
+      .inner
+      .buffers
+      .lock()
+      .expect("Lock BufferPool failed")
+      .pop()
+      .unwrap_or_else(|| Vec::with_capacity(self.inner.buffer_capacity))
+  }
+
+  /// Create a `Buffer` from `data`, its memory is returned to the pool once the `Buffer` is dropped or garbage collected
+  pub fn buffer(&self, data: Vec<u8>) -> Buffer {
+    let mut buffer = Buffer::from(data);
+    buffer.pool = Some(self.clone());
+    buffer
+  }
+
+  /// Return `data` to the pool, it's dropped if the pool is full or if it's smaller than the capacity of the pool
+  pub fn recycle(&self, mut data: Vec<u8>) {
+    if data.capacity() < self.inner.buffer_capacity {
+      return;
+    }
+    let mut buffers = self.inner.buffers.lock().expect("Lock BufferPool failed");
+    if buffers.len() < self.inner.max_buffers {
+      data.clear();
+      buffers.push(data);
+    }
+  }
+
+  /// The number of `Vec`s waiting in the pool
+  pub fn available(&self) -> usize {
+    self
+      .inner
+      .buffers
+      .lock()
+      .expect("Lock BufferPool failed")
+      .len()
+  }
+}
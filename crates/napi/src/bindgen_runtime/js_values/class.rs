@@ -1,15 +1,119 @@
 use std::any::type_name;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
+#[cfg(feature = "napi8")]
+use std::sync::RwLock;
 
 use super::Object;
+#[cfg(feature = "napi8")]
+use crate::type_tag;
 use crate::{
   bindgen_runtime::{FromNapiValue, TypeName, ValidateNapiValue},
-  check_status, sys, Env, NapiRaw, NapiValue, ValueType,
+  check_status, sys, Env, NapiRaw, NapiValue, TypeTag, ValueType,
 };
 
 pub type This<T = Object> = T;
 
+/// Implemented by `#[napi]` classes, so they can be constructed from Rust with `Env::construct`
+pub trait JavaScriptClass: 'static {
+  /// The null terminated JavaScript name the class constructor is registered with
+  #[doc(hidden)]
+  const JS_NAME: &'static str;
+
+  /// Set by `#[napi(type_tag = "...")]`, every instance is tagged with it and checked before being unwrapped.
+  ///
+  /// A `#[napi(extends)]` class derives its own tag from the tag of its base class.
+  ///
+  /// Type tags require Node-API 8, without the `napi8` feature the instances are neither tagged nor checked.
+  #[doc(hidden)]
+  const TYPE_TAG: Option<TypeTag> = None;
+}
+
+/// Called on every new instance of a `#[napi]` class, it's a no-op if the class has no `type_tag`
+#[doc(hidden)]
+pub unsafe fn tag_class_instance<T: JavaScriptClass>(
+  env: sys::napi_env,
+  value: sys::napi_value,
+) -> crate::Result<()> {
+  #[cfg(feature = "napi8")]
+  if let Some(tag) = T::TYPE_TAG.as_ref() {
+    unsafe { type_tag::type_tag_object(env, value, tag)? };
+  }
+  #[cfg(not(feature = "napi8"))]
+  let _ = (env, value, T::TYPE_TAG);
+  Ok(())
+}
+
+// (base tag, subclass tag), an object carries a single type tag so the subclasses of a class are looked up to accept their instances
+// the classes are registered again by every `Env` loading the module, e.g. in worker threads, the pairs are only stored once
+#[cfg(feature = "napi8")]
+static SUBCLASS_TYPE_TAGS: RwLock<Vec<(TypeTag, TypeTag)>> = RwLock::new(Vec::new());
+
+/// Called when a `#[napi(extends)]` class is registered, the instances of the subclass are accepted by the methods of the base class
+///
+/// It's a no-op without the `napi8` feature, the tags are never checked then.
+#[doc(hidden)]
+pub fn register_subclass_type_tag(base: Option<TypeTag>, subclass: Option<TypeTag>) {
+  #[cfg(feature = "napi8")]
+  if let (Some(base), Some(subclass)) = (base, subclass) {
+    let mut subclass_type_tags = SUBCLASS_TYPE_TAGS
+      .write()
+      .expect("Write SUBCLASS_TYPE_TAGS failed");
+    if !subclass_type_tags.contains(&(base, subclass)) {
+      subclass_type_tags.push((base, subclass));
+    }
+  }
+  #[cfg(not(feature = "napi8"))]
+  let _ = (base, subclass);
+}
+
+#[cfg(feature = "napi8")]
+unsafe fn has_class_type_tag(
+  env: sys::napi_env,
+  value: sys::napi_value,
+  tag: &TypeTag,
+) -> crate::Result<bool> {
+  if unsafe { type_tag::check_object_type_tag(env, value, tag)? } {
+    return Ok(true);
+  }
+  let subclasses = SUBCLASS_TYPE_TAGS
+    .read()
+    .expect("Read SUBCLASS_TYPE_TAGS failed")
+    .iter()
+    .filter(|(base, _)| base == tag)
+    .map(|(_, subclass)| *subclass)
+    .collect::<Vec<_>>();
+  for subclass in subclasses.iter() {
+    if unsafe { has_class_type_tag(env, value, subclass)? } {
+      return Ok(true);
+    }
+  }
+  Ok(false)
+}
+
+/// Called before unwrapping `T` from `value`, so objects from another addon or another class with the same layout are rejected
+#[doc(hidden)]
+pub unsafe fn check_class_instance<T: JavaScriptClass>(
+  env: sys::napi_env,
+  value: sys::napi_value,
+) -> crate::Result<()> {
+  #[cfg(feature = "napi8")]
+  if let Some(tag) = T::TYPE_TAG.as_ref() {
+    if !unsafe { has_class_type_tag(env, value, tag)? } {
+      return Err(crate::Error::new(
+        crate::Status::InvalidArg,
+        format!(
+          "Value is not an instance of class `{}`",
+          T::JS_NAME.trim_end_matches('\0')
+        ),
+      ));
+    }
+  }
+  #[cfg(not(feature = "napi8"))]
+  let _ = (env, value, T::TYPE_TAG);
+  Ok(())
+}
+
 pub struct ClassInstance<T: 'static> {
   pub value: sys::napi_value,
   inner: &'static mut T,
@@ -57,8 +161,9 @@ where
   }
 }
 
-impl<T: 'static> FromNapiValue for ClassInstance<T> {
+impl<T: JavaScriptClass> FromNapiValue for ClassInstance<T> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    unsafe { check_class_instance::<T>(env, napi_val)? };
     let mut value = ptr::null_mut();
     check_status!(
       unsafe { sys::napi_unwrap(env, napi_val, &mut value) },
@@ -1,8 +1,8 @@
 use std::{ptr, str::FromStr};
 
-use chrono::{DateTime, Local, LocalResult, NaiveDateTime, TimeZone};
+use chrono::{DateTime, Local, LocalResult, NaiveDateTime, TimeZone, Utc};
 
-use crate::{bindgen_prelude::*, check_status, sys, ValueType};
+use crate::{bindgen_prelude::*, check_status, sys, JsDate, ValueType};
 
 impl<Tz: TimeZone> TypeName for DateTime<Tz> {
   fn type_name() -> &'static str {
@@ -155,3 +155,24 @@ where
     }
   }
 }
+
+impl TryFrom<JsDate> for DateTime<Utc> {
+  type Error = Error;
+
+  fn try_from(value: JsDate) -> Result<Self> {
+    let milliseconds_since_epoch_utc = value.value_of()?;
+    if milliseconds_since_epoch_utc.is_nan() {
+      return Err(Error::new(
+        Status::DateExpected,
+        "Found invalid date".to_owned(),
+      ));
+    }
+    match Utc.timestamp_millis_opt(milliseconds_since_epoch_utc as i64) {
+      LocalResult::Single(dt) => Ok(dt),
+      _ => Err(Error::new(
+        Status::DateExpected,
+        "Found invalid date".to_owned(),
+      )),
+    }
+  }
+}
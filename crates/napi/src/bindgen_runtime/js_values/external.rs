@@ -36,11 +36,12 @@ impl<T: 'static> From<T> for External<T> {
 impl<T: 'static> ValidateNapiValue for &External<T> {}
 
 impl<T: 'static> External<T> {
+  /// `size_of::<T>()` is reported to Node.js GC as the memory used by this `External` object.
   pub fn new(value: T) -> Self {
     Self {
       type_id: TypeId::of::<T>(),
       obj: value,
-      size_hint: 0,
+      size_hint: std::mem::size_of::<T>(),
       adjusted_size: 0,
     }
   }
@@ -49,7 +50,7 @@ impl<T: 'static> External<T> {
   ///
   /// If getting the exact `size_hint` is difficult, you can provide an approximate value, it's only effect to the GC.
   ///
-  /// If your `External` object is not effect to GC, you can use `External::new` instead.
+  /// Use this instead of `External::new` when `T` owns heap allocations, which `size_of::<T>()` can't see.
   pub fn new_with_size_hint(value: T, size_hint: usize) -> Self {
     Self {
       type_id: TypeId::of::<T>(),
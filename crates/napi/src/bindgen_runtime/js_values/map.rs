@@ -16,7 +16,13 @@ impl<K, V, S> TypeName for HashMap<K, V, S> {
   }
 }
 
-impl<K: From<String> + Eq + Hash, V: FromNapiValue> ValidateNapiValue for HashMap<K, V> {}
+impl<K, V, S> ValidateNapiValue for HashMap<K, V, S>
+where
+  K: From<String> + Eq + Hash,
+  V: FromNapiValue,
+  S: Default + BuildHasher,
+{
+}
 
 impl<K, V, S> ToNapiValue for HashMap<K, V, S>
 where
@@ -111,7 +117,13 @@ impl<K, V, S> TypeName for IndexMap<K, V, S> {
 }
 
 #[cfg(feature = "object_indexmap")]
-impl<K: From<String> + Hash + Eq, V: FromNapiValue> ValidateNapiValue for IndexMap<K, V> {}
+impl<K, V, S> ValidateNapiValue for IndexMap<K, V, S>
+where
+  K: From<String> + Hash + Eq,
+  V: FromNapiValue,
+  S: Default + BuildHasher,
+{
+}
 
 #[cfg(feature = "object_indexmap")]
 impl<K, V, S> ToNapiValue for IndexMap<K, V, S>
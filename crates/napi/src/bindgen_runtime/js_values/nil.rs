@@ -79,3 +79,102 @@ impl ToNapiValue for Undefined {
     Ok(ret)
   }
 }
+
+/// `T | null`, unlike `Option<T>` which accepts both `null` and `undefined`, `undefined` is rejected.
+///
+/// Use `Either<T, Undefined>` for `T | undefined`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Nullable<T> {
+  #[default]
+  Null,
+  Value(T),
+}
+
+impl<T> Nullable<T> {
+  pub fn is_null(&self) -> bool {
+    matches!(self, Nullable::Null)
+  }
+
+  pub fn as_ref(&self) -> Nullable<&T> {
+    match self {
+      Nullable::Null => Nullable::Null,
+      Nullable::Value(value) => Nullable::Value(value),
+    }
+  }
+
+  pub fn into_option(self) -> Option<T> {
+    self.into()
+  }
+}
+
+impl<T> From<Option<T>> for Nullable<T> {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => Nullable::Value(value),
+      None => Nullable::Null,
+    }
+  }
+}
+
+impl<T> From<Nullable<T>> for Option<T> {
+  fn from(value: Nullable<T>) -> Self {
+    match value {
+      Nullable::Value(value) => Some(value),
+      Nullable::Null => None,
+    }
+  }
+}
+
+impl<T: TypeName> TypeName for Nullable<T> {
+  fn type_name() -> &'static str {
+    T::type_name()
+  }
+
+  fn value_type() -> ValueType {
+    T::value_type()
+  }
+}
+
+impl<T: ValidateNapiValue> ValidateNapiValue for Nullable<T> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    let received_type = type_of!(env, napi_val)?;
+    if received_type == ValueType::Null {
+      Ok(ptr::null_mut())
+    } else if let Ok(validate_ret) = unsafe { T::validate(env, napi_val) } {
+      Ok(validate_ret)
+    } else {
+      Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Expect value to be Nullable<{}>, but received {}",
+          T::value_type(),
+          received_type
+        ),
+      ))
+    }
+  }
+}
+
+impl<T: FromNapiValue> FromNapiValue for Nullable<T> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    match type_of!(env, napi_val)? {
+      ValueType::Null => Ok(Nullable::Null),
+      ValueType::Undefined => Err(Error::new(
+        Status::InvalidArg,
+        "Expect value to be Nullable<T>, but received undefined".to_owned(),
+      )),
+      _ => Ok(Nullable::Value(unsafe {
+        T::from_napi_value(env, napi_val)?
+      })),
+    }
+  }
+}
+
+impl<T: ToNapiValue> ToNapiValue for Nullable<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    match val {
+      Nullable::Value(value) => unsafe { T::to_napi_value(env, value) },
+      Nullable::Null => unsafe { Null::to_napi_value(env, Null) },
+    }
+  }
+}
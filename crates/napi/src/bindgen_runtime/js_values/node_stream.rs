@@ -0,0 +1,484 @@
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use futures_io::{AsyncRead, AsyncWrite};
+
+use crate::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use crate::{
+  check_pending_exception, check_status, sys, Env, Error, JsObject, NapiRaw, NapiValue, Result,
+  Status,
+};
+
+use super::{Buffer, Either, FromNapiValue, Function, TypeName, Unknown, ValidateNapiValue};
+
+const DEFAULT_HIGH_WATER_MARK: usize = 16 * 1024;
+
+/// A Node.js `stream.Readable` passed into Rust, read through `futures::AsyncRead`.
+///
+/// The stream is switched into flowing mode and its chunks are queued until they are read. Once more than `readableHighWaterMark` bytes are queued the stream is paused, and it's resumed from the reading thread through a `ThreadsafeFunction` after the queue drains.
+/// A chunk which is neither a `Buffer` nor a string destroys the stream, and the read fails with that error.
+///
+/// ```rust
+/// use futures::AsyncReadExt;
+/// use napi::bindgen_prelude::*;
+///
+/// #[napi]
+/// pub async fn read_to_string(input: NodeReadable) -> Result<String> {
+///   let mut content = String::new();
+///   let mut input = input;
+///   input.read_to_string(&mut content).await?;
+///   Ok(content)
+/// }
+/// ```
+pub struct NodeReadable {
+  state: Arc<Mutex<ReadState>>,
+  high_water_mark: usize,
+  resume: ThreadsafeFunction<(), Unknown, (), false>,
+}
+
+#[derive(Default)]
+struct ReadState {
+  chunks: VecDeque<Vec<u8>>,
+  offset: usize,
+  buffered: usize,
+  paused: bool,
+  ended: bool,
+  error: Option<String>,
+  waker: Option<Waker>,
+}
+
+impl ReadState {
+  fn wake(&mut self) {
+    if let Some(waker) = self.waker.take() {
+      waker.wake();
+    }
+  }
+}
+
+impl TypeName for NodeReadable {
+  fn type_name() -> &'static str {
+    "Readable"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for NodeReadable {}
+
+impl FromNapiValue for NodeReadable {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let object = unsafe { JsObject::from_napi_value(env, napi_val)? };
+    let high_water_mark = object
+      .get_named_property::<Option<f64>>("readableHighWaterMark")?
+      .map(|size| size as usize)
+      .unwrap_or(DEFAULT_HIGH_WATER_MARK);
+    let state = Arc::new(Mutex::new(ReadState::default()));
+    let env = Env::from_raw(env);
+
+    let data_state = state.clone();
+    let on_data = env.create_function_from_closure::<Unknown, (), _>("onData", move |ctx| {
+      let chunk = match ctx.first_arg::<Either<Buffer, String>>() {
+        Ok(Either::A(buffer)) => buffer.to_vec(),
+        Ok(Either::B(string)) => string.into_bytes(),
+        // an error thrown from the listener would be uncaught, fail the read through the `error` event instead
+        Err(_) => {
+          let error = ctx.env.create_error(Error::new(
+            Status::InvalidArg,
+            "Readable stream must emit Buffer or string chunks, objectMode isn't supported"
+              .to_owned(),
+          ))?;
+          unsafe { call_method(ctx.env.0, ctx.this, "destroy", &[error.raw()])? };
+          return Ok(());
+        }
+      };
+      let mut state = lock(&data_state);
+      if !chunk.is_empty() {
+        state.buffered += chunk.len();
+        state.chunks.push_back(chunk);
+      }
+      let should_pause = !state.paused && state.buffered >= high_water_mark;
+      state.paused |= should_pause;
+      state.wake();
+      drop(state);
+      if should_pause {
+        unsafe { call_method(ctx.env.0, ctx.this, "pause", &[])? };
+      }
+      Ok(())
+    })?;
+    let end_state = state.clone();
+    let on_end = env.create_function_from_closure::<Unknown, (), _>("onEnd", move |_| {
+      let mut state = lock(&end_state);
+      state.ended = true;
+      state.wake();
+      Ok(())
+    })?;
+    let error_state = state.clone();
+    let on_error = env.create_function_from_closure::<Unknown, (), _>("onError", move |ctx| {
+      let mut state = lock(&error_state);
+      state.error = Some(error_message(ctx.first_arg()?));
+      state.wake();
+      Ok(())
+    })?;
+    let close_state = state.clone();
+    let on_close = env.create_function_from_closure::<Unknown, (), _>("onClose", move |_| {
+      let mut state = lock(&close_state);
+      if !state.ended && state.error.is_none() {
+        state.error = Some("Readable stream was closed before it ended".to_owned());
+      }
+      state.wake();
+      Ok(())
+    })?;
+    for (event, listener) in [
+      ("data", on_data.raw()),
+      ("end", on_end.raw()),
+      ("error", on_error.raw()),
+      ("close", on_close.raw()),
+    ] {
+      let event = unsafe { <&str as super::ToNapiValue>::to_napi_value(env.0, event)? };
+      unsafe { call_method(env.0, napi_val, "on", &[event, listener])? };
+    }
+
+    let resume = unsafe { bound_method(env.0, napi_val, "resume")? };
+    let resume = unsafe { Function::<(), Unknown>::from_napi_value(env.0, resume)? }
+      .build_threadsafe_function::<()>()
+      .callee_handled::<false>()
+      .build()?;
+    unsafe { call_method(env.0, napi_val, "resume", &[])? };
+
+    Ok(Self {
+      state,
+      high_water_mark,
+      resume,
+    })
+  }
+}
+
+impl AsyncRead for NodeReadable {
+  fn poll_read(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut [u8],
+  ) -> Poll<io::Result<usize>> {
+    let mut state = lock(&self.state);
+    if state.chunks.is_empty() {
+      if let Some(reason) = &state.error {
+        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, reason.clone())));
+      }
+      if state.ended || buf.is_empty() {
+        return Poll::Ready(Ok(0));
+      }
+      state.waker = Some(cx.waker().clone());
+      return Poll::Pending;
+    }
+
+    let mut read = 0;
+    while read < buf.len() {
+      let offset = state.offset;
+      let Some(chunk) = state.chunks.front() else {
+        break;
+      };
+      let size = (chunk.len() - offset).min(buf.len() - read);
+      buf[read..read + size].copy_from_slice(&chunk[offset..offset + size]);
+      read += size;
+      if offset + size == chunk.len() {
+        state.chunks.pop_front();
+        state.offset = 0;
+      } else {
+        state.offset += size;
+      }
+    }
+    state.buffered -= read;
+
+    let should_resume = state.paused && state.buffered < self.high_water_mark;
+    state.paused &= !should_resume;
+    drop(state);
+    if should_resume {
+      let status = self
+        .resume
+        .call((), ThreadsafeFunctionCallMode::NonBlocking);
+      if status != Status::Ok {
+        return Poll::Ready(Err(io::Error::new(
+          io::ErrorKind::Other,
+          format!("Failed to resume Readable stream: {}", status),
+        )));
+      }
+    }
+    Poll::Ready(Ok(read))
+  }
+}
+
+/// A Node.js `stream.Writable` passed into Rust, written through `futures::AsyncWrite`.
+///
+/// Each `poll_write` hands one chunk to `writable.write()` through a `ThreadsafeFunction`, and the next write waits for its callback, so at most one chunk is buffered on the JavaScript side. `poll_close` calls `writable.end()` and resolves once it finishes, the stream isn't ended if the `NodeWritable` is dropped without being closed.
+///
+/// ```rust
+/// use futures::{io, AsyncWriteExt};
+/// use napi::bindgen_prelude::*;
+///
+/// #[napi]
+/// pub async fn pipe(input: NodeReadable, mut output: NodeWritable) -> Result<()> {
+///   io::copy(input, &mut output).await?;
+///   output.close().await?;
+///   Ok(())
+/// }
+/// ```
+pub struct NodeWritable {
+  state: Arc<Mutex<WriteState>>,
+  write: ThreadsafeFunction<Vec<u8>, Unknown, (Buffer, Unknown), false>,
+  end: ThreadsafeFunction<(), Unknown, Unknown, false>,
+}
+
+#[derive(Default)]
+struct WriteState {
+  pending: bool,
+  closing: bool,
+  closed: bool,
+  error: Option<String>,
+  waker: Option<Waker>,
+}
+
+impl WriteState {
+  fn wake(&mut self) {
+    if let Some(waker) = self.waker.take() {
+      waker.wake();
+    }
+  }
+
+  fn poll_error(&self) -> Poll<io::Result<()>> {
+    match &self.error {
+      Some(reason) => Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, reason.clone()))),
+      None => Poll::Ready(Ok(())),
+    }
+  }
+}
+
+impl TypeName for NodeWritable {
+  fn type_name() -> &'static str {
+    "Writable"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for NodeWritable {}
+
+impl FromNapiValue for NodeWritable {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let state = Arc::new(Mutex::new(WriteState::default()));
+    let env = Env::from_raw(env);
+
+    let error_state = state.clone();
+    let on_error = env.create_function_from_closure::<Unknown, (), _>("onError", move |ctx| {
+      let mut state = lock(&error_state);
+      state.error = Some(error_message(ctx.first_arg()?));
+      state.pending = false;
+      state.wake();
+      Ok(())
+    })?;
+    let event = unsafe { <&str as super::ToNapiValue>::to_napi_value(env.0, "error")? };
+    unsafe { call_method(env.0, napi_val, "on", &[event, on_error.raw()])? };
+
+    let write = unsafe { bound_method(env.0, napi_val, "write")? };
+    let write_state = state.clone();
+    let write = unsafe { Function::<Unknown, Unknown>::from_napi_value(env.0, write)? }
+      .build_threadsafe_function::<Vec<u8>>()
+      .callee_handled::<false>()
+      .build_callback(move |ctx| {
+        let state = write_state.clone();
+        let on_written =
+          ctx
+            .env
+            .create_function_from_closure::<Unknown, (), _>("onWritten", move |ctx| {
+              let mut state = lock(&state);
+              if let Some(error) = error_argument(ctx.env.0, ctx.args)? {
+                state.error.get_or_insert(error);
+              }
+              state.pending = false;
+              state.wake();
+              Ok(())
+            })?;
+        let on_written = unsafe { Unknown::from_raw_unchecked(ctx.env.0, on_written.raw()) };
+        Ok((Buffer::from(ctx.value), on_written))
+      })?;
+
+    let end = unsafe { bound_method(env.0, napi_val, "end")? };
+    let end_state = state.clone();
+    let end = unsafe { Function::<Unknown, Unknown>::from_napi_value(env.0, end)? }
+      .build_threadsafe_function::<()>()
+      .callee_handled::<false>()
+      .build_callback(move |ctx| {
+        let state = end_state.clone();
+        let on_finished =
+          ctx
+            .env
+            .create_function_from_closure::<Unknown, (), _>("onFinished", move |ctx| {
+              let mut state = lock(&state);
+              if let Some(error) = error_argument(ctx.env.0, ctx.args)? {
+                state.error.get_or_insert(error);
+              }
+              state.closed = true;
+              state.wake();
+              Ok(())
+            })?;
+        Ok(unsafe { Unknown::from_raw_unchecked(ctx.env.0, on_finished.raw()) })
+      })?;
+
+    Ok(Self { state, write, end })
+  }
+}
+
+impl AsyncWrite for NodeWritable {
+  fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+    let mut state = lock(&self.state);
+    if state.error.is_some() {
+      return state.poll_error().map_ok(|_| 0);
+    }
+    if state.closing {
+      return Poll::Ready(Err(io::Error::new(
+        io::ErrorKind::Other,
+        "Writable stream is already closed",
+      )));
+    }
+    if state.pending {
+      state.waker = Some(cx.waker().clone());
+      return Poll::Pending;
+    }
+    if buf.is_empty() {
+      return Poll::Ready(Ok(0));
+    }
+    state.pending = true;
+    drop(state);
+    let status = self
+      .write
+      .call(buf.to_vec(), ThreadsafeFunctionCallMode::NonBlocking);
+    if status != Status::Ok {
+      lock(&self.state).pending = false;
+      return Poll::Ready(Err(io::Error::new(
+        io::ErrorKind::Other,
+        format!("Failed to write to Writable stream: {}", status),
+      )));
+    }
+    Poll::Ready(Ok(buf.len()))
+  }
+
+  fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    let mut state = lock(&self.state);
+    if state.pending && state.error.is_none() {
+      state.waker = Some(cx.waker().clone());
+      return Poll::Pending;
+    }
+    state.poll_error()
+  }
+
+  fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    match self.as_mut().poll_flush(cx) {
+      Poll::Ready(Ok(())) => {}
+      other => return other,
+    }
+    let mut state = lock(&self.state);
+    if state.closed {
+      return state.poll_error();
+    }
+    state.waker = Some(cx.waker().clone());
+    if !state.closing {
+      state.closing = true;
+      drop(state);
+      let status = self.end.call((), ThreadsafeFunctionCallMode::NonBlocking);
+      if status != Status::Ok {
+        return Poll::Ready(Err(io::Error::new(
+          io::ErrorKind::Other,
+          format!("Failed to end Writable stream: {}", status),
+        )));
+      }
+    }
+    Poll::Pending
+  }
+}
+
+fn lock<T>(state: &Mutex<T>) -> MutexGuard<'_, T> {
+  state.lock().expect("Node stream state lock failed")
+}
+
+/// Read the message of a JavaScript error, or stringify any other thrown value.
+fn error_message(value: Unknown) -> String {
+  let message = match value.get_type() {
+    Ok(crate::ValueType::Object) => unsafe { value.cast::<JsObject>() }
+      .get_named_property::<Unknown>("message")
+      .ok(),
+    _ => None,
+  };
+  message
+    .unwrap_or(value)
+    .coerce_to_string()
+    .and_then(|message| message.into_utf8())
+    .and_then(|message| message.into_owned())
+    .unwrap_or_default()
+}
+
+/// The error passed to a Node.js style callback, if it's neither `null` nor `undefined`.
+fn error_argument(env: sys::napi_env, args: &[sys::napi_value]) -> Result<Option<String>> {
+  let Some(error) = args.first() else {
+    return Ok(None);
+  };
+  let error = unsafe { Unknown::from_raw_unchecked(env, *error) };
+  match error.get_type()? {
+    crate::ValueType::Null | crate::ValueType::Undefined => Ok(None),
+    _ => Ok(Some(error_message(error))),
+  }
+}
+
+unsafe fn call_method(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &str,
+  args: &[sys::napi_value],
+) -> Result<sys::napi_value> {
+  let method = unsafe { get_method(env, object, name)? };
+  let mut result = ptr::null_mut();
+  check_pending_exception!(
+    env,
+    unsafe { sys::napi_call_function(env, object, method, args.len(), args.as_ptr(), &mut result) },
+    "Call stream.{}() failed",
+    name
+  )?;
+  Ok(result)
+}
+
+/// `object[name].bind(object)`, so the method can back a `ThreadsafeFunction`.
+unsafe fn bound_method(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &str,
+) -> Result<sys::napi_value> {
+  let method = unsafe { get_method(env, object, name)? };
+  unsafe { call_method(env, method, "bind", &[object]) }
+}
+
+unsafe fn get_method(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &str,
+) -> Result<sys::napi_value> {
+  let mut method = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_named_property(
+        env,
+        object,
+        format!("{}\0", name).as_ptr().cast(),
+        &mut method,
+      )
+    },
+    "Get stream.{} failed",
+    name
+  )?;
+  Ok(method)
+}
@@ -41,20 +41,59 @@ impl Object {
         "Failed to get property with field `{field}`",
       )?;
 
-      let ty = type_of!(self.0.env, ret)?;
+      self.defined(ret)
+    }
+  }
 
-      Ok(if ty == ValueType::Undefined {
-        None
-      } else {
-        Some(ret)
-      })
+  /// Reads a field of a `#[napi(object)]`, with the key from the `PropertyKeyCache` when the `napi10_module_version` feature is enabled
+  #[doc(hidden)]
+  pub fn get_field<V: FromNapiValue>(&self, field: &'static str) -> Result<Option<V>> {
+    #[cfg(feature = "napi10_module_version")]
+    if let Some(key) = crate::PropertyKeyCache::get_raw(self.0.env, field) {
+      let mut ret = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_property(self.0.env, self.0.value, key, &mut ret) },
+        "Failed to get property with field `{field}`",
+      )?;
+      return unsafe {
+        self
+          .defined(ret)?
+          .map(|v| V::from_napi_value(self.0.env, v))
+          .transpose()
+      };
     }
+    self.get(field)
+  }
+
+  fn defined(&self, value: sys::napi_value) -> Result<Option<sys::napi_value>> {
+    let ty = type_of!(self.0.env, value)?;
+
+    Ok(if ty == ValueType::Undefined {
+      None
+    } else {
+      Some(value)
+    })
   }
 
   pub fn set<K: AsRef<str>, V: ToNapiValue>(&mut self, field: K, val: V) -> Result<()> {
     unsafe { self.set_inner(field.as_ref(), V::to_napi_value(self.0.env, val)?) }
   }
 
+  /// Writes a field of a `#[napi(object)]`, with the key from the `PropertyKeyCache` when the `napi10_module_version` feature is enabled
+  #[doc(hidden)]
+  pub fn set_field<V: ToNapiValue>(&mut self, field: &'static str, val: V) -> Result<()> {
+    let napi_val = unsafe { V::to_napi_value(self.0.env, val)? };
+    #[cfg(feature = "napi10_module_version")]
+    if let Some(key) = crate::PropertyKeyCache::get_raw(self.0.env, field) {
+      check_status!(
+        unsafe { sys::napi_set_property(self.0.env, self.0.value, key, napi_val) },
+        "Failed to set property with field `{field}`",
+      )?;
+      return Ok(());
+    }
+    unsafe { self.set_inner(field, napi_val) }
+  }
+
   unsafe fn set_inner(&mut self, field: &str, napi_val: sys::napi_value) -> Result<()> {
     let c_field = CString::new(field)?;
 
@@ -67,16 +106,18 @@ impl Object {
     }
   }
 
-  pub fn keys(obj: &Object) -> Result<Vec<String>> {
+  /// The enumerable string keys of the object, including the inherited ones like `for...in`,
+  /// see `JsObject::property_keys` to pick the keys and how they are converted.
+  pub fn keys(&self) -> Result<Vec<String>> {
     let mut names = ptr::null_mut();
     unsafe {
       check_status!(
-        sys::napi_get_property_names(obj.0.env, obj.0.value, &mut names),
+        sys::napi_get_property_names(self.0.env, self.0.value, &mut names),
         "Failed to get property names of given object"
       )?;
     }
 
-    let names = unsafe { Array::from_napi_value(obj.0.env, names)? };
+    let names = unsafe { Array::from_napi_value(self.0.env, names)? };
     let mut ret = vec![];
 
     for i in 0..names.len() {
@@ -98,3 +139,86 @@ impl TypeName for Object {
 }
 
 impl ValidateNapiValue for JsObject {}
+
+const WRITABLE_PROPERTY: sys::napi_property_attributes = sys::PropertyAttributes::writable
+  | sys::PropertyAttributes::enumerable
+  | sys::PropertyAttributes::configurable;
+
+const READONLY_PROPERTY: sys::napi_property_attributes =
+  sys::PropertyAttributes::enumerable | sys::PropertyAttributes::configurable;
+
+/// Collects the fields of a `#[napi(object)]` to create the object with a single `napi_define_properties` call,
+/// rather than a `napi_set_named_property` call and a `CString` allocation per field.
+/// The keys come from the `PropertyKeyCache` when the `napi10_module_version` feature is enabled.
+#[doc(hidden)]
+pub struct ObjectFields<const N: usize> {
+  env: sys::napi_env,
+  len: usize,
+  descriptors: [sys::napi_property_descriptor; N],
+}
+
+impl<const N: usize> ObjectFields<N> {
+  /// # Safety
+  ///
+  /// `env` must be the `napi_env` of the current call
+  pub unsafe fn new(env: sys::napi_env) -> Self {
+    Self {
+      env,
+      len: 0,
+      descriptors: [sys::napi_property_descriptor {
+        utf8name: ptr::null(),
+        name: ptr::null_mut(),
+        method: None,
+        getter: None,
+        setter: None,
+        value: ptr::null_mut(),
+        attributes: WRITABLE_PROPERTY,
+        data: ptr::null_mut(),
+      }; N],
+    }
+  }
+
+  /// `name` must end with a nul byte, the fields beyond `N` are ignored
+  pub fn push<V: ToNapiValue>(
+    &mut self,
+    name: &'static str,
+    value: V,
+    writable: bool,
+  ) -> Result<()> {
+    debug_assert!(name.ends_with('\0'));
+    if let Some(descriptor) = self.descriptors.get_mut(self.len) {
+      #[cfg(feature = "napi10_module_version")]
+      if let Some(key) = crate::PropertyKeyCache::get_raw(self.env, &name[..name.len() - 1]) {
+        descriptor.name = key;
+      }
+      if descriptor.name.is_null() {
+        descriptor.utf8name = name.as_ptr().cast();
+      }
+      descriptor.value = unsafe { V::to_napi_value(self.env, value)? };
+      descriptor.attributes = if writable {
+        WRITABLE_PROPERTY
+      } else {
+        READONLY_PROPERTY
+      };
+      self.len += 1;
+    }
+    Ok(())
+  }
+
+  pub fn into_object(self) -> Result<sys::napi_value> {
+    let mut object = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_object(self.env, &mut object) },
+      "Failed to create napi Object"
+    )?;
+    if self.len > 0 {
+      check_status!(
+        unsafe {
+          sys::napi_define_properties(self.env, object, self.len, self.descriptors.as_ptr())
+        },
+        "Failed to define the properties of the Object"
+      )?;
+    }
+    Ok(object)
+  }
+}
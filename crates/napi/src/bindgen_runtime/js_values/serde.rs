@@ -42,7 +42,19 @@ impl FromNapiValue for Value {
         }
       }
       #[cfg(feature = "napi6")]
-      ValueType::BigInt => todo!(),
+      ValueType::BigInt => {
+        let bigint = unsafe { BigInt::from_napi_value(env, napi_val)? };
+        match (i64::try_from(bigint.clone()), u64::try_from(bigint)) {
+          (Ok(n), _) => Value::Number(n.into()),
+          (_, Ok(n)) => Value::Number(n.into()),
+          _ => {
+            return Err(Error::new(
+              Status::InvalidArg,
+              "BigInt is out of the range of serde_json::Number".to_owned(),
+            ))
+          }
+        }
+      }
       ValueType::Null => Value::Null,
       ValueType::Function => {
         return Err(Error::new(
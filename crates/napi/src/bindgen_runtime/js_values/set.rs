@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use crate::bindgen_prelude::{Env, Result, ToNapiValue, *};
+use crate::{JsSet, NapiValue};
+
+impl<T, S> TypeName for HashSet<T, S> {
+  fn type_name() -> &'static str {
+    "HashSet"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T, S> ValidateNapiValue for HashSet<T, S>
+where
+  T: FromNapiValue + Eq + Hash,
+  S: Default + BuildHasher,
+{
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { JsSet::validate(env, napi_val) }
+  }
+}
+
+impl<T, S> ToNapiValue for HashSet<T, S>
+where
+  T: ToNapiValue,
+{
+  unsafe fn to_napi_value(raw_env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from(raw_env);
+    let set = JsSet::from_hash_set(&env, val)?;
+
+    unsafe { JsSet::to_napi_value(raw_env, set) }
+  }
+}
+
+impl<T, S> FromNapiValue for HashSet<T, S>
+where
+  T: FromNapiValue + Eq + Hash,
+  S: Default + BuildHasher,
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    // the other objects don't have the methods of `Set.prototype` called by `to_hash_set`
+    unsafe { JsSet::validate(env, napi_val)? };
+    let set = unsafe { JsSet::from_raw_unchecked(env, napi_val) };
+    set.to_hash_set()
+  }
+}
@@ -0,0 +1,297 @@
+use std::ffi::c_void;
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::Arc;
+
+use futures_core::Stream;
+use tokio::sync::Mutex;
+
+use crate::{bindgen_prelude::execute_tokio_future, check_status, sys, JsError, Result};
+
+use super::{ToNapiValue, TypeName};
+
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// Expose a Rust `Stream` to JavaScript as an async iterable.
+///
+/// Every `next()` call polls the stream once on the tokio runtime. The stream is dropped after it yields `None` or an `Err`, or when the iteration is stopped early with `break` or `return()`.
+///
+/// ```rust
+/// use futures::stream;
+/// use napi::bindgen_prelude::*;
+///
+/// #[napi]
+/// pub fn numbers() -> AsyncIterator<u32> {
+///   AsyncIterator::new(stream::iter([1, 2, 3].into_iter().map(Ok)))
+/// }
+/// ```
+pub struct AsyncIterator<T> {
+  inner: Arc<Mutex<Option<BoxedStream<T>>>>,
+}
+
+impl<T: 'static> AsyncIterator<T> {
+  pub fn new<S: Stream<Item = Result<T>> + Send + 'static>(stream: S) -> Self {
+    Self {
+      inner: Arc::new(Mutex::new(Some(Box::pin(stream)))),
+    }
+  }
+}
+
+impl<T> TypeName for AsyncIterator<T> {
+  fn type_name() -> &'static str {
+    "AsyncIterator"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+impl<T: ToNapiValue + Send + 'static> ToNapiValue for AsyncIterator<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut iterator = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_object(env, &mut iterator) },
+      "Failed to create async iterator object"
+    )?;
+    let inner = Box::into_raw(Box::new(val.inner));
+    check_status!(
+      unsafe {
+        sys::napi_wrap(
+          env,
+          iterator,
+          inner.cast(),
+          Some(drop_stream::<T>),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to wrap stream into async iterator object"
+    )
+    .map_err(|err| {
+      drop(unsafe { Box::from_raw(inner) });
+      err
+    })?;
+    unsafe {
+      define_method(env, iterator, "next", async_iterator_next::<T>)?;
+      define_method(env, iterator, "return", async_iterator_return::<T>)?;
+    };
+
+    let mut global = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_global(env, &mut global) },
+      "Get global object failed"
+    )?;
+    let mut symbol_object = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(env, global, "Symbol\0".as_ptr().cast(), &mut symbol_object)
+      },
+      "Get global Symbol failed"
+    )?;
+    let mut async_iterator_symbol = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(
+          env,
+          symbol_object,
+          "asyncIterator\0".as_ptr().cast(),
+          &mut async_iterator_symbol,
+        )
+      },
+      "Get Symbol.asyncIterator failed"
+    )?;
+    let mut symbol_function = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_function(
+          env,
+          "[Symbol.asyncIterator]\0".as_ptr().cast(),
+          22,
+          Some(async_iterator_self),
+          ptr::null_mut(),
+          &mut symbol_function,
+        )
+      },
+      "Create Symbol.asyncIterator function failed"
+    )?;
+    check_status!(
+      unsafe { sys::napi_set_property(env, iterator, async_iterator_symbol, symbol_function) },
+      "Failed to set Symbol.asyncIterator on async iterator object"
+    )?;
+
+    Ok(iterator)
+  }
+}
+
+unsafe fn define_method(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &str,
+  callback: unsafe extern "C" fn(sys::napi_env, sys::napi_callback_info) -> sys::napi_value,
+) -> Result<()> {
+  let mut function = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_create_function(
+        env,
+        name.as_ptr().cast(),
+        name.len(),
+        Some(callback),
+        ptr::null_mut(),
+        &mut function,
+      )
+    },
+    "Create {} function failed",
+    name
+  )?;
+  check_status!(
+    unsafe {
+      sys::napi_set_named_property(env, object, format!("{}\0", name).as_ptr().cast(), function)
+    },
+    "Failed to set {} on async iterator object",
+    name
+  )
+}
+
+unsafe extern "C" fn drop_stream<T>(
+  _env: sys::napi_env,
+  data: *mut c_void,
+  _finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(data.cast::<Arc<Mutex<Option<BoxedStream<T>>>>>()) });
+}
+
+unsafe fn get_stream<T>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> Result<Arc<Mutex<Option<BoxedStream<T>>>>> {
+  let mut this = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        info,
+        &mut 0,
+        ptr::null_mut(),
+        &mut this,
+        ptr::null_mut(),
+      )
+    },
+    "Get callback info from async iterator function failed"
+  )?;
+  let mut inner = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_unwrap(env, this, &mut inner) },
+    "Async iterator method called on an incompatible receiver"
+  )?;
+  Ok(Arc::clone(unsafe {
+    &*inner.cast::<Arc<Mutex<Option<BoxedStream<T>>>>>()
+  }))
+}
+
+unsafe fn create_iterator_result<T: ToNapiValue>(
+  env: sys::napi_env,
+  value: Option<T>,
+) -> Result<sys::napi_value> {
+  let mut result = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_object(env, &mut result) },
+    "Failed to create iterator result object"
+  )?;
+  let done = value.is_none();
+  let value = match value {
+    Some(value) => unsafe { T::to_napi_value(env, value)? },
+    None => unsafe { <()>::to_napi_value(env, ())? },
+  };
+  check_status!(
+    unsafe { sys::napi_set_named_property(env, result, "value\0".as_ptr().cast(), value) },
+    "Failed to set iterator result value"
+  )?;
+  let done = unsafe { bool::to_napi_value(env, done)? };
+  check_status!(
+    unsafe { sys::napi_set_named_property(env, result, "done\0".as_ptr().cast(), done) },
+    "Failed to set iterator result done"
+  )?;
+  Ok(result)
+}
+
+fn throw_or_return(env: sys::napi_env, result: Result<sys::napi_value>) -> sys::napi_value {
+  match result {
+    Ok(value) => value,
+    Err(err) => {
+      unsafe { JsError::from(err).throw_into(env) };
+      ptr::null_mut()
+    }
+  }
+}
+
+unsafe extern "C" fn async_iterator_next<T: ToNapiValue + Send + 'static>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let result = unsafe { get_stream::<T>(env, info) }.and_then(|inner| {
+    execute_tokio_future(
+      env,
+      async move {
+        let mut inner = inner.lock().await;
+        let Some(stream) = inner.as_mut() else {
+          return Ok(None);
+        };
+        match poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+          Some(Ok(value)) => Ok(Some(value)),
+          Some(Err(err)) => {
+            inner.take();
+            Err(err)
+          }
+          None => {
+            inner.take();
+            Ok(None)
+          }
+        }
+      },
+      |env, value| unsafe { create_iterator_result(env, value) },
+    )
+  });
+  throw_or_return(env, result)
+}
+
+unsafe extern "C" fn async_iterator_return<T: ToNapiValue + Send + 'static>(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let result = unsafe { get_stream::<T>(env, info) }.and_then(|inner| {
+    execute_tokio_future(
+      env,
+      async move {
+        inner.lock().await.take();
+        Ok(None::<T>)
+      },
+      |env, value| unsafe { create_iterator_result(env, value) },
+    )
+  });
+  throw_or_return(env, result)
+}
+
+unsafe extern "C" fn async_iterator_self(
+  env: sys::napi_env,
+  info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let mut this = ptr::null_mut();
+  let result = check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        info,
+        &mut 0,
+        ptr::null_mut(),
+        &mut this,
+        ptr::null_mut(),
+      )
+    },
+    "Get callback info from Symbol.asyncIterator function failed"
+  )
+  .map(|_| this);
+  throw_or_return(env, result)
+}
@@ -41,8 +41,41 @@ impl ToNapiValue for String {
   }
 }
 
+// the short strings, like the keys and the identifiers, are read with one call instead of two,
+// the longer ones pay a third call for the failed attempt, see `bench/string.ts`
+const STACK_BUFFER_LEN: usize = 256;
+
 impl FromNapiValue for String {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let mut buf = [0u8; STACK_BUFFER_LEN];
+    let mut written_char_count = 0;
+
+    check_status_and_type!(
+      unsafe {
+        sys::napi_get_value_string_utf8(
+          env,
+          napi_val,
+          buf.as_mut_ptr().cast(),
+          STACK_BUFFER_LEN,
+          &mut written_char_count,
+        )
+      },
+      env,
+      napi_val,
+      "Failed to convert JavaScript value `{}` into rust type `String`"
+    )?;
+
+    // a char is up to 4 bytes, the string is cut before the first one not fitting along with the `\0`
+    if written_char_count + 4 < STACK_BUFFER_LEN {
+      return match std::str::from_utf8(&buf[..written_char_count]) {
+        Err(e) => Err(Error::new(
+          Status::InvalidArg,
+          format!("Failed to read utf8 string, {}", e),
+        )),
+        Ok(s) => Ok(s.to_owned()),
+      };
+    }
+
     let mut len = 0;
 
     check_status_and_type!(
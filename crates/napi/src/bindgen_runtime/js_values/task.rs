@@ -1,16 +1,26 @@
 use std::ffi::c_void;
+use std::future::Future;
+use std::pin::Pin;
 use std::ptr;
 use std::rc::Rc;
-use std::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 
-use super::{FromNapiValue, ToNapiValue, TypeName, Unknown};
+#[cfg(feature = "napi5")]
+use super::Function;
+use super::{FromNapiValue, ToNapiValue, TypeName, Unknown, ValidateNapiValue};
+#[cfg(feature = "napi5")]
+use crate::NapiRaw;
 use crate::{
-  async_work, check_status, sys, Env, Error, JsError, JsObject, NapiValue, Status, Task,
+  async_work::{self, AsyncWorkOptions, AsyncWorkQueue, PENDING_IN_QUEUE},
+  check_status, sys, Env, Error, JsError, JsObject, NapiValue, Status, Task,
 };
 
 pub struct AsyncTask<T: Task> {
   inner: T,
   abort_signal: Option<AbortSignal>,
+  options: AsyncWorkOptions,
 }
 
 impl<T: Task> TypeName for T {
@@ -28,6 +38,7 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: None,
+      options: AsyncWorkOptions::default(),
     }
   }
 
@@ -35,6 +46,7 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: Some(signal),
+      options: AsyncWorkOptions::default(),
     }
   }
 
@@ -42,8 +54,21 @@ impl<T: Task> AsyncTask<T> {
     Self {
       inner: task,
       abort_signal: signal,
+      options: AsyncWorkOptions::default(),
     }
   }
+
+  /// The name `async_hooks` reports as the type of the async resource, `napi_rs_async_work` by default
+  pub fn with_resource_name<S: Into<String>>(mut self, name: S) -> Self {
+    self.options = self.options.with_resource_name(name);
+    self
+  }
+
+  /// Wait for a free slot in `queue` before running in libuv thread pool
+  pub fn with_queue(mut self, queue: &AsyncWorkQueue) -> Self {
+    self.options = self.options.with_queue(queue);
+    self
+  }
 }
 
 /// <https://developer.mozilla.org/zh-CN/docs/Web/API/AbortController>
@@ -51,6 +76,17 @@ pub struct AbortSignal {
   raw_work: Rc<AtomicPtr<sys::napi_async_work__>>,
   raw_deferred: Rc<AtomicPtr<sys::napi_deferred__>>,
   status: Rc<AtomicU8>,
+  token: CancellationToken,
+}
+
+impl AbortSignal {
+  /// Get a `Send` token which will be cancelled when the signal is aborted.
+  ///
+  /// `napi_cancel_async_work` can only cancel the `Task` which hasn't been started,
+  /// move the token into the `Task` and check it in `compute` to stop a running task early.
+  pub fn cancellation_token(&self) -> CancellationToken {
+    self.token.clone()
+  }
 }
 
 impl FromNapiValue for AbortSignal {
@@ -60,10 +96,12 @@ impl FromNapiValue for AbortSignal {
       Rc::new(AtomicPtr::new(ptr::null_mut()));
     let raw_promise: Rc<AtomicPtr<sys::napi_deferred__>> = Rc::new(AtomicPtr::new(ptr::null_mut()));
     let task_status = Rc::new(AtomicU8::new(0));
+    let token = CancellationToken::new();
     let abort_controller = AbortSignal {
       raw_work: async_work_inner.clone(),
       raw_deferred: raw_promise.clone(),
       status: task_status.clone(),
+      token: token.clone(),
     };
     let js_env = Env::from_raw(env);
     check_status!(unsafe {
@@ -84,6 +122,7 @@ impl FromNapiValue for AbortSignal {
       raw_work: async_work_inner,
       raw_deferred: raw_promise,
       status: task_status,
+      token,
     })
   }
 }
@@ -121,9 +160,13 @@ extern "C" fn on_abort(
     if abort_controller.status.load(Ordering::Relaxed) == 1 {
       return ptr::null_mut();
     }
+    abort_controller.token.cancel();
     let raw_async_work = abort_controller.raw_work.load(Ordering::Relaxed);
     let deferred = abort_controller.raw_deferred.load(Ordering::Relaxed);
-    sys::napi_cancel_async_work(env, raw_async_work);
+    // the work waiting in `AsyncWorkQueue` is cancelled when it's dequeued
+    if abort_controller.status.load(Ordering::Relaxed) != PENDING_IN_QUEUE {
+      sys::napi_cancel_async_work(env, raw_async_work);
+    }
     // abort function must be called from JavaScript main thread, so Relaxed Ordering is ok.
     abort_controller.status.store(2, Ordering::Relaxed);
     let abort_error = Error::new(Status::Cancelled, "AbortError".to_owned());
@@ -142,7 +185,12 @@ extern "C" fn on_abort(
 impl<T: Task> ToNapiValue for AsyncTask<T> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
     if let Some(abort_controller) = val.abort_signal {
-      let async_promise = async_work::run(env, val.inner, Some(abort_controller.status.clone()))?;
+      let async_promise = async_work::run(
+        env,
+        val.inner,
+        Some(abort_controller.status.clone()),
+        val.options,
+      )?;
       abort_controller
         .raw_work
         .store(async_promise.napi_async_work, Ordering::Relaxed);
@@ -151,7 +199,7 @@ impl<T: Task> ToNapiValue for AsyncTask<T> {
         .store(async_promise.deferred, Ordering::Relaxed);
       Ok(async_promise.promise_object().0.value)
     } else {
-      let async_promise = async_work::run(env, val.inner, None)?;
+      let async_promise = async_work::run(env, val.inner, None, val.options)?;
       Ok(async_promise.promise_object().0.value)
     }
   }
@@ -164,3 +212,214 @@ unsafe extern "C" fn async_task_abort_controller_finalize(
 ) {
   drop(unsafe { Box::from_raw(finalize_data as *mut AbortSignal) });
 }
+
+struct CancellationTokenInner {
+  cancelled: AtomicBool,
+  wakers: Mutex<Vec<Waker>>,
+  #[cfg(feature = "napi5")]
+  abort_listener: Mutex<Option<AbortListener>>,
+}
+
+/// The `abort` listener added to the `AbortSignal` a `CancellationToken` was created from
+#[cfg(feature = "napi5")]
+struct AbortListener {
+  // the shared handle of the `Env`, it doesn't keep the event loop alive
+  main_thread: crate::MainThreadHandle,
+  signal: SendRef,
+  listener: SendRef,
+}
+
+#[cfg(feature = "napi5")]
+struct SendRef(sys::napi_ref);
+
+// only used on the JavaScript thread
+#[cfg(feature = "napi5")]
+unsafe impl Send for SendRef {}
+
+/// The token is dropped once the task using it settles, the listener is removed from the signal then
+#[cfg(feature = "napi5")]
+impl Drop for CancellationTokenInner {
+  fn drop(&mut self) {
+    let Some(AbortListener {
+      main_thread,
+      signal,
+      listener,
+    }) = self.abort_listener.get_mut().ok().and_then(|l| l.take())
+    else {
+      return;
+    };
+    // a no-op once the `Env` is torn down, the signal and the references are gone with it
+    let _ = main_thread.run(move |env| {
+      // moves the whole `SendRef`s into the closure rather than their raw pointers
+      let (signal, listener) = (signal, listener);
+      let raw_env = env.raw();
+      let mut signal_value = ptr::null_mut();
+      let mut listener_value = ptr::null_mut();
+      let result = check_status!(unsafe {
+        sys::napi_get_reference_value(raw_env, signal.0, &mut signal_value)
+      })
+      .and_then(|_| {
+        check_status!(unsafe {
+          sys::napi_get_reference_value(raw_env, listener.0, &mut listener_value)
+        })
+      })
+      .and_then(|_| {
+        let signal = unsafe { JsObject::from_raw_unchecked(raw_env, signal_value) };
+        let listener = unsafe { Unknown::from_raw_unchecked(raw_env, listener_value) };
+        let remove_event_listener: Function<(&str, Unknown), Unknown> =
+          signal.get_named_property_unchecked("removeEventListener")?;
+        remove_event_listener.apply(&signal, ("abort", listener))?;
+        Ok(())
+      });
+      unsafe {
+        sys::napi_delete_reference(raw_env, signal.0);
+        sys::napi_delete_reference(raw_env, listener.0);
+      }
+      result
+    });
+  }
+}
+
+/// A thread safe cancellation notification, created from a JavaScript `AbortSignal`.
+///
+/// It can be received directly in `#[napi]` functions, moved into `async fn`, or obtained from `AbortSignal::cancellation_token` for `Task`s.
+///
+/// ```rust
+/// #[napi]
+/// async fn long_running(token: CancellationToken) -> Result<()> {
+///   tokio::select! {
+///     _ = token.cancelled() => Err(Error::new(Status::Cancelled, "AbortError")),
+///     _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => Ok(()),
+///   }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+  inner: Arc<CancellationTokenInner>,
+}
+
+impl CancellationToken {
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(CancellationTokenInner {
+        cancelled: AtomicBool::new(false),
+        wakers: Mutex::new(Vec::new()),
+        #[cfg(feature = "napi5")]
+        abort_listener: Mutex::new(None),
+      }),
+    }
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.cancelled.load(Ordering::Acquire)
+  }
+
+  /// Cancel the token and wake all the pending `cancelled()` futures
+  pub fn cancel(&self) {
+    if self.inner.cancelled.swap(true, Ordering::AcqRel) {
+      return;
+    }
+    let wakers = std::mem::take(&mut *self.inner.wakers.lock().unwrap());
+    for waker in wakers {
+      waker.wake();
+    }
+  }
+
+  /// Returns an `Err` with `Status::Cancelled` if the token has been cancelled
+  pub fn check(&self) -> crate::Result<()> {
+    if self.is_cancelled() {
+      Err(Error::new(Status::Cancelled, "AbortError".to_owned()))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// A future which resolves once the token is cancelled
+  pub fn cancelled(&self) -> Cancelled {
+    Cancelled {
+      token: self.clone(),
+    }
+  }
+}
+
+impl Default for CancellationToken {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Future returned by [`CancellationToken::cancelled`]
+pub struct Cancelled {
+  token: CancellationToken,
+}
+
+impl Future for Cancelled {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+    if self.token.is_cancelled() {
+      return Poll::Ready(());
+    }
+    let mut wakers = self.token.inner.wakers.lock().unwrap();
+    // check again with the lock held, `cancel` may have drained the wakers in the meantime
+    if self.token.is_cancelled() {
+      return Poll::Ready(());
+    }
+    if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+      wakers.push(cx.waker().clone());
+    }
+    Poll::Pending
+  }
+}
+
+impl TypeName for CancellationToken {
+  fn type_name() -> &'static str {
+    "AbortSignal"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for CancellationToken {}
+
+#[cfg(feature = "napi5")]
+impl FromNapiValue for CancellationToken {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    let signal = unsafe { JsObject::from_raw_unchecked(env, napi_val) };
+    let token = CancellationToken::new();
+    if signal.get_named_property_unchecked::<bool>("aborted")? {
+      token.cancel();
+      return Ok(token);
+    }
+    let js_env = Env::from_raw(env);
+    let main_thread = crate::MainThreadHandle::shared(env)?;
+    // a strong reference would keep the token, and so the listener, alive until the signal is collected
+    let token_in_listener = Arc::downgrade(&token.inner);
+    let on_abort: Function<Unknown, ()> =
+      js_env.create_function_from_closure("onabort", move |_| {
+        if let Some(inner) = token_in_listener.upgrade() {
+          CancellationToken { inner }.cancel();
+        }
+        Ok(())
+      })?;
+    let mut listener_ref = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_create_reference(env, on_abort.raw(), 1, &mut listener_ref)
+    })?;
+    let mut options = js_env.create_object()?;
+    options.set_named_property("once", true)?;
+    let add_event_listener: Function<(&str, Function<Unknown, ()>, JsObject), Unknown> =
+      signal.get_named_property_unchecked("addEventListener")?;
+    add_event_listener.apply(&signal, ("abort", on_abort, options))?;
+    let mut signal_ref = ptr::null_mut();
+    check_status!(unsafe { sys::napi_create_reference(env, napi_val, 1, &mut signal_ref) })?;
+    *token.inner.abort_listener.lock().unwrap() = Some(AbortListener {
+      main_thread,
+      signal: SendRef(signal_ref),
+      listener: SendRef(listener_ref),
+    });
+    Ok(token)
+  }
+}
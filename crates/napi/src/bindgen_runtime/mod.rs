@@ -18,6 +18,10 @@ pub mod iterator;
 mod js_values;
 mod module_register;
 
+/// Run Rust code when the JavaScript wrapper of a class instance is garbage collected.
+///
+/// Implement it for a `#[napi(custom_finalize)]` struct, or mark a `fn(&mut self)` / `fn(self)` method with `#[napi(finalize)]`, optionally taking `Env`.
+/// `finalize` always runs on the JavaScript thread of the `Env` which created the instance, either during garbage collection or when the `Env` is torn down, e.g. on worker thread exit.
 pub trait ObjectFinalize: Sized {
   #[allow(unused)]
   fn finalize(self, env: Env) -> Result<()> {
@@ -73,7 +77,7 @@ pub unsafe extern "C" fn raw_finalize_unchecked<T: ObjectFinalize>(
 /// called when node buffer is ready for gc
 #[doc(hidden)]
 pub unsafe extern "C" fn drop_buffer(
-  _env: sys::napi_env,
+  env: sys::napi_env,
   #[allow(unused)] finalize_data: *mut c_void,
   finalize_hint: *mut c_void,
 ) {
@@ -84,7 +88,12 @@ pub unsafe extern "C" fn drop_buffer(
       buffer.remove(&(finalize_data as *mut u8));
     });
   }
+  let buffer = unsafe { Box::from_raw(finalize_hint as *mut Buffer) };
   unsafe {
-    drop(Box::from_raw(finalize_hint as *mut Buffer));
-  }
+    crate::external_memory::adjust_external_memory_unchecked(
+      env,
+      -js_values::spare_capacity(buffer.capacity, buffer.len),
+    )
+  };
+  drop(buffer);
 }
@@ -57,6 +57,11 @@ type ModuleClassProperty = PersistedPerInstanceHashMap<
   HashMap<Option<&'static str>, (&'static str, Vec<Property>)>,
 >;
 
+type ModuleClassExtends = PersistedPerInstanceHashMap<
+  /* rust name */ &'static str,
+  /* base rust name */ &'static str,
+>;
+
 unsafe impl<K, V> Send for PersistedPerInstanceHashMap<K, V> {}
 unsafe impl<K, V> Sync for PersistedPerInstanceHashMap<K, V> {}
 
@@ -66,6 +71,7 @@ type RegisteredClassesMap = PersistedPerInstanceHashMap<ThreadId, RegisteredClas
 
 static MODULE_REGISTER_CALLBACK: Lazy<ModuleRegisterCallback> = Lazy::new(Default::default);
 static MODULE_CLASS_PROPERTIES: Lazy<ModuleClassProperty> = Lazy::new(Default::default);
+static MODULE_CLASS_EXTENDS: Lazy<ModuleClassExtends> = Lazy::new(Default::default);
 #[cfg(not(feature = "noop"))]
 static IS_FIRST_MODULE: AtomicBool = AtomicBool::new(true);
 #[cfg(not(feature = "noop"))]
@@ -99,7 +105,8 @@ fn wait_first_thread_registered() {
 }
 
 #[doc(hidden)]
-pub fn get_class_constructor(js_name: &'static str) -> Option<sys::napi_ref> {
+// Class constructors are created for every `Env` the addon is loaded into, keyed by the thread the `Env` runs on
+pub fn get_class_constructor(js_name: &str) -> Option<sys::napi_ref> {
   let current_id = std::thread::current().id();
   REGISTERED_CLASSES.borrow_mut(|map| {
     map
@@ -156,6 +163,13 @@ pub fn register_class(
   });
 }
 
+#[doc(hidden)]
+pub fn register_class_extends(rust_name: &'static str, base_rust_name: &'static str) {
+  MODULE_CLASS_EXTENDS.borrow_mut(|inner| {
+    inner.insert(rust_name, base_rust_name);
+  });
+}
+
 /// Get `C Callback` from defined Rust `fn`
 /// ```rust
 /// #[napi]
@@ -209,6 +223,15 @@ unsafe extern "C" fn napi_register_wasm_v1(
   unsafe { napi_register_module_v1(env, exports) }
 }
 
+#[cfg(all(feature = "napi10_module_version", not(feature = "noop")))]
+#[no_mangle]
+/// Declares Node-API 10 as the version of the module, the runtimes load the modules without this symbol as version 8.
+///
+/// Only the modules of version 10 can create references to strings, which the `PropertyKeyCache` keeps its keys with.
+pub extern "C" fn node_api_module_get_api_version_v1() -> i32 {
+  10
+}
+
 #[cfg(not(feature = "noop"))]
 #[no_mangle]
 /// Register the n-api module exports.
@@ -222,19 +245,18 @@ pub unsafe extern "C" fn napi_register_module_v1(
   env: sys::napi_env,
   exports: sys::napi_value,
 ) -> sys::napi_value {
-  if IS_FIRST_MODULE.load(Ordering::SeqCst) {
-    IS_FIRST_MODULE.store(false, Ordering::SeqCst);
-  } else {
+  // `swap` so only one of the workers requiring the addon at the same time sees `true`
+  if !IS_FIRST_MODULE.swap(false, Ordering::SeqCst) {
     wait_first_thread_registered();
   }
   let mut exports_objects: HashSet<String> = HashSet::default();
 
   {
-    let mut register_callback = MODULE_REGISTER_CALLBACK
-      .write()
-      .expect("Write MODULE_REGISTER_CALLBACK in napi_register_module_v1 failed");
+    let register_callback = MODULE_REGISTER_CALLBACK
+      .read()
+      .expect("Read MODULE_REGISTER_CALLBACK in napi_register_module_v1 failed");
     register_callback
-      .iter_mut()
+      .iter()
       .fold(
         HashMap::<Option<&'static str>, Vec<(&'static str, ExportRegisterCallback)>>::new(),
         |mut acc, (js_mod, item)| {
@@ -248,42 +270,18 @@ pub unsafe extern "C" fn napi_register_module_v1(
       )
       .iter()
       .for_each(|(js_mod, items)| {
-        let mut exports_js_mod = ptr::null_mut();
-        if let Some(js_mod_str) = js_mod {
-          let mod_name_c_str =
-            unsafe { CStr::from_bytes_with_nul_unchecked(js_mod_str.as_bytes()) };
-          if exports_objects.contains(*js_mod_str) {
-            check_status_or_throw!(
-              env,
-              unsafe {
-                sys::napi_get_named_property(
-                  env,
-                  exports,
-                  mod_name_c_str.as_ptr(),
-                  &mut exports_js_mod,
-                )
-              },
-              "Get mod {} from exports failed",
-              js_mod_str,
-            );
-          } else {
-            check_status_or_throw!(
-              env,
-              unsafe { sys::napi_create_object(env, &mut exports_js_mod) },
-              "Create export JavaScript Object [{}] failed",
-              js_mod_str
-            );
-            check_status_or_throw!(
-              env,
-              unsafe {
-                sys::napi_set_named_property(env, exports, mod_name_c_str.as_ptr(), exports_js_mod)
-              },
-              "Set exports Object [{}] into exports object failed",
-              js_mod_str
-            );
-            exports_objects.insert(js_mod_str.to_string());
+        let exports_js_mod = match js_mod {
+          Some(js_mod_str) => {
+            match unsafe { get_or_create_js_mod(env, exports, js_mod_str, &mut exports_objects) } {
+              Ok(exports_js_mod) => exports_js_mod,
+              Err(e) => {
+                unsafe { JsError::from(e).throw_into(env) };
+                return;
+              }
+            }
           }
-        }
+          None => ptr::null_mut(),
+        };
         for (name, callback) in items {
           unsafe {
             let js_name = CStr::from_bytes_with_nul_unchecked(name.as_bytes());
@@ -307,49 +305,59 @@ pub unsafe extern "C" fn napi_register_module_v1(
   }
 
   let mut registered_classes = HashMap::new();
+  let mut class_ptrs = HashMap::new();
+  let class_extends = MODULE_CLASS_EXTENDS.borrow_mut(|inner| inner.clone());
 
   MODULE_CLASS_PROPERTIES.borrow_mut(|inner| {
     inner.iter().for_each(|(rust_name, js_mods)| {
       for (js_mod, (js_name, props)) in js_mods {
-        let mut exports_js_mod = ptr::null_mut();
+        let exports_js_mod = match js_mod {
+          Some(js_mod_str) => {
+            match unsafe { get_or_create_js_mod(env, exports, js_mod_str, &mut exports_objects) } {
+              Ok(exports_js_mod) => exports_js_mod,
+              Err(e) => {
+                unsafe { JsError::from(e).throw_into(env) };
+                continue;
+              }
+            }
+          }
+          None => ptr::null_mut(),
+        };
         unsafe {
-          if let Some(js_mod_str) = js_mod {
-            let mod_name_c_str = CStr::from_bytes_with_nul_unchecked(js_mod_str.as_bytes());
-            if exports_objects.contains(*js_mod_str) {
-              check_status_or_throw!(
-                env,
-                sys::napi_get_named_property(
-                  env,
-                  exports,
-                  mod_name_c_str.as_ptr(),
-                  &mut exports_js_mod,
-                ),
-                "Get mod {} from exports failed",
-                js_mod_str,
-              );
-            } else {
-              check_status_or_throw!(
-                env,
-                sys::napi_create_object(env, &mut exports_js_mod),
-                "Create export JavaScript Object [{}] failed",
-                js_mod_str
-              );
-              check_status_or_throw!(
-                env,
-                sys::napi_set_named_property(env, exports, mod_name_c_str.as_ptr(), exports_js_mod),
-                "Set exports Object [{}] into exports object failed",
-                js_mod_str
-              );
-              exports_objects.insert(js_mod_str.to_string());
+          let (ctor, mut props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
+
+          let ctor = ctor.first().map(|c| c.method.unwrap()).unwrap_or(noop);
+          // the V8 signature of the methods defined by `napi_define_class` only accepts the instances of the class,
+          // so the methods and accessors of the base classes are defined again on the subclass
+          let mut base = class_extends.get(rust_name);
+          while let Some(base_rust_name) = base {
+            for base_props in inner
+              .get(base_rust_name)
+              .into_iter()
+              .flat_map(|m| m.values())
+            {
+              for prop in base_props.1.iter() {
+                if !prop.is_ctor
+                  && !prop.is_static()
+                  && !props.iter().any(|own| own.has_same_key(prop))
+                {
+                  props.push(prop);
+                }
+              }
             }
+            base = class_extends.get(base_rust_name);
           }
-          let (ctor, props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
-
-          let ctor = ctor
-            .first()
-            .map(|c| c.raw().method.unwrap())
-            .unwrap_or(noop);
-          let raw_props: Vec<_> = props.iter().map(|prop| prop.raw()).collect();
+          let raw_props = match props
+            .iter()
+            .map(|prop| prop.raw(env))
+            .collect::<Result<Vec<_>>>()
+          {
+            Ok(raw_props) => raw_props,
+            Err(e) => {
+              JsError::from(e).throw_into(env);
+              continue;
+            }
+          };
 
           let js_class_name = CStr::from_bytes_with_nul_unchecked(js_name.as_bytes());
           let mut class_ptr = ptr::null_mut();
@@ -375,6 +383,7 @@ pub unsafe extern "C" fn napi_register_module_v1(
           sys::napi_create_reference(env, class_ptr, 1, &mut ctor_ref);
 
           registered_classes.insert(js_name.to_string(), ctor_ref);
+          class_ptrs.insert(*rust_name, class_ptr);
 
           check_status_or_throw!(
             env,
@@ -404,6 +413,40 @@ pub unsafe extern "C" fn napi_register_module_v1(
     });
   });
 
+  check_status_or_throw!(
+    env,
+    unsafe {
+      sys::napi_add_env_cleanup_hook(
+        env,
+        Some(remove_registered_classes),
+        Box::into_raw(Box::new(std::thread::current().id())).cast(),
+      )
+    },
+    "Failed to add remove registered classes cleanup hook"
+  );
+
+  MODULE_CLASS_EXTENDS.borrow_mut(|inner| {
+    for (rust_name, base_rust_name) in inner.iter() {
+      let (Some(class), Some(base)) = (class_ptrs.get(rust_name), class_ptrs.get(base_rust_name))
+      else {
+        unsafe {
+          JsError::from(crate::Error::new(
+            crate::Status::GenericFailure,
+            format!(
+              "Base class `{}` of `{}` is not registered",
+              base_rust_name, rust_name
+            ),
+          ))
+          .throw_into(env)
+        };
+        continue;
+      };
+      if let Err(e) = unsafe { inherit_class(env, *class, *base) } {
+        unsafe { JsError::from(e).throw_into(env) };
+      }
+    }
+  });
+
   #[cfg(feature = "compat-mode")]
   {
     let module_exports = MODULE_EXPORTS.read().expect("Read MODULE_EXPORTS failed");
@@ -440,19 +483,113 @@ pub unsafe extern "C" fn napi_register_module_v1(
   exports
 }
 
+/// Forget the class constructors of an exiting `Env`, the references are released together with it
+#[cfg(not(feature = "noop"))]
+unsafe extern "C" fn remove_registered_classes(id: *mut std::ffi::c_void) {
+  let thread_id = unsafe { Box::from_raw(id.cast::<ThreadId>()) };
+  REGISTERED_CLASSES.borrow_mut(|map| map.remove(&*thread_id));
+}
+
+/// The object `js_mod` is exported on, created the first time. `a::b::c` is exported as the nested objects `exports.a.b.c`
+#[cfg(not(feature = "noop"))]
+unsafe fn get_or_create_js_mod(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  js_mod: &str,
+  exports_objects: &mut HashSet<String>,
+) -> Result<sys::napi_value> {
+  let mut parent = exports;
+  let mut path = String::new();
+  for name in js_mod.trim_end_matches('\0').split("::") {
+    if !path.is_empty() {
+      path.push_str("::");
+    }
+    path.push_str(name);
+    let name_c_str = format!("{}\0", name);
+    let mut object = ptr::null_mut();
+    if exports_objects.contains(&path) {
+      check_status!(
+        unsafe {
+          sys::napi_get_named_property(env, parent, name_c_str.as_ptr().cast(), &mut object)
+        },
+        "Get mod {} from exports failed",
+        path,
+      )?;
+    } else {
+      check_status!(
+        unsafe { sys::napi_create_object(env, &mut object) },
+        "Create export JavaScript Object [{}] failed",
+        path
+      )?;
+      check_status!(
+        unsafe { sys::napi_set_named_property(env, parent, name_c_str.as_ptr().cast(), object) },
+        "Set exports Object [{}] into exports object failed",
+        path
+      )?;
+      exports_objects.insert(path.clone());
+    }
+    parent = object;
+  }
+  Ok(parent)
+}
+
+/// Chain both the constructor and its `prototype` to the base class, like `class Derived extends Base`
+#[cfg(not(feature = "noop"))]
+unsafe fn inherit_class(
+  env: sys::napi_env,
+  class: sys::napi_value,
+  base: sys::napi_value,
+) -> Result<()> {
+  let get_named_property = |object: sys::napi_value, name: &str| -> Result<sys::napi_value> {
+    let mut value = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_named_property(env, object, name.as_ptr().cast(), &mut value) },
+      "Failed to get `{}` property",
+      name.trim_end_matches('\0')
+    )?;
+    Ok(value)
+  };
+  let mut global = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_global(env, &mut global) },
+    "Failed to get global object"
+  )?;
+  let object = get_named_property(global, "Object\0")?;
+  let set_prototype_of = get_named_property(object, "setPrototypeOf\0")?;
+  let class_prototype = get_named_property(class, "prototype\0")?;
+  let base_prototype = get_named_property(base, "prototype\0")?;
+  for args in [[class_prototype, base_prototype], [class, base]] {
+    let mut ret = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_call_function(env, object, set_prototype_of, 2, args.as_ptr(), &mut ret) },
+      "Failed to set the prototype of class"
+    )?;
+  }
+  Ok(())
+}
+
 #[cfg(not(feature = "noop"))]
 pub(crate) unsafe extern "C" fn noop(
   env: sys::napi_env,
   _info: sys::napi_callback_info,
 ) -> sys::napi_value {
-  if !crate::bindgen_runtime::___CALL_FROM_FACTORY.with(|s| s.load(Ordering::Relaxed)) {
+  unsafe {
+    throw_without_constructor(
+      env,
+      CStr::from_bytes_with_nul_unchecked(b"Class contains no `constructor`, can not new it!\0"),
+    )
+  }
+}
+
+/// The body of the constructors of the classes which can't be created from JavaScript,
+/// throws `message` unless the instance is created by a factory or `ToNapiValue`
+#[doc(hidden)]
+pub unsafe fn throw_without_constructor(env: sys::napi_env, message: &CStr) -> sys::napi_value {
+  if !crate::bindgen_runtime::___CALL_FROM_FACTORY
+    .with(|s| s.load(std::sync::atomic::Ordering::Relaxed))
+  {
     unsafe {
-      sys::napi_throw_error(
-        env,
-        ptr::null_mut(),
-        CStr::from_bytes_with_nul_unchecked(b"Class contains no `constructor`, can not new it!\0")
-          .as_ptr(),
-      );
+      sys::napi_throw_error(env, ptr::null_mut(), message.as_ptr());
     }
   }
   ptr::null_mut()
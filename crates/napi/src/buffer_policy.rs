@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::sys;
+
+/// How the memory of Rust is handed to JavaScript when creating a `Buffer`, an `ArrayBuffer` or a `TypedArray` from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalBufferPolicy {
+  /// Share the memory with JavaScript with an external buffer, and copy it in the runtimes rejecting external buffers
+  Auto,
+  /// Always copy the data into memory allocated by the JavaScript runtime
+  Copy,
+}
+
+static FORCE_COPY: AtomicBool = AtomicBool::new(false);
+// set once the runtime rejected an external buffer, the next ones are copied without trying again
+static EXTERNAL_BUFFERS_REJECTED: AtomicBool = AtomicBool::new(false);
+
+/// Set the `ExternalBufferPolicy` of the addon, e.g. `ExternalBufferPolicy::Copy` in `#[module_init]` for the runtimes
+/// which crash rather than reject the external buffers.
+///
+/// Electron with the V8 memory cage rejects them with `napi_no_external_buffers_allowed`, which is detected and handled without setting a policy.
+pub fn set_external_buffer_policy(policy: ExternalBufferPolicy) {
+  FORCE_COPY.store(policy == ExternalBufferPolicy::Copy, Ordering::Relaxed);
+}
+
+pub fn external_buffer_policy() -> ExternalBufferPolicy {
+  if FORCE_COPY.load(Ordering::Relaxed) {
+    ExternalBufferPolicy::Copy
+  } else {
+    ExternalBufferPolicy::Auto
+  }
+}
+
+/// Whether the JavaScript runtime rejected an external buffer, the data of the later buffers is copied
+pub fn external_buffers_rejected() -> bool {
+  EXTERNAL_BUFFERS_REJECTED.load(Ordering::Relaxed)
+}
+
+/// Returns `napi_no_external_buffers_allowed` without calling `create` if the buffers must be copied,
+/// the callers copy the data in this case like when the runtime rejects the external buffer.
+pub(crate) fn create_external(create: impl FnOnce() -> sys::napi_status) -> sys::napi_status {
+  if FORCE_COPY.load(Ordering::Relaxed) || EXTERNAL_BUFFERS_REJECTED.load(Ordering::Relaxed) {
+    return sys::Status::napi_no_external_buffers_allowed;
+  }
+  let status = create();
+  if status == sys::Status::napi_no_external_buffers_allowed {
+    EXTERNAL_BUFFERS_REJECTED.store(true, Ordering::Relaxed);
+  }
+  status
+}
@@ -0,0 +1,148 @@
+use std::ffi::c_void;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::Env;
+
+/// A Node-API feature the JavaScript runtime may be missing, probed with `Env::has_capability`.
+///
+/// The `napi*` features only declare which functions the addon may call, an addon built with `napi8` still loads in the runtimes without the newer functions as long as it doesn't call them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+  /// `Env::create_threadsafe_function`, Node-API 4
+  ThreadsafeFunction,
+  /// `Env::create_date`, Node-API 5
+  Date,
+  /// `Env::create_bigint_from_i64` and the other `BigInt` functions, Node-API 6
+  BigInt,
+  /// `Env::set_instance_data`, Node-API 6
+  InstanceData,
+  /// `JsArrayBuffer::detach`, Node-API 7
+  DetachArrayBuffer,
+  /// `JsObject::type_tag`, Node-API 8
+  TypeTagging,
+  /// `JsObject::freeze` and `JsObject::seal`, Node-API 8
+  ObjectFreeze,
+  /// `Env::add_removable_async_cleanup_hook`, Node-API 8
+  AsyncCleanupHook,
+  /// `Env::symbol_for`, Node-API 9
+  SymbolFor,
+  /// `Env::get_module_file_name`, Node-API 9
+  ModuleFileName,
+  /// The external strings, experimental
+  ExternalString,
+}
+
+const CAPABILITIES: usize = 11;
+
+// 0 not probed yet, 1 missing, 2 available
+#[allow(clippy::declare_interior_mutable_const)]
+const NOT_PROBED: AtomicU8 = AtomicU8::new(0);
+static PROBED: [AtomicU8; CAPABILITIES] = [NOT_PROBED; CAPABILITIES];
+
+impl Capability {
+  /// The function of the runtime looked up to probe the capability
+  pub fn symbol(&self) -> &'static str {
+    let symbol = self.c_symbol();
+    &symbol[..symbol.len() - 1]
+  }
+
+  /// The Node-API version introducing the capability, `None` for the experimental ones
+  pub fn napi_version(&self) -> Option<u32> {
+    match self {
+      Capability::ThreadsafeFunction => Some(4),
+      Capability::Date => Some(5),
+      Capability::BigInt | Capability::InstanceData => Some(6),
+      Capability::DetachArrayBuffer => Some(7),
+      Capability::TypeTagging | Capability::ObjectFreeze | Capability::AsyncCleanupHook => Some(8),
+      Capability::SymbolFor | Capability::ModuleFileName => Some(9),
+      Capability::ExternalString => None,
+    }
+  }
+
+  fn c_symbol(&self) -> &'static str {
+    match self {
+      Capability::ThreadsafeFunction => "napi_create_threadsafe_function\0",
+      Capability::Date => "napi_create_date\0",
+      Capability::BigInt => "napi_create_bigint_int64\0",
+      Capability::InstanceData => "napi_set_instance_data\0",
+      Capability::DetachArrayBuffer => "napi_detach_arraybuffer\0",
+      Capability::TypeTagging => "napi_type_tag_object\0",
+      Capability::ObjectFreeze => "napi_object_freeze\0",
+      Capability::AsyncCleanupHook => "napi_add_async_cleanup_hook\0",
+      Capability::SymbolFor => "node_api_symbol_for\0",
+      Capability::ModuleFileName => "node_api_get_module_file_name\0",
+      Capability::ExternalString => "node_api_create_external_string_latin1\0",
+    }
+  }
+}
+
+impl Env {
+  /// Whether the JavaScript runtime provides `capability`, to degrade features at runtime rather than calling a missing Node-API function.
+  ///
+  /// The function of the capability is looked up in the process the first time, and the result is cached.
+  pub fn has_capability(&self, capability: Capability) -> bool {
+    let probed = &PROBED[capability as usize];
+    match probed.load(Ordering::Relaxed) {
+      1 => false,
+      2 => true,
+      _ => {
+        let available = self.probe_capability(capability);
+        probed.store(if available { 2 } else { 1 }, Ordering::Relaxed);
+        available
+      }
+    }
+  }
+
+  #[cfg(not(target_family = "wasm"))]
+  fn probe_capability(&self, capability: Capability) -> bool {
+    !unsafe { find_host_symbol(capability.c_symbol().as_ptr().cast()) }.is_null()
+  }
+
+  // there is no symbol to look up, the functions are all provided by emnapi
+  #[cfg(target_family = "wasm")]
+  fn probe_capability(&self, capability: Capability) -> bool {
+    match capability.napi_version() {
+      Some(version) => self.supports_napi_version(version).unwrap_or(false),
+      None => false,
+    }
+  }
+}
+
+// the Node-API functions are exported by the executable, or by `libnode` when Node.js is embedded,
+// both are searched by the global lookup
+#[cfg(unix)]
+unsafe fn find_host_symbol(symbol: *const c_char) -> *mut c_void {
+  extern "C" {
+    fn dlopen(filename: *const c_char, flag: i32) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+  }
+  const RTLD_LAZY: i32 = 1;
+  let host = unsafe { dlopen(std::ptr::null(), RTLD_LAZY) };
+  if host.is_null() {
+    return host;
+  }
+  unsafe { dlsym(host, symbol) }
+}
+
+#[cfg(windows)]
+unsafe fn find_host_symbol(symbol: *const c_char) -> *mut c_void {
+  extern "system" {
+    fn GetModuleHandleW(module_name: *const u16) -> *mut c_void;
+    fn GetProcAddress(module: *mut c_void, proc_name: *const c_char) -> *mut c_void;
+  }
+  // `node.exe`, `electron.exe` or any other executable hosting the addon
+  let host = unsafe { GetModuleHandleW(std::ptr::null()) };
+  let found = unsafe { GetProcAddress(host, symbol) };
+  if !found.is_null() {
+    return found;
+  }
+  // with the GNU toolchain the addon links `libnode.dll`
+  let libnode = "libnode.dll\0".encode_utf16().collect::<Vec<u16>>();
+  let module = unsafe { GetModuleHandleW(libnode.as_ptr()) };
+  if module.is_null() {
+    return module;
+  }
+  unsafe { GetProcAddress(module, symbol) }
+}
@@ -1,3 +1,11 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+thread_local! {
+  /// Hooks added by `Env::add_env_cleanup_hook` which have neither run nor been removed yet
+  pub(crate) static LIVE_CLEANUP_HOOKS: RefCell<HashSet<usize>> = Default::default();
+}
+
 pub(crate) struct CleanupEnvHookData<T: 'static> {
   pub(crate) data: T,
   pub(crate) hook: Box<dyn FnOnce(T)>,
@@ -5,6 +5,7 @@ use std::convert::TryInto;
 use std::ffi::CString;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 use std::future::Future;
+use std::marker::PhantomData;
 use std::mem;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
@@ -15,33 +16,44 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 #[cfg(feature = "napi8")]
-use crate::async_cleanup_hook::AsyncCleanupHook;
+use crate::async_cleanup_hook::{AsyncCleanupHook, LIVE_ASYNC_CLEANUP_HOOKS};
 #[cfg(feature = "napi5")]
 use crate::bindgen_runtime::FunctionCallContext;
-#[cfg(feature = "napi4")]
-use crate::bindgen_runtime::ToNapiValue;
-use crate::bindgen_runtime::{FromNapiValue, Function, JsValuesTupleIntoVec, Unknown};
+use crate::bindgen_runtime::{
+  get_class_constructor, spare_capacity, ClassInstance, FromNapiValue, Function, JavaScriptClass,
+  JsValuesTupleIntoVec, ToNapiValue, TypeName, Unknown,
+};
 #[cfg(feature = "napi3")]
-use crate::cleanup_env::{CleanupEnvHook, CleanupEnvHookData};
+use crate::cleanup_env::{CleanupEnvHook, CleanupEnvHookData, LIVE_CLEANUP_HOOKS};
 #[cfg(feature = "serde-json")]
 use crate::js_values::{De, Ser};
+#[cfg(feature = "napi3")]
+use crate::script_cache;
 #[cfg(feature = "napi4")]
 use crate::threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunction};
 #[cfg(feature = "napi3")]
 use crate::JsError;
+#[cfg(feature = "napi4")]
+use crate::MainThreadHandle;
 use crate::{
-  async_work::{self, AsyncWorkPromise},
-  check_status,
+  async_work::{self, AsyncWorkOptions, AsyncWorkPromise},
+  check_pending_exception, check_status,
+  external_memory::adjust_external_memory_unchecked,
   js_values::*,
   sys,
-  task::Task,
-  Error, ExtendedErrorInfo, NodeVersion, Result, Status, ValueType,
+  task::{BlockingTask, Task},
+  Error, ExtendedErrorInfo, ExternalMemoryGuard, NodeVersion, Result, Status, ValueType,
 };
 
 pub type Callback = unsafe extern "C" fn(sys::napi_env, sys::napi_callback_info) -> sys::napi_value;
 
 pub(crate) static EMPTY_VEC: Vec<u8> = vec![];
 
+/// Appended to the script so V8 reports `filename` in stack traces of the errors thrown from it
+pub(crate) fn with_source_url(script: &str, filename: &str) -> String {
+  format!("{}\n//# sourceURL={}", script, filename)
+}
+
 #[derive(Clone, Copy)]
 /// `Env` is used to represent a context that the underlying N-API implementation can use to persist VM-specific state.
 ///
@@ -241,6 +253,36 @@ impl Env {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, raw_value) })
   }
 
+  /// Create a new JavaScript `Map` by calling the global `Map` constructor
+  pub fn create_map(&self) -> Result<JsMap> {
+    let constructor: JsFunction = self.get_global()?.get_named_property_unchecked("Map")?;
+    let map = constructor.new_instance::<JsUnknown>(&[])?;
+    Ok(unsafe { JsMap::from_raw_unchecked(self.0, map.raw()) })
+  }
+
+  /// Create a JavaScript `Proxy` from the traps of `proxy`
+  #[cfg(feature = "napi5")]
+  pub fn create_proxy(&self, proxy: JsProxy) -> Result<JsObject> {
+    proxy.into_js_object(self)
+  }
+
+  /// Create a new JavaScript `Set` by calling the global `Set` constructor
+  pub fn create_set(&self) -> Result<JsSet> {
+    let constructor: JsFunction = self.get_global()?.get_named_property_unchecked("Set")?;
+    let set = constructor.new_instance::<JsUnknown>(&[])?;
+    Ok(unsafe { JsSet::from_raw_unchecked(self.0, set.raw()) })
+  }
+
+  /// Create a new JavaScript `SharedArrayBuffer` by calling the global `SharedArrayBuffer` constructor
+  pub fn create_shared_arraybuffer(&self, length: usize) -> Result<JsSharedArrayBuffer> {
+    let constructor: JsFunction = self
+      .get_global()?
+      .get_named_property_unchecked("SharedArrayBuffer")?;
+    let length = self.create_double(length as f64)?;
+    let buffer = constructor.new_instance(&[length])?;
+    Ok(unsafe { JsSharedArrayBuffer::from_raw_unchecked(self.0, buffer.raw()) })
+  }
+
   pub fn create_empty_array(&self) -> Result<JsObject> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_create_array(self.0, &mut raw_value) })?;
@@ -286,14 +328,16 @@ impl Env {
         // the same data pointer if it's 0x0.
         sys::napi_create_buffer(self.0, length, ptr::null_mut(), &mut raw_value)
       } else {
-        let status = sys::napi_create_external_buffer(
-          self.0,
-          length,
-          data_ptr.cast(),
-          Some(drop_buffer),
-          hint_ptr.cast(),
-          &mut raw_value,
-        );
+        let status = crate::buffer_policy::create_external(|| {
+          sys::napi_create_external_buffer(
+            self.0,
+            length,
+            data_ptr.cast(),
+            Some(drop_buffer),
+            hint_ptr.cast(),
+            &mut raw_value,
+          )
+        });
         // electron doesn't support external buffers
         if status == sys::Status::napi_no_external_buffers_allowed {
           drop(Box::from_raw(hint_ptr));
@@ -308,6 +352,10 @@ impl Env {
           data = Vec::from_raw_parts(dest_data_ptr.cast(), length, length);
           status
         } else {
+          if status == sys::Status::napi_ok {
+            // reported as released in `drop_buffer`
+            adjust_external_memory_unchecked(self.0, spare_capacity(data.capacity(), length));
+          }
           status
         }
       }
@@ -356,21 +404,23 @@ impl Env {
     }
     let hint_ptr = Box::into_raw(Box::new((hint, finalize_callback)));
     unsafe {
-      let status = sys::napi_create_external_buffer(
-        self.0,
-        length,
-        data as *mut c_void,
-        Some(
-          raw_finalize_with_custom_callback::<Hint, Finalize>
-            as unsafe extern "C" fn(
-              env: sys::napi_env,
-              finalize_data: *mut c_void,
-              finalize_hint: *mut c_void,
-            ),
-        ),
-        hint_ptr.cast(),
-        &mut raw_value,
-      );
+      let status = crate::buffer_policy::create_external(|| {
+        sys::napi_create_external_buffer(
+          self.0,
+          length,
+          data as *mut c_void,
+          Some(
+            raw_finalize_with_custom_callback::<Hint, Finalize>
+              as unsafe extern "C" fn(
+                env: sys::napi_env,
+                finalize_data: *mut c_void,
+                finalize_hint: *mut c_void,
+              ),
+          ),
+          hint_ptr.cast(),
+          &mut raw_value,
+        )
+      });
       if status == sys::Status::napi_no_external_buffers_allowed {
         let (hint, finalize) = *Box::from_raw(hint_ptr);
         let mut result_data = ptr::null_mut();
@@ -416,6 +466,26 @@ impl Env {
     Ok(0)
   }
 
+  /// Report `size_of::<T>()` bytes of externally allocated memory to V8, the returned guard reports them as released when it is dropped.
+  ///
+  /// Use `Env::track_external_memory` for heap allocations owned by `T`, which `size_of` can't see.
+  pub fn adjust_external_memory_by<T>(&self) -> Result<ExternalMemoryGuard> {
+    self.track_external_memory(mem::size_of::<T>())
+  }
+
+  /// Report `size` bytes of externally allocated memory to V8, the returned guard reports them as released when it is dropped.
+  ///
+  /// Keep the guard next to the allocation it describes, e.g. as a field of a `#[napi]` class, so the accounting follows the allocation's lifetime.
+  pub fn track_external_memory(&self, size: usize) -> Result<ExternalMemoryGuard> {
+    let size = i64::try_from(size).map_err(|_| {
+      Error::new(
+        Status::InvalidArg,
+        format!("External memory size {} overflows i64", size),
+      )
+    })?;
+    ExternalMemoryGuard::new(self.0, size)
+  }
+
   /// This API allocates a node::Buffer object and initializes it with data copied from the passed-in buffer.
   ///
   /// While this is still a fully-supported data structure, in most cases using a TypedArray will suffice.
@@ -460,6 +530,7 @@ impl Env {
     ))
   }
 
+  /// The ownership of `data` is transferred to the `ArrayBuffer`, it's dropped once the `ArrayBuffer` is garbage collected or detached.
   pub fn create_arraybuffer_with_data(&self, mut data: Vec<u8>) -> Result<JsArrayBufferValue> {
     let length = data.len();
     let mut raw_value = ptr::null_mut();
@@ -472,14 +543,16 @@ impl Env {
         sys::napi_create_arraybuffer(self.0, length, ptr::null_mut(), &mut raw_value)
       } else {
         let hint_ptr = Box::into_raw(Box::new((length, data.capacity())));
-        let status = sys::napi_create_external_arraybuffer(
-          self.0,
-          data_ptr.cast(),
-          length,
-          Some(drop_buffer),
-          hint_ptr.cast(),
-          &mut raw_value,
-        );
+        let status = crate::buffer_policy::create_external(|| {
+          sys::napi_create_external_arraybuffer(
+            self.0,
+            data_ptr.cast(),
+            length,
+            Some(drop_buffer),
+            hint_ptr.cast(),
+            &mut raw_value,
+          )
+        });
         if status == sys::Status::napi_no_external_buffers_allowed {
           drop(Box::from_raw(hint_ptr));
           let mut underlying_data = ptr::null_mut();
@@ -488,6 +561,10 @@ impl Env {
           ptr::copy_nonoverlapping(data_ptr, underlying_data.cast(), length);
           status
         } else {
+          if status == sys::Status::napi_ok {
+            // reported as released in `drop_buffer`
+            adjust_external_memory_unchecked(self.0, spare_capacity(data.capacity(), length));
+          }
           status
         }
       }
@@ -533,28 +610,30 @@ impl Env {
     let mut raw_value = ptr::null_mut();
     let hint_ptr = Box::into_raw(Box::new((hint, finalize_callback)));
     unsafe {
-      let status = sys::napi_create_external_arraybuffer(
-        self.0,
-        if length == 0 {
-          // Rust uses 0x1 as the data pointer for empty buffers,
-          // but NAPI/V8 only allows multiple buffers to have
-          // the same data pointer if it's 0x0.
-          ptr::null_mut()
-        } else {
-          data as *mut c_void
-        },
-        length,
-        Some(
-          raw_finalize_with_custom_callback::<Hint, Finalize>
-            as unsafe extern "C" fn(
-              env: sys::napi_env,
-              finalize_data: *mut c_void,
-              finalize_hint: *mut c_void,
-            ),
-        ),
-        hint_ptr.cast(),
-        &mut raw_value,
-      );
+      let status = crate::buffer_policy::create_external(|| {
+        sys::napi_create_external_arraybuffer(
+          self.0,
+          if length == 0 {
+            // Rust uses 0x1 as the data pointer for empty buffers,
+            // but NAPI/V8 only allows multiple buffers to have
+            // the same data pointer if it's 0x0.
+            ptr::null_mut()
+          } else {
+            data as *mut c_void
+          },
+          length,
+          Some(
+            raw_finalize_with_custom_callback::<Hint, Finalize>
+              as unsafe extern "C" fn(
+                env: sys::napi_env,
+                finalize_data: *mut c_void,
+                finalize_hint: *mut c_void,
+              ),
+          ),
+          hint_ptr.cast(),
+          &mut raw_value,
+        )
+      });
       if status == sys::Status::napi_no_external_buffers_allowed {
         let (hint, finalize) = *Box::from_raw(hint_ptr);
         let mut underlying_data = ptr::null_mut();
@@ -770,8 +849,8 @@ impl Env {
     let mut raw_result = ptr::null_mut();
     let raw_properties = properties
       .iter()
-      .map(|prop| prop.raw())
-      .collect::<Vec<sys::napi_property_descriptor>>();
+      .map(|prop| prop.raw(self.0))
+      .collect::<Result<Vec<sys::napi_property_descriptor>>>()?;
     let c_name = CString::new(name)?;
     check_status!(unsafe {
       sys::napi_define_class(
@@ -930,7 +1009,7 @@ impl Env {
   #[deprecated(since = "3.0.0", note = "Please use `External::new` instead")]
   /// If `size_hint` provided, `Env::adjust_external_memory` will be called under the hood.
   ///
-  /// If no `size_hint` provided, global garbage collections will be triggered less times than expected.
+  /// If no `size_hint` provided, `size_of::<T>()` is used, heap allocations owned by `native_object` won't be counted.
   ///
   /// If getting the exact `native_object` size is difficult, you can provide an approximate value, it's only effect to the GC.
   pub fn create_external<T: 'static>(
@@ -938,24 +1017,23 @@ impl Env {
     native_object: T,
     size_hint: Option<i64>,
   ) -> Result<JsExternal> {
+    let size_hint = size_hint.unwrap_or(mem::size_of::<T>() as i64);
     let mut object_value = ptr::null_mut();
     check_status!(unsafe {
       sys::napi_create_external(
         self.0,
         Box::into_raw(Box::new(TaggedObject::new(native_object))).cast(),
         Some(raw_finalize::<TaggedObject<T>>),
-        Box::into_raw(Box::new(size_hint.unwrap_or(0))).cast(),
+        Box::into_raw(Box::new(size_hint)).cast(),
         &mut object_value,
       )
     })?;
-    if let Some(changed) = size_hint {
-      if changed != 0 {
-        let mut adjusted_value = 0i64;
-        check_status!(unsafe {
-          sys::napi_adjust_external_memory(self.0, changed, &mut adjusted_value)
-        })?;
-      }
-    };
+    if size_hint != 0 {
+      let mut adjusted_value = 0i64;
+      check_status!(unsafe {
+        sys::napi_adjust_external_memory(self.0, size_hint, &mut adjusted_value)
+      })?;
+    }
     Ok(unsafe { JsExternal::from_raw_unchecked(self.0, object_value) })
   }
 
@@ -997,9 +1075,93 @@ impl Env {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, result) })
   }
 
+  /// Construct an instance of the exported `#[napi]` class `C` through its JavaScript constructor, like `new C(...args)` in JavaScript.
+  ///
+  /// It returns the instance with a reference to the wrapped native struct, so factory functions in Rust can produce class instances, e.g. `env.construct::<Animal, _>(("Tom", 3))`.
+  pub fn construct<C, Args>(&self, args: Args) -> Result<ClassInstance<C>>
+  where
+    C: JavaScriptClass,
+    Args: JsValuesTupleIntoVec,
+  {
+    let ctor_ref = get_class_constructor(C::JS_NAME).ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        format!(
+          "Failed to get constructor of class `{}`",
+          C::JS_NAME.trim_end_matches('\0')
+        ),
+      )
+    })?;
+    let mut ctor = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(self.0, ctor_ref, &mut ctor) },
+      "Failed to get constructor reference of class `{}`",
+      C::JS_NAME.trim_end_matches('\0')
+    )?;
+    let raw_args = args.into_vec(self.0)?;
+    let mut instance = ptr::null_mut();
+    check_pending_exception!(self.0, unsafe {
+      sys::napi_new_instance(
+        self.0,
+        ctor,
+        raw_args.len(),
+        raw_args.as_ptr(),
+        &mut instance,
+      )
+    })?;
+    unsafe { ClassInstance::<C>::from_napi_value(self.0, instance) }
+  }
+
   /// Run [Task](./trait.Task.html) in libuv thread pool, return [AsyncWorkPromise](./struct.AsyncWorkPromise.html)
   pub fn spawn<T: 'static + Task>(&self, task: T) -> Result<AsyncWorkPromise> {
-    async_work::run(self.0, task, None)
+    async_work::run(self.0, task, None, AsyncWorkOptions::default())
+  }
+
+  /// Same as `Env::spawn`, with the async resource name and the `AsyncWorkQueue` in `options`
+  pub fn spawn_with_options<T: 'static + Task>(
+    &self,
+    task: T,
+    options: AsyncWorkOptions,
+  ) -> Result<AsyncWorkPromise> {
+    async_work::run(self.0, task, None, options)
+  }
+
+  /// Run `func` in libuv thread pool, the promise is resolved with its return value converted to JavaScript.
+  ///
+  /// A shorthand of `Env::spawn` for one-off jobs which don't need a `Task` implementation.
+  /// Like `Env::spawn`, it returns the `AsyncWorkPromise` rather than the bare promise, so the job can still be cancelled before it starts,
+  /// return its `promise_object` to JavaScript:
+  ///
+  /// ```
+  /// #[napi(ts_return_type = "Promise<number>")]
+  /// fn sum_in_background(env: Env, a: u32, b: u32) -> Result<JsObject> {
+  ///   let promise = env.spawn_blocking(move || Ok(a + b))?;
+  ///   Ok(promise.promise_object())
+  /// }
+  /// ```
+  pub fn spawn_blocking<F, R>(&self, func: F) -> Result<AsyncWorkPromise>
+  where
+    F: 'static + Send + FnOnce() -> Result<R>,
+    R: 'static + Send + ToNapiValue + TypeName,
+  {
+    self.spawn(BlockingTask {
+      func: Some(func),
+      _output: PhantomData,
+    })
+  }
+
+  /// Run `executor` in a new handle scope, the JavaScript values it creates are released when it returns rather than when the native call returns.
+  ///
+  /// Use it in the long loops creating temporary values. A JavaScript value returned from `executor` must go through `HandleScope::escape`,
+  /// the other ones are invalid after the scope closes.
+  pub fn with_handle_scope<T, F>(&self, executor: F) -> Result<T>
+  where
+    F: FnOnce(&HandleScope) -> Result<T>,
+  {
+    let scope = HandleScope::open(self.0)?;
+    let result = executor(&scope);
+    scope.close()?;
+    result
   }
 
   pub fn run_in_scope<T, F>(&self, executor: F) -> Result<T>
@@ -1027,17 +1189,44 @@ impl Env {
     unsafe { V::from_napi_value(self.0, raw_value) }
   }
 
-  /// `process.versions.napi`
+  /// Same as `Env::run_script`, `filename` shows up in the stack traces of the errors thrown from the script.
+  pub fn run_script_with_filename<S: AsRef<str>, F: AsRef<str>, V: FromNapiValue>(
+    &self,
+    script: S,
+    filename: F,
+  ) -> Result<V> {
+    self.run_script(with_source_url(script.as_ref(), filename.as_ref()))
+  }
+
+  /// Run `body` as the body of a function, use `return` to produce the result.
+  ///
+  /// The function is compiled on the first call and cached for the lifetime of the `Env`, so helper snippets evaluated repeatedly are not recompiled.
+  /// It's called with the global object as `this`, and `var` declarations stay local to the function.
+  #[cfg(feature = "napi3")]
+  pub fn run_script_cached<V: FromNapiValue>(
+    &self,
+    body: &str,
+    filename: Option<&str>,
+  ) -> Result<V> {
+    let raw_value = script_cache::call_cached_script(self.0, body, filename)?;
+    unsafe { V::from_napi_value(self.0, raw_value) }
+  }
+
+  /// The highest Node-API version supported by the running Node.js or Electron, the same as `process.versions.napi`
+  ///
+  /// It may be higher than the `napi*` feature flags the addon was compiled with.
   pub fn get_napi_version(&self) -> Result<u32> {
-    let global = self.get_global()?;
-    let process: JsObject = global.get_named_property("process")?;
-    let versions: JsObject = process.get_named_property("versions")?;
-    let napi_version: JsString = versions.get_named_property("napi")?;
-    napi_version
-      .into_utf8()?
-      .as_str()?
-      .parse()
-      .map_err(|e| Error::new(Status::InvalidArg, format!("{}", e)))
+    let mut version = 0;
+    check_status!(
+      unsafe { sys::napi_get_version(self.0, &mut version) },
+      "Get Node-API version failed"
+    )?;
+    Ok(version)
+  }
+
+  /// Whether the runtime supports Node-API `version`, to gate the usage of APIs at runtime rather than only by the compile time `napi*` features
+  pub fn supports_napi_version(&self, version: u32) -> Result<bool> {
+    Ok(self.get_napi_version()? >= version)
   }
 
   #[cfg(feature = "napi2")]
@@ -1061,25 +1250,59 @@ impl Env {
       data: cleanup_data,
       hook: Box::new(cleanup_fn),
     };
-    let hook_ref = Box::leak(Box::new(hook));
+    let hook_ref = Box::into_raw(Box::new(hook));
     check_status!(unsafe {
-      sys::napi_add_env_cleanup_hook(
-        self.0,
-        Some(cleanup_env::<T>),
-        hook_ref as *mut CleanupEnvHookData<T> as *mut _,
-      )
+      sys::napi_add_env_cleanup_hook(self.0, Some(cleanup_env::<T>), hook_ref.cast())
+    })
+    .map_err(|err| {
+      drop(unsafe { Box::from_raw(hook_ref) });
+      err
     })?;
+    LIVE_CLEANUP_HOOKS.with(|hooks| hooks.borrow_mut().insert(hook_ref as usize));
     Ok(CleanupEnvHook(hook_ref))
   }
 
+  /// Unregister a hook added by `Env::add_env_cleanup_hook`, the hook and its data are dropped without being called.
+  ///
+  /// Removing a hook which has already run or been removed returns an `InvalidArg` error.
   #[cfg(feature = "napi3")]
   pub fn remove_env_cleanup_hook<T>(&mut self, hook: CleanupEnvHook<T>) -> Result<()>
   where
     T: 'static,
   {
+    if !LIVE_CLEANUP_HOOKS.with(|hooks| hooks.borrow_mut().remove(&(hook.0 as usize))) {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Cleanup hook has already run or been removed".to_owned(),
+      ));
+    }
     check_status!(unsafe {
-      sys::napi_remove_env_cleanup_hook(self.0, Some(cleanup_env::<T>), hook.0 as *mut _)
+      sys::napi_remove_env_cleanup_hook(self.0, Some(cleanup_env::<T>), hook.0.cast())
     })
+    .map_err(|err| {
+      LIVE_CLEANUP_HOOKS.with(|hooks| hooks.borrow_mut().insert(hook.0 as usize));
+      err
+    })?;
+    drop(unsafe { Box::from_raw(hook.0) });
+    Ok(())
+  }
+
+  /// Run `callback` on a later turn of the event loop of this `Env`, like `setImmediate` in JavaScript.
+  ///
+  /// An `Err` returned by `callback` is thrown as an uncaught exception. Use `Env::main_thread_handle` to queue closures from other threads.
+  #[cfg(feature = "napi4")]
+  pub fn run_on_main_thread<F>(&self, callback: F) -> Result<()>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    // the handle is released right away, the queued callback keeps the event loop alive until it's called
+    MainThreadHandle::new(self.0)?.queue(Box::new(callback))
+  }
+
+  /// Create a handle which can be sent to other threads to run closures on the JavaScript thread of this `Env`
+  #[cfg(feature = "napi4")]
+  pub fn main_thread_handle(&self) -> Result<MainThreadHandle> {
+    MainThreadHandle::new(self.0)
   }
 
   #[cfg(feature = "napi4")]
@@ -1146,6 +1369,15 @@ impl Env {
     JsDeferred::new(self.raw())
   }
 
+  /// Creates a deferred promise resolved with a `Data` from a background thread by `JsDeferred::resolve_value`,
+  /// the `Data` is converted to a JavaScript value on the JavaScript thread.
+  #[cfg(feature = "napi4")]
+  pub fn create_typed_deferred<Data: 'static + Send + ToNapiValue>(
+    &self,
+  ) -> Result<(JsDeferred<Data>, JsObject)> {
+    JsDeferred::new(self.raw())
+  }
+
   /// This API does not observe leap seconds; they are ignored, as ECMAScript aligns with POSIX time specification.
   ///
   /// This API allocates a JavaScript Date object.
@@ -1158,10 +1390,21 @@ impl Env {
     Ok(unsafe { JsDate::from_raw_unchecked(self.0, js_value) })
   }
 
+  /// Create a JavaScript `Date` from a `chrono::DateTime`, with millisecond precision.
+  #[cfg(feature = "chrono_date")]
+  pub fn create_date_from_chrono<Tz: chrono::TimeZone>(
+    &self,
+    date: &chrono::DateTime<Tz>,
+  ) -> Result<JsDate> {
+    self.create_date(date.timestamp_millis() as f64)
+  }
+
   #[cfg(feature = "napi6")]
 
   /// This API associates data with the currently running Agent. data can later be retrieved using `Env::get_instance_data()`.
   ///
+  /// Every worker thread or context loading the addon has its own `Env`, so the data isn't shared between them.
+  ///
   /// Any existing data associated with the currently running Agent which was set by means of a previous call to `Env::set_instance_data()` will be overwritten.
   ///
   /// If a `finalize_cb` was provided by the previous call, it will not be called.
@@ -1174,8 +1417,11 @@ impl Env {
     check_status!(unsafe {
       sys::napi_set_instance_data(
         self.0,
-        Box::leak(Box::new((TaggedObject::new(native), finalize_cb))) as *mut (TaggedObject<T>, F)
-          as *mut c_void,
+        Box::into_raw(Box::new(InstanceData {
+          object: TaggedObject::new(native),
+          finalize_cb,
+        }))
+        .cast(),
         Some(
           set_instance_finalize_callback::<T, Hint, F>
             as unsafe extern "C" fn(
@@ -1227,6 +1473,27 @@ impl Env {
     }
   }
 
+  /// Retrieves the data associated with the currently running Agent, the value returned by `init` is associated first if no data is set.
+  ///
+  /// The value is dropped when the `Env` is torn down.
+  #[cfg(feature = "napi6")]
+  pub fn get_or_init_instance_data<T, F>(&self, init: F) -> Result<&'static mut T>
+  where
+    T: 'static,
+    F: FnOnce() -> T,
+  {
+    if let Some(data) = self.get_instance_data::<T>()? {
+      return Ok(data);
+    }
+    self.set_instance_data(init(), (), |_| {})?;
+    self.get_instance_data::<T>()?.ok_or_else(|| {
+      Error::new(
+        Status::GenericFailure,
+        "Instance data is empty right after it was set".to_owned(),
+      )
+    })
+  }
+
   /// Registers hook, which is a function of type `FnOnce(Arg)`, as a function to be run with the `arg` parameter once the current Node.js environment exits.
   ///
   /// Unlike [`add_env_cleanup_hook`](https://docs.rs/napi/latest/napi/struct.Env.html#method.add_env_cleanup_hook), the hook is allowed to be asynchronous.
@@ -1243,6 +1510,7 @@ impl Env {
     Arg: 'static,
   {
     let mut handle = ptr::null_mut();
+    let data = Box::into_raw(Box::new((arg, cleanup_fn)));
     check_status!(unsafe {
       sys::napi_add_async_cleanup_hook(
         self.0,
@@ -1250,11 +1518,20 @@ impl Env {
           async_finalize::<Arg, F>
             as unsafe extern "C" fn(handle: sys::napi_async_cleanup_hook_handle, data: *mut c_void),
         ),
-        Box::leak(Box::new((arg, cleanup_fn))) as *mut (Arg, F) as *mut c_void,
+        data.cast(),
         &mut handle,
       )
+    })
+    .map_err(|err| {
+      drop(unsafe { Box::from_raw(data) });
+      err
     })?;
-    Ok(AsyncCleanupHook(handle))
+    LIVE_ASYNC_CLEANUP_HOOKS.with(|hooks| hooks.borrow_mut().insert(handle as usize));
+    Ok(AsyncCleanupHook {
+      handle,
+      data: data.cast(),
+      drop_data: drop_async_cleanup_data::<Arg, F>,
+    })
   }
 
   /// This API is very similar to [`add_removable_async_cleanup_hook`](https://docs.rs/napi/latest/napi/struct.Env.html#method.add_removable_async_cleanup_hook)
@@ -1334,7 +1611,7 @@ impl Env {
   where
     T: Serialize,
   {
-    let s = Ser(self);
+    let s = Ser::new(self);
     node.serialize(s).map(JsUnknown)
   }
 
@@ -1376,6 +1653,7 @@ impl Env {
     Ok(result)
   }
 
+  /// The version of the running Node.js, Electron reports the version of the Node.js it bundles
   pub fn get_node_version(&self) -> Result<NodeVersion> {
     let mut result = ptr::null();
     check_status!(unsafe { sys::napi_get_node_version(self.0, &mut result) })?;
@@ -1393,12 +1671,13 @@ impl Env {
 pub fn noop_finalize<Hint>(_hint: Hint, _env: Env) {}
 
 unsafe extern "C" fn drop_buffer(
-  _env: sys::napi_env,
+  env: sys::napi_env,
   finalize_data: *mut c_void,
   hint: *mut c_void,
 ) {
   let length_ptr = hint as *mut (usize, usize);
   let (length, cap) = unsafe { *Box::from_raw(length_ptr) };
+  unsafe { adjust_external_memory_unchecked(env, -spare_capacity(cap, length)) };
   mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut u8, length, cap) });
 }
 
@@ -1423,6 +1702,14 @@ pub(crate) unsafe extern "C" fn raw_finalize<T>(
   };
 }
 
+/// `get_instance_data` reads the data pointer as a `TaggedObject`, so it must be the first field
+#[cfg(feature = "napi6")]
+#[repr(C)]
+struct InstanceData<T, F> {
+  object: TaggedObject<T>,
+  finalize_cb: F,
+}
+
 #[cfg(feature = "napi6")]
 unsafe extern "C" fn set_instance_finalize_callback<T, Hint, F>(
   raw_env: sys::napi_env,
@@ -1433,11 +1720,14 @@ unsafe extern "C" fn set_instance_finalize_callback<T, Hint, F>(
   Hint: 'static,
   F: FnOnce(FinalizeContext<T, Hint>),
 {
-  let (value, callback) = unsafe { *Box::from_raw(finalize_data as *mut (TaggedObject<T>, F)) };
+  let InstanceData {
+    object,
+    finalize_cb,
+  } = unsafe { *Box::from_raw(finalize_data as *mut InstanceData<T, F>) };
   let hint = unsafe { *Box::from_raw(finalize_hint as *mut Hint) };
   let env = Env::from_raw(raw_env);
-  callback(FinalizeContext {
-    value: value.object.unwrap(),
+  finalize_cb(FinalizeContext {
+    value: object.object.unwrap(),
     hint,
     env,
   });
@@ -1445,6 +1735,7 @@ unsafe extern "C" fn set_instance_finalize_callback<T, Hint, F>(
 
 #[cfg(feature = "napi3")]
 unsafe extern "C" fn cleanup_env<T: 'static>(hook_data: *mut c_void) {
+  let _ = LIVE_CLEANUP_HOOKS.try_with(|hooks| hooks.borrow_mut().remove(&(hook_data as usize)));
   let cleanup_env_hook = unsafe { Box::from_raw(hook_data as *mut CleanupEnvHookData<T>) };
   (cleanup_env_hook.hook)(cleanup_env_hook.data);
 }
@@ -1468,6 +1759,7 @@ unsafe extern "C" fn async_finalize<Arg, F>(
   Arg: 'static,
   F: FnOnce(Arg),
 {
+  let _ = LIVE_ASYNC_CLEANUP_HOOKS.try_with(|hooks| hooks.borrow_mut().remove(&(handle as usize)));
   let (arg, callback) = unsafe { *Box::from_raw(data as *mut (Arg, F)) };
   callback(arg);
   if !handle.is_null() {
@@ -1479,6 +1771,11 @@ unsafe extern "C" fn async_finalize<Arg, F>(
   }
 }
 
+#[cfg(feature = "napi8")]
+unsafe fn drop_async_cleanup_data<Arg, F>(data: *mut c_void) {
+  drop(unsafe { Box::from_raw(data as *mut (Arg, F)) });
+}
+
 #[cfg(feature = "napi5")]
 pub(crate) unsafe extern "C" fn trampoline<
   Return: ToNapiValue,
@@ -6,6 +6,7 @@ use std::fmt;
 use std::fmt::Display;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
+use std::sync::Arc;
 
 #[cfg(feature = "serde-json")]
 use serde::{de, ser};
@@ -19,13 +20,39 @@ pub type Result<T, S = Status> = std::result::Result<T, Error<S>>;
 
 /// Represent `JsError`.
 /// Return this Error in `js_function`, **napi-rs** will throw it as `JsError` for you.
-/// If you want throw it as `TypeError` or `RangeError`, you can use `JsTypeError/JsRangeError::from(Error).throw_into(env)`
+/// If you want throw it as `TypeError` or `RangeError`, use [`Error::with_kind`] or `JsTypeError/JsRangeError::from(Error).throw_into(env)`
 #[derive(Clone)]
 pub struct Error<S: AsRef<str> = Status> {
   pub status: S,
   pub reason: String,
   // Convert raw `JsError` into Error
   pub(crate) maybe_raw: sys::napi_ref,
+  // Boxed to keep `Result<T, Error>` small, most errors never set these
+  pub(crate) extra: Option<Box<ErrorExtra>>,
+}
+
+type ErrorProperty = Arc<dyn Fn(sys::napi_env) -> Result<sys::napi_value> + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub(crate) struct ErrorExtra {
+  kind: ErrorKind,
+  properties: Vec<(String, ErrorProperty)>,
+  cause: Option<Error>,
+}
+
+/// The JavaScript constructor used when an [`Error`] is converted into a JavaScript value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+  #[default]
+  Error,
+  TypeError,
+  RangeError,
+  #[cfg(feature = "napi9")]
+  SyntaxError,
+  /// A class exported with `#[napi]`, identified by its JavaScript name.
+  ///
+  /// The constructor is called with the error reason as the only argument.
+  Class(&'static str),
 }
 
 impl<S: AsRef<str>> std::fmt::Debug for Error<S> {
@@ -42,8 +69,8 @@ impl<S: AsRef<str>> std::fmt::Debug for Error<S> {
 impl<S: AsRef<str>> ToNapiValue for Error<S> {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
     if val.maybe_raw.is_null() {
-      let err = unsafe { JsError::from(val).into_value(env) };
-      Ok(err)
+      let kind = val.kind();
+      Ok(unsafe { create_js_error(env, val, kind) })
     } else {
       let mut value = std::ptr::null_mut();
       check_status!(
@@ -62,7 +89,13 @@ impl<S: AsRef<str>> ToNapiValue for Error<S> {
 unsafe impl<S> Send for Error<S> where S: Send + AsRef<str> {}
 unsafe impl<S> Sync for Error<S> where S: Sync + AsRef<str> {}
 
-impl<S: AsRef<str> + std::fmt::Debug> error::Error for Error<S> {}
+impl<S: AsRef<str> + std::fmt::Debug> error::Error for Error<S> {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    self
+      .cause()
+      .map(|cause| cause as &(dyn error::Error + 'static))
+  }
+}
 
 impl<S: AsRef<str>> From<std::convert::Infallible> for Error<S> {
   fn from(_: std::convert::Infallible) -> Self {
@@ -110,6 +143,7 @@ impl From<JsUnknown> for Error {
         status: Status::GenericFailure,
         reason: error_message,
         maybe_raw: result,
+        extra: None,
       };
     }
 
@@ -117,6 +151,7 @@ impl From<JsUnknown> for Error {
       status: Status::GenericFailure,
       reason: "".to_string(),
       maybe_raw: result,
+      extra: None,
     }
   }
 }
@@ -124,7 +159,12 @@ impl From<JsUnknown> for Error {
 #[cfg(feature = "anyhow")]
 impl From<anyhow::Error> for Error {
   fn from(value: anyhow::Error) -> Self {
-    Error::new(Status::GenericFailure, format!("{:?}", value))
+    let mut chain = value.chain().map(|err| err.to_string()).collect::<Vec<_>>();
+    let mut error = Error::new(Status::GenericFailure, chain.pop().unwrap_or_default());
+    while let Some(reason) = chain.pop() {
+      error = Error::new(Status::GenericFailure, reason).with_cause(error);
+    }
+    error
   }
 }
 
@@ -144,6 +184,7 @@ impl<S: AsRef<str>> Error<S> {
       status,
       reason: reason.to_string(),
       maybe_raw: ptr::null_mut(),
+      extra: None,
     }
   }
 
@@ -152,8 +193,54 @@ impl<S: AsRef<str>> Error<S> {
       status,
       reason: "".to_owned(),
       maybe_raw: ptr::null_mut(),
+      extra: None,
     }
   }
+
+  /// Choose the JavaScript constructor this error is thrown as.
+  ///
+  /// It has no effect on errors created from an existing JavaScript value.
+  pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+    self.extra.get_or_insert_with(Default::default).kind = kind;
+    self
+  }
+
+  pub fn kind(&self) -> ErrorKind {
+    self
+      .extra
+      .as_ref()
+      .map(|extra| extra.kind)
+      .unwrap_or_default()
+  }
+
+  /// Set the `cause` of the JavaScript error, it's converted recursively so the whole chain shows up in Node.js stack traces.
+  pub fn with_cause(mut self, cause: Error) -> Self {
+    self.extra.get_or_insert_with(Default::default).cause = Some(cause);
+    self
+  }
+
+  pub fn cause(&self) -> Option<&Error> {
+    self.extra.as_ref().and_then(|extra| extra.cause.as_ref())
+  }
+
+  /// Attach an extra property, e.g. `errno`, which is set on the JavaScript error object once it's created.
+  ///
+  /// Properties are applied after `code` and `message`, so they can override them.
+  pub fn with_property<K, V>(mut self, key: K, value: V) -> Self
+  where
+    K: Into<String>,
+    V: ToNapiValue + Clone + Send + Sync + 'static,
+  {
+    self
+      .extra
+      .get_or_insert_with(Default::default)
+      .properties
+      .push((
+        key.into(),
+        Arc::new(move |env| unsafe { V::to_napi_value(env, value.clone()) }),
+      ));
+    self
+  }
 }
 
 impl Error {
@@ -162,6 +249,16 @@ impl Error {
       status: Status::GenericFailure,
       reason: reason.into(),
       maybe_raw: ptr::null_mut(),
+      extra: None,
+    }
+  }
+
+  /// Convert an error and its [`source`](std::error::Error::source) chain, each source becomes the `cause` of the previous one.
+  pub fn from_error_chain<E: error::Error + ?Sized>(err: &E) -> Self {
+    let error = Error::from_reason(err.to_string());
+    match err.source() {
+      Some(source) => error.with_cause(Error::from_error_chain(source)),
+      None => error,
     }
   }
 }
@@ -172,6 +269,7 @@ impl From<std::ffi::NulError> for Error {
       status: Status::GenericFailure,
       reason: format!("{}", error),
       maybe_raw: ptr::null_mut(),
+      extra: None,
     }
   }
 }
@@ -182,6 +280,7 @@ impl From<std::io::Error> for Error {
       status: Status::GenericFailure,
       reason: format!("{}", error),
       maybe_raw: ptr::null_mut(),
+      extra: None,
     }
   }
 }
@@ -216,7 +315,7 @@ pub struct JsError<S: AsRef<str> = Status>(Error<S>);
 #[cfg(feature = "anyhow")]
 impl From<anyhow::Error> for JsError {
   fn from(value: anyhow::Error) -> Self {
-    JsError(Error::new(Status::GenericFailure, value.to_string()))
+    JsError(Error::from(value))
   }
 }
 
@@ -250,25 +349,8 @@ macro_rules! impl_object_methods {
           return err;
         }
 
-        let error_status = self.0.status.as_ref();
-        let status_len = error_status.len();
-        let error_code_string = CString::new(error_status).unwrap();
-        let reason_len = self.0.reason.len();
-        let reason = CString::new(self.0.reason.as_str()).unwrap();
-        let mut error_code = ptr::null_mut();
-        let mut reason_string = ptr::null_mut();
-        let mut js_error = ptr::null_mut();
-        let create_code_status = unsafe {
-          sys::napi_create_string_utf8(env, error_code_string.as_ptr(), status_len, &mut error_code)
-        };
-        debug_assert!(create_code_status == sys::Status::napi_ok);
-        let create_reason_status = unsafe {
-          sys::napi_create_string_utf8(env, reason.as_ptr(), reason_len, &mut reason_string)
-        };
-        debug_assert!(create_reason_status == sys::Status::napi_ok);
-        let create_error_status = unsafe { $kind(env, error_code, reason_string, &mut js_error) };
-        debug_assert!(create_error_status == sys::Status::napi_ok);
-        js_error
+        let kind = $kind.unwrap_or_else(|| self.0.kind());
+        unsafe { create_js_error(env, self.0, kind) }
       }
 
       pub fn into_unknown(self, env: Env) -> JsUnknown {
@@ -332,11 +414,98 @@ macro_rules! impl_object_methods {
   };
 }
 
-impl_object_methods!(JsError, sys::napi_create_error);
-impl_object_methods!(JsTypeError, sys::napi_create_type_error);
-impl_object_methods!(JsRangeError, sys::napi_create_range_error);
+impl_object_methods!(JsError, None);
+impl_object_methods!(JsTypeError, Some(ErrorKind::TypeError));
+impl_object_methods!(JsRangeError, Some(ErrorKind::RangeError));
 #[cfg(feature = "napi9")]
-impl_object_methods!(JsSyntaxError, sys::node_api_create_syntax_error);
+impl_object_methods!(JsSyntaxError, Some(ErrorKind::SyntaxError));
+
+unsafe fn create_js_error<S: AsRef<str>>(
+  env: sys::napi_env,
+  err: Error<S>,
+  kind: ErrorKind,
+) -> sys::napi_value {
+  let error_status = err.status.as_ref();
+  let status_len = error_status.len();
+  let error_code_string = CString::new(error_status).unwrap();
+  let reason_len = err.reason.len();
+  let reason = CString::new(err.reason.as_str()).unwrap();
+  let mut error_code = ptr::null_mut();
+  let mut reason_string = ptr::null_mut();
+  let mut js_error = ptr::null_mut();
+  let create_code_status = unsafe {
+    sys::napi_create_string_utf8(env, error_code_string.as_ptr(), status_len, &mut error_code)
+  };
+  debug_assert!(create_code_status == sys::Status::napi_ok);
+  let create_reason_status =
+    unsafe { sys::napi_create_string_utf8(env, reason.as_ptr(), reason_len, &mut reason_string) };
+  debug_assert!(create_reason_status == sys::Status::napi_ok);
+  let create_error_status = match kind {
+    ErrorKind::Error => unsafe {
+      sys::napi_create_error(env, error_code, reason_string, &mut js_error)
+    },
+    ErrorKind::TypeError => unsafe {
+      sys::napi_create_type_error(env, error_code, reason_string, &mut js_error)
+    },
+    ErrorKind::RangeError => unsafe {
+      sys::napi_create_range_error(env, error_code, reason_string, &mut js_error)
+    },
+    #[cfg(feature = "napi9")]
+    ErrorKind::SyntaxError => unsafe {
+      sys::node_api_create_syntax_error(env, error_code, reason_string, &mut js_error)
+    },
+    ErrorKind::Class(js_name) => unsafe {
+      create_class_error(env, js_name, error_code, reason_string, &mut js_error)
+    },
+  };
+  debug_assert!(create_error_status == sys::Status::napi_ok);
+  let ErrorExtra {
+    properties, cause, ..
+  } = err.extra.map(|extra| *extra).unwrap_or_default();
+  if let Some(cause) = cause {
+    if let Ok(cause) = unsafe { ToNapiValue::to_napi_value(env, cause) } {
+      let set_cause_status =
+        unsafe { sys::napi_set_named_property(env, js_error, "cause\0".as_ptr().cast(), cause) };
+      debug_assert!(set_cause_status == sys::Status::napi_ok);
+    }
+  }
+  for (key, value) in properties.iter() {
+    let Ok(value) = value(env) else {
+      continue;
+    };
+    let Ok(key) = CString::new(key.as_str()) else {
+      continue;
+    };
+    let set_property_status =
+      unsafe { sys::napi_set_named_property(env, js_error, key.as_ptr(), value) };
+    debug_assert!(set_property_status == sys::Status::napi_ok);
+  }
+  js_error
+}
+
+unsafe fn create_class_error(
+  env: sys::napi_env,
+  js_name: &str,
+  code: sys::napi_value,
+  msg: sys::napi_value,
+  result: *mut sys::napi_value,
+) -> sys::napi_status {
+  let Some(ctor_ref) = crate::bindgen_runtime::get_class_constructor(&format!("{}\0", js_name))
+  else {
+    // the class is not registered in current env, fallback to the plain `Error`
+    return unsafe { sys::napi_create_error(env, code, msg, result) };
+  };
+  let mut ctor = ptr::null_mut();
+  let status = unsafe { sys::napi_get_reference_value(env, ctor_ref, &mut ctor) };
+  if status != sys::Status::napi_ok {
+    return status;
+  }
+  let status = unsafe { sys::napi_new_instance(env, ctor, 1, [msg].as_ptr(), result) };
+  if status != sys::Status::napi_ok {
+    return status;
+  }
+  unsafe { sys::napi_set_named_property(env, *result, "code\0".as_ptr().cast(), code) }
+}
 
 #[doc(hidden)]
 #[macro_export]
@@ -407,7 +576,7 @@ macro_rules! check_status_and_type {
           }
           ValueType::Object => {
             let env_ = $crate::Env::from($env);
-            let json: $crate::JSON = env_.get_global()?.get_named_property_unchecked("JSON")?;
+            let json = env_.get_global()?.json()?;
             let object = json.stringify($crate::JsObject($crate::Value {
               value: $val,
               env: $env,
@@ -0,0 +1,53 @@
+#[cfg(not(target_family = "wasm"))]
+use crate::check_status;
+use crate::{sys, Result};
+
+/// Created by `Env::adjust_external_memory_by` and `Env::track_external_memory`
+///
+/// The tracked size is reported to V8 as released when the guard is dropped, so it must be dropped on the JavaScript thread of the `Env` that created it.
+pub struct ExternalMemoryGuard {
+  env: sys::napi_env,
+  size: i64,
+}
+
+impl ExternalMemoryGuard {
+  pub(crate) fn new(env: sys::napi_env, size: i64) -> Result<Self> {
+    #[cfg(not(target_family = "wasm"))]
+    if size != 0 {
+      let mut adjusted = 0i64;
+      check_status!(
+        unsafe { sys::napi_adjust_external_memory(env, size, &mut adjusted) },
+        "Adjust external memory failed"
+      )?;
+    }
+    Ok(Self { env, size })
+  }
+
+  /// The number of bytes reported to V8 by this guard
+  pub fn size(&self) -> i64 {
+    self.size
+  }
+}
+
+impl Drop for ExternalMemoryGuard {
+  fn drop(&mut self) {
+    unsafe { adjust_external_memory_unchecked(self.env, -self.size) };
+  }
+}
+
+/// Used in finalizers, where there is no way to surface the error
+pub(crate) unsafe fn adjust_external_memory_unchecked(env: sys::napi_env, size: i64) {
+  #[cfg(not(target_family = "wasm"))]
+  if size != 0 {
+    let mut adjusted = 0i64;
+    let status = unsafe { sys::napi_adjust_external_memory(env, size, &mut adjusted) };
+    debug_assert!(
+      status == sys::Status::napi_ok,
+      "Calling napi_adjust_external_memory failed"
+    );
+  }
+  #[cfg(target_family = "wasm")]
+  {
+    let _ = (env, size);
+  }
+}
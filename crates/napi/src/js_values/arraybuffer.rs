@@ -134,6 +134,27 @@ impl JsArrayBuffer {
     check_status!(unsafe { sys::napi_detach_arraybuffer(self.0.env, self.0.value) })
   }
 
+  /// Copy the contents into a `Vec` and detach the `ArrayBuffer`.
+  ///
+  /// Unlike `ArrayBuffer.prototype.transfer()`, the memory isn't moved: Node-API can't take the ownership of the backing store,
+  /// so it costs a copy of the whole buffer. The JavaScript side observes a zero length `ArrayBuffer` afterwards,
+  /// and the original memory is released by its finalizer.
+  #[cfg(feature = "napi7")]
+  pub fn detach_into_vec(self) -> Result<Vec<u8>> {
+    let mut data = ptr::null_mut();
+    let mut len: usize = 0;
+    check_status!(unsafe {
+      sys::napi_get_arraybuffer_info(self.0.env, self.0.value, &mut data, &mut len)
+    })?;
+    let owned = if len == 0 {
+      Vec::new()
+    } else {
+      unsafe { std::slice::from_raw_parts(data as *const u8, len) }.to_vec()
+    };
+    self.detach()?;
+    Ok(owned)
+  }
+
   #[cfg(feature = "napi7")]
   pub fn is_detached(&self) -> Result<bool> {
     let mut is_detached = false;
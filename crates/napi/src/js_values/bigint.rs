@@ -2,7 +2,10 @@ use std::convert::TryFrom;
 use std::ptr;
 
 use super::*;
-use crate::{bindgen_runtime::TypeName, check_status, sys, Result};
+use crate::{
+  bindgen_runtime::{BigInt, TypeName},
+  check_status, sys, Result,
+};
 
 #[derive(Clone, Copy)]
 pub struct JsBigInt {
@@ -201,6 +204,26 @@ impl TryFrom<JsBigInt> for u64 {
   }
 }
 
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt doesn't fit in an `i128`
+impl TryFrom<JsBigInt> for i128 {
+  type Error = Error;
+
+  fn try_from(mut value: JsBigInt) -> Result<i128> {
+    let (sign_bit, words) = value.get_words()?;
+    i128::try_from(BigInt { sign_bit, words })
+  }
+}
+
+/// Lossless conversion, returns an `InvalidArg` error if the BigInt is negative or doesn't fit in an `u128`
+impl TryFrom<JsBigInt> for u128 {
+  type Error = Error;
+
+  fn try_from(mut value: JsBigInt) -> Result<u128> {
+    let (sign_bit, words) = value.get_words()?;
+    u128::try_from(BigInt { sign_bit, words })
+  }
+}
+
 impl JsBigInt {
   /// <https://nodejs.org/api/n-api.html#n_api_napi_get_value_bigint_words>
   pub fn get_words(&mut self) -> Result<(bool, Vec<u64>)> {
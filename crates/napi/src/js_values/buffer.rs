@@ -3,7 +3,7 @@ use std::ops::{Deref, DerefMut};
 use std::ptr;
 
 use super::{Value, ValueType};
-use crate::bindgen_runtime::ValidateNapiValue;
+use crate::bindgen_runtime::{BufferSlice, FromNapiValue, ValidateNapiValue};
 use crate::{
   bindgen_runtime::TypeName, check_status, sys, Error, JsUnknown, NapiValue, Ref, Result, Status,
 };
@@ -55,6 +55,11 @@ impl JsBuffer {
   pub fn into_ref(self) -> Result<Ref<JsBufferValue>> {
     Ref::new(self.0, 1, self.into_value()?)
   }
+
+  /// Borrow the underlying data without copying, the slice can't outlive this `JsBuffer` handle.
+  pub fn as_buffer_slice(&mut self) -> Result<BufferSlice<'_>> {
+    unsafe { BufferSlice::from_napi_value(self.0.env, self.0.value) }
+  }
 }
 
 impl JsBufferValue {
@@ -6,7 +6,7 @@ use serde::de::{DeserializeSeed, EnumAccess, MapAccess, SeqAccess, Unexpected, V
 use crate::bindgen_runtime::FromNapiValue;
 #[cfg(feature = "napi6")]
 use crate::JsBigInt;
-use crate::{type_of, NapiValue, Value, ValueType};
+use crate::{check_status, sys, type_of, NapiValue, Value, ValueType};
 use crate::{Error, JsBoolean, JsNumber, JsObject, JsString, JsUnknown, Result, Status};
 
 pub struct De<'env>(pub(crate) &'env Value);
@@ -14,6 +14,12 @@ impl<'env> De<'env> {
   pub fn new(value: &'env JsObject) -> Self {
     Self(&value.0)
   }
+
+  fn is_array(&self) -> Result<bool> {
+    let mut is_array = false;
+    check_status!(unsafe { sys::napi_is_array(self.0.env, self.0.value, &mut is_array) })?;
+    Ok(is_array)
+  }
 }
 
 #[doc(hidden)]
@@ -83,6 +89,10 @@ impl<'x, 'de, 'env> serde::de::Deserializer<'x> for &'de mut De<'env> {
   where
     V: Visitor<'x>,
   {
+    // bytes serialized with `BytesMode::Array` come back as `number[]`
+    if self.is_array()? {
+      return self.deserialize_any(visitor);
+    }
     visitor.visit_bytes(unsafe { FromNapiValue::from_napi_value(self.0.env, self.0.value)? })
   }
 
@@ -90,6 +100,9 @@ impl<'x, 'de, 'env> serde::de::Deserializer<'x> for &'de mut De<'env> {
   where
     V: Visitor<'x>,
   {
+    if self.is_array()? {
+      return self.deserialize_any(visitor);
+    }
     visitor.visit_bytes(unsafe { FromNapiValue::from_napi_value(self.0.env, self.0.value)? })
   }
 
@@ -158,7 +171,7 @@ impl<'x, 'de, 'env> serde::de::Deserializer<'x> for &'de mut De<'env> {
     visitor.visit_unit()
   }
 
-  forward_to_deserialize_any! {
+  serde::forward_to_deserialize_any! {
      <V: Visitor<'x>>
       bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
       unit unit_struct seq tuple tuple_struct map struct identifier
@@ -94,7 +94,14 @@ struct DeferredData<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> {
   trace: DeferredTrace,
 }
 
-pub struct JsDeferred<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> {
+/// Resolves or rejects a JavaScript `Promise` from any thread.
+///
+/// The `Resolver` is called on the JavaScript thread to create the resolved value, `JsDeferred<Data>` is
+/// resolved with a `Data` from the other thread instead, see `Env::create_typed_deferred`.
+pub struct JsDeferred<
+  Data: ToNapiValue,
+  Resolver: FnOnce(Env) -> Result<Data> = Box<dyn FnOnce(Env) -> Result<Data> + Send>,
+> {
   pub(crate) tsfn: sys::napi_threadsafe_function,
   #[cfg(feature = "deferred_trace")]
   trace: DeferredTrace,
@@ -179,6 +186,13 @@ impl<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> JsDeferred<Data,
   }
 }
 
+impl<Data: 'static + Send + ToNapiValue> JsDeferred<Data> {
+  /// Consumes the deferred, and resolves the promise with `value`, which is converted to a JavaScript value on the JavaScript thread.
+  pub fn resolve_value(self, value: Data) {
+    self.resolve(Box::new(move |_| Ok(value)))
+  }
+}
+
 fn js_deferred_new_raw(
   env: sys::napi_env,
   resolve_deferred: sys::napi_threadsafe_function_call_js,
@@ -2,7 +2,7 @@ use std::ops::Deref;
 use std::ptr;
 
 use crate::check_status;
-use crate::{sys, Env, NapiRaw, Result};
+use crate::{sys, Env, NapiRaw, NapiValue, Result};
 
 pub struct EscapableHandleScope<T: NapiRaw> {
   handle_scope: sys::napi_escapable_handle_scope,
@@ -35,3 +35,37 @@ impl<T: NapiRaw> Deref for EscapableHandleScope<T> {
     &self.value
   }
 }
+
+/// The handle scope of `Env::with_handle_scope`, the JavaScript values created in it are released when it closes.
+pub struct HandleScope {
+  env: sys::napi_env,
+  handle_scope: sys::napi_escapable_handle_scope,
+}
+
+impl HandleScope {
+  pub(crate) fn open(env: sys::napi_env) -> Result<Self> {
+    let mut handle_scope = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_open_escapable_handle_scope(env, &mut handle_scope) },
+      "Failed to open handle scope"
+    )?;
+    Ok(Self { env, handle_scope })
+  }
+
+  /// Keep `value` alive in the outer scope to return it from the closure, only one value can escape a scope.
+  pub fn escape<T: NapiValue>(&self, value: T) -> Result<T> {
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_escape_handle(self.env, self.handle_scope, value.raw(), &mut result) },
+      "Failed to escape value from handle scope"
+    )?;
+    Ok(unsafe { T::from_raw_unchecked(self.env, result) })
+  }
+
+  pub(crate) fn close(self) -> Result<()> {
+    check_status!(
+      unsafe { sys::napi_close_escapable_handle_scope(self.env, self.handle_scope) },
+      "Failed to close handle scope"
+    )
+  }
+}
@@ -2,11 +2,11 @@ use std::ptr;
 
 use super::Value;
 #[cfg(feature = "napi4")]
+use crate::threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunction};
 use crate::{
-  bindgen_runtime::JsValuesTupleIntoVec,
-  threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunction},
+  bindgen_runtime::{FromNapiValue, JsValuesTupleIntoVec, TypeName},
+  JsString,
 };
-use crate::{bindgen_runtime::TypeName, JsString};
 use crate::{check_pending_exception, ValueType};
 use crate::{sys, Env, Error, JsObject, JsUnknown, NapiRaw, NapiValue, Result, Status};
 
@@ -123,6 +123,28 @@ impl JsFunction {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0.env, js_instance) })
   }
 
+  /// Same as `JsFunction::new_instance`, but the arguments are converted from a tuple of Rust values and the instance into `T`.
+  ///
+  /// For a `#[napi]` class `T` can be `ClassInstance<Class>`, to get the wrapped native struct.
+  pub fn new_instance_typed<Args, T>(&self, args: Args) -> Result<T>
+  where
+    Args: JsValuesTupleIntoVec,
+    T: FromNapiValue,
+  {
+    let mut js_instance = ptr::null_mut();
+    let raw_args = args.into_vec(self.0.env)?;
+    check_pending_exception!(self.0.env, unsafe {
+      sys::napi_new_instance(
+        self.0.env,
+        self.0.value,
+        raw_args.len(),
+        raw_args.as_ptr(),
+        &mut js_instance,
+      )
+    })?;
+    unsafe { T::from_napi_value(self.0.env, js_instance) }
+  }
+
   /// function name
   pub fn name(&self) -> Result<String> {
     let mut name = ptr::null_mut();
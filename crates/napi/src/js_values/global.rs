@@ -1,10 +1,25 @@
+#[cfg(feature = "napi5")]
+use std::cell::Cell;
+use std::collections::HashMap;
+#[cfg(feature = "napi5")]
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(feature = "napi5")]
+use std::sync::Mutex;
+
 use super::*;
-use crate::bindgen_runtime::{FromNapiValue, Function, Unknown};
+use crate::bindgen_runtime::{FromNapiValue, Function, JsValuesTupleIntoVec, Unknown};
+#[cfg(feature = "napi5")]
+use crate::{Env, MainThreadHandle};
 
 pub struct JsGlobal(pub(crate) Value);
 
 pub struct JsTimeout(pub(crate) Value);
 
+/// The `process` object of Node.js, from `JsGlobal::process`
+pub struct JsProcess(pub(crate) Value);
+
 pub struct JSON(pub(crate) Value);
 
 impl FromNapiValue for JSON {
@@ -48,4 +63,231 @@ impl JsGlobal {
       self.get_named_property_unchecked("clearTimeout")?;
     func.call(timer)
   }
+
+  /// `globalThis.JSON`
+  pub fn json(&self) -> Result<JSON> {
+    self.get_named_property_unchecked("JSON")
+  }
+
+  /// `globalThis.process`, fails in the runtimes without it like the browsers
+  pub fn process(&self) -> Result<JsProcess> {
+    let process: JsUnknown = self.get_named_property_unchecked("process")?;
+    if process.get_type()? != ValueType::Object {
+      return Err(Error::new(
+        Status::ObjectExpected,
+        "`globalThis.process` is not available in this JavaScript runtime".to_owned(),
+      ));
+    }
+    Ok(unsafe { JsProcess::from_raw_unchecked(self.0.env, process.raw()) })
+  }
+
+  /// Same as `fetch(resource, options)`, the `Promise` resolves with the `Response`, Node.js 18 and later
+  pub fn fetch(&self, resource: &str, options: Option<JsObject>) -> Result<JsPromise> {
+    match options {
+      Some(options) => self
+        .get_global_function::<(&str, JsObject), JsPromise>("fetch")?
+        .call((resource, options)),
+      None => self
+        .get_global_function::<&str, JsPromise>("fetch")?
+        .call(resource),
+    }
+  }
+
+  /// Same as `structuredClone(value)`, Node.js 17 and later
+  pub fn structured_clone<T: NapiValue>(&self, value: T) -> Result<T> {
+    self
+      .get_global_function::<T, T>("structuredClone")?
+      .call(value)
+  }
+
+  // the functions added by the recent Node.js versions are missing in the older ones
+  fn get_global_function<Args: JsValuesTupleIntoVec, Return>(
+    &self,
+    name: &str,
+  ) -> Result<Function<'_, Args, Return>> {
+    let func: JsUnknown = self.get_named_property_unchecked(name)?;
+    if func.get_type()? != ValueType::Function {
+      return Err(Error::new(
+        Status::FunctionExpected,
+        format!("`globalThis.{name}` is not available in this JavaScript runtime"),
+      ));
+    }
+    unsafe { Function::from_napi_value(self.0.env, func.raw()) }
+  }
+}
+
+impl JsProcess {
+  /// `process.env`, read from JavaScript so the variables set by JavaScript are included, unlike `std::env::vars`
+  pub fn env(&self) -> Result<HashMap<std::string::String, std::string::String>> {
+    self.get_named_property_unchecked("env")
+  }
+
+  /// `process.env[name]`
+  pub fn env_var(&self, name: &str) -> Result<Option<std::string::String>> {
+    let env: JsObject = self.get_named_property_unchecked("env")?;
+    env.get_named_property_unchecked(name)
+  }
+
+  /// `process.argv`, the path of the executable and of the script are the first elements
+  pub fn argv(&self) -> Result<Vec<std::string::String>> {
+    self.get_named_property_unchecked("argv")
+  }
+
+  /// `process.cwd()`, which follows `process.chdir()`
+  pub fn cwd(&self) -> Result<std::string::String> {
+    let cwd: Function<(), std::string::String> = self.get_named_property_unchecked("cwd")?;
+    cwd.apply(self, ())
+  }
+
+  /// `process.platform`, e.g. `linux`, `darwin` or `win32`
+  pub fn platform(&self) -> Result<std::string::String> {
+    self.get_named_property_unchecked("platform")
+  }
+}
+
+/// Returned by `Env::queue_microtask` and `Env::set_timeout`, to cancel the closure before it runs.
+///
+/// It can be sent to and cancelled from any thread.
+#[derive(Clone, Debug, Default)]
+pub struct ScheduledCallback {
+  cancelled: Arc<AtomicBool>,
+  #[cfg(feature = "napi5")]
+  timer: Option<Arc<ScheduledTimer>>,
+}
+
+impl ScheduledCallback {
+  /// The closure is dropped without running if it didn't run yet.
+  ///
+  /// The JavaScript timer of `Env::set_timeout` is cleared the next time the JavaScript thread runs, so it doesn't keep the event loop alive.
+  pub fn cancel(&self) {
+    self.cancelled.store(true, Ordering::Relaxed);
+    #[cfg(feature = "napi5")]
+    if let Some(timer) = &self.timer {
+      timer.clear();
+    }
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Ordering::Relaxed)
+  }
+}
+
+/// The `Timeout` of `Env::set_timeout`, referenced until the timer fires or is cleared
+#[cfg(feature = "napi5")]
+struct ScheduledTimer {
+  // the shared handle of the `Env`, it doesn't keep the event loop alive
+  main_thread: MainThreadHandle,
+  timeout: Arc<Mutex<Option<TimeoutRef>>>,
+}
+
+#[cfg(feature = "napi5")]
+struct TimeoutRef(sys::napi_ref);
+
+// only used on the JavaScript thread
+#[cfg(feature = "napi5")]
+unsafe impl Send for TimeoutRef {}
+
+#[cfg(feature = "napi5")]
+impl std::fmt::Debug for ScheduledTimer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ScheduledTimer").finish_non_exhaustive()
+  }
+}
+
+#[cfg(feature = "napi5")]
+impl ScheduledTimer {
+  fn clear(&self) {
+    let timeout = self.timeout.clone();
+    // a no-op once the `Env` is torn down, its timers and references are gone with it
+    let _ = self.main_thread.run(move |env| {
+      let Some(timeout) = take_timeout(&timeout) else {
+        return Ok(());
+      };
+      let mut value = ptr::null_mut();
+      let status = unsafe { sys::napi_get_reference_value(env.0, timeout.0, &mut value) };
+      unsafe { sys::napi_delete_reference(env.0, timeout.0) };
+      check_status!(status, "Get the timer of the scheduled callback failed")?;
+      env
+        .get_global()?
+        .clear_timeout(unsafe { JsTimeout::from_raw_unchecked(env.0, value) })?;
+      Ok(())
+    });
+  }
+}
+
+#[cfg(feature = "napi5")]
+fn take_timeout(timeout: &Mutex<Option<TimeoutRef>>) -> Option<TimeoutRef> {
+  timeout.lock().ok().and_then(|mut timeout| timeout.take())
+}
+
+#[cfg(feature = "napi5")]
+impl Env {
+  /// Same as `queueMicrotask(callback)`, `callback` runs once the current JavaScript job is done and before the next macrotask.
+  ///
+  /// An error returned from `callback` is thrown as an uncaught exception.
+  pub fn queue_microtask<F>(&self, callback: F) -> Result<ScheduledCallback>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    let (scheduled, callback) = self.scheduled_function("queueMicrotask", callback)?;
+    let global = self.get_global()?;
+    let queue_microtask: Function<Function<(), Unknown>, ()> =
+      global.get_global_function("queueMicrotask")?;
+    queue_microtask.call(callback)?;
+    Ok(scheduled)
+  }
+
+  /// Same as `setTimeout(callback, delay)`, with `delay` in milliseconds.
+  ///
+  /// An error returned from `callback` is thrown as an uncaught exception.
+  pub fn set_timeout<F>(&self, callback: F, delay: f64) -> Result<ScheduledCallback>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    let timeout = Arc::new(Mutex::new(None));
+    let fired = timeout.clone();
+    let (mut scheduled, callback) = self.scheduled_function("setTimeout", move |env| {
+      if let Some(fired) = take_timeout(&fired) {
+        unsafe { sys::napi_delete_reference(env.0, fired.0) };
+      }
+      callback(env)
+    })?;
+    let main_thread = MainThreadHandle::shared(self.0)?;
+    let timer = self.get_global()?.set_timeout(callback, delay)?;
+    let mut reference = ptr::null_mut();
+    // the timers are numbers in the runtimes other than Node.js, they are left to fire then
+    if unsafe { sys::napi_create_reference(self.0, timer.0.value, 1, &mut reference) }
+      == sys::Status::napi_ok
+    {
+      if let Ok(mut timeout) = timeout.lock() {
+        *timeout = Some(TimeoutRef(reference));
+      }
+      scheduled.timer = Some(Arc::new(ScheduledTimer {
+        main_thread,
+        timeout,
+      }));
+    }
+    Ok(scheduled)
+  }
+
+  fn scheduled_function<F>(
+    &self,
+    name: &str,
+    callback: F,
+  ) -> Result<(ScheduledCallback, Function<'_, (), Unknown>)>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    let scheduled = ScheduledCallback::default();
+    let cancelled = scheduled.cancelled.clone();
+    let callback = Cell::new(Some(callback));
+    let func =
+      self.create_function_from_closure::<(), (), _>(name, move |ctx| match callback.take() {
+        Some(callback) if !cancelled.load(Ordering::Relaxed) => callback(*ctx.env),
+        _ => Ok(()),
+      })?;
+    Ok((scheduled, unsafe {
+      Function::from_napi_value(self.0, func.raw())?
+    }))
+  }
 }
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::ptr;
+
+use super::*;
+use crate::bindgen_runtime::{FromNapiValue, Function, ToNapiValue, Unknown};
+use crate::Env;
+
+/// A JavaScript `Map` object.
+///
+/// All methods are dispatched to the corresponding `Map.prototype` methods, so subclasses and monkey patched prototypes behave the same as in JavaScript.
+pub struct JsMap(pub(crate) Value);
+
+impl TypeName for JsMap {
+  fn type_name() -> &'static str {
+    "Map"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsMap {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    if !unsafe { is_instance_of_global(env, napi_val, "Map")? } {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a Map object".to_owned(),
+      ));
+    }
+
+    Ok(ptr::null_mut())
+  }
+}
+
+impl JsMap {
+  /// Create a `Map` from the entries of a `HashMap`
+  pub fn from_hash_map<K, V, S>(env: &Env, map: HashMap<K, V, S>) -> Result<Self>
+  where
+    K: ToNapiValue,
+    V: ToNapiValue,
+  {
+    let mut js_map = env.create_map()?;
+    for (key, value) in map.into_iter() {
+      js_map.set(key, value)?;
+    }
+    Ok(js_map)
+  }
+
+  pub fn get<K: ToNapiValue, V: FromNapiValue>(&self, key: K) -> Result<Option<V>> {
+    let get: Function<K, Option<V>> = self.get_named_property_unchecked("get")?;
+    get.apply(self, key)
+  }
+
+  pub fn set<K: ToNapiValue, V: ToNapiValue>(&mut self, key: K, value: V) -> Result<()> {
+    let set: Function<(K, V), Unknown> = self.get_named_property_unchecked("set")?;
+    set.apply(&*self, (key, value))?;
+    Ok(())
+  }
+
+  pub fn has<K: ToNapiValue>(&self, key: K) -> Result<bool> {
+    let has: Function<K, bool> = self.get_named_property_unchecked("has")?;
+    has.apply(self, key)
+  }
+
+  /// Returns `true` if the key existed and has been removed
+  pub fn delete<K: ToNapiValue>(&mut self, key: K) -> Result<bool> {
+    let delete: Function<K, bool> = self.get_named_property_unchecked("delete")?;
+    delete.apply(&*self, key)
+  }
+
+  pub fn clear(&mut self) -> Result<()> {
+    let clear: Function<(), Unknown> = self.get_named_property_unchecked("clear")?;
+    clear.apply(&*self, ())?;
+    Ok(())
+  }
+
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Collect all `[key, value]` pairs in insertion order
+  pub fn entries<K: FromNapiValue, V: FromNapiValue>(&self) -> Result<Vec<(K, V)>> {
+    let entries: Function<(), JsObject> = self.get_named_property_unchecked("entries")?;
+    let iterator = entries.apply(self, ())?;
+    collect_iterator(&iterator)?
+      .into_iter()
+      .map(|entry| {
+        let entry = unsafe { JsObject::from_raw_unchecked(self.0.env, entry.raw()) };
+        let key = entry.get_element_unchecked::<JsUnknown>(0)?;
+        let value = entry.get_element_unchecked::<JsUnknown>(1)?;
+        Ok((
+          unsafe { K::from_napi_value(self.0.env, key.raw())? },
+          unsafe { V::from_napi_value(self.0.env, value.raw())? },
+        ))
+      })
+      .collect()
+  }
+
+  pub fn to_hash_map<K, V, S>(&self) -> Result<HashMap<K, V, S>>
+  where
+    K: FromNapiValue + Eq + Hash,
+    V: FromNapiValue,
+    S: Default + BuildHasher,
+  {
+    Ok(self.entries::<K, V>()?.into_iter().collect())
+  }
+}
+
+/// Drain a JavaScript iterator object by calling `next()` until it is `done`
+pub(super) fn collect_iterator(iterator: &JsObject) -> Result<Vec<JsUnknown>> {
+  let next: Function<(), JsObject> = iterator.get_named_property_unchecked("next")?;
+  let mut values = Vec::new();
+  loop {
+    let result = next.apply(iterator, ())?;
+    if result.get_named_property_unchecked::<bool>("done")? {
+      break;
+    }
+    values.push(result.get_named_property_unchecked::<JsUnknown>("value")?);
+  }
+  Ok(values)
+}
+
+pub(super) unsafe fn is_instance_of_global(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+  constructor_name: &str,
+) -> Result<bool> {
+  let constructor: JsFunction = Env::from_raw(env)
+    .get_global()?
+    .get_named_property_unchecked(constructor_name)?;
+  let mut result = false;
+  check_status!(unsafe { sys::napi_instanceof(env, napi_val, constructor.raw(), &mut result) })?;
+  Ok(result)
+}
@@ -8,7 +8,7 @@ use std::ptr;
 
 use crate::{
   bindgen_runtime::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue},
-  check_status, sys, type_of, Callback, Error, Result, Status, ValueType,
+  check_pending_exception, check_status, sys, type_of, Callback, Error, Result, Status, ValueType,
 };
 
 #[cfg(feature = "serde-json")]
@@ -29,10 +29,17 @@ mod either;
 mod escapable_handle_scope;
 mod function;
 mod global;
+mod map;
 mod number;
 mod object;
 mod object_property;
+mod promise;
+#[cfg(feature = "napi5")]
+mod proxy;
+mod set;
+mod shared_arraybuffer;
 mod string;
+mod symbol;
 mod tagged_object;
 mod undefined;
 mod value;
@@ -50,14 +57,20 @@ pub use de::De;
 #[cfg(feature = "napi4")]
 pub use deferred::*;
 pub use either::Either;
-pub use escapable_handle_scope::EscapableHandleScope;
+pub use escapable_handle_scope::{EscapableHandleScope, HandleScope};
 pub use function::JsFunction;
 pub use global::*;
+pub use map::JsMap;
 pub use number::JsNumber;
 pub use object::*;
 pub use object_property::*;
+pub use promise::JsPromise;
+#[cfg(feature = "napi5")]
+pub use proxy::{JsProxy, ProxyKey};
 #[cfg(feature = "serde-json")]
-pub use ser::Ser;
+pub use ser::{BytesMode, Ser};
+pub use set::JsSet;
+pub use shared_arraybuffer::{JsSharedArrayBuffer, SharedMemory};
 pub use string::*;
 pub(crate) use tagged_object::TaggedObject;
 pub use undefined::JsUndefined;
@@ -567,11 +580,14 @@ macro_rules! impl_object_methods {
 
       /// This method allows the efficient definition of multiple properties on a given object.
       pub fn define_properties(&mut self, properties: &[Property]) -> Result<()> {
-        let properties_iter = properties.iter().map(|property| property.raw());
+        let raw_properties = properties
+          .iter()
+          .map(|property| property.raw(self.0.env))
+          .collect::<Result<Vec<sys::napi_property_descriptor>>>()?;
         #[cfg(feature = "napi5")]
         {
-          let mut closures = properties_iter
-            .clone()
+          let mut closures = raw_properties
+            .iter()
             .map(|p| p.data)
             .filter(|data| !data.is_null())
             .collect::<Vec<*mut std::ffi::c_void>>();
@@ -592,10 +608,8 @@ macro_rules! impl_object_methods {
           sys::napi_define_properties(
             self.0.env,
             self.0.value,
-            properties.len(),
-            properties_iter
-              .collect::<Vec<sys::napi_property_descriptor>>()
-              .as_ptr(),
+            raw_properties.len(),
+            raw_properties.as_ptr(),
           )
         })
       }
@@ -621,14 +635,74 @@ macro_rules! impl_object_methods {
         Ok(length)
       }
 
+      /// Same as `Object.freeze`, properties can't be added, removed or changed afterwards
       #[cfg(feature = "napi8")]
       pub fn freeze(&mut self) -> Result<()> {
-        check_status!(unsafe { sys::napi_object_freeze(self.0.env, self.0.value) })
+        check_status!(
+          unsafe { sys::napi_object_freeze(self.0.env, self.0.value) },
+          "Freeze object failed"
+        )
       }
 
+      /// Same as `Object.seal`, properties can't be added or removed, but the values of writable properties can still be changed
       #[cfg(feature = "napi8")]
       pub fn seal(&mut self) -> Result<()> {
-        check_status!(unsafe { sys::napi_object_seal(self.0.env, self.0.value) })
+        check_status!(
+          unsafe { sys::napi_object_seal(self.0.env, self.0.value) },
+          "Seal object failed"
+        )
+      }
+
+      /// Same as `Object.isFrozen`
+      pub fn is_frozen(&self) -> Result<bool> {
+        self.test_integrity_level("isFrozen\0")
+      }
+
+      /// Same as `Object.isSealed`
+      pub fn is_sealed(&self) -> Result<bool> {
+        self.test_integrity_level("isSealed\0")
+      }
+
+      /// Attach `tag` to the object, an object can only be tagged once
+      #[cfg(feature = "napi8")]
+      pub fn type_tag(&mut self, tag: &crate::TypeTag) -> Result<()> {
+        unsafe { crate::type_tag::type_tag_object(self.0.env, self.0.value, tag) }
+      }
+
+      /// Whether the object was tagged with `tag` by `type_tag`, check it before trusting a native pointer stored on the object
+      #[cfg(feature = "napi8")]
+      pub fn check_type_tag(&self, tag: &crate::TypeTag) -> Result<bool> {
+        unsafe { crate::type_tag::check_object_type_tag(self.0.env, self.0.value, tag) }
+      }
+
+      /// Node-API has no equivalent of `Object.isFrozen` and `Object.isSealed`, call them on the global `Object`
+      fn test_integrity_level(&self, method: &str) -> Result<bool> {
+        let env = self.0.env;
+        let mut global = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_get_global(env, &mut global) },
+          "Get global object failed"
+        )?;
+        let mut object_ctor = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_get_named_property(env, global, "Object\0".as_ptr().cast(), &mut object_ctor)
+          },
+          "Get global Object failed"
+        )?;
+        let mut test_fn = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_get_named_property(env, object_ctor, method.as_ptr().cast(), &mut test_fn)
+          },
+          "Get Object.{} failed",
+          method.trim_end_matches('\0')
+        )?;
+        let mut result = ptr::null_mut();
+        check_pending_exception!(env, unsafe {
+          sys::napi_call_function(env, object_ctor, test_fn, 1, &self.0.value, &mut result)
+        })?;
+        unsafe { bool::from_napi_value(env, result) }
       }
     }
   };
@@ -659,6 +733,11 @@ impl_js_value_methods!(JsNumber);
 impl_js_value_methods!(JsString);
 impl_js_value_methods!(JsObject);
 impl_js_value_methods!(JsGlobal);
+impl_js_value_methods!(JsProcess);
+impl_js_value_methods!(JsMap);
+impl_js_value_methods!(JsSet);
+impl_js_value_methods!(JsSharedArrayBuffer);
+impl_js_value_methods!(JsPromise);
 #[cfg(feature = "napi5")]
 impl_js_value_methods!(JsDate);
 impl_js_value_methods!(JsFunction);
@@ -673,6 +752,11 @@ impl_object_methods!(JsArrayBuffer);
 impl_object_methods!(JsTypedArray);
 impl_object_methods!(JsDataView);
 impl_object_methods!(JsGlobal);
+impl_object_methods!(JsProcess);
+impl_object_methods!(JsMap);
+impl_object_methods!(JsSet);
+impl_object_methods!(JsSharedArrayBuffer);
+impl_object_methods!(JsPromise);
 impl_object_methods!(JSON);
 
 use ValueType::*;
@@ -688,6 +772,11 @@ impl_napi_value_trait!(JsNumber, Number);
 impl_napi_value_trait!(JsString, String);
 impl_napi_value_trait!(JsObject, Object);
 impl_napi_value_trait!(JsGlobal, Object);
+impl_napi_value_trait!(JsProcess, Object);
+impl_napi_value_trait!(JsMap, Object);
+impl_napi_value_trait!(JsSet, Object);
+impl_napi_value_trait!(JsSharedArrayBuffer, Object);
+impl_napi_value_trait!(JsPromise, Object);
 #[cfg(feature = "napi5")]
 impl_napi_value_trait!(JsDate, Object);
 impl_napi_value_trait!(JsTimeout, Object);
@@ -2,12 +2,16 @@
 use std::convert::TryFrom;
 #[cfg(feature = "napi5")]
 use std::ffi::c_void;
+#[cfg(feature = "napi6")]
+use std::marker::PhantomData;
 #[cfg(feature = "napi5")]
 use std::ptr;
 
 #[cfg(feature = "napi5")]
 use super::check_status;
 use super::Value;
+#[cfg(feature = "napi6")]
+use crate::bindgen_runtime::FromNapiValue;
 #[cfg(feature = "napi5")]
 use crate::sys;
 #[cfg(feature = "napi5")]
@@ -92,6 +96,174 @@ unsafe extern "C" fn finalize_callback<T, Hint, F>(
   }
 }
 
+#[cfg(feature = "napi6")]
+impl JsObject {
+  /// Start building a `napi_get_all_property_names` call.
+  ///
+  /// By default all properties, including the ones on the prototype chain, are collected and numeric keys are kept as numbers.
+  /// `JsObject::keys` is the shorthand for the enumerable string keys.
+  ///
+  /// ```rust
+  /// fn own_string_keys(obj: &JsObject) -> Result<Vec<String>> {
+  ///   obj
+  ///     .property_keys()
+  ///     .own_only()
+  ///     .skip_symbols()
+  ///     .numbers_to_strings()
+  ///     .iter::<String>()?
+  ///     .collect()
+  /// }
+  /// ```
+  pub fn property_keys(&self) -> PropertyKeys<'_> {
+    PropertyKeys {
+      object: self,
+      mode: KeyCollectionMode::IncludePrototypes,
+      filter: sys::KeyFilter::all_properties,
+      conversion: KeyConversion::KeepNumbers,
+    }
+  }
+}
+
+#[cfg(feature = "napi6")]
+/// Builder returned by [`JsObject::property_keys`]
+pub struct PropertyKeys<'a> {
+  object: &'a JsObject,
+  mode: KeyCollectionMode,
+  filter: sys::napi_key_filter,
+  conversion: KeyConversion,
+}
+
+#[cfg(feature = "napi6")]
+impl<'a> PropertyKeys<'a> {
+  pub fn mode(mut self, mode: KeyCollectionMode) -> Self {
+    self.mode = mode;
+    self
+  }
+
+  /// Only collect the object's own properties, equivalent to `Reflect.ownKeys`
+  pub fn own_only(self) -> Self {
+    self.mode(KeyCollectionMode::OwnOnly)
+  }
+
+  /// Also collect properties on the prototype chain, this is the default
+  pub fn include_prototypes(self) -> Self {
+    self.mode(KeyCollectionMode::IncludePrototypes)
+  }
+
+  /// Add a filter to the collected properties, filters are combined like the `napi_key_filter` bit flags
+  pub fn filter(mut self, filter: KeyFilter) -> Self {
+    self.filter |= sys::napi_key_filter::from(filter);
+    self
+  }
+
+  pub fn writable(self) -> Self {
+    self.filter(KeyFilter::Writable)
+  }
+
+  pub fn enumerable(self) -> Self {
+    self.filter(KeyFilter::Enumerable)
+  }
+
+  pub fn configurable(self) -> Self {
+    self.filter(KeyFilter::Configurable)
+  }
+
+  pub fn skip_strings(self) -> Self {
+    self.filter(KeyFilter::SkipStrings)
+  }
+
+  pub fn skip_symbols(self) -> Self {
+    self.filter(KeyFilter::SkipSymbols)
+  }
+
+  pub fn conversion(mut self, conversion: KeyConversion) -> Self {
+    self.conversion = conversion;
+    self
+  }
+
+  /// Convert numeric keys like array indices to strings
+  pub fn numbers_to_strings(self) -> Self {
+    self.conversion(KeyConversion::NumbersToStrings)
+  }
+
+  /// Keep numeric keys as numbers, this is the default
+  pub fn keep_numbers(self) -> Self {
+    self.conversion(KeyConversion::KeepNumbers)
+  }
+
+  /// Return the collected keys as a JavaScript `Array`
+  pub fn into_array(self) -> Result<JsObject> {
+    let mut properties_value = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_all_property_names(
+        self.object.0.env,
+        self.object.0.value,
+        self.mode.into(),
+        self.filter,
+        self.conversion.into(),
+        &mut properties_value,
+      )
+    })?;
+    Ok(JsObject(Value {
+      env: self.object.0.env,
+      value: properties_value,
+      value_type: crate::ValueType::Object,
+    }))
+  }
+
+  /// Collect the keys and iterate over them, converting each key into `T`
+  pub fn iter<T: FromNapiValue>(self) -> Result<PropertyKeysIter<T>> {
+    let env = self.object.0.env;
+    let array = self.into_array()?;
+    let mut len = 0;
+    check_status!(unsafe { sys::napi_get_array_length(env, array.0.value, &mut len) })?;
+    Ok(PropertyKeysIter {
+      array,
+      index: 0,
+      len,
+      _marker: PhantomData,
+    })
+  }
+}
+
+#[cfg(feature = "napi6")]
+/// Iterator over the keys collected by [`PropertyKeys`]
+pub struct PropertyKeysIter<T> {
+  array: JsObject,
+  index: u32,
+  len: u32,
+  _marker: PhantomData<T>,
+}
+
+#[cfg(feature = "napi6")]
+impl<T: FromNapiValue> Iterator for PropertyKeysIter<T> {
+  type Item = Result<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.index >= self.len {
+      return None;
+    }
+    let index = self.index;
+    self.index += 1;
+    let env = self.array.0.env;
+    let mut raw_value = ptr::null_mut();
+    if let Err(e) = check_status!(unsafe {
+      sys::napi_get_element(env, self.array.0.value, index, &mut raw_value)
+    }) {
+      return Some(Err(e));
+    }
+    Some(unsafe { T::from_napi_value(env, raw_value) })
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let remaining = (self.len - self.index) as usize;
+    (remaining, Some(remaining))
+  }
+}
+
+#[cfg(feature = "napi6")]
+impl<T: FromNapiValue> ExactSizeIterator for PropertyKeysIter<T> {}
+
 #[cfg(feature = "napi6")]
 pub enum KeyCollectionMode {
   IncludePrototypes,
@@ -11,7 +11,7 @@ use crate::{
   bindgen_runtime::{FromNapiValue, This, ToNapiValue},
   Env,
 };
-use crate::{sys, Callback, NapiRaw, Result};
+use crate::{check_status, sys, Callback, Error, NapiRaw, Result, Status};
 
 #[cfg(feature = "napi5")]
 #[derive(Copy, Clone)]
@@ -30,12 +30,148 @@ impl Default for PropertyClosures {
   }
 }
 
+/// Symbols shared by all realms, available as static properties of the global `Symbol`
+///
+/// Used as property keys with `Property::key_symbol`, or as values with `JsSymbol::well_known`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownSymbol {
+  AsyncDispose,
+  AsyncIterator,
+  Dispose,
+  HasInstance,
+  IsConcatSpreadable,
+  Iterator,
+  Match,
+  MatchAll,
+  Replace,
+  Search,
+  Species,
+  Split,
+  ToPrimitive,
+  ToStringTag,
+  Unscopables,
+}
+
+impl WellKnownSymbol {
+  /// The name of the symbol on the global `Symbol`, e.g. `iterator` for `Symbol.iterator`
+  pub fn name(&self) -> &'static str {
+    match self {
+      WellKnownSymbol::AsyncDispose => "asyncDispose",
+      WellKnownSymbol::AsyncIterator => "asyncIterator",
+      WellKnownSymbol::Dispose => "dispose",
+      WellKnownSymbol::HasInstance => "hasInstance",
+      WellKnownSymbol::IsConcatSpreadable => "isConcatSpreadable",
+      WellKnownSymbol::Iterator => "iterator",
+      WellKnownSymbol::Match => "match",
+      WellKnownSymbol::MatchAll => "matchAll",
+      WellKnownSymbol::Replace => "replace",
+      WellKnownSymbol::Search => "search",
+      WellKnownSymbol::Species => "species",
+      WellKnownSymbol::Split => "split",
+      WellKnownSymbol::ToPrimitive => "toPrimitive",
+      WellKnownSymbol::ToStringTag => "toStringTag",
+      WellKnownSymbol::Unscopables => "unscopables",
+    }
+  }
+
+  pub(crate) fn get(&self, env: sys::napi_env) -> Result<sys::napi_value> {
+    let symbol_object = get_global_symbol(env)?;
+    let name = CString::new(self.name())?;
+    let mut symbol = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_named_property(env, symbol_object, name.as_ptr(), &mut symbol) },
+      "Get Symbol.{} failed",
+      self.name()
+    )?;
+    let mut value_type = 0;
+    check_status!(unsafe { sys::napi_typeof(env, symbol, &mut value_type) })?;
+    if value_type == sys::ValueType::napi_symbol {
+      return Ok(symbol);
+    }
+    match self {
+      // `Symbol.dispose` and `Symbol.asyncDispose` are missing in older runtimes,
+      // fallback to the registered symbols used by the TypeScript `using` helpers
+      WellKnownSymbol::Dispose | WellKnownSymbol::AsyncDispose => {
+        symbol_for(env, symbol_object, &format!("Symbol.{}", self.name()))
+      }
+      _ => Err(Error::new(
+        Status::GenericFailure,
+        format!(
+          "Symbol.{} is not supported by this JavaScript runtime",
+          self.name()
+        ),
+      )),
+    }
+  }
+}
+
+/// `Symbol.for(key)`, `symbol_object` is the global `Symbol`
+fn symbol_for(
+  env: sys::napi_env,
+  symbol_object: sys::napi_value,
+  key: &str,
+) -> Result<sys::napi_value> {
+  let mut symbol_for = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_named_property(env, symbol_object, "for\0".as_ptr().cast(), &mut symbol_for)
+    },
+    "Get Symbol.for failed"
+  )?;
+  let mut raw_key = ptr::null_mut();
+  check_status!(unsafe {
+    sys::napi_create_string_utf8(env, key.as_ptr().cast(), key.len(), &mut raw_key)
+  })?;
+  let mut symbol = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_call_function(env, symbol_object, symbol_for, 1, &raw_key, &mut symbol) },
+    "Call Symbol.for failed"
+  )?;
+  Ok(symbol)
+}
+
+fn get_global_symbol(env: sys::napi_env) -> Result<sys::napi_value> {
+  let mut global = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_global(env, &mut global) },
+    "Get global object failed"
+  )?;
+  let mut symbol_object = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_named_property(env, global, "Symbol\0".as_ptr().cast(), &mut symbol_object)
+    },
+    "Get global Symbol failed"
+  )?;
+  Ok(symbol_object)
+}
+
+#[derive(Clone, Copy)]
+enum PropertySymbol {
+  WellKnown(WellKnownSymbol),
+  Registered(&'static str),
+  Value(sys::napi_value),
+}
+
+/// Descriptor of a property, passed to `JsObject::define_properties` and `Env::define_class`
+///
+/// ```rust
+/// use napi::{Property, WellKnownSymbol};
+///
+/// # fn properties(iterator: napi::Callback, version: napi::Callback) -> napi::Result<Vec<Property>> {
+/// Ok(vec![
+///   Property::new("version")?.with_getter(version).enumerable(false),
+///   Property::default().key_symbol(WellKnownSymbol::Iterator).with_method(iterator),
+/// ])
+/// # }
+/// ```
 #[derive(Clone)]
 pub struct Property {
   pub name: CString,
+  symbol: Option<PropertySymbol>,
   getter: sys::napi_callback,
   setter: sys::napi_callback,
-  method: sys::napi_callback,
+  pub(crate) method: sys::napi_callback,
   attrs: PropertyAttributes,
   value: sys::napi_value,
   pub(crate) is_ctor: bool,
@@ -47,6 +183,7 @@ impl Default for Property {
   fn default() -> Self {
     Property {
       name: Default::default(),
+      symbol: None,
       getter: Default::default(),
       setter: Default::default(),
       method: Default::default(),
@@ -145,17 +282,69 @@ impl Property {
     self
   }
 
+  /// Use a well-known symbol like `Symbol.iterator` as the key instead of `name`
+  pub fn key_symbol(mut self, symbol: WellKnownSymbol) -> Self {
+    self.symbol = Some(PropertySymbol::WellKnown(symbol));
+    self
+  }
+
+  /// Use `Symbol.for(key)` as the key instead of `name`, e.g. `nodejs.util.inspect.custom`
+  pub fn key_symbol_for(mut self, key: &'static str) -> Self {
+    self.symbol = Some(PropertySymbol::Registered(key));
+    self
+  }
+
+  /// Use `symbol` as the key instead of `name`, it must be a `JsSymbol` or `Symbol` created in the current scope
+  pub fn with_symbol_key<T: NapiRaw>(mut self, symbol: &T) -> Self {
+    self.symbol = Some(PropertySymbol::Value(unsafe { T::raw(symbol) }));
+    self
+  }
+
+  /// Whether the value can be changed by assignment, ignored for properties with a getter or a setter
+  pub fn writable(mut self, writable: bool) -> Self {
+    self.attrs.set(PropertyAttributes::Writable, writable);
+    self
+  }
+
+  /// Whether the property shows up in `Object.keys` and `for...in`
+  pub fn enumerable(mut self, enumerable: bool) -> Self {
+    self.attrs.set(PropertyAttributes::Enumerable, enumerable);
+    self
+  }
+
+  /// Whether the property can be deleted or redefined
+  pub fn configurable(mut self, configurable: bool) -> Self {
+    self
+      .attrs
+      .set(PropertyAttributes::Configurable, configurable);
+    self
+  }
+
+  /// Define the property on the class itself rather than on its prototype, only used by `Env::define_class`
+  pub fn static_member(mut self, is_static: bool) -> Self {
+    self.attrs.set(PropertyAttributes::Static, is_static);
+    self
+  }
+
   pub fn with_value<T: NapiRaw>(mut self, value: &T) -> Self {
     self.value = unsafe { T::raw(value) };
     self
   }
 
-  pub(crate) fn raw(&self) -> sys::napi_property_descriptor {
+  pub(crate) fn raw(&self, env: sys::napi_env) -> Result<sys::napi_property_descriptor> {
+    let (utf8name, name) = match self.symbol {
+      Some(PropertySymbol::WellKnown(symbol)) => (ptr::null(), symbol.get(env)?),
+      Some(PropertySymbol::Registered(key)) => {
+        (ptr::null(), symbol_for(env, get_global_symbol(env)?, key)?)
+      }
+      Some(PropertySymbol::Value(symbol)) => (ptr::null(), symbol),
+      None => (self.name.as_ptr(), ptr::null_mut()),
+    };
     #[cfg(feature = "napi5")]
     let closures = Box::into_raw(Box::new(self.closures));
-    sys::napi_property_descriptor {
-      utf8name: self.name.as_ptr(),
-      name: ptr::null_mut(),
+    Ok(sys::napi_property_descriptor {
+      utf8name,
+      name,
       method: self.method,
       getter: self.getter,
       setter: self.setter,
@@ -165,6 +354,21 @@ impl Property {
       data: ptr::null_mut(),
       #[cfg(feature = "napi5")]
       data: closures.cast(),
+    })
+  }
+
+  pub(crate) fn is_static(&self) -> bool {
+    self.attrs.contains(PropertyAttributes::Static)
+  }
+
+  /// Whether both properties are defined with the same key
+  pub(crate) fn has_same_key(&self, other: &Property) -> bool {
+    match (self.symbol, other.symbol) {
+      (None, None) => self.name == other.name,
+      (Some(PropertySymbol::WellKnown(a)), Some(PropertySymbol::WellKnown(b))) => a == b,
+      (Some(PropertySymbol::Registered(a)), Some(PropertySymbol::Registered(b))) => a == b,
+      (Some(PropertySymbol::Value(a)), Some(PropertySymbol::Value(b))) => a == b,
+      _ => false,
     }
   }
 
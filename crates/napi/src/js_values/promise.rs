@@ -0,0 +1,82 @@
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use std::future::Future;
+use std::ptr;
+
+use super::*;
+use crate::bindgen_runtime::Function;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use crate::bindgen_runtime::{FromNapiValue, Promise, ToNapiValue};
+use crate::Env;
+
+/// A native JavaScript `Promise` object, `napi_is_promise` rejects the other thenables.
+pub struct JsPromise(pub(crate) Value);
+
+impl TypeName for JsPromise {
+  fn type_name() -> &'static str {
+    "Promise"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsPromise {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    let mut is_promise = false;
+    check_status!(
+      unsafe { sys::napi_is_promise(env, napi_val, &mut is_promise) },
+      "Failed to check if value is promise"
+    )?;
+    if !is_promise {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected Promise object".to_owned(),
+      ));
+    }
+
+    Ok(ptr::null_mut())
+  }
+}
+
+impl JsPromise {
+  /// Register `then` and `catch` callbacks on the `Promise`, the returned future resolves with the fulfilled value converted to `T`, or the rejected reason as an `Error`.
+  ///
+  /// The callbacks are registered eagerly, so it must be called on the JavaScript thread, but the returned future can be awaited anywhere, e.g. in `Env::execute_tokio_future` or `#[napi] async fn`.
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  pub fn into_future<T: FromNapiValue>(self) -> Result<Promise<T>> {
+    unsafe { Promise::from_napi_value(self.0.env, self.0.value) }
+  }
+
+  /// Spawn `fut` on the tokio runtime, the `Promise` resolves with its output converted to a JavaScript value, to pass Rust futures to `JsPromise::all` and the other combinators.
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  pub fn from_future<T, F>(env: &Env, fut: F) -> Result<JsPromise>
+  where
+    T: 'static + Send + ToNapiValue,
+    F: 'static + Send + Future<Output = Result<T>>,
+  {
+    Ok(JsPromise(env.spawn_future(fut)?.0))
+  }
+
+  /// `Promise.all(promises)`, fulfilled with the array of the fulfilled values, or rejected with the first rejection reason.
+  pub fn all(env: &Env, promises: Vec<JsPromise>) -> Result<JsPromise> {
+    Self::combine(env, "all", promises)
+  }
+
+  /// `Promise.race(promises)`, settled like the first of the `promises` to settle.
+  pub fn race(env: &Env, promises: Vec<JsPromise>) -> Result<JsPromise> {
+    Self::combine(env, "race", promises)
+  }
+
+  /// `Promise.allSettled(promises)`, fulfilled with an array of `{ status, value }` or `{ status, reason }` once all the `promises` are settled.
+  pub fn all_settled(env: &Env, promises: Vec<JsPromise>) -> Result<JsPromise> {
+    Self::combine(env, "allSettled", promises)
+  }
+
+  fn combine(env: &Env, combinator: &str, promises: Vec<JsPromise>) -> Result<JsPromise> {
+    let constructor: JsObject = env.get_global()?.get_named_property_unchecked("Promise")?;
+    let combine: Function<Vec<JsPromise>, JsPromise> =
+      constructor.get_named_property(combinator)?;
+    combine.apply(&constructor, promises)
+  }
+}
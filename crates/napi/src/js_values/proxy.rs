@@ -0,0 +1,201 @@
+use std::rc::Rc;
+
+use super::*;
+use crate::bindgen_runtime::{FunctionCallContext, ToNapiValue, Undefined, Unknown};
+use crate::Env;
+
+/// The property key passed to the traps of a [`JsProxy`], symbols are passed as is
+pub type ProxyKey = Either<std::string::String, JsSymbol>;
+
+type GetTrap = Rc<dyn Fn(Env, ProxyKey) -> Result<Unknown>>;
+type SetTrap = Rc<dyn Fn(Env, ProxyKey, Unknown) -> Result<bool>>;
+type KeyTrap = Rc<dyn Fn(Env, ProxyKey) -> Result<bool>>;
+type OwnKeysTrap = Rc<dyn Fn(Env) -> Result<Unknown>>;
+
+/// A JavaScript `Proxy` with traps implemented by Rust closures, created with `Env::create_proxy`.
+///
+/// Properties are computed when they are accessed, nothing is stored on the target.
+/// When `own_keys` is set, a `getOwnPropertyDescriptor` trap is installed as well, so `Object.keys`, spreading and `JSON.stringify` see the keys, with the values returned by `get`.
+/// A key is reported as an own property when `has` returns `true`, or without `has`, when `get` doesn't return `undefined`.
+///
+/// ```rust
+/// use napi::{Either, Env, JsObject, JsProxy, Result};
+///
+/// fn create_env_record(env: Env) -> Result<JsObject> {
+///   env.create_proxy(
+///     JsProxy::new()
+///       .get(|_, key| match key {
+///         Either::A(name) => Ok(std::env::var(name).ok()),
+///         Either::B(_) => Ok(None),
+///       })
+///       .own_keys(|_| Ok(std::env::vars().map(|(name, _)| name).collect())),
+///   )
+/// }
+/// ```
+#[derive(Default)]
+pub struct JsProxy {
+  target: Option<JsObject>,
+  get: Option<GetTrap>,
+  set: Option<SetTrap>,
+  has: Option<KeyTrap>,
+  delete_property: Option<KeyTrap>,
+  own_keys: Option<OwnKeysTrap>,
+}
+
+impl JsProxy {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The object the operations without a trap are forwarded to, an empty object by default
+  pub fn with_target(mut self, target: JsObject) -> Self {
+    self.target = Some(target);
+    self
+  }
+
+  /// `proxy[key]`, returning `None` reads as `undefined`
+  pub fn get<R, F>(mut self, trap: F) -> Self
+  where
+    R: ToNapiValue,
+    F: 'static + Fn(Env, ProxyKey) -> Result<Option<R>>,
+  {
+    self.get = Some(Rc::new(move |env, key| {
+      let raw = match trap(env, key)? {
+        Some(value) => unsafe { R::to_napi_value(env.raw(), value)? },
+        None => unsafe { Undefined::to_napi_value(env.raw(), ())? },
+      };
+      Ok(unsafe { Unknown::from_raw_unchecked(env.raw(), raw) })
+    }));
+    self
+  }
+
+  /// `proxy[key] = value`, returning `false` throws a `TypeError` in strict mode
+  pub fn set<F>(mut self, trap: F) -> Self
+  where
+    F: 'static + Fn(Env, ProxyKey, Unknown) -> Result<bool>,
+  {
+    self.set = Some(Rc::new(trap));
+    self
+  }
+
+  /// `key in proxy`
+  pub fn has<F>(mut self, trap: F) -> Self
+  where
+    F: 'static + Fn(Env, ProxyKey) -> Result<bool>,
+  {
+    self.has = Some(Rc::new(trap));
+    self
+  }
+
+  /// `delete proxy[key]`, returning `false` throws a `TypeError` in strict mode
+  pub fn delete_property<F>(mut self, trap: F) -> Self
+  where
+    F: 'static + Fn(Env, ProxyKey) -> Result<bool>,
+  {
+    self.delete_property = Some(Rc::new(trap));
+    self
+  }
+
+  /// `Object.keys(proxy)`, `Reflect.ownKeys(proxy)` and everything else enumerating the properties,
+  /// the keys are strings or symbols, e.g. `String` or [`ProxyKey`]
+  pub fn own_keys<K, F>(mut self, trap: F) -> Self
+  where
+    K: ToNapiValue,
+    F: 'static + Fn(Env) -> Result<Vec<K>>,
+  {
+    self.own_keys = Some(Rc::new(move |env| {
+      let raw = unsafe { Vec::<K>::to_napi_value(env.raw(), trap(env)?)? };
+      Ok(unsafe { Unknown::from_raw_unchecked(env.raw(), raw) })
+    }));
+    self
+  }
+
+  pub(crate) fn into_js_object(self, env: &Env) -> Result<JsObject> {
+    let mut handler = env.create_object()?;
+    let writable = self.set.is_some();
+    if let Some(get) = self.get.clone() {
+      // (target, key, receiver)
+      let trap = env.create_function_from_closure::<(), _, _>("get", move |ctx| {
+        get(*ctx.env, proxy_arg(&ctx, 1)?)
+      })?;
+      handler.set_named_property("get", trap)?;
+    }
+    if let Some(set) = self.set {
+      // (target, key, value, receiver)
+      let trap = env.create_function_from_closure::<(), _, _>("set", move |ctx| {
+        set(*ctx.env, proxy_arg(&ctx, 1)?, proxy_arg(&ctx, 2)?)
+      })?;
+      handler.set_named_property("set", trap)?;
+    }
+    if let Some(has) = self.has.clone() {
+      // (target, key)
+      let trap = env.create_function_from_closure::<(), _, _>("has", move |ctx| {
+        has(*ctx.env, proxy_arg(&ctx, 1)?)
+      })?;
+      handler.set_named_property("has", trap)?;
+    }
+    if let Some(delete_property) = self.delete_property {
+      // (target, key)
+      let trap = env.create_function_from_closure::<(), _, _>("deleteProperty", move |ctx| {
+        delete_property(*ctx.env, proxy_arg(&ctx, 1)?)
+      })?;
+      handler.set_named_property("deleteProperty", trap)?;
+    }
+    if let Some(own_keys) = self.own_keys {
+      // (target)
+      let trap =
+        env.create_function_from_closure::<(), _, _>("ownKeys", move |ctx| own_keys(*ctx.env))?;
+      handler.set_named_property("ownKeys", trap)?;
+      // without it the keys are filtered out by `Object.keys`, because the target doesn't have them
+      let (has, get) = (self.has, self.get);
+      // (target, key)
+      let trap =
+        env.create_function_from_closure::<(), _, _>("getOwnPropertyDescriptor", move |ctx| {
+          let env = *ctx.env;
+          // the trap must return an object or `undefined`, `null` throws a `TypeError`
+          if let Some(has) = &has {
+            if !has(env, proxy_arg(&ctx, 1)?)? {
+              return Ok(Either::B(()));
+            }
+          }
+          let value = match &get {
+            Some(get) => Some(get(env, proxy_arg(&ctx, 1)?)?),
+            None => None,
+          };
+          if has.is_none() {
+            match &value {
+              Some(value) if value.get_type()? != ValueType::Undefined => {}
+              _ => return Ok(Either::B(())),
+            }
+          }
+          let mut descriptor = env.create_object()?;
+          if let Some(value) = value {
+            descriptor.set_named_property("value", value)?;
+          }
+          descriptor.set_named_property("writable", writable)?;
+          descriptor.set_named_property("enumerable", true)?;
+          // a non configurable property must exist on the target
+          descriptor.set_named_property("configurable", true)?;
+          Ok(Either::A(descriptor))
+        })?;
+      handler.set_named_property("getOwnPropertyDescriptor", trap)?;
+    }
+    let target = match self.target {
+      Some(target) => target,
+      None => env.create_object()?,
+    };
+    let constructor: JsFunction = env.get_global()?.get_named_property_unchecked("Proxy")?;
+    let proxy = constructor.new_instance(&[target, handler])?;
+    Ok(unsafe { JsObject::from_raw_unchecked(env.raw(), proxy.raw()) })
+  }
+}
+
+fn proxy_arg<T: FromNapiValue>(ctx: &FunctionCallContext, index: usize) -> Result<T> {
+  match ctx.args.get(index) {
+    Some(arg) => unsafe { T::from_napi_value(ctx.env.raw(), *arg) },
+    None => Err(Error::new(
+      Status::InvalidArg,
+      format!("Missing argument {} of the proxy trap", index),
+    )),
+  }
+}
@@ -5,11 +5,26 @@ use serde::{ser, Serialize, Serializer};
 use super::*;
 use crate::{Env, Error, Result};
 
-pub struct Ser<'env>(pub(crate) &'env Env);
+/// How `serialize_bytes` calls, e.g. from `serde_bytes`, are represented in JavaScript
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BytesMode {
+  /// Serialize bytes into a `Buffer`
+  #[default]
+  Buffer,
+  /// Serialize bytes into a plain `number[]`
+  Array,
+}
+
+pub struct Ser<'env>(pub(crate) &'env Env, pub(crate) BytesMode);
 
 impl<'env> Ser<'env> {
   pub fn new(env: &'env Env) -> Self {
-    Self(env)
+    Self(env, BytesMode::default())
+  }
+
+  pub fn with_bytes_mode(mut self, bytes_mode: BytesMode) -> Self {
+    self.1 = bytes_mode;
+    self
   }
 }
 
@@ -30,10 +45,19 @@ impl<'env> Serializer for Ser<'env> {
   }
 
   fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
-    self
-      .0
-      .create_buffer_with_data(v.to_owned())
-      .map(|js_value| js_value.value.0)
+    match self.1 {
+      BytesMode::Buffer => self
+        .0
+        .create_buffer_with_data(v.to_owned())
+        .map(|js_value| js_value.value.0),
+      BytesMode::Array => {
+        let mut array = self.0.create_array_with_length(v.len())?;
+        for (index, byte) in v.iter().enumerate() {
+          array.set_element(index as _, self.0.create_uint32(*byte as _)?)?;
+        }
+        Ok(array.0)
+      }
+    }
   }
 
   fn serialize_char(self, v: char) -> Result<Self::Ok> {
@@ -166,13 +190,18 @@ impl<'env> Serializer for Ser<'env> {
     let env = self.0;
     let key = env.create_string("")?;
     let obj = env.create_object()?;
-    Ok(MapSerializer { key, obj })
+    Ok(MapSerializer {
+      key,
+      obj,
+      bytes_mode: self.1,
+    })
   }
 
   fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
     let array = self.0.create_array_with_length(len.unwrap_or(0))?;
     Ok(SeqSerializer {
       current_index: 0,
+      bytes_mode: self.1,
       array,
     })
   }
@@ -197,6 +226,7 @@ impl<'env> Serializer for Ser<'env> {
     )?;
     Ok(SeqSerializer {
       current_index: 0,
+      bytes_mode: self.1,
       array,
     })
   }
@@ -240,6 +270,7 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       array: self.0.create_array_with_length(len)?,
       current_index: 0,
+      bytes_mode: self.1,
     })
   }
 
@@ -251,12 +282,14 @@ impl<'env> Serializer for Ser<'env> {
     Ok(SeqSerializer {
       array: self.0.create_array_with_length(len)?,
       current_index: 0,
+      bytes_mode: self.1,
     })
   }
 
   fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
     Ok(StructSerializer {
       obj: self.0.create_object()?,
+      bytes_mode: self.1,
     })
   }
 
@@ -279,6 +312,7 @@ impl<'env> Serializer for Ser<'env> {
     )?;
     Ok(StructSerializer {
       obj: self.0.create_object()?,
+      bytes_mode: self.1,
     })
   }
 }
@@ -286,6 +320,7 @@ impl<'env> Serializer for Ser<'env> {
 pub struct SeqSerializer {
   array: JsObject,
   current_index: usize,
+  bytes_mode: BytesMode,
 }
 
 impl ser::SerializeSeq for SeqSerializer {
@@ -299,7 +334,7 @@ impl ser::SerializeSeq for SeqSerializer {
     let env = Env::from_raw(self.array.0.env);
     self.array.set_element(
       self.current_index as _,
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     self.current_index += 1;
     Ok(())
@@ -322,7 +357,7 @@ impl ser::SerializeTuple for SeqSerializer {
     let env = Env::from_raw(self.array.0.env);
     self.array.set_element(
       self.current_index as _,
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     self.current_index += 1;
     Ok(())
@@ -345,7 +380,7 @@ impl ser::SerializeTupleStruct for SeqSerializer {
     let env = Env::from_raw(self.array.0.env);
     self.array.set_element(
       self.current_index as _,
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     self.current_index += 1;
     Ok(())
@@ -368,7 +403,7 @@ impl ser::SerializeTupleVariant for SeqSerializer {
     let env = Env::from_raw(self.array.0.env);
     self.array.set_element(
       self.current_index as _,
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     self.current_index += 1;
     Ok(())
@@ -382,6 +417,7 @@ impl ser::SerializeTupleVariant for SeqSerializer {
 pub struct MapSerializer {
   key: JsString,
   obj: JsObject,
+  bytes_mode: BytesMode,
 }
 
 #[doc(hidden)]
@@ -394,7 +430,7 @@ impl ser::SerializeMap for MapSerializer {
     T: ?Sized + Serialize,
   {
     let env = Env::from_raw(self.obj.0.env);
-    self.key = JsString(key.serialize(Ser::new(&env))?);
+    self.key = JsString(key.serialize(Ser(&env, self.bytes_mode))?);
     Ok(())
   }
 
@@ -409,7 +445,7 @@ impl ser::SerializeMap for MapSerializer {
         value: self.key.0.value,
         value_type: ValueType::String,
       }),
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     Ok(())
   }
@@ -421,8 +457,8 @@ impl ser::SerializeMap for MapSerializer {
   {
     let env = Env::from_raw(self.obj.0.env);
     self.obj.set_property(
-      JsString(key.serialize(Ser::new(&env))?),
-      JsUnknown(value.serialize(Ser::new(&env))?),
+      JsString(key.serialize(Ser(&env, self.bytes_mode))?),
+      JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?),
     )?;
     Ok(())
   }
@@ -434,6 +470,7 @@ impl ser::SerializeMap for MapSerializer {
 
 pub struct StructSerializer {
   obj: JsObject,
+  bytes_mode: BytesMode,
 }
 
 #[doc(hidden)]
@@ -448,7 +485,7 @@ impl ser::SerializeStruct for StructSerializer {
     let env = Env::from_raw(self.obj.0.env);
     self
       .obj
-      .set_named_property(key, JsUnknown(value.serialize(Ser::new(&env))?))?;
+      .set_named_property(key, JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?))?;
     Ok(())
   }
 
@@ -469,7 +506,7 @@ impl ser::SerializeStructVariant for StructSerializer {
     let env = Env::from_raw(self.obj.0.env);
     self
       .obj
-      .set_named_property(key, JsUnknown(value.serialize(Ser::new(&env))?))?;
+      .set_named_property(key, JsUnknown(value.serialize(Ser(&env, self.bytes_mode))?))?;
     Ok(())
   }
 
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+use std::ptr;
+
+use super::map::{collect_iterator, is_instance_of_global};
+use super::*;
+use crate::bindgen_runtime::{FromNapiValue, Function, ToNapiValue, Unknown};
+use crate::Env;
+
+/// A JavaScript `Set` object.
+///
+/// All methods are dispatched to the corresponding `Set.prototype` methods.
+pub struct JsSet(pub(crate) Value);
+
+impl TypeName for JsSet {
+  fn type_name() -> &'static str {
+    "Set"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsSet {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    if !unsafe { is_instance_of_global(env, napi_val, "Set")? } {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a Set object".to_owned(),
+      ));
+    }
+
+    Ok(ptr::null_mut())
+  }
+}
+
+impl JsSet {
+  /// Create a `Set` from the values of a `HashSet`
+  pub fn from_hash_set<T, S>(env: &Env, set: HashSet<T, S>) -> Result<Self>
+  where
+    T: ToNapiValue,
+  {
+    let mut js_set = env.create_set()?;
+    for value in set.into_iter() {
+      js_set.add(value)?;
+    }
+    Ok(js_set)
+  }
+
+  pub fn add<T: ToNapiValue>(&mut self, value: T) -> Result<()> {
+    let add: Function<T, Unknown> = self.get_named_property_unchecked("add")?;
+    add.apply(&*self, value)?;
+    Ok(())
+  }
+
+  pub fn has<T: ToNapiValue>(&self, value: T) -> Result<bool> {
+    let has: Function<T, bool> = self.get_named_property_unchecked("has")?;
+    has.apply(self, value)
+  }
+
+  /// Returns `true` if the value existed and has been removed
+  pub fn delete<T: ToNapiValue>(&mut self, value: T) -> Result<bool> {
+    let delete: Function<T, bool> = self.get_named_property_unchecked("delete")?;
+    delete.apply(&*self, value)
+  }
+
+  pub fn clear(&mut self) -> Result<()> {
+    let clear: Function<(), Unknown> = self.get_named_property_unchecked("clear")?;
+    clear.apply(&*self, ())?;
+    Ok(())
+  }
+
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Collect all values in insertion order
+  pub fn values<T: FromNapiValue>(&self) -> Result<Vec<T>> {
+    let values: Function<(), JsObject> = self.get_named_property_unchecked("values")?;
+    let iterator = values.apply(self, ())?;
+    collect_iterator(&iterator)?
+      .into_iter()
+      .map(|value| unsafe { T::from_napi_value(self.0.env, value.raw()) })
+      .collect()
+  }
+
+  pub fn to_hash_set<T, S>(&self) -> Result<HashSet<T, S>>
+  where
+    T: FromNapiValue + Eq + Hash,
+    S: Default + BuildHasher,
+  {
+    Ok(self.values::<T>()?.into_iter().collect())
+  }
+}
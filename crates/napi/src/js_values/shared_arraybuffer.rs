@@ -0,0 +1,185 @@
+use std::ptr::{self, NonNull};
+use std::sync::atomic::AtomicU8;
+
+use super::map::is_instance_of_global;
+use super::*;
+#[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
+use crate::bindgen_prelude::{CUSTOM_GC_TSFN, CUSTOM_GC_TSFN_DESTROYED, THREADS_CAN_ACCESS_ENV};
+use crate::bindgen_runtime::{FromNapiValue, ToNapiValue};
+use crate::{check_status_or_throw, Env};
+
+/// A JavaScript `SharedArrayBuffer` object.
+///
+/// Node-API has no dedicated functions for `SharedArrayBuffer`, so it's created through the global constructor and its memory is reached through a `Uint8Array` view.
+pub struct JsSharedArrayBuffer(pub(crate) Value);
+
+impl TypeName for JsSharedArrayBuffer {
+  fn type_name() -> &'static str {
+    "SharedArrayBuffer"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsSharedArrayBuffer {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    if !unsafe { is_instance_of_global(env, napi_val, "SharedArrayBuffer")? } {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a SharedArrayBuffer object".to_owned(),
+      ));
+    }
+
+    Ok(ptr::null_mut())
+  }
+}
+
+impl JsSharedArrayBuffer {
+  pub fn byte_length(&self) -> Result<usize> {
+    self
+      .get_named_property_unchecked::<f64>("byteLength")
+      .map(|len| len as usize)
+  }
+
+  /// Get a handle to the backing memory which can be sent to other threads.
+  ///
+  /// The `SharedArrayBuffer` is kept alive until the returned `SharedMemory` is dropped.
+  pub fn share(&self) -> Result<SharedMemory> {
+    let env = Env::from_raw(self.0.env);
+    let constructor: JsFunction = env
+      .get_global()?
+      .get_named_property_unchecked("Uint8Array")?;
+    let view = constructor.new_instance(&[self])?;
+    let mut length = 0;
+    let mut data = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_typedarray_info(
+          self.0.env,
+          view.raw(),
+          ptr::null_mut(),
+          &mut length,
+          &mut data,
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Get SharedArrayBuffer data failed"
+    )?;
+    let mut reference = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(self.0.env, self.0.value, 1, &mut reference) },
+      "Failed to create reference to SharedArrayBuffer"
+    )?;
+    Ok(SharedMemory {
+      data: NonNull::new(data.cast()).unwrap_or(NonNull::dangling()),
+      length,
+      raw: (reference, self.0.env),
+    })
+  }
+}
+
+/// The backing memory of a `SharedArrayBuffer`.
+///
+/// The memory may be written by JavaScript or other threads at any time, so it's only exposed as atomics.
+pub struct SharedMemory {
+  data: NonNull<AtomicU8>,
+  length: usize,
+  raw: (sys::napi_ref, sys::napi_env),
+}
+
+// SAFETY: the memory is only reachable through atomics, and the reference is released on the JavaScript thread.
+unsafe impl Send for SharedMemory {}
+unsafe impl Sync for SharedMemory {}
+
+impl SharedMemory {
+  pub fn len(&self) -> usize {
+    self.length
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.length == 0
+  }
+
+  pub fn as_ptr(&self) -> *mut u8 {
+    self.data.as_ptr().cast()
+  }
+
+  pub fn as_atomic_slice(&self) -> &[AtomicU8] {
+    unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.length) }
+  }
+}
+
+impl ToNapiValue for &SharedMemory {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut value = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(env, val.raw.0, &mut value) },
+      "Failed to get SharedArrayBuffer from reference"
+    )?;
+    Ok(value)
+  }
+}
+
+impl FromNapiValue for SharedMemory {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    unsafe { JsSharedArrayBuffer::validate(env, napi_val)? };
+    unsafe { JsSharedArrayBuffer::from_raw_unchecked(env, napi_val) }.share()
+  }
+}
+
+impl TypeName for SharedMemory {
+  fn type_name() -> &'static str {
+    "SharedArrayBuffer"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for SharedMemory {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { JsSharedArrayBuffer::validate(env, napi_val) }
+  }
+}
+
+impl Drop for SharedMemory {
+  fn drop(&mut self) {
+    let (ref_, env) = self.raw;
+    #[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
+    {
+      if CUSTOM_GC_TSFN_DESTROYED.load(std::sync::atomic::Ordering::SeqCst) {
+        return;
+      }
+      if !THREADS_CAN_ACCESS_ENV.borrow_mut(|m| m.get(&std::thread::current().id()).is_some()) {
+        let status = unsafe {
+          sys::napi_call_threadsafe_function(
+            CUSTOM_GC_TSFN.load(std::sync::atomic::Ordering::SeqCst),
+            ref_.cast(),
+            1,
+          )
+        };
+        assert!(
+          status == sys::Status::napi_ok || status == sys::Status::napi_closing,
+          "Call custom GC in SharedMemory::drop failed {}",
+          Status::from(status)
+        );
+        return;
+      }
+    }
+    let mut ref_count = 0;
+    check_status_or_throw!(
+      env,
+      unsafe { sys::napi_reference_unref(env, ref_, &mut ref_count) },
+      "Failed to unref SharedArrayBuffer reference in drop"
+    );
+    check_status_or_throw!(
+      env,
+      unsafe { sys::napi_delete_reference(env, ref_) },
+      "Failed to delete SharedArrayBuffer reference in drop"
+    );
+  }
+}
@@ -1,13 +1,17 @@
+#[cfg(feature = "experimental")]
+use std::ffi::c_void;
 use std::mem;
 use std::ptr;
 
 use crate::bindgen_runtime::TypeName;
 use crate::bindgen_runtime::ValidateNapiValue;
 use crate::ValueType;
-use crate::{check_status, sys, Result, Value};
+use crate::{check_status, sys, Error, Result, Status, Value};
+#[cfg(feature = "experimental")]
+use crate::{Env, NapiValue};
 
 pub use latin1::JsStringLatin1;
-pub use utf16::JsStringUtf16;
+pub use utf16::{JsStringUtf16, Utf16Slice};
 pub use utf8::JsStringUtf8;
 
 mod latin1;
@@ -17,6 +21,17 @@ mod utf8;
 #[derive(Clone, Copy)]
 pub struct JsString(pub(crate) Value);
 
+/// The narrowest encoding representing a [`JsString`] without losing anything, see `JsString::encoding`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+  /// Every char fits in Latin-1, `JsString::into_latin1` keeps them
+  Latin1,
+  /// Well-formed UTF-16, `JsString::into_utf8` keeps every char
+  Utf16,
+  /// With lone surrogates, only `JsString::into_utf16` keeps them, they are replaced with `U+FFFD` in UTF-8
+  Utf16WithLoneSurrogates,
+}
+
 impl TypeName for JsString {
   fn type_name() -> &'static str {
     "String"
@@ -54,6 +69,19 @@ impl JsString {
     Ok(length)
   }
 
+  /// Detect the narrowest encoding of the string, it's copied as UTF-16 to be inspected
+  pub fn encoding(&self) -> Result<StringEncoding> {
+    let utf16 = self.into_utf16()?;
+    let units = utf16.as_utf16_slice();
+    Ok(if units.is_latin1() {
+      StringEncoding::Latin1
+    } else if units.is_well_formed() {
+      StringEncoding::Utf16
+    } else {
+      StringEncoding::Utf16WithLoneSurrogates
+    })
+  }
+
   pub fn into_utf8(self) -> Result<JsStringUtf8> {
     let mut written_char_count = 0;
     let len = self.utf8_len()? + 1;
@@ -79,6 +107,36 @@ impl JsString {
     })
   }
 
+  /// Read a string known to be ASCII, like the identifiers of a tokenizer.
+  ///
+  /// Unlike `into_utf8`, the string isn't scanned to compute its UTF-8 length first, its length in UTF-16 code units is the length of an ASCII string.
+  ///
+  /// An `InvalidArg` error is returned if the string isn't ASCII.
+  pub fn into_ascii_string(self) -> Result<String> {
+    let len = self.utf16_len()?;
+    let mut bytes = Vec::<u8>::with_capacity(len + 1);
+    let mut written_char_count = 0;
+    check_status!(unsafe {
+      sys::napi_get_value_string_utf8(
+        self.0.env,
+        self.0.value,
+        bytes.as_mut_ptr().cast(),
+        len + 1,
+        &mut written_char_count,
+      )
+    })?;
+    unsafe { bytes.set_len(written_char_count) };
+    // an ASCII string takes one UTF-8 byte per UTF-16 code unit, a non ASCII char is written as non ASCII bytes,
+    // or cut off with the rest of the string if it doesn't fit in the buffer
+    if written_char_count != len || !bytes.is_ascii() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "The string passed to `JsString::into_ascii_string` isn't ASCII".to_owned(),
+      ));
+    }
+    Ok(unsafe { String::from_utf8_unchecked(bytes) })
+  }
+
   pub fn into_utf16(self) -> Result<JsStringUtf16> {
     let mut written_char_count = 0usize;
     let len = self.utf16_len()? + 1;
@@ -125,3 +183,97 @@ impl JsString {
     })
   }
 }
+
+#[cfg(feature = "experimental")]
+impl JsString {
+  /// Hand the UTF-16 `data` to JavaScript without copying it, it's dropped when the string is garbage collected.
+  ///
+  /// The runtime may copy the string anyway, for example when V8 is built with the sandbox, `data` is dropped right away then.
+  pub fn external_from_vec_utf16(env: &Env, data: Vec<u16>) -> Result<JsString> {
+    unsafe {
+      create_external_string(
+        env,
+        data,
+        |env, data, length, finalize, hint, result, copied| {
+          sys::node_api_create_external_string_utf16(
+            env, data, length, finalize, hint, result, copied,
+          )
+        },
+      )
+    }
+  }
+
+  /// Same as `JsString::external_from_vec_utf16`, for the Latin-1 encoded `data`
+  pub fn external_latin1(env: &Env, data: Vec<u8>) -> Result<JsString> {
+    unsafe {
+      create_external_string(
+        env,
+        data,
+        |env, data, length, finalize, hint, result, copied| {
+          sys::node_api_create_external_string_latin1(
+            env,
+            data.cast(),
+            length,
+            finalize,
+            hint,
+            result,
+            copied,
+          )
+        },
+      )
+    }
+  }
+}
+
+#[cfg(feature = "experimental")]
+type CreateExternalString<T> = unsafe fn(
+  sys::napi_env,
+  *const T,
+  usize,
+  sys::napi_finalize,
+  *mut c_void,
+  *mut sys::napi_value,
+  *mut bool,
+) -> sys::napi_status;
+
+#[cfg(feature = "experimental")]
+unsafe fn create_external_string<T>(
+  env: &Env,
+  data: Vec<T>,
+  create: CreateExternalString<T>,
+) -> Result<JsString> {
+  let mut data = mem::ManuallyDrop::new(data);
+  let length = data.len();
+  let data_ptr = data.as_mut_ptr();
+  let hint_ptr = Box::into_raw(Box::new((length, data.capacity())));
+  let mut raw_value = ptr::null_mut();
+  // when the string is copied, the finalizer has already been called
+  let mut copied = false;
+  let status = unsafe {
+    create(
+      env.raw(),
+      data_ptr,
+      length,
+      Some(drop_external_string::<T>),
+      hint_ptr.cast(),
+      &mut raw_value,
+      &mut copied,
+    )
+  };
+  if status != sys::Status::napi_ok {
+    // the finalizer isn't called when the string isn't created
+    unsafe { drop_external_string::<T>(env.raw(), data_ptr.cast(), hint_ptr.cast()) };
+  }
+  check_status!(status, "Create external string failed")?;
+  Ok(unsafe { JsString::from_raw_unchecked(env.raw(), raw_value) })
+}
+
+#[cfg(feature = "experimental")]
+unsafe extern "C" fn drop_external_string<T>(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  let (length, cap) = unsafe { *Box::from_raw(hint as *mut (usize, usize)) };
+  mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut T, length, cap) });
+}
@@ -1,5 +1,8 @@
+use std::char::{decode_utf16, DecodeUtf16};
 use std::convert::TryFrom;
+use std::iter::Copied;
 use std::ops::Deref;
+use std::slice;
 
 use crate::{Error, JsString, Result, Status};
 
@@ -21,6 +24,16 @@ impl JsStringUtf16 {
     self.buf.as_slice()
   }
 
+  /// Borrow the code units without the trailing `\0`, keeping the lone surrogates `as_str` fails on
+  pub fn as_utf16_slice(&self) -> Utf16Slice<'_> {
+    Utf16Slice::new(
+      self
+        .as_slice()
+        .split_last()
+        .map_or(&[], |(_, prefix)| prefix),
+    )
+  }
+
   pub fn len(&self) -> usize {
     self.buf.len()
   }
@@ -61,3 +74,46 @@ impl From<JsStringUtf16> for Vec<u16> {
     value.as_slice().to_vec()
   }
 }
+
+/// A borrowed UTF-16 string, JavaScript strings may contain lone surrogates unlike a Rust `str`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf16Slice<'a>(&'a [u16]);
+
+impl<'a> Utf16Slice<'a> {
+  pub fn new(units: &'a [u16]) -> Self {
+    Self(units)
+  }
+
+  pub fn as_slice(&self) -> &'a [u16] {
+    self.0
+  }
+
+  /// The number of code units, same as `string.length`
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// The chars, a lone surrogate is an `Err` with `DecodeUtf16Error::unpaired_surrogate`
+  pub fn chars(&self) -> DecodeUtf16<Copied<slice::Iter<'a, u16>>> {
+    decode_utf16(self.0.iter().copied())
+  }
+
+  /// Same as `string.isWellFormed()`, whether there is no lone surrogate
+  pub fn is_well_formed(&self) -> bool {
+    self.chars().all(|c| c.is_ok())
+  }
+
+  /// Whether every code unit fits in Latin-1, so `JsString::into_latin1` doesn't truncate them
+  pub fn is_latin1(&self) -> bool {
+    self.0.iter().all(|unit| *unit <= 0xff)
+  }
+
+  /// Replace the lone surrogates with `U+FFFD`, like `JsString::into_utf8` does
+  pub fn to_string_lossy(&self) -> String {
+    String::from_utf16_lossy(self.0)
+  }
+}
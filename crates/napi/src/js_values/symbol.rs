@@ -0,0 +1,58 @@
+use std::ptr;
+
+use super::*;
+use crate::bindgen_runtime::Function;
+use crate::Env;
+
+impl JsSymbol {
+  /// Same as `Symbol.for(key)`, the symbol is shared by every realm and every addon in the process
+  pub fn for_key(env: &Env, key: &str) -> Result<JsSymbol> {
+    #[cfg(feature = "napi9")]
+    {
+      env.symbol_for(key)
+    }
+    #[cfg(not(feature = "napi9"))]
+    {
+      let symbol_for: Function<&str, JsSymbol> =
+        global_symbol(env)?.get_named_property_unchecked("for")?;
+      symbol_for.call(key)
+    }
+  }
+
+  /// Get a well-known symbol like `Symbol.asyncIterator`, fails if the JavaScript runtime doesn't support it.
+  ///
+  /// `Symbol.dispose` and `Symbol.asyncDispose` fallback to `Symbol.for("Symbol.dispose")` and `Symbol.for("Symbol.asyncDispose")` like the TypeScript `using` helpers.
+  pub fn well_known(env: &Env, symbol: WellKnownSymbol) -> Result<JsSymbol> {
+    let raw = symbol.get(env.raw())?;
+    Ok(unsafe { JsSymbol::from_raw_unchecked(env.raw(), raw) })
+  }
+
+  /// Same as `symbol.description`, `None` for `Symbol()`
+  pub fn description(&self) -> Result<Option<std::string::String>> {
+    let mut description = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(
+          self.0.env,
+          self.0.value,
+          "description\0".as_ptr().cast(),
+          &mut description,
+        )
+      },
+      "Get symbol description failed"
+    )?;
+    unsafe { Option::<std::string::String>::from_napi_value(self.0.env, description) }
+  }
+
+  /// Same as `Symbol.keyFor(symbol)`, the key of a symbol from `Symbol.for`, `None` for any other symbol
+  pub fn key_for(&self) -> Result<Option<std::string::String>> {
+    let env = Env::from_raw(self.0.env);
+    let key_for: Function<JsSymbol, Option<std::string::String>> =
+      global_symbol(&env)?.get_named_property_unchecked("keyFor")?;
+    key_for.call(*self)
+  }
+}
+
+fn global_symbol(env: &Env) -> Result<JsObject> {
+  env.get_global()?.get_named_property_unchecked("Symbol")
+}
@@ -14,6 +14,12 @@
 //!
 //! The details of N-API versions and support matrix: [n_api_version_matrix](https://nodejs.org/api/n-api.html#n_api_n_api_version_matrix)
 //!
+//! ### napi10_module_version
+//! Declares Node-API 10 as the version of the module with `node_api_module_get_api_version_v1`, it's loaded as version 8 otherwise.
+//! The property keys of the `#[napi(object)]` conversions are cached per `Env` then, see [`PropertyKeyCache`].
+//!
+//! The module can only be loaded by the runtimes supporting Node-API 10, the older ones fail to load it.
+//!
 //! ### tokio_rt
 //! With `tokio_rt` feature, `napi-rs` provides a ***tokio runtime*** in an additional thread.
 //! And you can easily run tokio `future` in it and return `promise`.
@@ -33,6 +39,29 @@
 //! }
 //! ```
 //!
+//! ### async_std_rt
+//! With `async_std_rt` feature and without `tokio_rt`, the `#[napi] async fn` are run on the global executor of [async-std](https://docs.rs/async-std),
+//! for the projects already using it, instead of a tokio runtime running next to it.
+//!
+//! ```
+//! #[napi]
+//! pub async fn read_file(path: String) -> Result<Buffer> {
+//!     async_std::fs::read(path).await.map(Buffer::from).map_err(Error::from)
+//! }
+//! ```
+//!
+//! ### tracing_layer
+//! With `tracing_layer` feature, the [`tracing_layer::JsLoggerLayer`] forwards the [tracing](https://docs.rs/tracing) events to a JavaScript logger,
+//! through a `ThreadsafeFunction`, with a level filter which can be changed from JavaScript.
+//!
+//! ```
+//! #[napi]
+//! pub fn init_logger(logger: Function<LogRecord, Unknown>, level: String) -> Result<()> {
+//!     tracing_subscriber::registry().with(JsLoggerLayer::new(logger, &level)?).init();
+//!     Ok(())
+//! }
+//! ```
+//!
 //! ### latin1
 //!
 //! Decode latin1 string from JavaScript using [encoding_rs](https://docs.rs/encoding_rs).
@@ -41,7 +70,7 @@
 //!
 //! ### serde-json
 //!
-//! Enable Serialize/Deserialize data cross `JavaScript Object` and `Rust struct`.
+//! Enable Serialize/Deserialize data cross `JavaScript Object` and `Rust struct`, see also the [`serde`] module.
 //!
 //! ```
 //! #[derive(Serialize, Debug, Deserialize)]
@@ -69,30 +98,59 @@
 mod async_cleanup_hook;
 #[cfg(feature = "napi8")]
 pub use async_cleanup_hook::AsyncCleanupHook;
+#[cfg(all(feature = "async_std_rt", feature = "napi4", not(feature = "tokio_rt")))]
+mod async_std_runtime;
 mod async_work;
 mod bindgen_runtime;
+mod buffer_policy;
 mod call_context;
+mod capability;
 #[cfg(feature = "napi3")]
 mod cleanup_env;
 mod env;
 mod error;
+mod external_memory;
 mod js_values;
+#[cfg(feature = "napi4")]
+mod main_thread;
+mod panic;
+#[cfg(feature = "napi10_module_version")]
+mod property_key_cache;
+#[cfg(feature = "napi3")]
+mod script_cache;
+#[cfg(feature = "serde-json")]
+pub mod serde;
 mod status;
 mod task;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 mod tokio_runtime;
+#[cfg(all(feature = "tracing_layer", feature = "napi4"))]
+pub mod tracing_layer;
+mod type_tag;
 mod value_type;
 #[cfg(feature = "napi3")]
 pub use cleanup_env::CleanupEnvHook;
+pub use external_memory::ExternalMemoryGuard;
+#[cfg(feature = "napi4")]
+pub use main_thread::MainThreadHandle;
+pub use panic::{reset_panic_handler, set_panic_handler, CaughtPanic};
+#[cfg(feature = "napi10_module_version")]
+pub use property_key_cache::PropertyKeyCache;
 #[cfg(feature = "napi4")]
 pub mod threadsafe_function;
+pub use type_tag::TypeTag;
 
 mod version;
 
 pub use napi_sys as sys;
 
-pub use async_work::AsyncWorkPromise;
+pub use async_work::{AsyncWorkOptions, AsyncWorkPromise, AsyncWorkQueue};
+pub use buffer_policy::{
+  external_buffer_policy, external_buffers_rejected, set_external_buffer_policy,
+  ExternalBufferPolicy,
+};
 pub use call_context::CallContext;
+pub use capability::Capability;
 
 pub use bindgen_runtime::iterator;
 pub use env::*;
@@ -102,9 +160,6 @@ pub use status::Status;
 pub use task::Task;
 pub use value_type::*;
 pub use version::NodeVersion;
-#[cfg(feature = "serde-json")]
-#[macro_use]
-extern crate serde;
 
 pub type ContextlessResult<T> = Result<Option<T>>;
 
@@ -142,6 +197,8 @@ macro_rules! assert_type_of {
 pub use crate::bindgen_runtime::ctor as module_init;
 
 pub mod bindgen_prelude {
+  #[cfg(all(feature = "async_std_rt", not(feature = "tokio_rt")))]
+  pub use crate::async_std_runtime::*;
   #[cfg(all(feature = "compat-mode", not(feature = "noop")))]
   pub use crate::bindgen_runtime::register_module_exports;
   #[cfg(feature = "tokio_rt")]
@@ -166,8 +223,11 @@ pub mod bindgen_prelude {
 #[doc(hidden)]
 pub mod __private {
   pub use crate::bindgen_runtime::{
-    get_class_constructor, iterator::create_iterator, register_class, ___CALL_FROM_FACTORY,
+    check_class_instance, get_class_constructor, iterator::create_iterator, register_class,
+    register_class_extends, register_subclass_type_tag, tag_class_instance,
+    throw_without_constructor, ___CALL_FROM_FACTORY,
   };
+  pub use crate::panic::catch_unwind;
 
   use crate::sys;
 
@@ -206,5 +266,14 @@ pub mod __private {
 #[cfg(feature = "tokio_rt")]
 pub extern crate tokio;
 
+#[cfg(feature = "async_std_rt")]
+pub extern crate async_std;
+
+#[cfg(feature = "tracing_layer")]
+pub extern crate tracing;
+
+#[cfg(feature = "tracing_layer")]
+pub extern crate tracing_subscriber;
+
 #[cfg(feature = "error_anyhow")]
 pub extern crate anyhow;
@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::{
+  atomic::{AtomicPtr, Ordering},
+  Arc, RwLock, Weak,
+};
+
+use crate::{check_status, sys, Env, Error, JsError, Result, Status};
+
+type MainThreadCallback = Box<dyn FnOnce(Env) -> Result<()>>;
+
+static MAIN_THREAD_ASYNC_RESOURCE_NAME: &str = "napi_rs_main_thread";
+
+thread_local! {
+  /// The handles of `MainThreadHandle::shared`, keyed by `Env`
+  static SHARED_HANDLES: RefCell<HashMap<usize, MainThreadHandle>> = Default::default();
+}
+
+/// Created by `Env::main_thread_handle`
+///
+/// It can be cloned and sent to other threads, the closures passed to `MainThreadHandle::run` are called on the JavaScript thread of the `Env`, in the order they were queued.
+///
+/// The event loop is kept alive until every clone of the handle is dropped, closures still queued when the `Env` is torn down are dropped without being called.
+/// The handle can outlive its `Env`, `run` returns an `Err` with `Status::Closing` then.
+#[derive(Clone)]
+pub struct MainThreadHandle {
+  inner: Arc<RawMainThreadHandle>,
+}
+
+struct RawMainThreadHandle {
+  raw: AtomicPtr<sys::napi_threadsafe_function__>,
+  /// Set by the finalizer of the threadsafe function, which is freed by Node.js once it returns
+  aborted: RwLock<bool>,
+}
+
+impl RawMainThreadHandle {
+  fn with_read_aborted<RT, F>(&self, f: F) -> RT
+  where
+    F: FnOnce(bool, sys::napi_threadsafe_function) -> RT,
+  {
+    let aborted_guard = self
+      .aborted
+      .read()
+      .expect("Main thread handle aborted lock failed");
+    f(*aborted_guard, self.raw.load(Ordering::SeqCst))
+  }
+}
+
+impl Drop for RawMainThreadHandle {
+  fn drop(&mut self) {
+    self.with_read_aborted(|aborted, raw| {
+      if !aborted {
+        unsafe {
+          sys::napi_release_threadsafe_function(raw, sys::ThreadsafeFunctionReleaseMode::release)
+        };
+      }
+    })
+  }
+}
+
+impl MainThreadHandle {
+  pub(crate) fn new(env: sys::napi_env) -> Result<Self> {
+    let mut noop_fn = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_function(
+          env,
+          MAIN_THREAD_ASYNC_RESOURCE_NAME.as_ptr().cast(),
+          MAIN_THREAD_ASYNC_RESOURCE_NAME.len(),
+          Some(noop),
+          ptr::null_mut(),
+          &mut noop_fn,
+        )
+      },
+      "Create main thread function failed"
+    )?;
+    let mut async_resource_name = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_string_utf8(
+          env,
+          MAIN_THREAD_ASYNC_RESOURCE_NAME.as_ptr().cast(),
+          MAIN_THREAD_ASYNC_RESOURCE_NAME.len(),
+          &mut async_resource_name,
+        )
+      },
+      "Create main thread async resource name failed"
+    )?;
+    let handle = Arc::new(RawMainThreadHandle {
+      raw: AtomicPtr::new(ptr::null_mut()),
+      aborted: RwLock::new(false),
+    });
+    let mut raw_tsfn = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_threadsafe_function(
+          env,
+          noop_fn,
+          ptr::null_mut(),
+          async_resource_name,
+          0,
+          1,
+          Weak::into_raw(Arc::downgrade(&handle)).cast_mut().cast(),
+          Some(main_thread_finalize),
+          ptr::null_mut(),
+          Some(call_main_thread_callback),
+          &mut raw_tsfn,
+        )
+      },
+      "Create main thread threadsafe function failed"
+    )?;
+    handle.raw.store(raw_tsfn, Ordering::SeqCst);
+    Ok(Self { inner: handle })
+  }
+
+  /// Queue `callback` to be called on the JavaScript thread, it's dropped without being called if the `Env` has been torn down.
+  ///
+  /// An `Err` returned by `callback` is thrown as an uncaught exception.
+  pub fn run<F>(&self, callback: F) -> Result<()>
+  where
+    F: 'static + Send + FnOnce(Env) -> Result<()>,
+  {
+    self.queue(Box::new(callback))
+  }
+
+  /// One handle per `Env` shared by the internal callers, it doesn't keep the event loop alive.
+  ///
+  /// Must be called on the JavaScript thread, the cached handle is released when the `Env` is torn down.
+  pub(crate) fn shared(env: sys::napi_env) -> Result<Self> {
+    let cached = SHARED_HANDLES.with(|handles| handles.borrow().get(&(env as usize)).cloned());
+    if let Some(handle) = cached {
+      return Ok(handle);
+    }
+    let handle = Self::new(env)?;
+    handle.unref(env)?;
+    Env::from_raw(env).add_env_cleanup_hook(env as usize, |env| {
+      let _ = SHARED_HANDLES.try_with(|handles| handles.borrow_mut().remove(&env));
+    })?;
+    SHARED_HANDLES.with(|handles| handles.borrow_mut().insert(env as usize, handle.clone()));
+    Ok(handle)
+  }
+
+  /// Don't keep the event loop alive for this handle, must be called on the JavaScript thread
+  pub(crate) fn unref(&self, env: sys::napi_env) -> Result<()> {
+    self.inner.with_read_aborted(|aborted, raw| {
+      if aborted {
+        return Ok(());
+      }
+      check_status!(
+        unsafe { sys::napi_unref_threadsafe_function(env, raw) },
+        "Unref main thread threadsafe function failed"
+      )
+    })
+  }
+
+  /// `callback` doesn't need to be `Send` when it's queued from the JavaScript thread itself
+  pub(crate) fn queue(&self, callback: MainThreadCallback) -> Result<()> {
+    self.inner.with_read_aborted(|aborted, raw| {
+      if aborted {
+        return Err(Error::new(
+          Status::Closing,
+          "The Env of the main thread handle has been torn down",
+        ));
+      }
+      let data = Box::into_raw(Box::new(callback));
+      let status = unsafe {
+        sys::napi_call_threadsafe_function(
+          raw,
+          data.cast(),
+          sys::ThreadsafeFunctionCallMode::nonblocking,
+        )
+      };
+      if status != sys::Status::napi_ok {
+        drop(unsafe { Box::from_raw(data) });
+      }
+      check_status!(status, "Queue closure onto the JavaScript thread failed")
+    })
+  }
+}
+
+unsafe extern "C" fn noop(_env: sys::napi_env, _info: sys::napi_callback_info) -> sys::napi_value {
+  ptr::null_mut()
+}
+
+unsafe extern "C" fn main_thread_finalize(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  _finalize_hint: *mut c_void,
+) {
+  let handle = unsafe { Weak::from_raw(finalize_data.cast::<RawMainThreadHandle>()) };
+  if let Some(handle) = handle.upgrade() {
+    *handle
+      .aborted
+      .write()
+      .expect("Main thread handle aborted lock failed") = true;
+  }
+}
+
+unsafe extern "C" fn call_main_thread_callback(
+  env: sys::napi_env,
+  _js_callback: sys::napi_value,
+  _context: *mut c_void,
+  data: *mut c_void,
+) {
+  let callback = unsafe { Box::from_raw(data.cast::<MainThreadCallback>()) };
+  // env is null when the threadsafe function is torn down with closures still queued
+  if env.is_null() {
+    return;
+  }
+  if let Err(err) = callback(Env::from_raw(env)) {
+    unsafe { sys::napi_fatal_exception(env, JsError::from(err).into_value(env)) };
+  }
+}
@@ -0,0 +1,126 @@
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Once, RwLock};
+
+use crate::{Error, Result, Status};
+
+type PanicHandler = Box<dyn Fn(&CaughtPanic) -> Error + Send + Sync>;
+
+static PANIC_HANDLER: RwLock<Option<PanicHandler>> = RwLock::new(None);
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+  static CATCH_UNWIND_DEPTH: Cell<usize> = const { Cell::new(0) };
+  static LAST_BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A panic caught by `#[napi(catch_unwind)]`, passed to the handler registered with [`set_panic_handler`]
+pub struct CaughtPanic {
+  message: String,
+  backtrace: Option<String>,
+}
+
+impl CaughtPanic {
+  /// The payload of `panic!`, or a debug representation if it's not a string
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// The Rust backtrace captured where the panic happened, `None` if backtraces are not supported on the platform
+  pub fn backtrace(&self) -> Option<&str> {
+    self.backtrace.as_deref()
+  }
+
+  /// The default conversion, an `Error` with the panic message and the `backtrace` property
+  pub fn to_error(&self) -> Error {
+    let error = Error::new(Status::GenericFailure, self.message.clone());
+    match &self.backtrace {
+      Some(backtrace) => error.with_property("backtrace", backtrace.clone()),
+      None => error,
+    }
+  }
+}
+
+/// Customize how a panic caught by `#[napi(catch_unwind)]` is converted into the thrown JavaScript error.
+///
+/// By default the error message is the panic message, and the Rust backtrace is attached as the `backtrace` property.
+///
+/// ```rust
+/// use napi::CaughtPanic;
+///
+/// napi::set_panic_handler(|panic: &CaughtPanic| {
+///   panic.to_error().with_property("code", "ERR_RUST_PANIC")
+/// });
+/// ```
+pub fn set_panic_handler<F>(handler: F)
+where
+  F: Fn(&CaughtPanic) -> Error + Send + Sync + 'static,
+{
+  *PANIC_HANDLER
+    .write()
+    .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Box::new(handler));
+}
+
+/// Restore the default panic to error conversion
+pub fn reset_panic_handler() {
+  *PANIC_HANDLER
+    .write()
+    .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Run `f`, converting a panic into an `Error`. Called by the code generated for `#[napi(catch_unwind)]`.
+#[doc(hidden)]
+pub fn catch_unwind<T, F: FnOnce() -> Result<T>>(f: F) -> Result<T> {
+  install_panic_hook();
+  CATCH_UNWIND_DEPTH.with(|depth| depth.set(depth.get() + 1));
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+  CATCH_UNWIND_DEPTH.with(|depth| depth.set(depth.get() - 1));
+  result.unwrap_or_else(|payload| {
+    let caught = CaughtPanic {
+      message: panic_message(payload.as_ref()),
+      backtrace: LAST_BACKTRACE.with(|backtrace| backtrace.borrow_mut().take()),
+    };
+    Err(panic_to_error(&caught))
+  })
+}
+
+fn panic_to_error(caught: &CaughtPanic) -> Error {
+  let handler = PANIC_HANDLER
+    .read()
+    .unwrap_or_else(|poisoned| poisoned.into_inner());
+  match handler.as_ref() {
+    Some(handler) => handler(caught),
+    None => caught.to_error(),
+  }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+  if let Some(string) = payload.downcast_ref::<String>() {
+    string.clone()
+  } else if let Some(string) = payload.downcast_ref::<&str>() {
+    string.to_string()
+  } else {
+    format!("panic from Rust code: {:?}", payload)
+  }
+}
+
+// The backtrace is only available inside the panic hook, so it's captured there and picked up by `catch_unwind` after unwinding.
+// The previous hook is still called, panics are printed to stderr as before.
+fn install_panic_hook() {
+  INSTALL_PANIC_HOOK.call_once(|| {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+      if CATCH_UNWIND_DEPTH.with(|depth| depth.get()) > 0 {
+        let backtrace = Backtrace::force_capture();
+        let backtrace = match backtrace.status() {
+          BacktraceStatus::Captured => Some(backtrace.to_string()),
+          _ => None,
+        };
+        LAST_BACKTRACE.with(|last| *last.borrow_mut() = backtrace);
+      }
+      previous_hook(info);
+    }));
+  });
+}
@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ptr;
+
+use crate::{bindgen_runtime::FromNapiValue, check_status, sys, Env, JsString, Result};
+
+thread_local! {
+  /// Keys created by `PropertyKeyCache`, keyed by `Env` and then by the name of the key
+  static PROPERTY_KEYS: RefCell<HashMap<usize, HashMap<&'static str, sys::napi_ref>>> = Default::default();
+}
+
+/// Property keys created once per `Env` with `node_api_create_property_key_utf8`, and kept alive by a reference.
+///
+/// Reading or writing a property by name creates the key string on every call,
+/// the cached keys are internalized strings which are only created once, and are faster to look up.
+/// The conversions of the `#[napi(object)]` structs read and write their fields with them.
+///
+/// Enabled by the `napi10_module_version` feature, the references to strings need the module to declare Node-API 10.
+pub struct PropertyKeyCache;
+
+impl PropertyKeyCache {
+  /// The key `name`, created on the first call in the `env`
+  pub fn get(env: &Env, name: &'static str) -> Result<JsString> {
+    let key = match Self::get_raw(env.raw(), name) {
+      Some(key) => key,
+      None => {
+        let mut key = ptr::null_mut();
+        check_status!(unsafe {
+          sys::napi_create_string_utf8(env.raw(), name.as_ptr().cast(), name.len(), &mut key)
+        })?;
+        key
+      }
+    };
+    unsafe { JsString::from_napi_value(env.raw(), key) }
+  }
+
+  /// `None` if the key can't be created or referenced, the callers fall back to the key strings then
+  pub(crate) fn get_raw(env: sys::napi_env, name: &'static str) -> Option<sys::napi_value> {
+    let cached = PROPERTY_KEYS.with(|keys| {
+      keys
+        .borrow()
+        .get(&(env as usize))
+        .and_then(|keys| keys.get(name).copied())
+    });
+    let mut key = ptr::null_mut();
+    if let Some(reference) = cached {
+      let status = unsafe { sys::napi_get_reference_value(env, reference, &mut key) };
+      return (status == sys::Status::napi_ok).then_some(key);
+    }
+    let status = unsafe {
+      sys::node_api_create_property_key_utf8(env, name.as_ptr().cast(), name.len(), &mut key)
+    };
+    if status != sys::Status::napi_ok {
+      return None;
+    }
+    let mut reference = ptr::null_mut();
+    // the key is still usable without the reference, it's created again on the next call then
+    if unsafe { sys::napi_create_reference(env, key, 1, &mut reference) } != sys::Status::napi_ok {
+      return Some(key);
+    }
+    let is_first_key = PROPERTY_KEYS.with(|keys| {
+      let mut keys = keys.borrow_mut();
+      let env_keys = keys.entry(env as usize).or_default();
+      env_keys.insert(name, reference);
+      env_keys.len() == 1
+    });
+    if is_first_key {
+      let _ = Env::from_raw(env).add_env_cleanup_hook(env as usize, clear_cache);
+    }
+    Some(key)
+  }
+}
+
+fn clear_cache(env: usize) {
+  let keys = PROPERTY_KEYS
+    .try_with(|keys| keys.borrow_mut().remove(&env))
+    .ok()
+    .flatten();
+  for reference in keys.into_iter().flat_map(|keys| keys.into_values()) {
+    unsafe { sys::napi_delete_reference(env as sys::napi_env, reference) };
+  }
+}
@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ptr;
+
+use crate::{check_status, env::with_source_url, sys, Env, Result};
+
+thread_local! {
+  /// Functions compiled by `Env::run_script_cached`, keyed by `Env` and then by the wrapped source
+  static SCRIPT_CACHE: RefCell<HashMap<usize, HashMap<String, sys::napi_ref>>> = Default::default();
+}
+
+/// Compile `body` into a function once per `Env`, and call it with the global object as `this`
+pub(crate) fn call_cached_script(
+  env: sys::napi_env,
+  body: &str,
+  filename: Option<&str>,
+) -> Result<sys::napi_value> {
+  let source = format!("(function () {{\n{}\n}})", body);
+  let source = match filename {
+    Some(filename) => with_source_url(&source, filename),
+    None => source,
+  };
+  let function = match cached_function(env, &source)? {
+    Some(function) => function,
+    None => compile(env, source)?,
+  };
+  let mut global = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_global(env, &mut global) },
+    "Get global object failed"
+  )?;
+  let mut result = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_call_function(env, global, function, 0, ptr::null(), &mut result) },
+    "Call cached script failed"
+  )?;
+  Ok(result)
+}
+
+fn cached_function(env: sys::napi_env, source: &str) -> Result<Option<sys::napi_value>> {
+  let Some(reference) = SCRIPT_CACHE.with(|cache| {
+    cache
+      .borrow()
+      .get(&(env as usize))
+      .and_then(|scripts| scripts.get(source).copied())
+  }) else {
+    return Ok(None);
+  };
+  let mut function = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_reference_value(env, reference, &mut function) },
+    "Get cached script failed"
+  )?;
+  Ok(Some(function))
+}
+
+fn compile(env: sys::napi_env, source: String) -> Result<sys::napi_value> {
+  let mut raw_source = ptr::null_mut();
+  check_status!(unsafe {
+    sys::napi_create_string_utf8(env, source.as_ptr().cast(), source.len(), &mut raw_source)
+  })?;
+  let mut function = ptr::null_mut();
+  check_status!(unsafe { sys::napi_run_script(env, raw_source, &mut function) })?;
+  let mut reference = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_reference(env, function, 1, &mut reference) },
+    "Create reference for cached script failed"
+  )?;
+  let is_first_script = SCRIPT_CACHE.with(|cache| {
+    let mut cache = cache.borrow_mut();
+    let scripts = cache.entry(env as usize).or_default();
+    scripts.insert(source, reference);
+    scripts.len() == 1
+  });
+  if is_first_script {
+    Env::from_raw(env).add_env_cleanup_hook(env as usize, clear_cache)?;
+  }
+  Ok(function)
+}
+
+fn clear_cache(env: usize) {
+  let scripts = SCRIPT_CACHE
+    .try_with(|cache| cache.borrow_mut().remove(&env))
+    .ok()
+    .flatten();
+  for reference in scripts
+    .into_iter()
+    .flat_map(|scripts| scripts.into_values())
+  {
+    unsafe { sys::napi_delete_reference(env as sys::napi_env, reference) };
+  }
+}
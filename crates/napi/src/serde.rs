@@ -0,0 +1,60 @@
+//! Convert between `serde` data and JavaScript values directly through Node-API, without a `JSON.stringify`/`JSON.parse` round-trip.
+//!
+//! ```
+//! use napi::bindgen_prelude::*;
+//! use napi::serde::{from_js_value, to_js_value_with_bytes_mode, BytesMode};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Image {
+//!   name: String,
+//!   #[serde(with = "serde_bytes")]
+//!   pixels: Vec<u8>,
+//! }
+//!
+//! #[napi]
+//! fn invert(env: Env, image: Unknown) -> Result<Unknown> {
+//!   let mut image: Image = from_js_value(image)?;
+//!   image.pixels.iter_mut().for_each(|p| *p = !*p);
+//!   to_js_value_with_bytes_mode(&env, &image, BytesMode::Array)
+//! }
+//! ```
+
+use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+
+pub use crate::js_values::BytesMode;
+use crate::js_values::{De, Ser};
+use crate::{Env, JsUnknown, Result};
+
+/// Serialize `value` into a JavaScript value, bytes are serialized into `Buffer`
+pub fn to_js_value<T>(env: &Env, value: &T) -> Result<JsUnknown>
+where
+  T: Serialize + ?Sized,
+{
+  to_js_value_with_bytes_mode(env, value, BytesMode::default())
+}
+
+/// Serialize `value` into a JavaScript value, with bytes serialized as `bytes_mode`
+pub fn to_js_value_with_bytes_mode<T>(
+  env: &Env,
+  value: &T,
+  bytes_mode: BytesMode,
+) -> Result<JsUnknown>
+where
+  T: Serialize + ?Sized,
+{
+  value
+    .serialize(Ser::new(env).with_bytes_mode(bytes_mode))
+    .map(JsUnknown)
+}
+
+/// Deserialize `T` from a JavaScript value
+///
+/// Bytes can be deserialized from `Buffer`, any `TypedArray` or `number[]`.
+pub fn from_js_value<T>(value: JsUnknown) -> Result<T>
+where
+  T: DeserializeOwned,
+{
+  let mut de = De(&value.0);
+  T::deserialize(&mut de)
+}
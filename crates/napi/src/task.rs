@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use crate::{
   bindgen_runtime::{ToNapiValue, TypeName},
   Env, Error, Result,
@@ -25,3 +27,30 @@ pub trait Task: Send + Sized {
     Ok(())
   }
 }
+
+/// The `Task` behind `Env::spawn_blocking`
+pub(crate) struct BlockingTask<F, R> {
+  pub(crate) func: Option<F>,
+  pub(crate) _output: PhantomData<R>,
+}
+
+impl<F, R> Task for BlockingTask<F, R>
+where
+  F: 'static + Send + FnOnce() -> Result<R>,
+  R: 'static + Send + ToNapiValue + TypeName,
+{
+  type Output = R;
+  type JsValue = R;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    let func = self
+      .func
+      .take()
+      .expect("Blocking task has been computed already");
+    func()
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
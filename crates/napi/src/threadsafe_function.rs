@@ -9,6 +9,8 @@ use std::sync::{
   Arc, RwLock, RwLockWriteGuard,
 };
 
+#[cfg(feature = "tokio_rt")]
+use crate::bindgen_runtime::Promise;
 use crate::bindgen_runtime::{
   FromNapiValue, JsValuesTupleIntoVec, TypeName, Unknown, ValidateNapiValue,
 };
@@ -114,10 +116,12 @@ impl Drop for ThreadsafeFunctionHandle {
   }
 }
 
-#[repr(u8)]
 enum ThreadsafeFunctionCallVariant {
   Direct,
   WithCallback,
+  /// Like `WithCallback`, but a returned `Promise` which can't be converted to `Return` is handed to this callback
+  #[cfg(feature = "tokio_rt")]
+  WithPromiseCallback(Box<dyn FnOnce(sys::napi_env, sys::napi_value) -> Result<()>>),
 }
 
 struct ThreadsafeFunctionCallJsBackData<T, Return = Unknown> {
@@ -379,6 +383,36 @@ impl<
   pub fn raw(&self) -> sys::napi_threadsafe_function {
     self.handle.get_raw()
   }
+
+  /// Queue the call data, the data is dropped if `napi_call_threadsafe_function` rejects the call.
+  fn try_call_raw<D>(&self, data: D, mode: ThreadsafeFunctionCallMode) -> Result<()> {
+    self.handle.with_read_aborted(|aborted| {
+      if aborted {
+        return Err(Error::new(
+          Status::Closing,
+          "Threadsafe function has been aborted",
+        ));
+      }
+
+      let data = Box::into_raw(Box::new(data));
+      let status = unsafe {
+        sys::napi_call_threadsafe_function(self.handle.get_raw(), data.cast(), mode.into())
+      };
+      if status == sys::Status::napi_ok {
+        return Ok(());
+      }
+      // `call_js_cb` will never receive the rejected data, so it must be released here
+      drop(unsafe { Box::from_raw(data) });
+      Err(match Status::from(status) {
+        Status::QueueFull => Error::new(
+          Status::QueueFull,
+          format!("Threadsafe function queue is full, the max queue size is {MaxQueueSize}"),
+        ),
+        Status::Closing => Error::new(Status::Closing, "Threadsafe function is closing"),
+        status => Error::new(status, "Call threadsafe function failed"),
+      })
+    })
+  }
 }
 
 impl<
@@ -389,6 +423,21 @@ impl<
     const MaxQueueSize: usize,
   > ThreadsafeFunction<T, Return, CallJsBackArgs, true, { Weak }, { MaxQueueSize }>
 {
+  /// Like `call`, but returns an `Err` with `Status::QueueFull` when the queue is full in `NonBlocking` mode,
+  /// or `Status::Closing` when the function has been released.
+  ///
+  /// Use `max_queue_size` on the `ThreadsafeFunctionBuilder` to bound the queue.
+  pub fn try_call(&self, value: Result<T>, mode: ThreadsafeFunctionCallMode) -> Result<()> {
+    self.try_call_raw(
+      value.map(|data| ThreadsafeFunctionCallJsBackData {
+        data,
+        call_variant: ThreadsafeFunctionCallVariant::Direct,
+        callback: Box::new(|_d: Result<Return>, _| Ok(())),
+      }),
+      mode,
+    )
+  }
+
   /// See [napi_call_threadsafe_function](https://nodejs.org/api/n-api.html#n_api_napi_call_threadsafe_function)
   /// for more information.
   pub fn call(&self, value: Result<T>, mode: ThreadsafeFunctionCallMode) -> Status {
@@ -447,8 +496,16 @@ impl<
 
   #[cfg(feature = "tokio_rt")]
   /// Call the ThreadsafeFunction, and handle the return value with in `async` way
+  ///
+  /// If the JavaScript callback returns a `Promise` and `Return` isn't a `Promise` itself, the returned future resolves with its settled value.
   pub async fn call_async(&self, value: Result<T>) -> Result<Return> {
-    let (sender, receiver) = tokio::sync::oneshot::channel::<Result<Return>>();
+    let receiver = self.send_async_call(value)?;
+    receive_async_return(receiver).await
+  }
+
+  #[cfg(feature = "tokio_rt")]
+  fn send_async_call(&self, value: Result<T>) -> Result<AsyncReturnReceiver<Return>> {
+    let (callback, call_variant, receiver) = async_call_handlers::<Return>();
 
     self.handle.with_read_aborted(|aborted| {
       if aborted {
@@ -462,13 +519,10 @@ impl<
             Box::into_raw(Box::new(value.map(|data| {
               ThreadsafeFunctionCallJsBackData {
                 data,
-                call_variant: ThreadsafeFunctionCallVariant::WithCallback,
+                call_variant,
                 callback: Box::new(move |d: Result<Return>, _| {
-                  sender
-                    .send(d)
-                    // The only reason for send to return Err is if the receiver isn't listening
-                    // Not hiding the error would result in a napi_fatal_error call, it's safe to ignore it instead.
-                    .or(Ok(()))
+                  callback(d);
+                  Ok(())
                 }),
               }
             })))
@@ -479,15 +533,7 @@ impl<
         "Threadsafe function call_async failed"
       )
     })?;
-    receiver
-      .await
-      .map_err(|_| {
-        crate::Error::new(
-          Status::GenericFailure,
-          "Receive value from threadsafe function sender failed",
-        )
-      })
-      .and_then(|ret| ret)
+    Ok(receiver)
   }
 }
 
@@ -499,6 +545,21 @@ impl<
     const MaxQueueSize: usize,
   > ThreadsafeFunction<T, Return, CallJsBackArgs, false, { Weak }, { MaxQueueSize }>
 {
+  /// Like `call`, but returns an `Err` with `Status::QueueFull` when the queue is full in `NonBlocking` mode,
+  /// or `Status::Closing` when the function has been released.
+  ///
+  /// Use `max_queue_size` on the `ThreadsafeFunctionBuilder` to bound the queue.
+  pub fn try_call(&self, value: T, mode: ThreadsafeFunctionCallMode) -> Result<()> {
+    self.try_call_raw(
+      ThreadsafeFunctionCallJsBackData {
+        data: value,
+        call_variant: ThreadsafeFunctionCallVariant::Direct,
+        callback: Box::new(|_d: Result<Return>, _: Env| Ok(())),
+      },
+      mode,
+    )
+  }
+
   /// See [napi_call_threadsafe_function](https://nodejs.org/api/n-api.html#n_api_napi_call_threadsafe_function)
   /// for more information.
   pub fn call(&self, value: T, mode: ThreadsafeFunctionCallMode) -> Status {
@@ -553,8 +614,16 @@ impl<
 
   #[cfg(feature = "tokio_rt")]
   /// Call the ThreadsafeFunction, and handle the return value with in `async` way
+  ///
+  /// If the JavaScript callback returns a `Promise` and `Return` isn't a `Promise` itself, the returned future resolves with its settled value.
   pub async fn call_async(&self, value: T) -> Result<Return> {
-    let (sender, receiver) = tokio::sync::oneshot::channel::<Return>();
+    let receiver = self.send_async_call(value)?;
+    receive_async_return(receiver).await
+  }
+
+  #[cfg(feature = "tokio_rt")]
+  fn send_async_call(&self, value: T) -> Result<AsyncReturnReceiver<Return>> {
+    let (callback, call_variant, receiver) = async_call_handlers::<Return>();
 
     self.handle.with_read_aborted(|aborted| {
       if aborted {
@@ -566,14 +635,10 @@ impl<
           self.handle.get_raw(),
           Box::into_raw(Box::new(ThreadsafeFunctionCallJsBackData {
             data: value,
-            call_variant: ThreadsafeFunctionCallVariant::WithCallback,
-            callback: Box::new(move |d, _| {
-              d.and_then(|d| {
-                sender
-                  .send(d)
-                  // The only reason for send to return Err is if the receiver isn't listening
-                  // Not hiding the error would result in a napi_fatal_error call, it's safe to ignore it instead.
-                  .or(Ok(()))
+            call_variant,
+            callback: Box::new(move |d: Result<Return>, _| {
+              d.map(|d| {
+                callback(Ok(d));
               })
             }),
           }))
@@ -582,10 +647,61 @@ impl<
         )
       })
     })?;
+    Ok(receiver)
+  }
+}
 
-    receiver
-      .await
-      .map_err(|err| crate::Error::new(Status::GenericFailure, format!("{}", err)))
+#[cfg(feature = "tokio_rt")]
+enum AsyncReturn<Return: FromNapiValue> {
+  Value(Return),
+  Promise(Promise<Return>),
+}
+
+#[cfg(feature = "tokio_rt")]
+type AsyncReturnReceiver<Return> = tokio::sync::oneshot::Receiver<Result<AsyncReturn<Return>>>;
+
+/// Create the handlers of a `call_async`, only one of them is called on the JavaScript thread:
+/// the returned callback with the converted return value, or the `WithPromiseCallback` variant with a returned `Promise` that can't be converted to `Return`.
+#[cfg(feature = "tokio_rt")]
+#[allow(clippy::type_complexity)]
+fn async_call_handlers<Return: 'static + FromNapiValue>() -> (
+  Box<dyn FnOnce(Result<Return>)>,
+  ThreadsafeFunctionCallVariant,
+  AsyncReturnReceiver<Return>,
+) {
+  let (sender, receiver) = tokio::sync::oneshot::channel();
+  let sender = Arc::new(sync::Mutex::new(Some(sender)));
+  let send = move |value: Result<AsyncReturn<Return>>| {
+    if let Some(sender) = sender.lock().ok().and_then(|mut sender| sender.take()) {
+      // The only reason for send to return Err is if the receiver isn't listening
+      // Not hiding the error would result in a napi_fatal_error call, it's safe to ignore it instead.
+      let _ = sender.send(value);
+    }
+  };
+  let send_promise = send.clone();
+  (
+    Box::new(move |value| send(value.map(AsyncReturn::Value))),
+    ThreadsafeFunctionCallVariant::WithPromiseCallback(Box::new(move |env, promise| {
+      send_promise(unsafe { Promise::from_napi_value(env, promise) }.map(AsyncReturn::Promise));
+      Ok(())
+    })),
+    receiver,
+  )
+}
+
+#[cfg(feature = "tokio_rt")]
+async fn receive_async_return<Return: FromNapiValue>(
+  receiver: AsyncReturnReceiver<Return>,
+) -> Result<Return> {
+  let value = receiver.await.map_err(|_| {
+    crate::Error::new(
+      Status::GenericFailure,
+      "Receive value from threadsafe function sender failed",
+    )
+  })??;
+  match value {
+    AsyncReturn::Value(value) => Ok(value),
+    AsyncReturn::Promise(promise) => promise.await,
   }
 }
 
@@ -671,7 +787,7 @@ unsafe extern "C" fn call_js_cb<
         args.as_ptr(),
         &mut return_value,
       );
-      if let ThreadsafeFunctionCallVariant::WithCallback = call_variant {
+      if !matches!(call_variant, ThreadsafeFunctionCallVariant::Direct) {
         // throw Error in JavaScript callback
         let callback_arg = if status == sys::Status::napi_pending_exception {
           let mut exception = ptr::null_mut();
@@ -682,11 +798,24 @@ unsafe extern "C" fn call_js_cb<
             maybe_raw: error_reference,
             status: Status::from(status),
             reason: "".to_owned(),
+            extra: None,
           })
         } else {
           unsafe { Return::from_napi_value(raw_env, return_value) }
         };
-        if let Err(err) = callback(callback_arg, Env::from_raw(raw_env)) {
+        let result = match call_variant {
+          // a `Promise` which can't be taken as `Return` directly is awaited instead
+          #[cfg(feature = "tokio_rt")]
+          ThreadsafeFunctionCallVariant::WithPromiseCallback(promise_callback)
+            if callback_arg.is_err()
+              && status == sys::Status::napi_ok
+              && is_promise(raw_env, return_value) =>
+          {
+            promise_callback(raw_env, return_value)
+          }
+          _ => callback(callback_arg, Env::from_raw(raw_env)),
+        };
+        if let Err(err) = result {
           unsafe { sys::napi_fatal_exception(raw_env, JsError::from(err).into_value(raw_env)) };
         }
       }
@@ -709,6 +838,13 @@ unsafe extern "C" fn call_js_cb<
   handle_call_js_cb_status(status, raw_env)
 }
 
+#[cfg(feature = "tokio_rt")]
+fn is_promise(env: sys::napi_env, value: sys::napi_value) -> bool {
+  let mut is_promise = false;
+  let status = unsafe { sys::napi_is_promise(env, value, &mut is_promise) };
+  status == sys::Status::napi_ok && is_promise
+}
+
 fn handle_call_js_cb_status(status: sys::napi_status, raw_env: sys::napi_env) {
   if status == sys::Status::napi_ok {
     return;
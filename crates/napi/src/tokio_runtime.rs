@@ -1,6 +1,10 @@
-use std::{future::Future, marker::PhantomData, sync::RwLock};
+use std::{
+  future::Future,
+  marker::PhantomData,
+  sync::{Mutex, RwLock},
+};
 
-use once_cell::sync::{Lazy, OnceCell};
+use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
 
 use crate::{sys, Error, JsDeferred, JsUnknown, NapiValue, Result};
@@ -22,14 +26,14 @@ fn create_runtime() -> Option<Runtime> {
 }
 
 pub(crate) static RT: Lazy<RwLock<Option<Runtime>>> = Lazy::new(|| {
-  if let Some(user_defined_rt) = unsafe { USER_DEFINED_RT.take() } {
-    RwLock::new(user_defined_rt)
-  } else {
-    RwLock::new(create_runtime())
-  }
+  let user_defined_rt = USER_DEFINED_RT
+    .lock()
+    .expect("Lock USER_DEFINED_RT failed")
+    .take();
+  RwLock::new(user_defined_rt.or_else(create_runtime))
 });
 
-static mut USER_DEFINED_RT: OnceCell<Option<Runtime>> = OnceCell::new();
+static USER_DEFINED_RT: Mutex<Option<Runtime>> = Mutex::new(None);
 
 /// Create a custom Tokio runtime used by the NAPI-RS.
 /// You can control the tokio runtime configuration by yourself.
@@ -44,8 +48,9 @@ static mut USER_DEFINED_RT: OnceCell<Option<Runtime>> = OnceCell::new();
 ///    create_custom_tokio_runtime(rt);
 /// }
 pub fn create_custom_tokio_runtime(rt: Runtime) {
-  unsafe {
-    USER_DEFINED_RT.get_or_init(move || Some(rt));
+  let mut user_defined_rt = USER_DEFINED_RT.lock().expect("Lock USER_DEFINED_RT failed");
+  if user_defined_rt.is_none() {
+    *user_defined_rt = Some(rt);
   }
 }
 
@@ -201,3 +206,6 @@ pub fn execute_tokio_future<
 
   Ok(promise.0.value)
 }
+
+/// The name of `execute_tokio_future` shared with the `async_std_rt` feature, the `#[napi] async fn` are run by it
+pub use self::execute_tokio_future as execute_future;
@@ -0,0 +1,201 @@
+use std::fmt;
+use std::sync::{
+  atomic::{AtomicU8, Ordering},
+  Arc,
+};
+
+use tracing::{
+  field::{Field, Visit},
+  level_filters::LevelFilter,
+  Event, Level, Metadata, Subscriber,
+};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::{
+  bindgen_runtime::{Function, ToNapiValue, Unknown},
+  sys,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  Env, Error, Result, Status,
+};
+
+/// An event of `tracing`, passed to the JavaScript logger of the [`JsLoggerLayer`] as
+/// `{ level, target, message, fields }`
+pub struct LogRecord {
+  /// `"error"`, `"warn"`, `"info"`, `"debug"` or `"trace"`
+  pub level: &'static str,
+  pub target: String,
+  pub message: String,
+  /// The other fields of the event, formatted with `Debug` except the strings
+  pub fields: Vec<(&'static str, String)>,
+}
+
+impl ToNapiValue for LogRecord {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env_wrapper = Env::from_raw(env);
+    let mut obj = env_wrapper.create_object()?;
+    obj.set("level", val.level)?;
+    obj.set("target", val.target)?;
+    obj.set("message", val.message)?;
+    let mut fields = env_wrapper.create_object()?;
+    for (name, value) in val.fields {
+      fields.set(name, value)?;
+    }
+    obj.set("fields", fields)?;
+    unsafe { ToNapiValue::to_napi_value(env, obj) }
+  }
+}
+
+#[derive(Default)]
+struct LogRecordVisitor {
+  message: String,
+  fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for LogRecordVisitor {
+  fn record_str(&mut self, field: &Field, value: &str) {
+    if field.name() == "message" {
+      self.message = value.to_owned();
+    } else {
+      self.fields.push((field.name(), value.to_owned()));
+    }
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+    if field.name() == "message" {
+      self.message = format!("{:?}", value);
+    } else {
+      self.fields.push((field.name(), format!("{:?}", value)));
+    }
+  }
+}
+
+/// The level filter of a [`JsLoggerLayer`], which can be changed from JavaScript after the subscriber is installed.
+#[derive(Clone)]
+pub struct JsLoggerLevel(Arc<AtomicU8>);
+
+impl JsLoggerLevel {
+  /// Sets the most verbose level forwarded to the logger,
+  /// one of `"off"`, `"error"`, `"warn"`, `"info"`, `"debug"` or `"trace"`
+  pub fn set(&self, level: &str) -> Result<()> {
+    let filter = level
+      .parse::<LevelFilter>()
+      .map_err(|_| Error::new(Status::InvalidArg, format!("Unknown log level `{}`", level)))?;
+    self.0.store(filter_to_u8(filter), Ordering::Relaxed);
+    // the callsites cache whether they are enabled, and the max level is cached by `tracing` as well
+    tracing::callsite::rebuild_interest_cache();
+    Ok(())
+  }
+
+  pub fn get(&self) -> LevelFilter {
+    u8_to_filter(self.0.load(Ordering::Relaxed))
+  }
+}
+
+fn filter_to_u8(filter: LevelFilter) -> u8 {
+  match filter.into_level() {
+    None => 0,
+    Some(Level::ERROR) => 1,
+    Some(Level::WARN) => 2,
+    Some(Level::INFO) => 3,
+    Some(Level::DEBUG) => 4,
+    Some(_) => 5,
+  }
+}
+
+fn u8_to_filter(level: u8) -> LevelFilter {
+  match level {
+    0 => LevelFilter::OFF,
+    1 => LevelFilter::ERROR,
+    2 => LevelFilter::WARN,
+    3 => LevelFilter::INFO,
+    4 => LevelFilter::DEBUG,
+    _ => LevelFilter::TRACE,
+  }
+}
+
+fn level_name(level: &Level) -> &'static str {
+  match *level {
+    Level::ERROR => "error",
+    Level::WARN => "warn",
+    Level::INFO => "info",
+    Level::DEBUG => "debug",
+    _ => "trace",
+  }
+}
+
+/// A `tracing_subscriber` [`Layer`] forwarding the `tracing` events to a JavaScript logger.
+///
+/// The logger is called on the JavaScript thread with a [`LogRecord`], by a weak `ThreadsafeFunction`
+/// which doesn't keep the process alive. The events are dropped if the queue of the JavaScript thread is closing.
+///
+/// ```
+/// use std::sync::OnceLock;
+///
+/// use napi::{bindgen_prelude::*, tracing_layer::{JsLoggerLayer, JsLoggerLevel, LogRecord}};
+/// use tracing_subscriber::prelude::*;
+///
+/// static LEVEL: OnceLock<JsLoggerLevel> = OnceLock::new();
+///
+/// #[napi]
+/// pub fn init_logger(logger: Function<LogRecord, Unknown>, level: String) -> Result<()> {
+///   let layer = JsLoggerLayer::new(logger, &level)?;
+///   LEVEL.get_or_init(|| layer.level());
+///   tracing_subscriber::registry().with(layer).init();
+///   Ok(())
+/// }
+///
+/// #[napi]
+/// pub fn set_log_level(level: String) -> Result<()> {
+///   LEVEL.get().map(|l| l.set(&level)).unwrap_or(Ok(()))
+/// }
+/// ```
+pub struct JsLoggerLayer {
+  logger: ThreadsafeFunction<LogRecord, Unknown, LogRecord, false, true>,
+  level: JsLoggerLevel,
+}
+
+impl JsLoggerLayer {
+  /// `level` is parsed like [`JsLoggerLevel::set`]
+  pub fn new(logger: Function<LogRecord, Unknown>, level: &str) -> Result<Self> {
+    let logger = logger
+      .build_threadsafe_function::<LogRecord>()
+      .weak::<true>()
+      .build()?;
+    let level_filter = JsLoggerLevel(Arc::new(AtomicU8::new(0)));
+    level_filter.set(level)?;
+    Ok(Self {
+      logger,
+      level: level_filter,
+    })
+  }
+
+  /// The handle to change the level filter of this layer
+  pub fn level(&self) -> JsLoggerLevel {
+    self.level.clone()
+  }
+}
+
+impl<S: Subscriber> Layer<S> for JsLoggerLayer {
+  fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+    *metadata.level() <= self.level.get()
+  }
+
+  fn max_level_hint(&self) -> Option<LevelFilter> {
+    Some(self.level.get())
+  }
+
+  fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+    let metadata = event.metadata();
+    let mut visitor = LogRecordVisitor::default();
+    event.record(&mut visitor);
+    self.logger.call(
+      LogRecord {
+        level: level_name(metadata.level()),
+        target: metadata.target().to_owned(),
+        message: visitor.message,
+        fields: visitor.fields,
+      },
+      ThreadsafeFunctionCallMode::NonBlocking,
+    );
+  }
+}
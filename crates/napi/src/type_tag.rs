@@ -0,0 +1,99 @@
+#[cfg(feature = "napi8")]
+use crate::{check_status, sys};
+use crate::{Error, Result, Status};
+
+/// A 128-bit tag attached to a JavaScript object with `JsObject::type_tag`, usually a UUID.
+///
+/// Unlike `instanceof`, the tag can't be forged from JavaScript or by another addon, so it's safe to check it before unwrapping a native pointer.
+///
+/// Tagging requires the `napi8` feature, without it tags are never attached nor checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeTag {
+  lower: u64,
+  upper: u64,
+}
+
+impl TypeTag {
+  pub const fn new(lower: u64, upper: u64) -> Self {
+    Self { lower, upper }
+  }
+
+  /// Parse a UUID like `3d9c8b29-63f3-4f4b-a9b6-6e3c3f2fcd22`, the first 64 bits are the `upper` half
+  pub fn from_uuid(uuid: &str) -> Result<Self> {
+    let hex = uuid.replace('-', "");
+    if hex.len() != 32 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Invalid type tag UUID `{}`", uuid),
+      ));
+    }
+    let value = u128::from_str_radix(&hex, 16).map_err(|_| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Invalid type tag UUID `{}`", uuid),
+      )
+    })?;
+    Ok(Self::new(value as u64, (value >> 64) as u64))
+  }
+
+  /// The tag of a subclass, derived from the tag of its base class and its JavaScript name
+  #[doc(hidden)]
+  pub const fn subclass(&self, js_name: &str) -> Self {
+    // FNV-1a of the name, seeded with each half of the base tag
+    const PRIME: u64 = 0x100000001b3;
+    let name = js_name.as_bytes();
+    let mut lower = self.lower ^ 0xcbf29ce484222325;
+    let mut upper = self.upper ^ 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < name.len() {
+      lower = (lower ^ name[i] as u64).wrapping_mul(PRIME);
+      upper = (upper ^ name[i] as u64).wrapping_mul(PRIME);
+      i += 1;
+    }
+    Self::new(lower, upper)
+  }
+
+  pub fn lower(&self) -> u64 {
+    self.lower
+  }
+
+  pub fn upper(&self) -> u64 {
+    self.upper
+  }
+}
+
+#[cfg(feature = "napi8")]
+impl From<&TypeTag> for sys::napi_type_tag {
+  fn from(tag: &TypeTag) -> Self {
+    sys::napi_type_tag {
+      lower: tag.lower,
+      upper: tag.upper,
+    }
+  }
+}
+
+#[cfg(feature = "napi8")]
+pub(crate) unsafe fn type_tag_object(
+  env: sys::napi_env,
+  value: sys::napi_value,
+  tag: &TypeTag,
+) -> Result<()> {
+  check_status!(
+    unsafe { sys::napi_type_tag_object(env, value, &tag.into()) },
+    "Type tag object failed"
+  )
+}
+
+#[cfg(feature = "napi8")]
+pub(crate) unsafe fn check_object_type_tag(
+  env: sys::napi_env,
+  value: sys::napi_value,
+  tag: &TypeTag,
+) -> Result<bool> {
+  let mut result = false;
+  check_status!(
+    unsafe { sys::napi_check_object_type_tag(env, value, &tag.into(), &mut result) },
+    "Check object type tag failed"
+  )?;
+  Ok(result)
+}
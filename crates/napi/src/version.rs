@@ -1,14 +1,29 @@
 use crate::{sys, Error, Status};
 use std::ffi::CStr;
+use std::fmt;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NodeVersion {
   pub major: u32,
   pub minor: u32,
   pub patch: u32,
+  /// `process.release.name`, `node` for Node.js
   pub release: &'static str,
 }
 
+impl NodeVersion {
+  /// Whether this version is `major.minor.patch` or later
+  pub fn at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+    (self.major, self.minor, self.patch) >= (major, minor, patch)
+  }
+}
+
+impl fmt::Display for NodeVersion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)
+  }
+}
+
 impl TryFrom<sys::napi_node_version> for NodeVersion {
   type Error = Error;
 
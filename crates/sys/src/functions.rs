@@ -484,12 +484,12 @@ mod napi1 {
   );
 }
 
-#[cfg(feature = "napi2")]
 mod napi2 {
   use super::super::types::*;
   use std::os::raw::c_int;
 
-  generate!(
+  generate_versioned!(
+    "napi2",
     extern "C" {
       fn napi_get_uv_event_loop(env: napi_env, loop_: *mut *mut uv_loop_s) -> napi_status;
 
@@ -498,13 +498,13 @@ mod napi2 {
   );
 }
 
-#[cfg(feature = "napi3")]
 mod napi3 {
   use std::os::raw::c_void;
 
   use super::super::types::*;
 
-  generate!(
+  generate_versioned!(
+    "napi3",
     extern "C" {
       fn napi_fatal_exception(env: napi_env, err: napi_value) -> napi_status;
       fn napi_add_env_cleanup_hook(
@@ -528,12 +528,12 @@ mod napi3 {
   );
 }
 
-#[cfg(feature = "napi4")]
 mod napi4 {
   use super::super::types::*;
   use std::os::raw::c_void;
 
-  generate!(
+  generate_versioned!(
+    "napi4",
     extern "C" {
       fn napi_create_threadsafe_function(
         env: napi_env,
@@ -572,12 +572,12 @@ mod napi4 {
   );
 }
 
-#[cfg(feature = "napi5")]
 mod napi5 {
   use super::super::types::*;
   use std::ffi::c_void;
 
-  generate!(
+  generate_versioned!(
+    "napi5",
     extern "C" {
       fn napi_create_date(env: napi_env, time: f64, result: *mut napi_value) -> napi_status;
       fn napi_is_date(env: napi_env, value: napi_value, is_date: *mut bool) -> napi_status;
@@ -594,12 +594,12 @@ mod napi5 {
   );
 }
 
-#[cfg(feature = "napi6")]
 mod napi6 {
   use super::super::types::*;
   use std::os::raw::{c_int, c_void};
 
-  generate!(
+  generate_versioned!(
+    "napi6",
     extern "C" {
       fn napi_create_bigint_int64(
         env: napi_env,
@@ -656,11 +656,11 @@ mod napi6 {
   );
 }
 
-#[cfg(feature = "napi7")]
 mod napi7 {
   use super::super::types::*;
 
-  generate!(
+  generate_versioned!(
+    "napi7",
     extern "C" {
       fn napi_detach_arraybuffer(env: napi_env, arraybuffer: napi_value) -> napi_status;
       fn napi_is_detached_arraybuffer(
@@ -672,13 +672,13 @@ mod napi7 {
   );
 }
 
-#[cfg(feature = "napi8")]
 mod napi8 {
   use std::os::raw::c_void;
 
   use super::super::types::*;
 
-  generate!(
+  generate_versioned!(
+    "napi8",
     extern "C" {
       fn napi_add_async_cleanup_hook(
         env: napi_env,
@@ -694,17 +694,30 @@ mod napi8 {
       fn napi_object_freeze(env: napi_env, object: napi_value) -> napi_status;
 
       fn napi_object_seal(env: napi_env, object: napi_value) -> napi_status;
+
+      fn napi_type_tag_object(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+      ) -> napi_status;
+
+      fn napi_check_object_type_tag(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+        result: *mut bool,
+      ) -> napi_status;
     }
   );
 }
 
-#[cfg(feature = "napi9")]
 mod napi9 {
   use std::os::raw::c_char;
 
   use super::super::types::*;
 
-  generate!(
+  generate_versioned!(
+    "napi9",
     extern "C" {
       fn node_api_symbol_for(
         env: napi_env,
@@ -728,13 +741,50 @@ mod napi9 {
   );
 }
 
-#[cfg(feature = "experimental")]
+mod napi10 {
+  use std::os::raw::c_char;
+
+  use super::super::types::*;
+
+  generate_versioned!(
+    "napi10",
+    extern "C" {
+      fn node_api_create_property_key_latin1(
+        env: napi_env,
+        str_: *const c_char,
+        length: usize,
+        result: *mut napi_value,
+      ) -> napi_status;
+      fn node_api_create_property_key_utf8(
+        env: napi_env,
+        str_: *const c_char,
+        length: usize,
+        result: *mut napi_value,
+      ) -> napi_status;
+      fn node_api_create_property_key_utf16(
+        env: napi_env,
+        str_: *const u16,
+        length: usize,
+        result: *mut napi_value,
+      ) -> napi_status;
+      fn node_api_create_buffer_from_arraybuffer(
+        env: napi_env,
+        arraybuffer: napi_value,
+        byte_offset: usize,
+        byte_length: usize,
+        result: *mut napi_value,
+      ) -> napi_status;
+    }
+  );
+}
+
 mod experimental {
   use std::os::raw::{c_char, c_void};
 
   use super::super::types::*;
 
-  generate!(
+  generate_versioned!(
+    "experimental",
     extern "C" {
       fn node_api_create_external_string_latin1(
         env: napi_env,
@@ -755,29 +805,28 @@ mod experimental {
         result: *mut napi_value,
         copied: *mut bool,
       ) -> napi_status;
+
+      fn node_api_post_finalizer(
+        env: napi_env,
+        finalize_cb: napi_finalize,
+        finalize_data: *mut c_void,
+        finalize_hint: *mut c_void,
+      ) -> napi_status;
     }
   );
 }
 
-#[cfg(feature = "experimental")]
 pub use experimental::*;
 
 pub use napi1::*;
-#[cfg(feature = "napi2")]
+pub use napi10::*;
 pub use napi2::*;
-#[cfg(feature = "napi3")]
 pub use napi3::*;
-#[cfg(feature = "napi4")]
 pub use napi4::*;
-#[cfg(feature = "napi5")]
 pub use napi5::*;
-#[cfg(feature = "napi6")]
 pub use napi6::*;
-#[cfg(feature = "napi7")]
 pub use napi7::*;
-#[cfg(feature = "napi8")]
 pub use napi8::*;
-#[cfg(feature = "napi9")]
 pub use napi9::*;
 
 #[cfg(all(windows, not(target_env = "msvc"), feature = "dyn-symbols"))]
@@ -846,6 +895,8 @@ pub(super) unsafe fn load_all() -> Result<libloading::Library, libloading::Error
   napi8::load(&host)?;
   #[cfg(feature = "napi9")]
   napi9::load(&host)?;
+  #[cfg(feature = "napi10")]
+  napi10::load(&host)?;
   #[cfg(feature = "experimental")]
   experimental::load(&host)?;
   Ok(host)
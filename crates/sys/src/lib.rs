@@ -81,8 +81,56 @@ macro_rules! generate {
   };
 }
 
+// the functions of a Node-API version which feature isn't enabled
+//
+// calling them fails to compile, with a warning naming the feature to enable,
+// or returns `napi_generic_failure` with the `runtime-version-check` feature,
+// for the code checking the Node-API version of the runtime before calling them
+macro_rules! generate_versioned {
+  ("napi2", $($decls:tt)+) => { generate_versioned!(@ "napi2", "requires the `napi2` feature of napi-sys", $($decls)+); };
+  ("napi3", $($decls:tt)+) => { generate_versioned!(@ "napi3", "requires the `napi3` feature of napi-sys", $($decls)+); };
+  ("napi4", $($decls:tt)+) => { generate_versioned!(@ "napi4", "requires the `napi4` feature of napi-sys", $($decls)+); };
+  ("napi5", $($decls:tt)+) => { generate_versioned!(@ "napi5", "requires the `napi5` feature of napi-sys", $($decls)+); };
+  ("napi6", $($decls:tt)+) => { generate_versioned!(@ "napi6", "requires the `napi6` feature of napi-sys", $($decls)+); };
+  ("napi7", $($decls:tt)+) => { generate_versioned!(@ "napi7", "requires the `napi7` feature of napi-sys", $($decls)+); };
+  ("napi8", $($decls:tt)+) => { generate_versioned!(@ "napi8", "requires the `napi8` feature of napi-sys", $($decls)+); };
+  ("napi9", $($decls:tt)+) => { generate_versioned!(@ "napi9", "requires the `napi9` feature of napi-sys", $($decls)+); };
+  ("napi10", $($decls:tt)+) => { generate_versioned!(@ "napi10", "requires the `napi10` feature of napi-sys", $($decls)+); };
+  ("experimental", $($decls:tt)+) => { generate_versioned!(@ "experimental", "requires the `experimental` feature of napi-sys", $($decls)+); };
+  (@ $feature:literal, $note:literal, extern "C" {
+    $(fn $name:ident($($param:ident: $ptype:ty$(,)?)*)$( -> $rtype:ty)?;)+
+  }) => {
+    #[cfg(feature = $feature)]
+    generate!(extern "C" {
+      $(fn $name($($param: $ptype,)*)$( -> $rtype)?;)+
+    });
+
+    $(
+      #[cfg(all(not(feature = $feature), not(feature = "runtime-version-check")))]
+      #[deprecated(note = $note)]
+      #[allow(clippy::missing_safety_doc, unused_variables)]
+      pub unsafe fn $name<EnableTheFeature: crate::unavailable::FeatureNotEnabled>(
+        $($param: $ptype,)*
+      )$( -> $rtype)* {
+        unreachable!()
+      }
+
+      #[cfg(all(not(feature = $feature), feature = "runtime-version-check"))]
+      #[allow(clippy::missing_safety_doc, unused_variables)]
+      pub unsafe fn $name($($param: $ptype,)*)$( -> $rtype)* {
+        crate::Status::napi_generic_failure
+      }
+    )+
+  };
+}
+
 mod functions;
 mod types;
+mod unavailable {
+  /// Never implemented, so the functions of the disabled Node-API versions can't be called
+  #[allow(dead_code)]
+  pub trait FeatureNotEnabled {}
+}
 
 pub use functions::*;
 pub use types::*;
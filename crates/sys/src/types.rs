@@ -168,20 +168,16 @@ pub struct napi_extended_error_info {
   pub error_code: napi_status,
 }
 
-#[cfg(feature = "napi6")]
 pub type napi_key_collection_mode = i32;
 
-#[cfg(feature = "napi6")]
 pub mod KeyCollectionMode {
   pub use super::napi_key_collection_mode;
   pub const include_prototypes: napi_key_collection_mode = 0;
   pub const own_only: napi_key_collection_mode = 1;
 }
 
-#[cfg(feature = "napi6")]
 pub type napi_key_filter = i32;
 
-#[cfg(feature = "napi6")]
 pub mod KeyFilter {
   use super::napi_key_filter;
 
@@ -193,27 +189,28 @@ pub mod KeyFilter {
   pub const skip_symbols: napi_key_filter = 1 << 4;
 }
 
-#[cfg(feature = "napi6")]
 pub type napi_key_conversion = i32;
 
-#[cfg(feature = "napi6")]
 pub mod KeyConversion {
   use super::napi_key_conversion;
 
   pub const keep_numbers: napi_key_conversion = 0;
   pub const numbers_to_strings: napi_key_conversion = 1;
 }
-#[cfg(feature = "napi8")]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct napi_async_cleanup_hook_handle__ {
   _unused: [u8; 0],
 }
-#[cfg(feature = "napi8")]
 pub type napi_async_cleanup_hook_handle = *mut napi_async_cleanup_hook_handle__;
-#[cfg(feature = "napi8")]
 pub type napi_async_cleanup_hook =
   Option<unsafe extern "C" fn(handle: napi_async_cleanup_hook_handle, data: *mut c_void)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct napi_type_tag {
+  pub lower: u64,
+  pub upper: u64,
+}
 
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -234,30 +231,24 @@ pub struct napi_async_work__ {
 }
 pub type napi_async_work = *mut napi_async_work__;
 
-#[cfg(feature = "napi4")]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct napi_threadsafe_function__ {
   _unused: [u8; 0],
 }
 
-#[cfg(feature = "napi4")]
 pub type napi_threadsafe_function = *mut napi_threadsafe_function__;
 
-#[cfg(feature = "napi4")]
 pub type napi_threadsafe_function_release_mode = i32;
 
-#[cfg(feature = "napi4")]
 pub mod ThreadsafeFunctionReleaseMode {
   use super::napi_threadsafe_function_release_mode;
   pub const release: napi_threadsafe_function_release_mode = 0;
   pub const abort: napi_threadsafe_function_release_mode = 1;
 }
 
-#[cfg(feature = "napi4")]
 pub type napi_threadsafe_function_call_mode = i32;
 
-#[cfg(feature = "napi4")]
 pub mod ThreadsafeFunctionCallMode {
   use super::napi_threadsafe_function_call_mode;
 
@@ -270,7 +261,6 @@ pub type napi_async_execute_callback =
 pub type napi_async_complete_callback =
   Option<unsafe extern "C" fn(env: napi_env, status: napi_status, data: *mut c_void)>;
 
-#[cfg(feature = "napi4")]
 pub type napi_threadsafe_function_call_js = Option<
   unsafe extern "C" fn(
     env: napi_env,
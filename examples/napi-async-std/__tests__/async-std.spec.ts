@@ -0,0 +1,27 @@
+import { readFileSync } from 'node:fs'
+
+import test from 'ava'
+
+const bindings = require('../index.node')
+
+test('should run the async fn on the async-std executor', async (t) => {
+  t.is(await bindings.sleep(50), 50)
+})
+
+test('should read the file or reject with the io error', async (t) => {
+  t.is(
+    (await bindings.readFile(__filename)).toString('utf8'),
+    readFileSync(__filename, 'utf8'),
+  )
+  await t.throwsAsync(() => bindings.readFile('/not/exist'))
+})
+
+test('should reject on panic', async (t) => {
+  await t.throwsAsync(() => bindings.panicInAsync(), {
+    message: 'Panic in async-std task',
+  })
+})
+
+test('should block on the future', (t) => {
+  t.is(bindings.blockOnSleep(10), 10)
+})
@@ -0,0 +1,5 @@
+fn main() {
+  use napi_build::setup;
+
+  setup();
+}
@@ -0,0 +1,31 @@
+#![deny(clippy::all)]
+
+use std::time::Duration;
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+#[napi]
+pub async fn sleep(ms: u32) -> u32 {
+  async_std::task::sleep(Duration::from_millis(ms as u64)).await;
+  ms
+}
+
+#[napi]
+pub async fn read_file(path: String) -> Result<Buffer> {
+  async_std::fs::read(path)
+    .await
+    .map(Buffer::from)
+    .map_err(Error::from)
+}
+
+#[napi]
+pub async fn panic_in_async() {
+  panic!("Panic in async-std task");
+}
+
+#[napi]
+pub fn block_on_sleep(ms: u32) -> u32 {
+  block_on(async_std::task::sleep(Duration::from_millis(ms as u64)));
+  ms
+}
@@ -13,3 +13,10 @@ test('should resolve deferred from background thread', async (t) => {
 test('should reject deferred from background thread', async (t) => {
   await t.throwsAsync(() => bindings.testDeferred(true), { message: 'Fail' })
 })
+
+test('should resolve typed deferred with a Rust value', async (t) => {
+  t.deepEqual(await bindings.testTypedDeferred('napi'), ['napi', 'NAPI'])
+  await t.throwsAsync(() => bindings.testTypedDeferred(''), {
+    message: 'Empty name',
+  })
+})
@@ -47,3 +47,15 @@ test.serial('should be able to execute future paralleled', async (t) => {
     t.deepEqual(readFileSync(filepath), fileContent)
   }
 })
+
+test.serial('should await JsPromise from Rust', async (t) => {
+  if (napiVersion < 4) {
+    t.is(bindings.testAwaitJsPromise, undefined)
+    return
+  }
+  t.is(await bindings.testAwaitJsPromise(Promise.resolve(41)), 42)
+  await t.throwsAsync(
+    () => bindings.testAwaitJsPromise(Promise.reject(new Error('rejected'))),
+    { message: 'rejected' },
+  )
+})
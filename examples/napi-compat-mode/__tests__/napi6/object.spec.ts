@@ -0,0 +1,29 @@
+import test from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const bindings = require('../../index.node')
+
+test('should get own string keys', (t) => {
+  if (napiVersion >= 6) {
+    const sym = Symbol('sym')
+    const obj = Object.create({ inherited: 1 })
+    obj[0] = 'zero'
+    obj.own = 'own'
+    obj[sym] = 'symbol'
+    t.deepEqual(bindings.testGetOwnStringKeys(obj), ['0', 'own'])
+  } else {
+    t.is(bindings.testGetOwnStringKeys, undefined)
+  }
+})
+
+test('should get enumerable keys including prototypes', (t) => {
+  if (napiVersion >= 6) {
+    const obj = Object.create({ inherited: 1 })
+    obj[1] = 'one'
+    Object.defineProperty(obj, 'hidden', { value: 1, enumerable: false })
+    t.deepEqual(bindings.testGetAllKeys(obj), [1, 'inherited'])
+  } else {
+    t.is(bindings.testGetAllKeys, undefined)
+  }
+})
@@ -29,3 +29,18 @@ test('is detached arraybuffer should work fine', (t) => {
     t.is((e as any).code, 'DetachableArraybufferExpected')
   }
 })
+
+test('should detach ArrayBuffer created from Rust owned data', (t) => {
+  const ab = bindings.testCreateDetachableArrayBuffer(4)
+  t.is(ab.byteLength, 4)
+  bindings.testDetachArrayBuffer(ab)
+  t.true(bindings.testIsDetachedArrayBuffer(ab))
+  t.is(ab.byteLength, 0)
+})
+
+test('should transfer ArrayBuffer contents on detach', (t) => {
+  const ab = new Uint8Array([1, 2, 3]).buffer
+  const buf = bindings.testDetachArrayBufferIntoBuffer(ab)
+  t.deepEqual(buf, Buffer.from([1, 2, 3]))
+  t.true(bindings.testIsDetachedArrayBuffer(ab))
+})
@@ -1,6 +1,6 @@
 use std::thread;
 
-use napi::{CallContext, Error, JsObject, Result};
+use napi::{CallContext, Error, JsObject, JsString, Result};
 
 #[js_function(1)]
 pub fn test_deferred(ctx: CallContext) -> Result<JsObject> {
@@ -18,3 +18,20 @@ pub fn test_deferred(ctx: CallContext) -> Result<JsObject> {
 
   Ok(promise)
 }
+
+#[js_function(1)]
+pub fn test_typed_deferred(ctx: CallContext) -> Result<JsObject> {
+  let name = ctx.get::<JsString>(0)?.into_utf8()?.into_owned()?;
+  let (deferred, promise) = ctx.env.create_typed_deferred::<Vec<String>>()?;
+
+  thread::spawn(move || {
+    thread::sleep(std::time::Duration::from_millis(10));
+    if name.is_empty() {
+      deferred.reject(Error::from_reason("Empty name"));
+    } else {
+      deferred.resolve_value(vec![name.clone(), name.to_uppercase()]);
+    }
+  });
+
+  Ok(promise)
+}
@@ -25,6 +25,7 @@ pub fn register_js(exports: &mut JsObject, env: &Env) -> Result<()> {
   )?;
   exports.create_named_method("testTsfnWithRef", test_tsfn_with_ref)?;
   exports.create_named_method("testDeferred", deferred::test_deferred)?;
+  exports.create_named_method("testTypedDeferred", deferred::test_typed_deferred)?;
 
   let obj = env.define_class::<Unknown>(
     "A",
@@ -2,9 +2,11 @@ use napi::{JsObject, Result};
 
 mod bigint;
 mod instance;
+mod object;
 
 use bigint::*;
 use instance::*;
+use object::*;
 
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testCreateBigintFromI64", test_create_bigint_from_i64)?;
@@ -19,5 +21,8 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("setInstanceData", set_instance_data)?;
   exports.create_named_method("getInstanceData", get_instance_data)?;
   exports.create_named_method("getWrongTypeInstanceData", get_wrong_type_instance_data)?;
+
+  exports.create_named_method("testGetOwnStringKeys", test_get_own_string_keys)?;
+  exports.create_named_method("testGetAllKeys", test_get_all_keys)?;
   Ok(())
 }
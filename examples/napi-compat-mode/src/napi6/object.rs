@@ -0,0 +1,24 @@
+use napi::*;
+
+#[js_function(1)]
+pub fn test_get_own_string_keys(ctx: CallContext) -> Result<JsObject> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let keys = obj
+    .property_keys()
+    .own_only()
+    .skip_symbols()
+    .numbers_to_strings()
+    .iter::<String>()?
+    .collect::<Result<Vec<String>>>()?;
+  let mut arr = ctx.env.create_array_with_length(keys.len())?;
+  for (index, key) in keys.into_iter().enumerate() {
+    arr.set_element(index as u32, ctx.env.create_string_from_std(key)?)?;
+  }
+  Ok(arr)
+}
+
+#[js_function(1)]
+pub fn test_get_all_keys(ctx: CallContext) -> Result<JsObject> {
+  let obj = ctx.get::<JsObject>(0)?;
+  obj.property_keys().enumerable().into_array()
+}
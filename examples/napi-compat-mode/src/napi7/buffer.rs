@@ -12,3 +12,22 @@ pub fn is_detach_arraybuffer(ctx: CallContext) -> Result<JsBoolean> {
   let input = ctx.get::<JsArrayBuffer>(0)?;
   ctx.env.get_boolean(input.is_detached()?)
 }
+
+#[js_function(1)]
+pub fn create_detachable_arraybuffer(ctx: CallContext) -> Result<JsArrayBuffer> {
+  let length = ctx.get::<JsNumber>(0)?.get_uint32()?;
+  ctx
+    .env
+    .create_arraybuffer_with_data(vec![1; length as usize])
+    .map(|buffer| buffer.into_raw())
+}
+
+#[js_function(1)]
+pub fn detach_arraybuffer_into_buffer(ctx: CallContext) -> Result<JsBuffer> {
+  let input = ctx.get::<JsArrayBuffer>(0)?;
+  let data = input.detach_into_vec()?;
+  ctx
+    .env
+    .create_buffer_with_data(data)
+    .map(|buffer| buffer.into_raw())
+}
@@ -7,5 +7,13 @@ use buffer::*;
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testDetachArrayBuffer", detach_arraybuffer)?;
   exports.create_named_method("testIsDetachedArrayBuffer", is_detach_arraybuffer)?;
+  exports.create_named_method(
+    "testCreateDetachableArrayBuffer",
+    create_detachable_arraybuffer,
+  )?;
+  exports.create_named_method(
+    "testDetachArrayBufferIntoBuffer",
+    detach_arraybuffer_into_buffer,
+  )?;
   Ok(())
 }
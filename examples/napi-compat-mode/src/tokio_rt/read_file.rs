@@ -1,5 +1,5 @@
 use futures::prelude::*;
-use napi::{CallContext, Error, JsObject, JsString, Result, Status};
+use napi::{CallContext, Error, JsObject, JsPromise, JsString, Result, Status};
 
 #[js_function(1)]
 pub fn test_execute_tokio_readfile(ctx: CallContext) -> Result<JsObject> {
@@ -34,3 +34,13 @@ pub fn error_from_tokio_future(ctx: CallContext) -> Result<JsObject> {
     |&mut env, data| env.create_buffer_with_data(data).map(|v| v.into_raw()),
   )
 }
+
+#[js_function(1)]
+pub fn await_js_promise(ctx: CallContext) -> Result<JsObject> {
+  let promise = ctx.get::<JsPromise>(0)?.into_future::<u32>()?;
+  ctx
+    .env
+    .execute_tokio_future(promise.map_ok(|v| v + 1), |&mut env, v| {
+      env.create_uint32(v)
+    })
+}
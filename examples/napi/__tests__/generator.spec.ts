@@ -1,6 +1,6 @@
 import test from 'ava'
 
-import { Fib, Fib2, Fib3 } from '../index.cjs'
+import { Countdown, Fib, Fib2, Fib3 } from '../index.cjs'
 
 for (const [index, factory] of [
   () => new Fib(),
@@ -55,3 +55,12 @@ for (const [index, factory] of [
     })
   })
 }
+
+test('should be able to iterate a Rust Iterator', (t) => {
+  const values = []
+  for (const value of new Countdown(3)) {
+    values.push(value)
+  }
+  t.deepEqual(values, [2, 1, 0])
+  t.deepEqual([...new Countdown(0)], [])
+})
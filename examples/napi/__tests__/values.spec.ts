@@ -1,6 +1,13 @@
+import { createHook } from 'node:async_hooks'
+import { getEventListeners } from 'node:events'
 import { exec } from 'node:child_process'
 import { join } from 'node:path'
+import { Readable, Writable } from 'node:stream'
+import { setTimeout as sleep } from 'node:timers/promises'
 import { fileURLToPath } from 'node:url'
+import { inspect } from 'node:util'
+import { setFlagsFromString } from 'node:v8'
+import { runInNewContext } from 'node:vm'
 
 import { Subject, take } from 'rxjs'
 import { spy } from 'sinon'
@@ -9,6 +16,7 @@ import {
   DEFAULT_COST,
   add,
   fibonacci,
+  clamp,
   call0,
   call1,
   call2,
@@ -34,52 +42,106 @@ import {
   getIndexMapping,
   sumIndexMapping,
   indexmapPassthrough,
+  getJsMap,
+  sumJsMap,
+  jsMapRemove,
+  getSet,
+  sumSet,
+  jsSetHas,
   getCwd,
+  callImplFn,
   Animal,
+  Temperature,
+  Square,
+  Disk,
+  type Measurable,
   Kind,
   NinjaTurtle,
   ClassWithFactory,
+  Session,
+  openSession,
   CustomNumEnum,
   Context,
   GetterSetterWithClosures,
+  TrackedBuffer,
+  NativeResource,
+  releasedResources,
   enumToI32,
+  KebabStringEnum,
+  kebabStringEnumToIndex,
+  shapeArea,
+  scaleShape,
+  messageLen,
   listObjKeys,
   createObj,
   mapOption,
+  incrementNullable,
+  returnUndefinedIfNull,
   readFile,
   throwError,
   customStatusCode,
+  throwTypeErrorWithErrno,
+  throwCustomClassError,
+  throwErrorWithCause,
+  throwErrorFromChain,
+  CustomErrorClass,
   panic,
   readPackageJson,
   getPackageJsonName,
   getBuffer,
   getEmptyBuffer,
+  createPooledBuffer,
+  dropPooledBuffer,
+  pooledBuffersAvailable,
+  getCopiedBuffer,
   asyncBufferToArray,
   readFileAsync,
   eitherStringOrNumber,
+  doubleEither,
   returnEither,
   either3,
   either4,
   withoutAbortController,
   withAbortController,
+  spawnBlockingSum,
+  installPanicHandler,
+  resetPanicHandler,
+  namedDelaySum,
+  serialDelay,
+  peakSerialDelayConcurrency,
+  withCancellationToken,
+  waitForAbort,
+  sleepOrAbort,
   asyncMultiTwo,
+  asyncRange,
+  asyncRangeWithError,
+  readStreamToString,
+  pipeStream,
   bigintAdd,
   createBigInt,
   createBigIntI64,
   bigintGetU64AsString,
   callThreadsafeFunction,
+  callOnNextTick,
+  sendToMainThread,
+  takeMainThreadValues,
   threadsafeFunctionThrowError,
   threadsafeFunctionClosureCapture,
   tsfnCallWithCallback,
   tsfnAsyncCall,
   tsfnThrowFromJs,
   asyncPlus100,
+  combinePromises,
   getGlobal,
+  structuredCloneObject,
   getUndefined,
   getNull,
   setSymbolInObj,
   createSymbol,
   createSymbolFor,
+  createRegisteredSymbol,
+  getAsyncIteratorSymbol,
+  describeSymbol,
   threadsafeFunctionFatalMode,
   createExternal,
   getExternal,
@@ -88,6 +150,7 @@ import {
   xxh2,
   xxh3,
   xxh64Alias,
+  path,
   tsRename,
   acceptArraybuffer,
   acceptSlice,
@@ -106,6 +169,23 @@ import {
   convertU32Array,
   createExternalTypedArray,
   mutateTypedArray,
+  mutateBufferSlice,
+  sumTypedArray,
+  doubleTypedArray,
+  createTypedArrayFromVec,
+  createSharedArraybuffer,
+  fillSharedMemoryInThread,
+  StoreU32,
+  Car,
+  Vehicle,
+  Wallet,
+  walletBalance,
+  SavingsWallet,
+  savingsWalletRate,
+  Connection,
+  BufferedWriter,
+  StoreString,
+  firstInStore,
   receiveAllOptionalObject,
   objectGetNamedPropertyShouldPerformTypecheck,
   fnReceivedAliased,
@@ -124,10 +204,20 @@ import {
   testSerdeRoundtrip,
   testSerdeBigNumberPrecision,
   testSerdeBufferBytes,
+  testSerdeBytesMode,
   createObjWithProperty,
+  createObjWithPropertyAttributes,
+  createFrozenConfig,
+  createLazyConfig,
+  formatPrice,
+  createPackageManifest,
+  sealObject,
+  objectIntegrityLevel,
   receiveObjectOnlyFromJs,
   dateToNumber,
   chronoUtcDateToMillis,
+  jsDateToChronoMillis,
+  jsDateFromChrono,
   chronoLocalDateToMillis,
   chronoDateWithTimezoneToMillis,
   chronoDateFixtureReturn1,
@@ -149,6 +239,8 @@ import {
   eitherFromObjects,
   overrideIndividualArgOnFunction,
   overrideIndividualArgOnFunctionWithCbArg,
+  parseVersion,
+  MIN_SUPPORTED_VERSION,
   createObjectWithClassField,
   receiveObjectWithClassField,
   AnotherClassForEither,
@@ -161,26 +253,41 @@ import {
   captureErrorInCallback,
   bigintFromI128,
   bigintFromI64,
+  bigintFromNegativeI128,
+  bigintToI128,
   acceptThreadsafeFunction,
   acceptThreadsafeFunctionFatal,
   acceptThreadsafeFunctionTupleArgs,
   promiseInEither,
   runScript,
+  constructAnimal,
+  runScriptWithFilename,
+  runCachedScript,
+  scheduleCallbacks,
   tsfnReturnPromise,
+  tsfnCallAsyncTyped,
   tsfnReturnPromiseTimeout,
   returnFromSharedCrate,
   chronoNativeDateTime,
   chronoNativeDateTimeReturn,
   throwAsyncError,
   getModuleFileName,
+  getRuntimeNapiVersion,
+  nodeVersionAtLeast,
+  sumWithHandleScopes,
+  getProcessInfo,
   throwSyntaxError,
   type AliasedStruct,
   returnObjectOnlyToJs,
   buildThreadsafeFunctionFromFunction,
+  tsfnQueueFull,
   createOptionalExternal,
   getOptionalExternal,
   mutateOptionalExternal,
   panicInAsync,
+  initJsLogger,
+  setJsLoggerLevel,
+  logWithTracing,
 } from '../index.cjs'
 
 import { test } from './test.framework.js'
@@ -205,6 +312,13 @@ test('number', (t) => {
   )
 })
 
+test('default arguments', (t) => {
+  t.is(clamp(2), 1)
+  t.is(clamp(-2), 0)
+  t.is(clamp(2, 0, 5), 2)
+  t.is(clamp(-2, undefined, 5), 0)
+})
+
 test('string', (t) => {
   t.true(contains('hello', 'ell'))
   t.false(contains('John', 'jn'))
@@ -240,11 +354,75 @@ test('map', (t) => {
   t.deepEqual(indexmapPassthrough({ a: 101, b: 102 }), { a: 101, b: 102 })
 })
 
+test('js Map and Set', (t) => {
+  const map = getJsMap()
+  t.true(map instanceof Map)
+  t.deepEqual(Array.from(map.entries()).sort(), [
+    ['a', 101],
+    ['b', 102],
+  ])
+  t.is(
+    sumJsMap(
+      new Map([
+        ['a', 101],
+        ['b', 102],
+      ]),
+    ),
+    203,
+  )
+  t.is(jsMapRemove(new Map([['a', 1]]), 'a'), 0)
+  t.deepEqual(getSet(), new Set(['a']))
+  t.is(sumSet(new Set([1, 2, 3])), 6)
+  t.throws(() => sumSet([1, 2, 3] as any), {
+    code: 'InvalidArg',
+    message: 'Expected a Set object',
+  })
+  t.true(jsSetHas(new Set(['a']), 'a'))
+  t.false(jsSetHas(new Set(['a']), 'b'))
+})
+
 test('enum', (t) => {
   t.deepEqual([Kind.Dog, Kind.Cat, Kind.Duck], [0, 1, 2])
   t.is(enumToI32(CustomNumEnum.Eight), 8)
 })
 
+test('string enum', (t) => {
+  t.is(KebabStringEnum.VariantTwo, 'variant-two')
+  t.is(kebabStringEnumToIndex(KebabStringEnum.VariantTwo), 1)
+  t.throws(() => kebabStringEnumToIndex(1 as any), {
+    message: 'Expect value to be String, but received Number',
+  })
+})
+
+test('tagged enum', (t) => {
+  t.is(shapeArea({ type: 'Rectangle', width: 2, height: 3 }), 6)
+  t.is(shapeArea({ type: 'Empty' }), 0)
+  t.deepEqual(scaleShape({ type: 'Circle', radius: 1 }, 2), {
+    type: 'Circle',
+    radius: 2,
+  })
+  t.deepEqual(scaleShape({ type: 'polygon', field0: [1, 2] }, 3), {
+    type: 'polygon',
+    field0: [3, 6],
+  })
+  t.is(messageLen({ kind: 'Text', content: 'hello' }), 5)
+  t.is(messageLen({ kind: 'Text', content: 'hi', replyTo: 1 }), 2)
+  t.is(messageLen({ kind: 'Text', content: 'hi', replyTo: null as any }), 2)
+  t.is(messageLen({ kind: 'Ping' }), 0)
+  const err = t.throws(() =>
+    // @ts-expect-error
+    shapeArea({ type: 'Triangle' }),
+  )
+  t.is(
+    err?.message,
+    'value `Triangle` does not match any variant of enum `Shape`',
+  )
+  t.throws(() =>
+    // @ts-expect-error
+    shapeArea({ type: 'Circle' }),
+  )
+})
+
 test('function call', async (t) => {
   t.is(
     call0(() => 42),
@@ -356,11 +534,38 @@ test('class', (t) => {
   }
 })
 
+test('construct class from rust', (t) => {
+  const animal = constructAnimal('旺财')
+  t.true(animal instanceof Animal)
+  t.is(animal.kind, Kind.Dog)
+  t.is(animal.name, '旺财 Jr.')
+})
+
 test('async self in class', async (t) => {
   const b = new Bird('foo')
   t.is(await b.getNameAsync(), 'foo')
 })
 
+test('static methods and properties', (t) => {
+  t.is(Temperature.ABSOLUTE_ZERO, -273.15)
+  t.is(Temperature.BOILING_POINT, 100)
+  t.true(Object.keys(Temperature).includes('ABSOLUTE_ZERO'))
+  const body = Temperature.fromFahrenheit(98.6)
+  t.true(body instanceof Temperature)
+  t.is(Math.round(body.celsius * 10) / 10, 37)
+  t.is(new Temperature(100).fahrenheit, 212)
+})
+
+test('methods exported from trait impls', (t) => {
+  const shapes: Measurable[] = [new Square(3), new Disk(1)]
+  t.deepEqual(
+    shapes.map((shape) => shape.describe()),
+    ['square of side 3', 'disk of radius 1'],
+  )
+  t.is(shapes[0].area(), 9)
+  t.is(shapes[1].area(), Math.PI)
+})
+
 test('class factory', (t) => {
   const duck = ClassWithFactory.withName('Default')
   t.is(duck.name, 'Default')
@@ -386,6 +591,21 @@ test('class factory', (t) => {
   )
 })
 
+test('class without constructor', (t) => {
+  const guest = Session.guest()
+  t.true(guest instanceof Session)
+  t.is(guest.user, 'guest')
+  const session = openSession('napi')
+  t.true(session instanceof Session)
+  t.is(session.user, 'napi')
+  // @ts-expect-error
+  const error = t.throws(() => new Session())
+  t.is(
+    error?.message,
+    'Class `Session` can\'t be constructed from JavaScript, its instances are created by Rust',
+  )
+})
+
 test('async class factory', async (t) => {
   const instance = await ClassWithFactory.with4Name('foo')
   t.is(instance.name, 'foo-4')
@@ -417,6 +637,73 @@ test('class in object field', (t) => {
   t.is(receiveObjectWithClassField(obj), obj.bird)
 })
 
+test('generic class instantiations', (t) => {
+  const numbers = new StoreU32([1, 2])
+  numbers.push(3)
+  t.is(numbers.size, 3)
+  t.is(numbers.get(2), 3)
+  t.is(numbers.get(3), null)
+  const strings = new StoreString(['a'])
+  strings.push('b')
+  t.is(strings.join(','), 'a,b')
+  t.is(firstInStore(strings), 'a')
+})
+
+test('class extends', (t) => {
+  const car = new Car('napi')
+  t.true(car instanceof Car)
+  t.true(car instanceof Vehicle)
+  t.is(Object.getPrototypeOf(Car), Vehicle)
+  t.is(car.brand(), 'napi')
+  t.is(car.wheels(), 4)
+  t.is(new Vehicle(2).wheels(), 2)
+})
+
+test('type tagged class', (t) => {
+  const wallet = new Wallet(100)
+  t.is(wallet.balance(), 100)
+  t.is(walletBalance(wallet), 100)
+  t.throws(() => walletBalance(new Vehicle(2) as any), {
+    message: 'Value is not an instance of class `Wallet`',
+  })
+  t.throws(() => walletBalance(new Car('napi') as any), {
+    message: 'Value is not an instance of class `Wallet`',
+  })
+  t.throws(() => Wallet.prototype.balance.call(new Car('napi')))
+})
+
+test('type tagged subclass', (t) => {
+  const savings = new SavingsWallet(50, 2)
+  t.true(savings instanceof Wallet)
+  t.is(savings.balance(), 50)
+  t.is(walletBalance(savings), 50)
+  t.is(savingsWalletRate(savings), 2)
+  t.throws(() => savingsWalletRate(new Wallet(100) as any), {
+    message: 'Value is not an instance of class `SavingsWallet`',
+  })
+})
+
+test('class with custom toString and inspect', (t) => {
+  const wallet = new Wallet(100)
+  t.is(wallet.toString(), 'Wallet { balance: 100 }')
+  t.is(`${wallet}`, 'Wallet { balance: 100 }')
+  t.is(inspect(wallet), 'Wallet { balance: 100 }')
+})
+
+test('disposable class', async (t) => {
+  const dispose = (Symbol.dispose ??
+    Symbol.for('Symbol.dispose')) as typeof Symbol.dispose
+  const connection = new Connection()
+  t.false(connection.closed)
+  connection[dispose]()
+  t.true(connection.closed)
+  const asyncDispose = (Symbol.asyncDispose ??
+    Symbol.for('Symbol.asyncDispose')) as typeof Symbol.asyncDispose
+  const writer = new BufferedWriter()
+  await writer[asyncDispose]()
+  t.true(writer.flushed)
+})
+
 test('custom finalize class', (t) => {
   t.notThrows(() => new CustomFinalize(200, 200))
 })
@@ -463,6 +750,11 @@ test('callback', (t) => {
       t.is((err as Error).message, 'Testing')
     },
   )
+
+  t.is(
+    callImplFn((n, s) => `${s}: ${n}`),
+    'napi: 42',
+  )
 })
 
 Napi4Test('callback function return Promise', async (t) => {
@@ -551,8 +843,94 @@ test('create object from Property', (t) => {
   t.is(obj.getter, 42)
 })
 
+test('freeze and seal object', (t) => {
+  const config = createFrozenConfig()
+  t.true(Object.isFrozen(config))
+  t.deepEqual(objectIntegrityLevel(config), { frozen: true, sealed: true })
+  const sealed = sealObject({ a: 1 })
+  t.true(Object.isSealed(sealed))
+  t.deepEqual(objectIntegrityLevel(sealed), { frozen: false, sealed: true })
+  t.deepEqual(objectIntegrityLevel({}), { frozen: false, sealed: false })
+})
+
+test('proxy with rust traps', (t) => {
+  const config = createLazyConfig(['host', 'port'])
+  t.is(config.host, 'default host')
+  t.is(config.missing, undefined)
+  t.true('port' in config)
+  t.false('missing' in config)
+  config.port = 8080 as any
+  t.is(config.port, '8080')
+  t.deepEqual(Object.keys(config), ['host', 'port'])
+  t.deepEqual({ ...config }, { host: 'default host', port: '8080' })
+  t.deepEqual(Object.getOwnPropertyDescriptor(config, 'port'), {
+    value: '8080',
+    writable: true,
+    enumerable: true,
+    configurable: true,
+  })
+  t.is(Object.getOwnPropertyDescriptor(config, 'missing'), undefined)
+  t.is(Object.getOwnPropertyDescriptor(config, Symbol.iterator), undefined)
+  delete config.host
+  t.false('host' in config)
+  t.is(JSON.stringify(config), '{"port":"8080"}')
+})
+
+test('options object argument', (t) => {
+  t.is(formatPrice({ amount: 1234567.891, currency: 'USD' }), '1,234,567.89 USD')
+  t.is(
+    formatPrice({
+      amount: -1234.5,
+      currency: '€',
+      decimals: 1,
+      symbolFirst: true,
+      thousandsSeparator: '.',
+      decimalSeparator: ',',
+    }),
+    '-€1.234,5',
+  )
+  t.throws(
+    // @ts-expect-error
+    () => formatPrice({ amount: 1 }),
+    void 0,
+    'Missing field `currency`',
+  )
+})
+
+test('readonly object fields', (t) => {
+  const manifest = createPackageManifest('napi', null)
+  t.deepEqual(manifest, { name: 'napi', version: '0.0.0' })
+  t.throws(() => {
+    'use strict'
+    // @ts-expect-error
+    manifest.name = 'changed'
+  })
+  manifest.version = '1.0.0'
+  t.is(manifest.version, '1.0.0')
+  const described = createPackageManifest('napi', 'Node-API bindings')
+  t.is(described.description, 'Node-API bindings')
+  t.false(Object.getOwnPropertyDescriptor(described, 'description')?.writable)
+})
+
+test('create object with property attributes', (t) => {
+  const obj = createObjWithPropertyAttributes()
+  t.deepEqual(Object.keys(obj), ['frozen'])
+  t.is(obj.hidden, 'hidden')
+  t.is(Object.prototype.toString.call(obj), '[object NapiRsObject]')
+  t.throws(() => {
+    'use strict'
+    // @ts-expect-error
+    obj.frozen = 2
+  })
+  t.is(obj.frozen, 1)
+})
+
 test('global', (t) => {
   t.is(getGlobal(), global)
+  const value = { nested: { list: [1, 2] }, date: new Date(0) }
+  const cloned = structuredCloneObject(value)
+  t.not(cloned, value)
+  t.deepEqual(cloned, value)
 })
 
 test('get undefined', (t) => {
@@ -586,11 +964,35 @@ test('create symbol', (t) => {
   t.is(createSymbol().toString(), 'Symbol(a symbol)')
 })
 
+test('symbol registry and well-known symbols', (t) => {
+  t.is(createRegisteredSymbol('napi'), Symbol.for('napi'))
+  t.is(getAsyncIteratorSymbol(), Symbol.asyncIterator)
+  t.deepEqual(describeSymbol(Symbol.for('napi')), {
+    description: 'napi',
+    key: 'napi',
+  })
+  t.deepEqual(describeSymbol(Symbol('local')), { description: 'local' })
+  t.deepEqual(describeSymbol(Symbol()), {})
+})
+
 test('Option', (t) => {
   t.is(mapOption(null), null)
   t.is(mapOption(3), 4)
 })
 
+test('Nullable', (t) => {
+  t.is(incrementNullable(null), null)
+  t.is(incrementNullable(1), 2)
+  t.throws(
+    // @ts-expect-error
+    () => incrementNullable(undefined),
+    void 0,
+    'Expect value to be Nullable<T>, but received undefined',
+  )
+  t.is(returnUndefinedIfNull(null), undefined)
+  t.is(returnUndefinedIfNull(1), 1)
+})
+
 test('Result', (t) => {
   t.throws(() => throwError(), void 0, 'Manual Error')
   if (!process.env.SKIP_UNWIND_TEST) {
@@ -598,6 +1000,23 @@ test('Result', (t) => {
   }
 })
 
+test('panic to error', (t) => {
+  if (process.env.SKIP_UNWIND_TEST) {
+    t.pass('unwind is not supported')
+    return
+  }
+  const err = t.throws(() => panic(), { message: `Don't panic` })
+  if (!process.env.WASI_TEST) {
+    t.is(typeof (err as Error & { backtrace?: string }).backtrace, 'string')
+  }
+  installPanicHandler()
+  try {
+    t.throws(() => panic(), { code: 'ERR_RUST_PANIC', message: `Don't panic` })
+  } finally {
+    resetPanicHandler()
+  }
+})
+
 test('Async error with stack trace', async (t) => {
   const err = await t.throwsAsync(() => throwAsyncError())
   t.not(err?.stack, undefined)
@@ -613,6 +1032,39 @@ test('custom status code in Error', (t) => {
   })
 })
 
+test('error kind and extra properties', (t) => {
+  const err = t.throws(() => throwTypeErrorWithErrno(), {
+    instanceOf: TypeError,
+    code: 'InvalidArg',
+    message: 'Invalid file descriptor',
+  })
+  t.is((err as any).errno, -9)
+  t.is((err as any).syscall, 'read')
+})
+
+test('throw custom class as error', (t) => {
+  const err = t.throws(() => throwCustomClassError(), {
+    instanceOf: CustomErrorClass as any,
+  })
+  t.is((err as unknown as CustomErrorClass).message, 'Custom class error')
+  t.is((err as any).code, 'GenericFailure')
+})
+
+test('error cause chain', (t) => {
+  const err = t.throws(() => throwErrorWithCause(), {
+    message: 'Failed to load config',
+  })
+  t.true(err!.cause instanceof Error)
+  t.is((err!.cause as Error).message, 'Missing field `name`')
+  t.is((err!.cause as any).code, 'InvalidArg')
+
+  t.is(throwErrorFromChain('8080'), 8080)
+  const chained = t.throws(() => throwErrorFromChain('port'), {
+    message: 'Failed to parse port',
+  })
+  t.is((chained!.cause as Error).message, 'invalid digit found in string')
+})
+
 test('function ts type override', (t) => {
   // @ts-expect-error
   t.deepEqual(tsRename({ foo: 1, bar: 2, baz: 2 }), ['foo', 'bar', 'baz'])
@@ -632,6 +1084,13 @@ test('function individual ts arg type override', (t) => {
   )
 })
 
+test('ts type override on params, returns and consts', (t) => {
+  const [major, minor, patch] = parseVersion(MIN_SUPPORTED_VERSION)
+  t.deepEqual([major, minor, patch], [1, 2, 3])
+  // @ts-expect-error
+  t.deepEqual(parseVersion('latest'), [0])
+})
+
 test('option object', (t) => {
   t.notThrows(() => receiveAllOptionalObject())
   t.notThrows(() => receiveAllOptionalObject({}))
@@ -699,6 +1158,14 @@ test('serde-roundtrip', (t) => {
   t.is(err!.message, 'JS symbols cannot be represented as a serde_json::Value')
 })
 
+test('serde-roundtrip-bigint', (t) => {
+  t.is(testSerdeRoundtrip(12n), 12)
+  t.is(testSerdeRoundtrip(2n ** 60n), 2n ** 60n)
+  t.is(testSerdeRoundtrip(2n ** 63n), 2n ** 63n)
+  const err = t.throws(() => testSerdeRoundtrip(2n ** 64n))
+  t.is(err?.message, 'BigInt is out of the range of serde_json::Number')
+})
+
 test('serde-large-number-precision', (t) => {
   t.is(testSerdeBigNumberPrecision('12345').number, 12345)
   t.is(
@@ -724,6 +1191,19 @@ test('serde-buffer-bytes', (t) => {
   t.is(testSerdeBufferBytes({ code: Buffer.alloc(0) }), 0n)
 })
 
+test('serde-bytes-mode', (t) => {
+  t.deepEqual(
+    testSerdeBytesMode({ code: new Uint8Array([1, 2, 3]) }, false),
+    { code: Buffer.from([1, 2, 3]) },
+  )
+  t.deepEqual(testSerdeBytesMode({ code: Buffer.from([1, 2, 3]) }, true), {
+    code: [1, 2, 3],
+  })
+  t.deepEqual(testSerdeBytesMode({ code: [4, 5, 6] }, true), {
+    code: [4, 5, 6],
+  })
+})
+
 test('buffer', (t) => {
   let buf = getBuffer()
   t.is(buf.toString('utf-8'), 'Hello world')
@@ -735,6 +1215,23 @@ test('buffer', (t) => {
   t.is(a.toString(), '')
   t.is(b.toString(), '')
 
+  for (let i = 0; i < 10; i++) {
+    t.deepEqual(createPooledBuffer(4, i), Buffer.from([i, i, i, i]))
+  }
+  t.is(createPooledBuffer(0, 1).length, 0)
+  const available = pooledBuffersAvailable()
+  dropPooledBuffer()
+  t.is(pooledBuffersAvailable(), Math.min(available + 1, 4))
+  for (let i = 0; i < 4; i++) {
+    dropPooledBuffer()
+  }
+  // the pool keeps up to 4 buffers
+  t.is(pooledBuffersAvailable(), 4)
+  const pooled = createPooledBuffer(4, 1)
+  t.is(pooledBuffersAvailable(), 3)
+  t.deepEqual(pooled, Buffer.from([1, 1, 1, 1]))
+  t.is(getCopiedBuffer().toString(), 'copied')
+
   // @ts-expect-error
   t.true(Array.isArray(asyncBufferToArray(Buffer.from([1, 2, 3]).buffer)))
 })
@@ -800,6 +1297,36 @@ test('mutate TypedArray', (t) => {
   t.deepEqual(input, new Float32Array([2.0, 4.0, 6.0, 8.0, 10.0]))
 })
 
+test('mutate buffer slice in place', (t) => {
+  const input = Buffer.from([1, 2, 255])
+  mutateBufferSlice(input)
+  t.deepEqual(input, Buffer.from([2, 3, 0]))
+})
+
+test('generic typed array', (t) => {
+  t.is(sumTypedArray(new Float64Array([1.5, 2.5, 3])), 7)
+  t.deepEqual(
+    doubleTypedArray(new Int32Array([1, -2, 3])),
+    new Int32Array([2, -4, 6]),
+  )
+  t.deepEqual(createTypedArrayFromVec(), new BigInt64Array([1n, -2n, 3n]))
+  t.throws(() => doubleTypedArray(new Float64Array([1]) as any), {
+    code: 'InvalidArg',
+    message: 'Expected Int32Array, got Float64',
+  })
+})
+
+test('shared array buffer', (t) => {
+  const sab = createSharedArraybuffer(4)
+  t.true(sab instanceof SharedArrayBuffer)
+  t.is(sab.byteLength, 4)
+  t.is(fillSharedMemoryInThread(sab, 7), 4)
+  t.deepEqual(new Uint8Array(sab), new Uint8Array([7, 7, 7, 7]))
+  t.throws(() => fillSharedMemoryInThread(new ArrayBuffer(4) as any, 1), {
+    code: 'InvalidArg',
+  })
+})
+
 test('deref uint8 array', (t) => {
   t.is(
     derefUint8Array(new Uint8Array([1, 2]), new Uint8ClampedArray([3, 4])),
@@ -835,6 +1362,68 @@ test('async move', async (t) => {
   t.is(await asyncMultiTwo(2), 4)
 })
 
+test('stream as async iterator', async (t) => {
+  const values: number[] = []
+  for await (const n of asyncRange(1, 5)) {
+    values.push(n)
+  }
+  t.deepEqual(values, [1, 2, 3, 4])
+  const iterator = asyncRange(0, 10)
+  t.deepEqual(await iterator.next(), { value: 0, done: false })
+  t.deepEqual(await iterator.return!(), { value: undefined, done: true })
+  t.deepEqual(await iterator.next(), { value: undefined, done: true })
+  const errored: number[] = []
+  await t.throwsAsync(
+    async () => {
+      for await (const n of asyncRangeWithError(3)) {
+        errored.push(n)
+      }
+    },
+    { message: 'reached 3' },
+  )
+  t.deepEqual(errored, [0, 1, 2])
+})
+
+test('read Node.js Readable as AsyncRead', async (t) => {
+  t.is(
+    await readStreamToString(Readable.from(['hello', ' ', 'world'])),
+    'hello world',
+  )
+  const failing = new Readable({
+    read() {
+      this.destroy(new Error('read failed'))
+    },
+  })
+  await t.throwsAsync(() => readStreamToString(failing), {
+    message: 'read failed',
+  })
+  await t.throwsAsync(() => readStreamToString(Readable.from([{ a: 1 }])), {
+    message:
+      "Readable stream must emit Buffer or string chunks, objectMode isn't supported",
+  })
+})
+
+test('pipe Node.js Readable into Writable', async (t) => {
+  const chunks: Buffer[] = []
+  const output = new Writable({
+    highWaterMark: 4,
+    write(chunk, _encoding, callback) {
+      chunks.push(chunk)
+      setTimeout(callback, 1)
+    },
+  })
+  const input = Readable.from(
+    Array.from({ length: 64 }, (_, i) => Buffer.alloc(1024, i)),
+    { objectMode: false },
+  )
+  t.is(await pipeStream(input, output), 64 * 1024)
+  t.true(output.writableFinished)
+  t.deepEqual(
+    Buffer.concat(chunks),
+    Buffer.concat(Array.from({ length: 64 }, (_, i) => Buffer.alloc(1024, i))),
+  )
+})
+
 test('buffer passthrough', async (t) => {
   const fixture = Buffer.from('hello world')
   const ret = await bufferPassThrough(fixture)
@@ -861,6 +1450,13 @@ test('either', (t) => {
   t.is(eitherStringOrNumber('hello'), 'hello'.length)
 })
 
+test('overloaded either', (t) => {
+  const doubled: string = doubleEither('ab')
+  t.is(doubled, 'abab')
+  const doubledNumber: number = doubleEither(21)
+  t.is(doubledNumber, 42)
+})
+
 test('return either', (t) => {
   t.is(returnEither(2), 2)
   t.is(returnEither(42), '42')
@@ -951,6 +1547,32 @@ test('should be able to run script', async (t) => {
   t.is(await runScript(`Promise.resolve(1)`), 1)
 })
 
+test('run script with filename', (t) => {
+  t.is(runScriptWithFilename(`1 + 1`, 'add.js'), 2)
+  const err = t.throws(() =>
+    runScriptWithFilename(`throw new Error('boom')`, 'throw-from-script.js'),
+  )
+  t.true(err!.stack!.includes('throw-from-script.js'))
+})
+
+test('schedule callbacks', async (t) => {
+  const calls: string[] = []
+  scheduleCallbacks((name) => {
+    calls.push(name)
+  })
+  calls.push('sync')
+  await new Promise((resolve) => setTimeout(resolve, 50))
+  t.deepEqual(calls, ['sync', 'microtask', 'timeout'])
+})
+
+test('run cached script', (t) => {
+  const body = `globalThis.cachedScriptCalls = (globalThis.cachedScriptCalls ?? 0) + 1
+return globalThis.cachedScriptCalls`
+  t.is(runCachedScript(body), 1)
+  t.is(runCachedScript(body), 2)
+  t.is(runCachedScript(`return this === globalThis`), true)
+})
+
 test('should be able to return object from shared crate', (t) => {
   t.deepEqual(returnFromSharedCrate(), {
     value: 42,
@@ -960,6 +1582,30 @@ test('should be able to return object from shared crate', (t) => {
 const AbortSignalTest =
   typeof AbortController !== 'undefined' ? test : test.skip
 
+test('spawn blocking closure in libuv thread pool', async (t) => {
+  t.is(await spawnBlockingSum(1, 2), 3)
+})
+
+test('async task with resource name', async (t) => {
+  const types: string[] = []
+  const hook = createHook({
+    init(_asyncId, type) {
+      types.push(type)
+    },
+  }).enable()
+  t.is(await namedDelaySum(1, 2), 3)
+  hook.disable()
+  t.true(types.includes('DelaySum'))
+})
+
+test('async task with concurrency limit', async (t) => {
+  t.deepEqual(
+    await Promise.all([serialDelay(1), serialDelay(2), serialDelay(3)]),
+    [1, 2, 3],
+  )
+  t.is(peakSerialDelayConcurrency(), 1)
+})
+
 AbortSignalTest('async task without abort controller', async (t) => {
   t.is(await withoutAbortController(1, 2), 3)
 })
@@ -976,6 +1622,27 @@ AbortSignalTest('async task with abort controller', async (t) => {
   }
 })
 
+AbortSignalTest('abort running task with cancellation token', async (t) => {
+  const ctrl = new AbortController()
+  const promise = withCancellationToken(ctrl.signal)
+  setTimeout(() => ctrl.abort(), 50)
+  await t.throwsAsync(() => promise, { message: 'AbortError' })
+})
+
+AbortSignalTest('cancellation token in async fn', async (t) => {
+  const ctrl = new AbortController()
+  const promise = waitForAbort(ctrl.signal)
+  ctrl.abort()
+  t.true(await promise)
+})
+
+AbortSignalTest('remove the abort listener once the async fn settles', async (t) => {
+  const ctrl = new AbortController()
+  t.false(await sleepOrAbort(ctrl.signal, 1))
+  await new Promise((resolve) => setTimeout(resolve, 10))
+  t.deepEqual(getEventListeners(ctrl.signal, 'abort'), [])
+})
+
 AbortSignalTest('abort resolved task', async (t) => {
   const ctrl = new AbortController()
   await withAbortController(1, 2, ctrl.signal).then(() => ctrl.abort())
@@ -1012,9 +1679,34 @@ BigIntTest('js mod test', (t) => {
   t.is(xx3.digest(), BigInt('1116'))
 })
 
+test('per item namespace', (t) => {
+  t.is(path.joinSegments(['usr', 'local', 'bin']), 'usr/local/bin')
+  const segments = new path.PathSegments('/usr/local/')
+  t.is(segments.depth, 2)
+  segments.push('bin')
+  t.is(segments.base, 'bin')
+  t.is(segments.depth, 3)
+  t.true(segments instanceof path.PathSegments)
+})
+
+test('nested namespace', (t) => {
+  t.true(path.posix.isAbsolute('/usr/local'))
+  t.false(path.posix.isAbsolute('usr/local'))
+  t.is(path.posix.SEPARATOR, '/')
+})
+
 BigIntTest('from i128 i64', (t) => {
   t.is(bigintFromI64(), BigInt('100'))
   t.is(bigintFromI128(), BigInt('-100'))
+  t.is(bigintFromNegativeI128(), BigInt('-18446744073709551617'))
+})
+
+BigIntTest('BigInt lossless conversion', (t) => {
+  t.is(bigintToI128(BigInt('-18446744073709551617')), '-18446744073709551617')
+  t.is(bigintToI128(BigInt(-100)), '-100')
+  t.throws(() => bigintToI128(BigInt(2) ** BigInt(127)), {
+    code: 'InvalidArg',
+  })
 })
 
 Napi4Test('call ThreadsafeFunction', (t) => {
@@ -1095,6 +1787,32 @@ Napi4Test('Promise should reject raw error in rust', async (t) => {
   t.is(err, fxError)
 })
 
+Napi4Test('combine promises with rust futures', async (t) => {
+  t.deepEqual(
+    await combinePromises('all', [Promise.resolve('a'), Promise.resolve(1)]),
+    ['a', 1, 2],
+  )
+  t.is(await combinePromises('race', [Promise.resolve('fast')]), 'fast')
+  t.is(
+    await combinePromises('race', [
+      new Promise((resolve) => setTimeout(() => resolve('slow'), 100)),
+    ]),
+    1,
+  )
+  const fxError = new Error('rejected')
+  t.deepEqual(
+    await combinePromises('allSettled', [Promise.reject(fxError)]),
+    [
+      { status: 'rejected', reason: fxError },
+      { status: 'fulfilled', value: 1 },
+    ],
+  )
+  const err = await t.throwsAsync(() =>
+    combinePromises('all', [Promise.reject(fxError)]),
+  )
+  t.is(err, fxError)
+})
+
 Napi4Test('call ThreadsafeFunction with callback', async (t) => {
   await t.notThrowsAsync(
     () =>
@@ -1131,6 +1849,64 @@ test('Throw from ThreadsafeFunction JavaScript callback', async (t) => {
   )
 })
 
+Napi4Test('run closure on main thread', async (t) => {
+  const value = await new Promise<number>((resolve) => {
+    let called = false
+    callOnNextTick((value) => {
+      called = true
+      resolve(value)
+    })
+    t.false(called)
+  })
+  t.is(value, 42)
+  sendToMainThread([1, 2, 3])
+  const values: number[] = []
+  while (values.length < 3) {
+    await new Promise((resolve) => setTimeout(resolve, 10))
+    values.push(...takeMainThreadValues())
+  }
+  t.deepEqual(values, [1, 2, 3])
+})
+
+Napi4Test('route the tracing events to the js logger', async (t) => {
+  const records: {
+    level: string
+    target: string
+    message: string
+    fields: Record<string, string>
+  }[] = []
+  initJsLogger((record) => {
+    records.push(record)
+  }, 'info')
+  logWithTracing('hello')
+  while (records.length < 1) {
+    await new Promise((resolve) => setTimeout(resolve, 10))
+  }
+  t.deepEqual(records, [
+    {
+      level: 'info',
+      target: 'napi_examples::tracing_logger',
+      message: 'hello',
+      fields: { answer: '42' },
+    },
+  ])
+  setJsLoggerLevel('debug')
+  logWithTracing('verbose')
+  while (records.length < 3) {
+    await new Promise((resolve) => setTimeout(resolve, 10))
+  }
+  t.deepEqual(
+    records.slice(1).map(({ level, message }) => [level, message]),
+    [
+      ['debug', 'verbose from debug'],
+      ['info', 'verbose'],
+    ],
+  )
+  t.throws(() => setJsLoggerLevel('loud'), {
+    message: 'Unknown log level `loud`',
+  })
+})
+
 Napi4Test('accept ThreadsafeFunction', async (t) => {
   await new Promise<void>((resolve, reject) => {
     acceptThreadsafeFunction((err, value) => {
@@ -1195,6 +1971,20 @@ Napi4Test('threadsafe function return Promise and await in Rust', async (t) => {
   await new Promise((resolve) => setTimeout(resolve, 400))
 })
 
+Napi4Test('threadsafe function call_async awaits returned Promise', async (t) => {
+  const value = await tsfnCallAsyncTyped((err, value) => {
+    if (err) {
+      throw err
+    }
+    return value === 1 ? value + 1 : Promise.resolve(value * 10)
+  })
+  t.is(value, 22)
+  await t.throwsAsync(
+    () => tsfnCallAsyncTyped(() => Promise.reject(new Error('rejected'))),
+    { message: 'rejected' },
+  )
+})
+
 Napi4Test('object only from js', (t) => {
   return new Promise((resolve, reject) => {
     receiveObjectOnlyFromJs({
@@ -1225,6 +2015,10 @@ Napi4Test('build ThreadsafeFunction from Function', (t) => {
   return subject.pipe(take(3))
 })
 
+Napi4Test('ThreadsafeFunction try_call returns QueueFull', (t) => {
+  t.deepEqual(tsfnQueueFull(() => {}), ['Ok', 'QueueFull'])
+})
+
 Napi4Test('promise in either', async (t) => {
   t.is(await promiseInEither(1), false)
   t.is(await promiseInEither(20), true)
@@ -1241,6 +2035,15 @@ Napi5Test('Date test', (t) => {
   t.is(dateToNumber(fixture), fixture.valueOf())
 })
 
+Napi5Test('JsDate chrono interop', (t) => {
+  const fixture = new Date('2022-02-09T19:31:55.396Z')
+  t.is(jsDateToChronoMillis(fixture), fixture.getTime())
+  t.deepEqual(jsDateFromChrono(fixture.getTime()), fixture)
+  t.throws(() => jsDateToChronoMillis(new Date('invalid')), {
+    code: 'DateExpected',
+  })
+})
+
 Napi5Test('Date to chrono test', (t) => {
   const fixture = new Date('2022-02-09T19:31:55.396Z')
   t.is(chronoUtcDateToMillis(fixture), fixture.getTime())
@@ -1270,6 +2073,29 @@ Napi5Test('Class with getter setter closures', (t) => {
   t.is(instance.age, 0.3)
 })
 
+test('finalize method is called when the instance is released', async (t) => {
+  setFlagsFromString('--expose-gc')
+  const gc = runInNewContext('gc')
+  const before = releasedResources()
+  ;(() => {
+    for (let i = 0; i < 10; i++) {
+      new NativeResource(`resource ${i}`)
+    }
+  })()
+  // the finalizers are queued by the GC and run on a later tick
+  for (let i = 0; i < 100 && releasedResources() - before < 10; i++) {
+    gc()
+    await sleep(10)
+  }
+  t.is(releasedResources() - before, 10)
+})
+
+test('track external memory with guard', (t) => {
+  const buffer = new TrackedBuffer(1024)
+  t.is(buffer.length, 1024)
+  t.is(buffer.trackedSize, 1024)
+})
+
 Napi5Test('Date to chrono::NativeDateTime test', (t) => {
   const fixture = new Date()
   t.is(chronoNativeDateTime(fixture), fixture.valueOf())
@@ -1299,6 +2125,36 @@ Napi9Test('get module file name', (t) => {
   )
 })
 
+test('runtime version detection', (t) => {
+  t.is(getRuntimeNapiVersion(), Number(process.versions.napi))
+  const [major, minor, patch] = process.versions.node.split('.').map(Number)
+  t.true(nodeVersionAtLeast(major, minor, patch))
+  t.true(nodeVersionAtLeast(major, 0, 0))
+  t.false(nodeVersionAtLeast(major + 1, 0, 0))
+})
+
+test('process info', (t) => {
+  process.env.NAPI_RS_PROCESS_INFO = 'from js'
+  const info = getProcessInfo()
+  t.is(info.cwd, process.cwd())
+  t.is(info.platform, process.platform)
+  t.deepEqual(info.argv, process.argv)
+  t.is(info.env.NAPI_RS_PROCESS_INFO, 'from js')
+  delete process.env.NAPI_RS_PROCESS_INFO
+})
+
+test('handle scopes', (t) => {
+  const nums = Array.from({ length: 3000 }, (_, i) => i)
+  t.is(
+    sumWithHandleScopes(nums),
+    nums.reduce((a, b) => a + b, 0),
+  )
+  t.is(sumWithHandleScopes([]), 0)
+  // the elements are converted in several handle scopes
+  const input = new Uint32Array(nums)
+  t.deepEqual(convertU32Array(input), nums)
+})
+
 test('throw syntax error', (t) => {
   const message = `Syntax Error: Unexpected token '}'`
   const code = 'InvalidCharacterError'
@@ -4,7 +4,14 @@ import { Worker } from 'node:worker_threads'
 
 import test from 'ava'
 
-import { Animal, Kind, DEFAULT_COST } from '../index.cjs'
+import {
+  Animal,
+  Kind,
+  DEFAULT_COST,
+  countCallsInEnv,
+  runOnStashedMainThreadHandle,
+  cancelStashedTimeout,
+} from '../index.cjs'
 
 const __dirname = join(fileURLToPath(import.meta.url), '..')
 
@@ -117,3 +124,106 @@ t('should be able to new Class in worker thread concurrently', async (t) => {
     }),
   )
 })
+
+t('instance data is isolated between worker threads', async (t) => {
+  const before = countCallsInEnv()
+  await Promise.all(
+    Array.from({ length: concurrency }).map(() => {
+      const w = new Worker(join(__dirname, 'worker.cjs'), {
+        execArgv: ['--experimental-wasi-unstable-preview1'],
+        env: process.env,
+      })
+      return new Promise<void>((resolve, reject) => {
+        w.postMessage({ type: 'instance-data' })
+        w.on('message', (msg) => {
+          t.deepEqual(msg, [1, 2, 3])
+          resolve()
+        })
+        w.on('error', (err) => {
+          reject(err)
+        })
+      }).then(() => w.terminate())
+    }),
+  )
+  t.is(countCallsInEnv(), before + 1)
+})
+
+t('env cleanup hook joins background thread on worker exit', async (t) => {
+  const w = new Worker(join(__dirname, 'worker.cjs'), {
+    execArgv: ['--experimental-wasi-unstable-preview1'],
+    env: process.env,
+  })
+  await new Promise<void>((resolve, reject) => {
+    w.postMessage({ type: 'cleanup-hook' })
+    w.on('message', (msg) => {
+      t.is(msg, 'started')
+      resolve()
+    })
+    w.on('error', (err) => {
+      reject(err)
+    })
+  })
+  t.is(await w.terminate(), 1)
+})
+
+t('main thread handle outlives the worker it was created in', async (t) => {
+  const w = new Worker(join(__dirname, 'worker.cjs'), {
+    execArgv: ['--experimental-wasi-unstable-preview1'],
+    env: process.env,
+  })
+  await new Promise<void>((resolve, reject) => {
+    w.postMessage({ type: 'main-thread-handle' })
+    w.on('message', (msg) => {
+      t.is(msg, 'stashed')
+      resolve()
+    })
+    w.on('error', (err) => {
+      reject(err)
+    })
+  })
+  await w.terminate()
+  t.false(runOnStashedMainThreadHandle())
+})
+
+t('cancel a timeout after the worker it was scheduled in exited', async (t) => {
+  const w = new Worker(join(__dirname, 'worker.cjs'), {
+    execArgv: ['--experimental-wasi-unstable-preview1'],
+    env: process.env,
+  })
+  await new Promise<void>((resolve, reject) => {
+    w.postMessage({ type: 'timeout' })
+    w.on('message', (msg) => {
+      t.is(msg, 'stashed')
+      resolve()
+    })
+    w.on('error', (err) => {
+      reject(err)
+    })
+  })
+  await w.terminate()
+  t.true(cancelStashedTimeout())
+})
+
+t('should be able to require in new worker threads after others exited', async (t) => {
+  for (let round = 0; round < 3; round++) {
+    await Promise.all(
+      Array.from({ length: concurrency }).map(() => {
+        const w = new Worker(join(__dirname, 'worker.cjs'), {
+          execArgv: ['--experimental-wasi-unstable-preview1'],
+          env: process.env,
+        })
+        return new Promise<void>((resolve, reject) => {
+          w.postMessage({ type: 'constructor' })
+          w.on('message', (msg) => {
+            t.is(msg, 'Ellie')
+            resolve()
+          })
+          w.on('error', (err) => {
+            reject(err)
+          })
+        }).then(() => w.terminate())
+      }),
+    )
+  }
+  t.true(Animal.withKind(Kind.Cat) instanceof Animal)
+})
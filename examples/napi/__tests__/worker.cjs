@@ -46,6 +46,23 @@ parentPort.on('message', ({ type }) => {
       }
       parentPort.postMessage(ellie.name)
       break
+    case 'instance-data':
+      parentPort.postMessage(
+        Array.from({ length: 3 }).map(() => native.countCallsInEnv()),
+      )
+      break
+    case 'cleanup-hook':
+      native.startBackgroundWorker()
+      parentPort.postMessage('started')
+      break
+    case 'main-thread-handle':
+      native.stashMainThreadHandle()
+      parentPort.postMessage('stashed')
+      break
+    case 'timeout':
+      native.stashTimeout()
+      parentPort.postMessage('stashed')
+      break
     default:
       throw new TypeError(`Unknown message type: ${type}`)
   }
@@ -364,16 +364,22 @@ export const Blake2BHasher = __napiModule.exports.Blake2BHasher
 export const Blake2bHasher = __napiModule.exports.Blake2bHasher
 export const Blake2BKey = __napiModule.exports.Blake2BKey
 export const Blake2bKey = __napiModule.exports.Blake2bKey
+export const Car = __napiModule.exports.Car
+export const BufferedWriter = __napiModule.exports.BufferedWriter
 export const CatchOnConstructor = __napiModule.exports.CatchOnConstructor
 export const CatchOnConstructor2 = __napiModule.exports.CatchOnConstructor2
 export const ClassWithFactory = __napiModule.exports.ClassWithFactory
+export const Connection = __napiModule.exports.Connection
 export const Context = __napiModule.exports.Context
+export const Countdown = __napiModule.exports.Countdown
 export const CssRuleList = __napiModule.exports.CssRuleList
 export const CSSRuleList = __napiModule.exports.CSSRuleList
 export const CssStyleSheet = __napiModule.exports.CssStyleSheet
 export const CSSStyleSheet = __napiModule.exports.CSSStyleSheet
+export const CustomErrorClass = __napiModule.exports.CustomErrorClass
 export const CustomFinalize = __napiModule.exports.CustomFinalize
 export const DefaultUseNullableClass = __napiModule.exports.DefaultUseNullableClass
+export const Disk = __napiModule.exports.Disk
 export const Dog = __napiModule.exports.Dog
 export const Fib = __napiModule.exports.Fib
 export const Fib2 = __napiModule.exports.Fib2
@@ -382,12 +388,22 @@ export const GetterSetterWithClosures = __napiModule.exports.GetterSetterWithClo
 export const JsClassForEither = __napiModule.exports.JsClassForEither
 export const JsRemote = __napiModule.exports.JsRemote
 export const JsRepo = __napiModule.exports.JsRepo
+export const NativeResource = __napiModule.exports.NativeResource
 export const NinjaTurtle = __napiModule.exports.NinjaTurtle
 export const NotUseNullableClass = __napiModule.exports.NotUseNullableClass
 export const NotWritableClass = __napiModule.exports.NotWritableClass
 export const Optional = __napiModule.exports.Optional
+export const SavingsWallet = __napiModule.exports.SavingsWallet
 export const Selector = __napiModule.exports.Selector
+export const StoreString = __napiModule.exports.StoreString
+export const StoreU32 = __napiModule.exports.StoreU32
+export const Session = __napiModule.exports.Session
+export const Square = __napiModule.exports.Square
+export const Temperature = __napiModule.exports.Temperature
+export const TrackedBuffer = __napiModule.exports.TrackedBuffer
 export const UseNullableClass = __napiModule.exports.UseNullableClass
+export const Vehicle = __napiModule.exports.Vehicle
+export const Wallet = __napiModule.exports.Wallet
 export const Width = __napiModule.exports.Width
 export const acceptArraybuffer = __napiModule.exports.acceptArraybuffer
 export const acceptSlice = __napiModule.exports.acceptSlice
@@ -406,6 +422,8 @@ export const arrayBufferPassThrough = __napiModule.exports.arrayBufferPassThroug
 export const asyncBufferToArray = __napiModule.exports.asyncBufferToArray
 export const asyncMultiTwo = __napiModule.exports.asyncMultiTwo
 export const asyncPlus100 = __napiModule.exports.asyncPlus100
+export const asyncRange = __napiModule.exports.asyncRange
+export const asyncRangeWithError = __napiModule.exports.asyncRangeWithError
 export const asyncReduceBuffer = __napiModule.exports.asyncReduceBuffer
 export const asyncTaskOptionalReturn = __napiModule.exports.asyncTaskOptionalReturn
 export const asyncTaskReadFile = __napiModule.exports.asyncTaskReadFile
@@ -413,7 +431,9 @@ export const asyncTaskVoidReturn = __napiModule.exports.asyncTaskVoidReturn
 export const bigintAdd = __napiModule.exports.bigintAdd
 export const bigintFromI128 = __napiModule.exports.bigintFromI128
 export const bigintFromI64 = __napiModule.exports.bigintFromI64
+export const bigintFromNegativeI128 = __napiModule.exports.bigintFromNegativeI128
 export const bigintGetU64AsString = __napiModule.exports.bigintGetU64AsString
+export const bigintToI128 = __napiModule.exports.bigintToI128
 export const bufferPassThrough = __napiModule.exports.bufferPassThrough
 export const buildThreadsafeFunctionFromFunction = __napiModule.exports.buildThreadsafeFunctionFromFunction
 export const call0 = __napiModule.exports.call0
@@ -424,8 +444,11 @@ export const callbackReturnPromiseAndSpawn = __napiModule.exports.callbackReturn
 export const callFunction = __napiModule.exports.callFunction
 export const callFunctionWithArg = __napiModule.exports.callFunctionWithArg
 export const callFunctionWithArgAndCtx = __napiModule.exports.callFunctionWithArgAndCtx
+export const callImplFn = __napiModule.exports.callImplFn
 export const callLongThreadsafeFunction = __napiModule.exports.callLongThreadsafeFunction
+export const callOnNextTick = __napiModule.exports.callOnNextTick
 export const callThreadsafeFunction = __napiModule.exports.callThreadsafeFunction
+export const cancelStashedTimeout = __napiModule.exports.cancelStashedTimeout
 export const captureErrorInCallback = __napiModule.exports.captureErrorInCallback
 export const chronoDateAdd1Minute = __napiModule.exports.chronoDateAdd1Minute
 export const chronoDateFixtureReturn1 = __napiModule.exports.chronoDateFixtureReturn1
@@ -438,28 +461,44 @@ export const chronoNativeDateTime = __napiModule.exports.chronoNativeDateTime
 export const chronoNativeDateTimeReturn = __napiModule.exports.chronoNativeDateTimeReturn
 export const chronoUtcDateReturn = __napiModule.exports.chronoUtcDateReturn
 export const chronoUtcDateToMillis = __napiModule.exports.chronoUtcDateToMillis
+export const clamp = __napiModule.exports.clamp
+export const combinePromises = __napiModule.exports.combinePromises
 export const concatLatin1 = __napiModule.exports.concatLatin1
 export const concatStr = __napiModule.exports.concatStr
 export const concatUtf16 = __napiModule.exports.concatUtf16
+export const constructAnimal = __napiModule.exports.constructAnimal
 export const contains = __napiModule.exports.contains
 export const convertU32Array = __napiModule.exports.convertU32Array
+export const countCallsInEnv = __napiModule.exports.countCallsInEnv
 export const createBigInt = __napiModule.exports.createBigInt
 export const createBigIntI64 = __napiModule.exports.createBigIntI64
 export const createExternal = __napiModule.exports.createExternal
 export const createExternalString = __napiModule.exports.createExternalString
 export const createExternalTypedArray = __napiModule.exports.createExternalTypedArray
+export const createFrozenConfig = __napiModule.exports.createFrozenConfig
+export const createLazyConfig = __napiModule.exports.createLazyConfig
 export const createObj = __napiModule.exports.createObj
 export const createObjectWithClassField = __napiModule.exports.createObjectWithClassField
 export const createObjWithProperty = __napiModule.exports.createObjWithProperty
+export const createObjWithPropertyAttributes = __napiModule.exports.createObjWithPropertyAttributes
 export const createOptionalExternal = __napiModule.exports.createOptionalExternal
+export const createPackageManifest = __napiModule.exports.createPackageManifest
+export const createPooledBuffer = __napiModule.exports.createPooledBuffer
 export const createReferenceOnFunction = __napiModule.exports.createReferenceOnFunction
+export const createRegisteredSymbol = __napiModule.exports.createRegisteredSymbol
+export const createSharedArraybuffer = __napiModule.exports.createSharedArraybuffer
 export const createSymbol = __napiModule.exports.createSymbol
 export const createSymbolFor = __napiModule.exports.createSymbolFor
+export const createTypedArrayFromVec = __napiModule.exports.createTypedArrayFromVec
 export const CustomNumEnum = __napiModule.exports.CustomNumEnum
 export const customStatusCode = __napiModule.exports.customStatusCode
 export const dateToNumber = __napiModule.exports.dateToNumber
 export const DEFAULT_COST = __napiModule.exports.DEFAULT_COST
 export const derefUint8Array = __napiModule.exports.derefUint8Array
+export const describeSymbol = __napiModule.exports.describeSymbol
+export const doubleEither = __napiModule.exports.doubleEither
+export const doubleTypedArray = __napiModule.exports.doubleTypedArray
+export const dropPooledBuffer = __napiModule.exports.dropPooledBuffer
 export const either3 = __napiModule.exports.either3
 export const either4 = __napiModule.exports.either4
 export const eitherBoolOrFunction = __napiModule.exports.eitherBoolOrFunction
@@ -472,14 +511,20 @@ export const enumToI32 = __napiModule.exports.enumToI32
 export const f32ArrayToArray = __napiModule.exports.f32ArrayToArray
 export const f64ArrayToArray = __napiModule.exports.f64ArrayToArray
 export const fibonacci = __napiModule.exports.fibonacci
+export const fillSharedMemoryInThread = __napiModule.exports.fillSharedMemoryInThread
+export const firstInStore = __napiModule.exports.firstInStore
 export const fnReceivedAliased = __napiModule.exports.fnReceivedAliased
+export const formatPrice = __napiModule.exports.formatPrice
+export const getAsyncIteratorSymbol = __napiModule.exports.getAsyncIteratorSymbol
 export const getBtreeMapping = __napiModule.exports.getBtreeMapping
 export const getBuffer = __napiModule.exports.getBuffer
+export const getCopiedBuffer = __napiModule.exports.getCopiedBuffer
 export const getCwd = __napiModule.exports.getCwd
 export const getEmptyBuffer = __napiModule.exports.getEmptyBuffer
 export const getExternal = __napiModule.exports.getExternal
 export const getGlobal = __napiModule.exports.getGlobal
 export const getIndexMapping = __napiModule.exports.getIndexMapping
+export const getJsMap = __napiModule.exports.getJsMap
 export const getMapping = __napiModule.exports.getMapping
 export const getModuleFileName = __napiModule.exports.getModuleFileName
 export const getNestedNumArr = __napiModule.exports.getNestedNumArr
@@ -488,6 +533,9 @@ export const getNumArr = __napiModule.exports.getNumArr
 export const getNums = __napiModule.exports.getNums
 export const getOptionalExternal = __napiModule.exports.getOptionalExternal
 export const getPackageJsonName = __napiModule.exports.getPackageJsonName
+export const getProcessInfo = __napiModule.exports.getProcessInfo
+export const getRuntimeNapiVersion = __napiModule.exports.getRuntimeNapiVersion
+export const getSet = __napiModule.exports.getSet
 export const getStrFromObject = __napiModule.exports.getStrFromObject
 export const getterFromObj = __napiModule.exports.getterFromObj
 export const getUndefined = __napiModule.exports.getUndefined
@@ -496,14 +544,31 @@ export const i16ArrayToArray = __napiModule.exports.i16ArrayToArray
 export const i32ArrayToArray = __napiModule.exports.i32ArrayToArray
 export const i64ArrayToArray = __napiModule.exports.i64ArrayToArray
 export const i8ArrayToArray = __napiModule.exports.i8ArrayToArray
+export const incrementNullable = __napiModule.exports.incrementNullable
 export const indexmapPassthrough = __napiModule.exports.indexmapPassthrough
+export const initJsLogger = __napiModule.exports.initJsLogger
+export const installPanicHandler = __napiModule.exports.installPanicHandler
+export const jsDateFromChrono = __napiModule.exports.jsDateFromChrono
+export const jsDateToChronoMillis = __napiModule.exports.jsDateToChronoMillis
+export const jsMapRemove = __napiModule.exports.jsMapRemove
+export const jsSetHas = __napiModule.exports.jsSetHas
+export const KebabStringEnum = __napiModule.exports.KebabStringEnum
+export const kebabStringEnumToIndex = __napiModule.exports.kebabStringEnumToIndex
 export const Kind = __napiModule.exports.Kind
 export const listObjKeys = __napiModule.exports.listObjKeys
+export const logWithTracing = __napiModule.exports.logWithTracing
 export const mapOption = __napiModule.exports.mapOption
+export const messageLen = __napiModule.exports.messageLen
+export const MIN_SUPPORTED_VERSION = __napiModule.exports.MIN_SUPPORTED_VERSION
+export const mutateBufferSlice = __napiModule.exports.mutateBufferSlice
 export const mutateExternal = __napiModule.exports.mutateExternal
 export const mutateOptionalExternal = __napiModule.exports.mutateOptionalExternal
 export const mutateTypedArray = __napiModule.exports.mutateTypedArray
+export const namedDelaySum = __napiModule.exports.namedDelaySum
+export const nodeVersionAtLeast = __napiModule.exports.nodeVersionAtLeast
 export const objectGetNamedPropertyShouldPerformTypecheck = __napiModule.exports.objectGetNamedPropertyShouldPerformTypecheck
+export const objectIntegrityLevel = __napiModule.exports.objectIntegrityLevel
+export const openSession = __napiModule.exports.openSession
 export const optionEnd = __napiModule.exports.optionEnd
 export const optionOnly = __napiModule.exports.optionOnly
 export const optionStart = __napiModule.exports.optionStart
@@ -512,11 +577,16 @@ export const overrideIndividualArgOnFunction = __napiModule.exports.overrideIndi
 export const overrideIndividualArgOnFunctionWithCbArg = __napiModule.exports.overrideIndividualArgOnFunctionWithCbArg
 export const panic = __napiModule.exports.panic
 export const panicInAsync = __napiModule.exports.panicInAsync
+export const parseVersion = __napiModule.exports.parseVersion
+export const peakSerialDelayConcurrency = __napiModule.exports.peakSerialDelayConcurrency
+export const pipeStream = __napiModule.exports.pipeStream
 export const plusOne = __napiModule.exports.plusOne
+export const pooledBuffersAvailable = __napiModule.exports.pooledBuffersAvailable
 export const promiseInEither = __napiModule.exports.promiseInEither
 export const readFile = __napiModule.exports.readFile
 export const readFileAsync = __napiModule.exports.readFileAsync
 export const readPackageJson = __napiModule.exports.readPackageJson
+export const readStreamToString = __napiModule.exports.readStreamToString
 export const receiveAllOptionalObject = __napiModule.exports.receiveAllOptionalObject
 export const receiveClassOrNumber = __napiModule.exports.receiveClassOrNumber
 export const receiveDifferentClass = __napiModule.exports.receiveDifferentClass
@@ -526,6 +596,8 @@ export const receiveObjectWithClassField = __napiModule.exports.receiveObjectWit
 export const receiveStrictObject = __napiModule.exports.receiveStrictObject
 export const receiveString = __napiModule.exports.receiveString
 export const referenceAsCallback = __napiModule.exports.referenceAsCallback
+export const releasedResources = __napiModule.exports.releasedResources
+export const resetPanicHandler = __napiModule.exports.resetPanicHandler
 export const returnEither = __napiModule.exports.returnEither
 export const returnEitherClass = __napiModule.exports.returnEitherClass
 export const returnFromSharedCrate = __napiModule.exports.returnFromSharedCrate
@@ -535,28 +607,58 @@ export const returnObjectOnlyToJs = __napiModule.exports.returnObjectOnlyToJs
 export const returnUndefined = __napiModule.exports.returnUndefined
 export const returnUndefinedIfInvalid = __napiModule.exports.returnUndefinedIfInvalid
 export const returnUndefinedIfInvalidPromise = __napiModule.exports.returnUndefinedIfInvalidPromise
+export const returnUndefinedIfNull = __napiModule.exports.returnUndefinedIfNull
 export const roundtripStr = __napiModule.exports.roundtripStr
+export const runCachedScript = __napiModule.exports.runCachedScript
+export const runOnStashedMainThreadHandle = __napiModule.exports.runOnStashedMainThreadHandle
 export const runScript = __napiModule.exports.runScript
+export const runScriptWithFilename = __napiModule.exports.runScriptWithFilename
+export const savingsWalletRate = __napiModule.exports.savingsWalletRate
+export const scaleShape = __napiModule.exports.scaleShape
+export const scheduleCallbacks = __napiModule.exports.scheduleCallbacks
+export const sealObject = __napiModule.exports.sealObject
+export const sendToMainThread = __napiModule.exports.sendToMainThread
+export const serialDelay = __napiModule.exports.serialDelay
+export const setJsLoggerLevel = __napiModule.exports.setJsLoggerLevel
 export const setSymbolInObj = __napiModule.exports.setSymbolInObj
+export const shapeArea = __napiModule.exports.shapeArea
+export const sleepOrAbort = __napiModule.exports.sleepOrAbort
+export const spawnBlockingSum = __napiModule.exports.spawnBlockingSum
+export const startBackgroundWorker = __napiModule.exports.startBackgroundWorker
+export const stashMainThreadHandle = __napiModule.exports.stashMainThreadHandle
+export const stashTimeout = __napiModule.exports.stashTimeout
 export const Status = __napiModule.exports.Status
 export const StringEnum = __napiModule.exports.StringEnum
+export const structuredCloneObject = __napiModule.exports.structuredCloneObject
 export const sumBtreeMapping = __napiModule.exports.sumBtreeMapping
 export const sumIndexMapping = __napiModule.exports.sumIndexMapping
+export const sumJsMap = __napiModule.exports.sumJsMap
 export const sumMapping = __napiModule.exports.sumMapping
 export const sumNums = __napiModule.exports.sumNums
+export const sumSet = __napiModule.exports.sumSet
+export const sumTypedArray = __napiModule.exports.sumTypedArray
+export const sumWithHandleScopes = __napiModule.exports.sumWithHandleScopes
+export const takeMainThreadValues = __napiModule.exports.takeMainThreadValues
 export const testSerdeBigNumberPrecision = __napiModule.exports.testSerdeBigNumberPrecision
 export const testSerdeBufferBytes = __napiModule.exports.testSerdeBufferBytes
+export const testSerdeBytesMode = __napiModule.exports.testSerdeBytesMode
 export const testSerdeRoundtrip = __napiModule.exports.testSerdeRoundtrip
 export const threadsafeFunctionClosureCapture = __napiModule.exports.threadsafeFunctionClosureCapture
 export const threadsafeFunctionFatalMode = __napiModule.exports.threadsafeFunctionFatalMode
 export const threadsafeFunctionFatalModeError = __napiModule.exports.threadsafeFunctionFatalModeError
 export const threadsafeFunctionThrowError = __napiModule.exports.threadsafeFunctionThrowError
 export const throwAsyncError = __napiModule.exports.throwAsyncError
+export const throwCustomClassError = __napiModule.exports.throwCustomClassError
 export const throwError = __napiModule.exports.throwError
+export const throwErrorFromChain = __napiModule.exports.throwErrorFromChain
+export const throwErrorWithCause = __napiModule.exports.throwErrorWithCause
 export const throwSyntaxError = __napiModule.exports.throwSyntaxError
+export const throwTypeErrorWithErrno = __napiModule.exports.throwTypeErrorWithErrno
 export const toJsObj = __napiModule.exports.toJsObj
 export const tsfnAsyncCall = __napiModule.exports.tsfnAsyncCall
+export const tsfnCallAsyncTyped = __napiModule.exports.tsfnCallAsyncTyped
 export const tsfnCallWithCallback = __napiModule.exports.tsfnCallWithCallback
+export const tsfnQueueFull = __napiModule.exports.tsfnQueueFull
 export const tsfnReturnPromise = __napiModule.exports.tsfnReturnPromise
 export const tsfnReturnPromiseTimeout = __napiModule.exports.tsfnReturnPromiseTimeout
 export const tsfnThrowFromJs = __napiModule.exports.tsfnThrowFromJs
@@ -585,8 +687,12 @@ export const validateTypedArray = __napiModule.exports.validateTypedArray
 export const validateTypedArraySlice = __napiModule.exports.validateTypedArraySlice
 export const validateUint8ClampedSlice = __napiModule.exports.validateUint8ClampedSlice
 export const validateUndefined = __napiModule.exports.validateUndefined
+export const waitForAbort = __napiModule.exports.waitForAbort
+export const walletBalance = __napiModule.exports.walletBalance
 export const withAbortController = __napiModule.exports.withAbortController
+export const withCancellationToken = __napiModule.exports.withCancellationToken
 export const withoutAbortController = __napiModule.exports.withoutAbortController
 export const xxh64Alias = __napiModule.exports.xxh64Alias
+export const path = __napiModule.exports.path
 export const xxh2 = __napiModule.exports.xxh2
 export const xxh3 = __napiModule.exports.xxh3
@@ -387,16 +387,22 @@ module.exports.Blake2BHasher = __napiModule.exports.Blake2BHasher
 module.exports.Blake2bHasher = __napiModule.exports.Blake2bHasher
 module.exports.Blake2BKey = __napiModule.exports.Blake2BKey
 module.exports.Blake2bKey = __napiModule.exports.Blake2bKey
+module.exports.Car = __napiModule.exports.Car
+module.exports.BufferedWriter = __napiModule.exports.BufferedWriter
 module.exports.CatchOnConstructor = __napiModule.exports.CatchOnConstructor
 module.exports.CatchOnConstructor2 = __napiModule.exports.CatchOnConstructor2
 module.exports.ClassWithFactory = __napiModule.exports.ClassWithFactory
+module.exports.Connection = __napiModule.exports.Connection
 module.exports.Context = __napiModule.exports.Context
+module.exports.Countdown = __napiModule.exports.Countdown
 module.exports.CssRuleList = __napiModule.exports.CssRuleList
 module.exports.CSSRuleList = __napiModule.exports.CSSRuleList
 module.exports.CssStyleSheet = __napiModule.exports.CssStyleSheet
 module.exports.CSSStyleSheet = __napiModule.exports.CSSStyleSheet
+module.exports.CustomErrorClass = __napiModule.exports.CustomErrorClass
 module.exports.CustomFinalize = __napiModule.exports.CustomFinalize
 module.exports.DefaultUseNullableClass = __napiModule.exports.DefaultUseNullableClass
+module.exports.Disk = __napiModule.exports.Disk
 module.exports.Dog = __napiModule.exports.Dog
 module.exports.Fib = __napiModule.exports.Fib
 module.exports.Fib2 = __napiModule.exports.Fib2
@@ -405,12 +411,22 @@ module.exports.GetterSetterWithClosures = __napiModule.exports.GetterSetterWithC
 module.exports.JsClassForEither = __napiModule.exports.JsClassForEither
 module.exports.JsRemote = __napiModule.exports.JsRemote
 module.exports.JsRepo = __napiModule.exports.JsRepo
+module.exports.NativeResource = __napiModule.exports.NativeResource
 module.exports.NinjaTurtle = __napiModule.exports.NinjaTurtle
 module.exports.NotUseNullableClass = __napiModule.exports.NotUseNullableClass
 module.exports.NotWritableClass = __napiModule.exports.NotWritableClass
 module.exports.Optional = __napiModule.exports.Optional
+module.exports.SavingsWallet = __napiModule.exports.SavingsWallet
 module.exports.Selector = __napiModule.exports.Selector
+module.exports.StoreString = __napiModule.exports.StoreString
+module.exports.StoreU32 = __napiModule.exports.StoreU32
+module.exports.Session = __napiModule.exports.Session
+module.exports.Square = __napiModule.exports.Square
+module.exports.Temperature = __napiModule.exports.Temperature
+module.exports.TrackedBuffer = __napiModule.exports.TrackedBuffer
 module.exports.UseNullableClass = __napiModule.exports.UseNullableClass
+module.exports.Vehicle = __napiModule.exports.Vehicle
+module.exports.Wallet = __napiModule.exports.Wallet
 module.exports.Width = __napiModule.exports.Width
 module.exports.acceptArraybuffer = __napiModule.exports.acceptArraybuffer
 module.exports.acceptSlice = __napiModule.exports.acceptSlice
@@ -429,6 +445,8 @@ module.exports.arrayBufferPassThrough = __napiModule.exports.arrayBufferPassThro
 module.exports.asyncBufferToArray = __napiModule.exports.asyncBufferToArray
 module.exports.asyncMultiTwo = __napiModule.exports.asyncMultiTwo
 module.exports.asyncPlus100 = __napiModule.exports.asyncPlus100
+module.exports.asyncRange = __napiModule.exports.asyncRange
+module.exports.asyncRangeWithError = __napiModule.exports.asyncRangeWithError
 module.exports.asyncReduceBuffer = __napiModule.exports.asyncReduceBuffer
 module.exports.asyncTaskOptionalReturn = __napiModule.exports.asyncTaskOptionalReturn
 module.exports.asyncTaskReadFile = __napiModule.exports.asyncTaskReadFile
@@ -436,7 +454,9 @@ module.exports.asyncTaskVoidReturn = __napiModule.exports.asyncTaskVoidReturn
 module.exports.bigintAdd = __napiModule.exports.bigintAdd
 module.exports.bigintFromI128 = __napiModule.exports.bigintFromI128
 module.exports.bigintFromI64 = __napiModule.exports.bigintFromI64
+module.exports.bigintFromNegativeI128 = __napiModule.exports.bigintFromNegativeI128
 module.exports.bigintGetU64AsString = __napiModule.exports.bigintGetU64AsString
+module.exports.bigintToI128 = __napiModule.exports.bigintToI128
 module.exports.bufferPassThrough = __napiModule.exports.bufferPassThrough
 module.exports.buildThreadsafeFunctionFromFunction = __napiModule.exports.buildThreadsafeFunctionFromFunction
 module.exports.call0 = __napiModule.exports.call0
@@ -447,8 +467,11 @@ module.exports.callbackReturnPromiseAndSpawn = __napiModule.exports.callbackRetu
 module.exports.callFunction = __napiModule.exports.callFunction
 module.exports.callFunctionWithArg = __napiModule.exports.callFunctionWithArg
 module.exports.callFunctionWithArgAndCtx = __napiModule.exports.callFunctionWithArgAndCtx
+module.exports.callImplFn = __napiModule.exports.callImplFn
 module.exports.callLongThreadsafeFunction = __napiModule.exports.callLongThreadsafeFunction
+module.exports.callOnNextTick = __napiModule.exports.callOnNextTick
 module.exports.callThreadsafeFunction = __napiModule.exports.callThreadsafeFunction
+module.exports.cancelStashedTimeout = __napiModule.exports.cancelStashedTimeout
 module.exports.captureErrorInCallback = __napiModule.exports.captureErrorInCallback
 module.exports.chronoDateAdd1Minute = __napiModule.exports.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = __napiModule.exports.chronoDateFixtureReturn1
@@ -461,28 +484,44 @@ module.exports.chronoNativeDateTime = __napiModule.exports.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = __napiModule.exports.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = __napiModule.exports.chronoUtcDateReturn
 module.exports.chronoUtcDateToMillis = __napiModule.exports.chronoUtcDateToMillis
+module.exports.clamp = __napiModule.exports.clamp
+module.exports.combinePromises = __napiModule.exports.combinePromises
 module.exports.concatLatin1 = __napiModule.exports.concatLatin1
 module.exports.concatStr = __napiModule.exports.concatStr
 module.exports.concatUtf16 = __napiModule.exports.concatUtf16
+module.exports.constructAnimal = __napiModule.exports.constructAnimal
 module.exports.contains = __napiModule.exports.contains
 module.exports.convertU32Array = __napiModule.exports.convertU32Array
+module.exports.countCallsInEnv = __napiModule.exports.countCallsInEnv
 module.exports.createBigInt = __napiModule.exports.createBigInt
 module.exports.createBigIntI64 = __napiModule.exports.createBigIntI64
 module.exports.createExternal = __napiModule.exports.createExternal
 module.exports.createExternalString = __napiModule.exports.createExternalString
 module.exports.createExternalTypedArray = __napiModule.exports.createExternalTypedArray
+module.exports.createFrozenConfig = __napiModule.exports.createFrozenConfig
+module.exports.createLazyConfig = __napiModule.exports.createLazyConfig
 module.exports.createObj = __napiModule.exports.createObj
 module.exports.createObjectWithClassField = __napiModule.exports.createObjectWithClassField
 module.exports.createObjWithProperty = __napiModule.exports.createObjWithProperty
+module.exports.createObjWithPropertyAttributes = __napiModule.exports.createObjWithPropertyAttributes
 module.exports.createOptionalExternal = __napiModule.exports.createOptionalExternal
+module.exports.createPackageManifest = __napiModule.exports.createPackageManifest
+module.exports.createPooledBuffer = __napiModule.exports.createPooledBuffer
 module.exports.createReferenceOnFunction = __napiModule.exports.createReferenceOnFunction
+module.exports.createRegisteredSymbol = __napiModule.exports.createRegisteredSymbol
+module.exports.createSharedArraybuffer = __napiModule.exports.createSharedArraybuffer
 module.exports.createSymbol = __napiModule.exports.createSymbol
 module.exports.createSymbolFor = __napiModule.exports.createSymbolFor
+module.exports.createTypedArrayFromVec = __napiModule.exports.createTypedArrayFromVec
 module.exports.CustomNumEnum = __napiModule.exports.CustomNumEnum
 module.exports.customStatusCode = __napiModule.exports.customStatusCode
 module.exports.dateToNumber = __napiModule.exports.dateToNumber
 module.exports.DEFAULT_COST = __napiModule.exports.DEFAULT_COST
 module.exports.derefUint8Array = __napiModule.exports.derefUint8Array
+module.exports.describeSymbol = __napiModule.exports.describeSymbol
+module.exports.doubleEither = __napiModule.exports.doubleEither
+module.exports.doubleTypedArray = __napiModule.exports.doubleTypedArray
+module.exports.dropPooledBuffer = __napiModule.exports.dropPooledBuffer
 module.exports.either3 = __napiModule.exports.either3
 module.exports.either4 = __napiModule.exports.either4
 module.exports.eitherBoolOrFunction = __napiModule.exports.eitherBoolOrFunction
@@ -495,14 +534,20 @@ module.exports.enumToI32 = __napiModule.exports.enumToI32
 module.exports.f32ArrayToArray = __napiModule.exports.f32ArrayToArray
 module.exports.f64ArrayToArray = __napiModule.exports.f64ArrayToArray
 module.exports.fibonacci = __napiModule.exports.fibonacci
+module.exports.fillSharedMemoryInThread = __napiModule.exports.fillSharedMemoryInThread
+module.exports.firstInStore = __napiModule.exports.firstInStore
 module.exports.fnReceivedAliased = __napiModule.exports.fnReceivedAliased
+module.exports.formatPrice = __napiModule.exports.formatPrice
+module.exports.getAsyncIteratorSymbol = __napiModule.exports.getAsyncIteratorSymbol
 module.exports.getBtreeMapping = __napiModule.exports.getBtreeMapping
 module.exports.getBuffer = __napiModule.exports.getBuffer
+module.exports.getCopiedBuffer = __napiModule.exports.getCopiedBuffer
 module.exports.getCwd = __napiModule.exports.getCwd
 module.exports.getEmptyBuffer = __napiModule.exports.getEmptyBuffer
 module.exports.getExternal = __napiModule.exports.getExternal
 module.exports.getGlobal = __napiModule.exports.getGlobal
 module.exports.getIndexMapping = __napiModule.exports.getIndexMapping
+module.exports.getJsMap = __napiModule.exports.getJsMap
 module.exports.getMapping = __napiModule.exports.getMapping
 module.exports.getModuleFileName = __napiModule.exports.getModuleFileName
 module.exports.getNestedNumArr = __napiModule.exports.getNestedNumArr
@@ -511,6 +556,9 @@ module.exports.getNumArr = __napiModule.exports.getNumArr
 module.exports.getNums = __napiModule.exports.getNums
 module.exports.getOptionalExternal = __napiModule.exports.getOptionalExternal
 module.exports.getPackageJsonName = __napiModule.exports.getPackageJsonName
+module.exports.getProcessInfo = __napiModule.exports.getProcessInfo
+module.exports.getRuntimeNapiVersion = __napiModule.exports.getRuntimeNapiVersion
+module.exports.getSet = __napiModule.exports.getSet
 module.exports.getStrFromObject = __napiModule.exports.getStrFromObject
 module.exports.getterFromObj = __napiModule.exports.getterFromObj
 module.exports.getUndefined = __napiModule.exports.getUndefined
@@ -519,14 +567,31 @@ module.exports.i16ArrayToArray = __napiModule.exports.i16ArrayToArray
 module.exports.i32ArrayToArray = __napiModule.exports.i32ArrayToArray
 module.exports.i64ArrayToArray = __napiModule.exports.i64ArrayToArray
 module.exports.i8ArrayToArray = __napiModule.exports.i8ArrayToArray
+module.exports.incrementNullable = __napiModule.exports.incrementNullable
 module.exports.indexmapPassthrough = __napiModule.exports.indexmapPassthrough
+module.exports.initJsLogger = __napiModule.exports.initJsLogger
+module.exports.installPanicHandler = __napiModule.exports.installPanicHandler
+module.exports.jsDateFromChrono = __napiModule.exports.jsDateFromChrono
+module.exports.jsDateToChronoMillis = __napiModule.exports.jsDateToChronoMillis
+module.exports.jsMapRemove = __napiModule.exports.jsMapRemove
+module.exports.jsSetHas = __napiModule.exports.jsSetHas
+module.exports.KebabStringEnum = __napiModule.exports.KebabStringEnum
+module.exports.kebabStringEnumToIndex = __napiModule.exports.kebabStringEnumToIndex
 module.exports.Kind = __napiModule.exports.Kind
 module.exports.listObjKeys = __napiModule.exports.listObjKeys
+module.exports.logWithTracing = __napiModule.exports.logWithTracing
 module.exports.mapOption = __napiModule.exports.mapOption
+module.exports.messageLen = __napiModule.exports.messageLen
+module.exports.MIN_SUPPORTED_VERSION = __napiModule.exports.MIN_SUPPORTED_VERSION
+module.exports.mutateBufferSlice = __napiModule.exports.mutateBufferSlice
 module.exports.mutateExternal = __napiModule.exports.mutateExternal
 module.exports.mutateOptionalExternal = __napiModule.exports.mutateOptionalExternal
 module.exports.mutateTypedArray = __napiModule.exports.mutateTypedArray
+module.exports.namedDelaySum = __napiModule.exports.namedDelaySum
+module.exports.nodeVersionAtLeast = __napiModule.exports.nodeVersionAtLeast
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = __napiModule.exports.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.objectIntegrityLevel = __napiModule.exports.objectIntegrityLevel
+module.exports.openSession = __napiModule.exports.openSession
 module.exports.optionEnd = __napiModule.exports.optionEnd
 module.exports.optionOnly = __napiModule.exports.optionOnly
 module.exports.optionStart = __napiModule.exports.optionStart
@@ -535,11 +600,16 @@ module.exports.overrideIndividualArgOnFunction = __napiModule.exports.overrideIn
 module.exports.overrideIndividualArgOnFunctionWithCbArg = __napiModule.exports.overrideIndividualArgOnFunctionWithCbArg
 module.exports.panic = __napiModule.exports.panic
 module.exports.panicInAsync = __napiModule.exports.panicInAsync
+module.exports.parseVersion = __napiModule.exports.parseVersion
+module.exports.peakSerialDelayConcurrency = __napiModule.exports.peakSerialDelayConcurrency
+module.exports.pipeStream = __napiModule.exports.pipeStream
 module.exports.plusOne = __napiModule.exports.plusOne
+module.exports.pooledBuffersAvailable = __napiModule.exports.pooledBuffersAvailable
 module.exports.promiseInEither = __napiModule.exports.promiseInEither
 module.exports.readFile = __napiModule.exports.readFile
 module.exports.readFileAsync = __napiModule.exports.readFileAsync
 module.exports.readPackageJson = __napiModule.exports.readPackageJson
+module.exports.readStreamToString = __napiModule.exports.readStreamToString
 module.exports.receiveAllOptionalObject = __napiModule.exports.receiveAllOptionalObject
 module.exports.receiveClassOrNumber = __napiModule.exports.receiveClassOrNumber
 module.exports.receiveDifferentClass = __napiModule.exports.receiveDifferentClass
@@ -549,6 +619,8 @@ module.exports.receiveObjectWithClassField = __napiModule.exports.receiveObjectW
 module.exports.receiveStrictObject = __napiModule.exports.receiveStrictObject
 module.exports.receiveString = __napiModule.exports.receiveString
 module.exports.referenceAsCallback = __napiModule.exports.referenceAsCallback
+module.exports.releasedResources = __napiModule.exports.releasedResources
+module.exports.resetPanicHandler = __napiModule.exports.resetPanicHandler
 module.exports.returnEither = __napiModule.exports.returnEither
 module.exports.returnEitherClass = __napiModule.exports.returnEitherClass
 module.exports.returnFromSharedCrate = __napiModule.exports.returnFromSharedCrate
@@ -558,28 +630,58 @@ module.exports.returnObjectOnlyToJs = __napiModule.exports.returnObjectOnlyToJs
 module.exports.returnUndefined = __napiModule.exports.returnUndefined
 module.exports.returnUndefinedIfInvalid = __napiModule.exports.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = __napiModule.exports.returnUndefinedIfInvalidPromise
+module.exports.returnUndefinedIfNull = __napiModule.exports.returnUndefinedIfNull
 module.exports.roundtripStr = __napiModule.exports.roundtripStr
+module.exports.runCachedScript = __napiModule.exports.runCachedScript
+module.exports.runOnStashedMainThreadHandle = __napiModule.exports.runOnStashedMainThreadHandle
 module.exports.runScript = __napiModule.exports.runScript
+module.exports.runScriptWithFilename = __napiModule.exports.runScriptWithFilename
+module.exports.savingsWalletRate = __napiModule.exports.savingsWalletRate
+module.exports.scaleShape = __napiModule.exports.scaleShape
+module.exports.scheduleCallbacks = __napiModule.exports.scheduleCallbacks
+module.exports.sealObject = __napiModule.exports.sealObject
+module.exports.sendToMainThread = __napiModule.exports.sendToMainThread
+module.exports.serialDelay = __napiModule.exports.serialDelay
+module.exports.setJsLoggerLevel = __napiModule.exports.setJsLoggerLevel
 module.exports.setSymbolInObj = __napiModule.exports.setSymbolInObj
+module.exports.shapeArea = __napiModule.exports.shapeArea
+module.exports.sleepOrAbort = __napiModule.exports.sleepOrAbort
+module.exports.spawnBlockingSum = __napiModule.exports.spawnBlockingSum
+module.exports.startBackgroundWorker = __napiModule.exports.startBackgroundWorker
+module.exports.stashMainThreadHandle = __napiModule.exports.stashMainThreadHandle
+module.exports.stashTimeout = __napiModule.exports.stashTimeout
 module.exports.Status = __napiModule.exports.Status
 module.exports.StringEnum = __napiModule.exports.StringEnum
+module.exports.structuredCloneObject = __napiModule.exports.structuredCloneObject
 module.exports.sumBtreeMapping = __napiModule.exports.sumBtreeMapping
 module.exports.sumIndexMapping = __napiModule.exports.sumIndexMapping
+module.exports.sumJsMap = __napiModule.exports.sumJsMap
 module.exports.sumMapping = __napiModule.exports.sumMapping
 module.exports.sumNums = __napiModule.exports.sumNums
+module.exports.sumSet = __napiModule.exports.sumSet
+module.exports.sumTypedArray = __napiModule.exports.sumTypedArray
+module.exports.sumWithHandleScopes = __napiModule.exports.sumWithHandleScopes
+module.exports.takeMainThreadValues = __napiModule.exports.takeMainThreadValues
 module.exports.testSerdeBigNumberPrecision = __napiModule.exports.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = __napiModule.exports.testSerdeBufferBytes
+module.exports.testSerdeBytesMode = __napiModule.exports.testSerdeBytesMode
 module.exports.testSerdeRoundtrip = __napiModule.exports.testSerdeRoundtrip
 module.exports.threadsafeFunctionClosureCapture = __napiModule.exports.threadsafeFunctionClosureCapture
 module.exports.threadsafeFunctionFatalMode = __napiModule.exports.threadsafeFunctionFatalMode
 module.exports.threadsafeFunctionFatalModeError = __napiModule.exports.threadsafeFunctionFatalModeError
 module.exports.threadsafeFunctionThrowError = __napiModule.exports.threadsafeFunctionThrowError
 module.exports.throwAsyncError = __napiModule.exports.throwAsyncError
+module.exports.throwCustomClassError = __napiModule.exports.throwCustomClassError
 module.exports.throwError = __napiModule.exports.throwError
+module.exports.throwErrorFromChain = __napiModule.exports.throwErrorFromChain
+module.exports.throwErrorWithCause = __napiModule.exports.throwErrorWithCause
 module.exports.throwSyntaxError = __napiModule.exports.throwSyntaxError
+module.exports.throwTypeErrorWithErrno = __napiModule.exports.throwTypeErrorWithErrno
 module.exports.toJsObj = __napiModule.exports.toJsObj
 module.exports.tsfnAsyncCall = __napiModule.exports.tsfnAsyncCall
+module.exports.tsfnCallAsyncTyped = __napiModule.exports.tsfnCallAsyncTyped
 module.exports.tsfnCallWithCallback = __napiModule.exports.tsfnCallWithCallback
+module.exports.tsfnQueueFull = __napiModule.exports.tsfnQueueFull
 module.exports.tsfnReturnPromise = __napiModule.exports.tsfnReturnPromise
 module.exports.tsfnReturnPromiseTimeout = __napiModule.exports.tsfnReturnPromiseTimeout
 module.exports.tsfnThrowFromJs = __napiModule.exports.tsfnThrowFromJs
@@ -608,8 +710,12 @@ module.exports.validateTypedArray = __napiModule.exports.validateTypedArray
 module.exports.validateTypedArraySlice = __napiModule.exports.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = __napiModule.exports.validateUint8ClampedSlice
 module.exports.validateUndefined = __napiModule.exports.validateUndefined
+module.exports.waitForAbort = __napiModule.exports.waitForAbort
+module.exports.walletBalance = __napiModule.exports.walletBalance
 module.exports.withAbortController = __napiModule.exports.withAbortController
+module.exports.withCancellationToken = __napiModule.exports.withCancellationToken
 module.exports.withoutAbortController = __napiModule.exports.withoutAbortController
 module.exports.xxh64Alias = __napiModule.exports.xxh64Alias
+module.exports.path = __napiModule.exports.path
 module.exports.xxh2 = __napiModule.exports.xxh2
 module.exports.xxh3 = __napiModule.exports.xxh3
@@ -85,6 +85,45 @@ function requireNative() {
     } else {
       loadErrors.push(new Error(`Unsupported architecture on Android ${process.arch}`))
     }
+  } else if (process.platform === 'ios') {
+    if (process.arch === 'arm64') {
+      try {
+        return require('./example.ios-arm64.node')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+      try {
+        return require('@examples/napi-ios-arm64')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+
+      try {
+        return require('./example.ios-arm64-sim.node')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+      try {
+        return require('@examples/napi-ios-arm64-sim')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+
+    } else if (process.arch === 'x64') {
+      try {
+        return require('./example.ios-x64.node')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+      try {
+        return require('@examples/napi-ios-x64')
+      } catch (e) {
+        loadErrors.push(e)
+      }
+
+    } else {
+      loadErrors.push(new Error(`Unsupported architecture on iOS: ${process.arch}`))
+    }
   } else if (process.platform === 'win32') {
     if (process.arch === 'x64') {
       try {
@@ -375,16 +414,22 @@ module.exports.Blake2BHasher = nativeBinding.Blake2BHasher
 module.exports.Blake2bHasher = nativeBinding.Blake2bHasher
 module.exports.Blake2BKey = nativeBinding.Blake2BKey
 module.exports.Blake2bKey = nativeBinding.Blake2bKey
+module.exports.Car = nativeBinding.Car
+module.exports.BufferedWriter = nativeBinding.BufferedWriter
 module.exports.CatchOnConstructor = nativeBinding.CatchOnConstructor
 module.exports.CatchOnConstructor2 = nativeBinding.CatchOnConstructor2
 module.exports.ClassWithFactory = nativeBinding.ClassWithFactory
+module.exports.Connection = nativeBinding.Connection
 module.exports.Context = nativeBinding.Context
+module.exports.Countdown = nativeBinding.Countdown
 module.exports.CssRuleList = nativeBinding.CssRuleList
 module.exports.CSSRuleList = nativeBinding.CSSRuleList
 module.exports.CssStyleSheet = nativeBinding.CssStyleSheet
 module.exports.CSSStyleSheet = nativeBinding.CSSStyleSheet
+module.exports.CustomErrorClass = nativeBinding.CustomErrorClass
 module.exports.CustomFinalize = nativeBinding.CustomFinalize
 module.exports.DefaultUseNullableClass = nativeBinding.DefaultUseNullableClass
+module.exports.Disk = nativeBinding.Disk
 module.exports.Dog = nativeBinding.Dog
 module.exports.Fib = nativeBinding.Fib
 module.exports.Fib2 = nativeBinding.Fib2
@@ -393,12 +438,22 @@ module.exports.GetterSetterWithClosures = nativeBinding.GetterSetterWithClosures
 module.exports.JsClassForEither = nativeBinding.JsClassForEither
 module.exports.JsRemote = nativeBinding.JsRemote
 module.exports.JsRepo = nativeBinding.JsRepo
+module.exports.NativeResource = nativeBinding.NativeResource
 module.exports.NinjaTurtle = nativeBinding.NinjaTurtle
 module.exports.NotUseNullableClass = nativeBinding.NotUseNullableClass
 module.exports.NotWritableClass = nativeBinding.NotWritableClass
 module.exports.Optional = nativeBinding.Optional
+module.exports.SavingsWallet = nativeBinding.SavingsWallet
 module.exports.Selector = nativeBinding.Selector
+module.exports.StoreString = nativeBinding.StoreString
+module.exports.StoreU32 = nativeBinding.StoreU32
+module.exports.Session = nativeBinding.Session
+module.exports.Square = nativeBinding.Square
+module.exports.Temperature = nativeBinding.Temperature
+module.exports.TrackedBuffer = nativeBinding.TrackedBuffer
 module.exports.UseNullableClass = nativeBinding.UseNullableClass
+module.exports.Vehicle = nativeBinding.Vehicle
+module.exports.Wallet = nativeBinding.Wallet
 module.exports.Width = nativeBinding.Width
 module.exports.acceptArraybuffer = nativeBinding.acceptArraybuffer
 module.exports.acceptSlice = nativeBinding.acceptSlice
@@ -417,6 +472,8 @@ module.exports.arrayBufferPassThrough = nativeBinding.arrayBufferPassThrough
 module.exports.asyncBufferToArray = nativeBinding.asyncBufferToArray
 module.exports.asyncMultiTwo = nativeBinding.asyncMultiTwo
 module.exports.asyncPlus100 = nativeBinding.asyncPlus100
+module.exports.asyncRange = nativeBinding.asyncRange
+module.exports.asyncRangeWithError = nativeBinding.asyncRangeWithError
 module.exports.asyncReduceBuffer = nativeBinding.asyncReduceBuffer
 module.exports.asyncTaskOptionalReturn = nativeBinding.asyncTaskOptionalReturn
 module.exports.asyncTaskReadFile = nativeBinding.asyncTaskReadFile
@@ -424,7 +481,9 @@ module.exports.asyncTaskVoidReturn = nativeBinding.asyncTaskVoidReturn
 module.exports.bigintAdd = nativeBinding.bigintAdd
 module.exports.bigintFromI128 = nativeBinding.bigintFromI128
 module.exports.bigintFromI64 = nativeBinding.bigintFromI64
+module.exports.bigintFromNegativeI128 = nativeBinding.bigintFromNegativeI128
 module.exports.bigintGetU64AsString = nativeBinding.bigintGetU64AsString
+module.exports.bigintToI128 = nativeBinding.bigintToI128
 module.exports.bufferPassThrough = nativeBinding.bufferPassThrough
 module.exports.buildThreadsafeFunctionFromFunction = nativeBinding.buildThreadsafeFunctionFromFunction
 module.exports.buildThreadsafeFunctionFromFunctionCalleeHandle = nativeBinding.buildThreadsafeFunctionFromFunctionCalleeHandle
@@ -436,8 +495,11 @@ module.exports.callbackReturnPromiseAndSpawn = nativeBinding.callbackReturnPromi
 module.exports.callFunction = nativeBinding.callFunction
 module.exports.callFunctionWithArg = nativeBinding.callFunctionWithArg
 module.exports.callFunctionWithArgAndCtx = nativeBinding.callFunctionWithArgAndCtx
+module.exports.callImplFn = nativeBinding.callImplFn
 module.exports.callLongThreadsafeFunction = nativeBinding.callLongThreadsafeFunction
+module.exports.callOnNextTick = nativeBinding.callOnNextTick
 module.exports.callThreadsafeFunction = nativeBinding.callThreadsafeFunction
+module.exports.cancelStashedTimeout = nativeBinding.cancelStashedTimeout
 module.exports.captureErrorInCallback = nativeBinding.captureErrorInCallback
 module.exports.chronoDateAdd1Minute = nativeBinding.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = nativeBinding.chronoDateFixtureReturn1
@@ -450,29 +512,45 @@ module.exports.chronoNativeDateTime = nativeBinding.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = nativeBinding.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = nativeBinding.chronoUtcDateReturn
 module.exports.chronoUtcDateToMillis = nativeBinding.chronoUtcDateToMillis
+module.exports.clamp = nativeBinding.clamp
+module.exports.combinePromises = nativeBinding.combinePromises
 module.exports.concatLatin1 = nativeBinding.concatLatin1
 module.exports.concatStr = nativeBinding.concatStr
 module.exports.concatUtf16 = nativeBinding.concatUtf16
+module.exports.constructAnimal = nativeBinding.constructAnimal
 module.exports.contains = nativeBinding.contains
 module.exports.convertU32Array = nativeBinding.convertU32Array
+module.exports.countCallsInEnv = nativeBinding.countCallsInEnv
 module.exports.createBigInt = nativeBinding.createBigInt
 module.exports.createBigIntI64 = nativeBinding.createBigIntI64
 module.exports.createExternal = nativeBinding.createExternal
 module.exports.createExternalString = nativeBinding.createExternalString
 module.exports.createExternalTypedArray = nativeBinding.createExternalTypedArray
+module.exports.createFrozenConfig = nativeBinding.createFrozenConfig
+module.exports.createLazyConfig = nativeBinding.createLazyConfig
 module.exports.createObj = nativeBinding.createObj
 module.exports.createObjectWithClassField = nativeBinding.createObjectWithClassField
 module.exports.createObjWithProperty = nativeBinding.createObjWithProperty
+module.exports.createObjWithPropertyAttributes = nativeBinding.createObjWithPropertyAttributes
 module.exports.createOptionalExternal = nativeBinding.createOptionalExternal
+module.exports.createPackageManifest = nativeBinding.createPackageManifest
+module.exports.createPooledBuffer = nativeBinding.createPooledBuffer
 module.exports.createReferenceOnFunction = nativeBinding.createReferenceOnFunction
+module.exports.createRegisteredSymbol = nativeBinding.createRegisteredSymbol
+module.exports.createSharedArraybuffer = nativeBinding.createSharedArraybuffer
 module.exports.createSymbol = nativeBinding.createSymbol
 module.exports.createSymbolFor = nativeBinding.createSymbolFor
+module.exports.createTypedArrayFromVec = nativeBinding.createTypedArrayFromVec
 module.exports.CustomNumEnum = nativeBinding.CustomNumEnum
 module.exports.customStatusCode = nativeBinding.customStatusCode
 module.exports.CustomStringEnum = nativeBinding.CustomStringEnum
 module.exports.dateToNumber = nativeBinding.dateToNumber
 module.exports.DEFAULT_COST = nativeBinding.DEFAULT_COST
 module.exports.derefUint8Array = nativeBinding.derefUint8Array
+module.exports.describeSymbol = nativeBinding.describeSymbol
+module.exports.doubleEither = nativeBinding.doubleEither
+module.exports.doubleTypedArray = nativeBinding.doubleTypedArray
+module.exports.dropPooledBuffer = nativeBinding.dropPooledBuffer
 module.exports.either3 = nativeBinding.either3
 module.exports.either4 = nativeBinding.either4
 module.exports.eitherBoolOrFunction = nativeBinding.eitherBoolOrFunction
@@ -485,14 +563,20 @@ module.exports.enumToI32 = nativeBinding.enumToI32
 module.exports.f32ArrayToArray = nativeBinding.f32ArrayToArray
 module.exports.f64ArrayToArray = nativeBinding.f64ArrayToArray
 module.exports.fibonacci = nativeBinding.fibonacci
+module.exports.fillSharedMemoryInThread = nativeBinding.fillSharedMemoryInThread
+module.exports.firstInStore = nativeBinding.firstInStore
 module.exports.fnReceivedAliased = nativeBinding.fnReceivedAliased
+module.exports.formatPrice = nativeBinding.formatPrice
+module.exports.getAsyncIteratorSymbol = nativeBinding.getAsyncIteratorSymbol
 module.exports.getBtreeMapping = nativeBinding.getBtreeMapping
 module.exports.getBuffer = nativeBinding.getBuffer
+module.exports.getCopiedBuffer = nativeBinding.getCopiedBuffer
 module.exports.getCwd = nativeBinding.getCwd
 module.exports.getEmptyBuffer = nativeBinding.getEmptyBuffer
 module.exports.getExternal = nativeBinding.getExternal
 module.exports.getGlobal = nativeBinding.getGlobal
 module.exports.getIndexMapping = nativeBinding.getIndexMapping
+module.exports.getJsMap = nativeBinding.getJsMap
 module.exports.getMapping = nativeBinding.getMapping
 module.exports.getModuleFileName = nativeBinding.getModuleFileName
 module.exports.getNestedNumArr = nativeBinding.getNestedNumArr
@@ -501,6 +585,9 @@ module.exports.getNumArr = nativeBinding.getNumArr
 module.exports.getNums = nativeBinding.getNums
 module.exports.getOptionalExternal = nativeBinding.getOptionalExternal
 module.exports.getPackageJsonName = nativeBinding.getPackageJsonName
+module.exports.getProcessInfo = nativeBinding.getProcessInfo
+module.exports.getRuntimeNapiVersion = nativeBinding.getRuntimeNapiVersion
+module.exports.getSet = nativeBinding.getSet
 module.exports.getStrFromObject = nativeBinding.getStrFromObject
 module.exports.getterFromObj = nativeBinding.getterFromObj
 module.exports.getUndefined = nativeBinding.getUndefined
@@ -509,14 +596,31 @@ module.exports.i16ArrayToArray = nativeBinding.i16ArrayToArray
 module.exports.i32ArrayToArray = nativeBinding.i32ArrayToArray
 module.exports.i64ArrayToArray = nativeBinding.i64ArrayToArray
 module.exports.i8ArrayToArray = nativeBinding.i8ArrayToArray
+module.exports.incrementNullable = nativeBinding.incrementNullable
 module.exports.indexmapPassthrough = nativeBinding.indexmapPassthrough
+module.exports.initJsLogger = nativeBinding.initJsLogger
+module.exports.installPanicHandler = nativeBinding.installPanicHandler
+module.exports.jsDateFromChrono = nativeBinding.jsDateFromChrono
+module.exports.jsDateToChronoMillis = nativeBinding.jsDateToChronoMillis
+module.exports.jsMapRemove = nativeBinding.jsMapRemove
+module.exports.jsSetHas = nativeBinding.jsSetHas
+module.exports.KebabStringEnum = nativeBinding.KebabStringEnum
+module.exports.kebabStringEnumToIndex = nativeBinding.kebabStringEnumToIndex
 module.exports.Kind = nativeBinding.Kind
 module.exports.listObjKeys = nativeBinding.listObjKeys
+module.exports.logWithTracing = nativeBinding.logWithTracing
 module.exports.mapOption = nativeBinding.mapOption
+module.exports.messageLen = nativeBinding.messageLen
+module.exports.MIN_SUPPORTED_VERSION = nativeBinding.MIN_SUPPORTED_VERSION
+module.exports.mutateBufferSlice = nativeBinding.mutateBufferSlice
 module.exports.mutateExternal = nativeBinding.mutateExternal
 module.exports.mutateOptionalExternal = nativeBinding.mutateOptionalExternal
 module.exports.mutateTypedArray = nativeBinding.mutateTypedArray
+module.exports.namedDelaySum = nativeBinding.namedDelaySum
+module.exports.nodeVersionAtLeast = nativeBinding.nodeVersionAtLeast
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = nativeBinding.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.objectIntegrityLevel = nativeBinding.objectIntegrityLevel
+module.exports.openSession = nativeBinding.openSession
 module.exports.optionEnd = nativeBinding.optionEnd
 module.exports.optionOnly = nativeBinding.optionOnly
 module.exports.optionStart = nativeBinding.optionStart
@@ -525,11 +629,16 @@ module.exports.overrideIndividualArgOnFunction = nativeBinding.overrideIndividua
 module.exports.overrideIndividualArgOnFunctionWithCbArg = nativeBinding.overrideIndividualArgOnFunctionWithCbArg
 module.exports.panic = nativeBinding.panic
 module.exports.panicInAsync = nativeBinding.panicInAsync
+module.exports.parseVersion = nativeBinding.parseVersion
+module.exports.peakSerialDelayConcurrency = nativeBinding.peakSerialDelayConcurrency
+module.exports.pipeStream = nativeBinding.pipeStream
 module.exports.plusOne = nativeBinding.plusOne
+module.exports.pooledBuffersAvailable = nativeBinding.pooledBuffersAvailable
 module.exports.promiseInEither = nativeBinding.promiseInEither
 module.exports.readFile = nativeBinding.readFile
 module.exports.readFileAsync = nativeBinding.readFileAsync
 module.exports.readPackageJson = nativeBinding.readPackageJson
+module.exports.readStreamToString = nativeBinding.readStreamToString
 module.exports.receiveAllOptionalObject = nativeBinding.receiveAllOptionalObject
 module.exports.receiveClassOrNumber = nativeBinding.receiveClassOrNumber
 module.exports.receiveDifferentClass = nativeBinding.receiveDifferentClass
@@ -539,6 +648,8 @@ module.exports.receiveObjectWithClassField = nativeBinding.receiveObjectWithClas
 module.exports.receiveStrictObject = nativeBinding.receiveStrictObject
 module.exports.receiveString = nativeBinding.receiveString
 module.exports.referenceAsCallback = nativeBinding.referenceAsCallback
+module.exports.releasedResources = nativeBinding.releasedResources
+module.exports.resetPanicHandler = nativeBinding.resetPanicHandler
 module.exports.returnEither = nativeBinding.returnEither
 module.exports.returnEitherClass = nativeBinding.returnEitherClass
 module.exports.returnFromSharedCrate = nativeBinding.returnFromSharedCrate
@@ -547,28 +658,58 @@ module.exports.returnObjectOnlyToJs = nativeBinding.returnObjectOnlyToJs
 module.exports.returnUndefined = nativeBinding.returnUndefined
 module.exports.returnUndefinedIfInvalid = nativeBinding.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = nativeBinding.returnUndefinedIfInvalidPromise
+module.exports.returnUndefinedIfNull = nativeBinding.returnUndefinedIfNull
 module.exports.roundtripStr = nativeBinding.roundtripStr
+module.exports.runCachedScript = nativeBinding.runCachedScript
+module.exports.runOnStashedMainThreadHandle = nativeBinding.runOnStashedMainThreadHandle
 module.exports.runScript = nativeBinding.runScript
+module.exports.runScriptWithFilename = nativeBinding.runScriptWithFilename
+module.exports.savingsWalletRate = nativeBinding.savingsWalletRate
+module.exports.scaleShape = nativeBinding.scaleShape
+module.exports.scheduleCallbacks = nativeBinding.scheduleCallbacks
+module.exports.sealObject = nativeBinding.sealObject
+module.exports.sendToMainThread = nativeBinding.sendToMainThread
+module.exports.serialDelay = nativeBinding.serialDelay
+module.exports.setJsLoggerLevel = nativeBinding.setJsLoggerLevel
 module.exports.setSymbolInObj = nativeBinding.setSymbolInObj
+module.exports.shapeArea = nativeBinding.shapeArea
+module.exports.sleepOrAbort = nativeBinding.sleepOrAbort
+module.exports.spawnBlockingSum = nativeBinding.spawnBlockingSum
+module.exports.startBackgroundWorker = nativeBinding.startBackgroundWorker
+module.exports.stashMainThreadHandle = nativeBinding.stashMainThreadHandle
+module.exports.stashTimeout = nativeBinding.stashTimeout
 module.exports.Status = nativeBinding.Status
 module.exports.StringEnum = nativeBinding.StringEnum
+module.exports.structuredCloneObject = nativeBinding.structuredCloneObject
 module.exports.sumBtreeMapping = nativeBinding.sumBtreeMapping
 module.exports.sumIndexMapping = nativeBinding.sumIndexMapping
+module.exports.sumJsMap = nativeBinding.sumJsMap
 module.exports.sumMapping = nativeBinding.sumMapping
 module.exports.sumNums = nativeBinding.sumNums
+module.exports.sumSet = nativeBinding.sumSet
+module.exports.sumTypedArray = nativeBinding.sumTypedArray
+module.exports.sumWithHandleScopes = nativeBinding.sumWithHandleScopes
+module.exports.takeMainThreadValues = nativeBinding.takeMainThreadValues
 module.exports.testSerdeBigNumberPrecision = nativeBinding.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = nativeBinding.testSerdeBufferBytes
+module.exports.testSerdeBytesMode = nativeBinding.testSerdeBytesMode
 module.exports.testSerdeRoundtrip = nativeBinding.testSerdeRoundtrip
 module.exports.threadsafeFunctionClosureCapture = nativeBinding.threadsafeFunctionClosureCapture
 module.exports.threadsafeFunctionFatalMode = nativeBinding.threadsafeFunctionFatalMode
 module.exports.threadsafeFunctionFatalModeError = nativeBinding.threadsafeFunctionFatalModeError
 module.exports.threadsafeFunctionThrowError = nativeBinding.threadsafeFunctionThrowError
 module.exports.throwAsyncError = nativeBinding.throwAsyncError
+module.exports.throwCustomClassError = nativeBinding.throwCustomClassError
 module.exports.throwError = nativeBinding.throwError
+module.exports.throwErrorFromChain = nativeBinding.throwErrorFromChain
+module.exports.throwErrorWithCause = nativeBinding.throwErrorWithCause
 module.exports.throwSyntaxError = nativeBinding.throwSyntaxError
+module.exports.throwTypeErrorWithErrno = nativeBinding.throwTypeErrorWithErrno
 module.exports.toJsObj = nativeBinding.toJsObj
 module.exports.tsfnAsyncCall = nativeBinding.tsfnAsyncCall
+module.exports.tsfnCallAsyncTyped = nativeBinding.tsfnCallAsyncTyped
 module.exports.tsfnCallWithCallback = nativeBinding.tsfnCallWithCallback
+module.exports.tsfnQueueFull = nativeBinding.tsfnQueueFull
 module.exports.tsfnReturnPromise = nativeBinding.tsfnReturnPromise
 module.exports.tsfnReturnPromiseTimeout = nativeBinding.tsfnReturnPromiseTimeout
 module.exports.tsfnThrowFromJs = nativeBinding.tsfnThrowFromJs
@@ -597,8 +738,12 @@ module.exports.validateTypedArray = nativeBinding.validateTypedArray
 module.exports.validateTypedArraySlice = nativeBinding.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = nativeBinding.validateUint8ClampedSlice
 module.exports.validateUndefined = nativeBinding.validateUndefined
+module.exports.waitForAbort = nativeBinding.waitForAbort
+module.exports.walletBalance = nativeBinding.walletBalance
 module.exports.withAbortController = nativeBinding.withAbortController
+module.exports.withCancellationToken = nativeBinding.withCancellationToken
 module.exports.withoutAbortController = nativeBinding.withoutAbortController
 module.exports.xxh64Alias = nativeBinding.xxh64Alias
+module.exports.path = nativeBinding.path
 module.exports.xxh2 = nativeBinding.xxh2
 module.exports.xxh3 = nativeBinding.xxh3
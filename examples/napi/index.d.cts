@@ -91,6 +91,20 @@ export declare class Blake2BKey {
 }
 export type Blake2bKey = Blake2BKey
 
+/** Inherits `wheels()` from `Vehicle` */
+export declare class Car extends Vehicle {
+  constructor(brand: string)
+  brand(): string
+}
+
+/** Flushed at the end of the scope of an `await using` declaration */
+export declare class BufferedWriter implements AsyncDisposable {
+  constructor()
+  get flushed(): boolean
+  flush(): Promise<void>
+  [Symbol.asyncDispose](): Promise<void>
+}
+
 export declare class CatchOnConstructor {
   constructor()
 }
@@ -107,6 +121,14 @@ export declare class ClassWithFactory {
   setName(name: string): this
 }
 
+/** Closed at the end of the scope of a `using` declaration */
+export declare class Connection implements Disposable {
+  constructor()
+  get closed(): boolean
+  close(): void
+  [Symbol.dispose](): void
+}
+
 export declare class Context {
   maybeNeed?: boolean
   buffer: Uint8Array
@@ -116,6 +138,12 @@ export declare class Context {
   method(): string
 }
 
+export declare class Countdown {
+  remaining: number
+  constructor(remaining: number)
+  [Symbol.iterator](): Iterator<number, void, void>
+}
+
 export declare class CssRuleList {
   getRules(): Array<string>
   get parentStyleSheet(): CSSStyleSheet
@@ -130,6 +158,11 @@ export declare class CssStyleSheet {
 }
 export type CSSStyleSheet = CssStyleSheet
 
+export declare class CustomErrorClass {
+  message: string
+  constructor(message: string)
+}
+
 export declare class CustomFinalize {
   constructor(width: number, height: number)
 }
@@ -142,6 +175,14 @@ export declare class DefaultUseNullableClass {
   constructor(requiredNumberField: number, requiredStringField: string, optionalNumberField?: number, optionalStringField?: string)
 }
 
+export declare class Disk implements Measurable {
+  radius: number
+  constructor(radius: number)
+  /** The area in square units */
+  area(): number
+  describe(): string
+}
+
 export declare class Dog {
   name: string
   constructor(name: string)
@@ -182,6 +223,11 @@ export declare class JsRepo {
   remote(): JsRemote
 }
 
+export declare class NativeResource {
+  name: string
+  constructor(name: string)
+}
+
 export declare class NinjaTurtle {
   name: string
   static isInstanceOf(value: unknown): boolean
@@ -213,6 +259,11 @@ export declare class Optional {
   static optionOnly(optional?: string | undefined | null): string
 }
 
+/** Tagged with its own tag derived from the tag of `Wallet` */
+export declare class SavingsWallet extends Wallet {
+  constructor(balance: number, rate: number)
+}
+
 export declare class Selector {
   orderBy: Array<string>
   select: Array<string>
@@ -221,6 +272,53 @@ export declare class Selector {
   constructor(orderBy: Array<string>, select: Array<string>, struct: string, where?: string)
 }
 
+/** Exported as `StoreU32` and `StoreString` */
+export declare class StoreString {
+  constructor(items: Array<string>)
+  push(item: string): void
+  get(index: number): string | null
+  get size(): number
+  join(separator: string): string
+}
+
+/** Exported as `StoreU32` and `StoreString` */
+export declare class StoreU32 {
+  constructor(items: Array<number>)
+  push(item: number): void
+  get(index: number): number | null
+  get size(): number
+}
+
+export declare class Session {
+  readonly user: string
+  private constructor()
+  static guest(): Session
+}
+
+export declare class Square implements Measurable {
+  side: number
+  constructor(side: number)
+  /** The area in square units */
+  area(): number
+  describe(): string
+}
+
+export declare class Temperature {
+  celsius: number
+  constructor(celsius: number)
+  /** Absolute zero in degrees Celsius */
+  static readonly ABSOLUTE_ZERO: number
+  static readonly BOILING_POINT: number
+  static fromFahrenheit(fahrenheit: number): Temperature
+  get fahrenheit(): number
+}
+
+export declare class TrackedBuffer {
+  constructor(size: number)
+  get length(): number
+  get trackedSize(): number
+}
+
 export declare class UseNullableClass {
   requiredNumberField: number
   requiredStringField: string
@@ -229,6 +327,18 @@ export declare class UseNullableClass {
   constructor(requiredNumberField: number, requiredStringField: string, nullableNumberField: number | null, nullableStringField: string | null)
 }
 
+export declare class Vehicle {
+  constructor(wheels: number)
+  wheels(): number
+}
+
+/** Instances are tagged, unwrapping anything else as a `Wallet` fails instead of reading foreign memory */
+export declare class Wallet {
+  constructor(balance: number)
+  balance(): number
+  toString(): string
+}
+
 export declare class Width {
   value: number
   constructor(value: number)
@@ -283,6 +393,10 @@ export declare function asyncMultiTwo(arg: number): Promise<number>
 
 export declare function asyncPlus100(p: Promise<number>): Promise<number>
 
+export declare function asyncRange(from: number, to: number): AsyncIterableIterator<number>
+
+export declare function asyncRangeWithError(to: number): AsyncIterableIterator<number>
+
 export declare function asyncReduceBuffer(buf: Buffer): Promise<number>
 
 export declare function asyncTaskOptionalReturn(): Promise<number | null>
@@ -301,8 +415,12 @@ export declare function bigintFromI128(): bigint
 
 export declare function bigintFromI64(): bigint
 
+export declare function bigintFromNegativeI128(): bigint
+
 export declare function bigintGetU64AsString(bi: bigint): string
 
+export declare function bigintToI128(bi: bigint): string
+
 export declare function bufferPassThrough(buf: Buffer): Promise<Buffer>
 
 export declare function buildThreadsafeFunctionFromFunction(callback: (arg0: number, arg1: number) => number): void
@@ -329,10 +447,17 @@ export declare function callFunctionWithArg(cb: (arg0: number, arg1: number) =>
 
 export declare function callFunctionWithArgAndCtx(ctx: Animal, cb: (arg: string) => void, name: string): void
 
+export declare function callImplFn(callback: (arg0: number, arg1: string) => string): string
+
 export declare function callLongThreadsafeFunction(tsfn: (err: Error | null, arg: number) => unknown): void
 
+export declare function callOnNextTick(callback: (arg: number) => void): void
+
 export declare function callThreadsafeFunction(tsfn: (err: Error | null, arg: number) => unknown): void
 
+/** Cancel the stashed timeout, `false` if there was none */
+export declare function cancelStashedTimeout(): boolean
+
 export declare function captureErrorInCallback(cb1: () => void, cb2: (arg0: Error) => void): void
 
 export declare function chronoDateAdd1Minute(input: Date): Date
@@ -357,16 +482,24 @@ export declare function chronoUtcDateReturn(): Date | null
 
 export declare function chronoUtcDateToMillis(input: Date): number
 
+export declare function clamp(value: number, min?: number, max?: number): number
+
+export declare function combinePromises(combinator: 'all' | 'race' | 'allSettled', promises: Array<Promise<unknown>>): Promise<unknown>
+
 export declare function concatLatin1(s: string): string
 
 export declare function concatStr(s: string): string
 
 export declare function concatUtf16(s: string): string
 
+export declare function constructAnimal(name: string): Animal
+
 export declare function contains(source: string, target: string): boolean
 
 export declare function convertU32Array(input: Uint32Array): Array<number>
 
+export declare function countCallsInEnv(): number
+
 export declare function createBigInt(): bigint
 
 export declare function createBigIntI64(): bigint
@@ -377,20 +510,37 @@ export declare function createExternalString(content: string): ExternalObject<st
 
 export declare function createExternalTypedArray(): Uint32Array
 
+export declare function createFrozenConfig(): object
+
+/** The values are only computed when they are read, until they are overwritten */
+export declare function createLazyConfig(keys: Array<string>): Record<string, string>
+
 export declare function createObj(): object
 
 export declare function createObjectWithClassField(): ObjectFieldClassInstance
 
 export declare function createObjWithProperty(): { value: ArrayBuffer, get getter(): number }
 
+export declare function createObjWithPropertyAttributes(): { readonly frozen: number, hidden: string }
+
 export declare function createOptionalExternal(size?: number | undefined | null): ExternalObject<number> | null
 
+export declare function createPackageManifest(name: string, description?: string | undefined | null): PackageManifest
+
+export declare function createPooledBuffer(len: number, fill: number): Buffer
+
 export declare function createReferenceOnFunction(cb: () => void): Promise<void>
 
+export declare function createRegisteredSymbol(key: string): symbol
+
+export declare function createSharedArraybuffer(length: number): SharedArrayBuffer
+
 export declare function createSymbol(): symbol
 
 export declare function createSymbolFor(desc: string): symbol
 
+export declare function createTypedArrayFromVec(): BigInt64Array
+
 /** You could break the step and for an new continuous value. */
 export declare const enum CustomNumEnum {
   One = 1,
@@ -430,6 +580,16 @@ export interface DefaultUseNullableStruct {
 
 export declare function derefUint8Array(a: Uint8Array, b: Uint8ClampedArray): number
 
+export declare function describeSymbol(symbol: symbol): SymbolDescription
+
+export declare function doubleEither(input: string): string
+export declare function doubleEither(input: number): number
+
+export declare function doubleTypedArray(input: Int32Array): Int32Array
+
+/** A pooled `Buffer` dropped in Rust, without being sent to JavaScript, returns its memory to the pool as well */
+export declare function dropPooledBuffer(): void
+
 export declare function either3(input: string | number | boolean): number
 
 export declare function either4(input: string | number | boolean | Obj): number
@@ -456,12 +616,31 @@ export declare function f64ArrayToArray(input: Float64Array): Array<number>
 
 export declare function fibonacci(n: number): number
 
+export declare function fillSharedMemoryInThread(memory: SharedArrayBuffer, value: number): number
+
+export declare function firstInStore(store: Store<string>): string | null
+
 export declare function fnReceivedAliased(s: AliasedStruct, e: ALIAS): void
 
+export declare function formatPrice(options: FormatPriceOptions): string
+
+export interface FormatPriceOptions {
+  amount: number
+  currency: string
+  decimals?: number
+  symbolFirst?: boolean
+  thousandsSeparator?: string
+  decimalSeparator?: string
+}
+
+export declare function getAsyncIteratorSymbol(): symbol
+
 export declare function getBtreeMapping(): Record<string, number>
 
 export declare function getBuffer(): Buffer
 
+export declare function getCopiedBuffer(): Buffer
+
 export declare function getCwd(callback: (arg0: string) => void): void
 
 export declare function getEmptyBuffer(): Buffer
@@ -472,6 +651,8 @@ export declare function getGlobal(): typeof global
 
 export declare function getIndexMapping(): Record<string, number>
 
+export declare function getJsMap(): Map<any, any>
+
 export declare function getMapping(): Record<string, number>
 
 export declare function getModuleFileName(): string
@@ -489,6 +670,12 @@ export declare function getOptionalExternal(external?: ExternalObject<number> |
 
 export declare function getPackageJsonName(packageJson: PackageJson): string
 
+export declare function getProcessInfo(): ProcessInfo
+
+export declare function getRuntimeNapiVersion(): number
+
+export declare function getSet(): Set<string>
+
 export declare function getStrFromObject(): void
 
 export declare function getterFromObj(): number
@@ -505,8 +692,29 @@ export declare function i64ArrayToArray(input: BigInt64Array): Array<number>
 
 export declare function i8ArrayToArray(input: Int8Array): Array<number>
 
+export declare function incrementNullable(val: number | null): number | null
+
 export declare function indexmapPassthrough(fixture: Record<string, number>): Record<string, number>
 
+export declare function initJsLogger(logger: (record: { level: string, target: string, message: string, fields: Record<string, string> }) => void, level: string): void
+
+export declare function installPanicHandler(): void
+
+export declare function jsDateFromChrono(millis: number): Date
+
+export declare function jsDateToChronoMillis(input: Date): number
+
+export declare function jsMapRemove(map: Map<any, any>, key: string): number
+
+export declare function jsSetHas(set: Set<any>, value: string): boolean
+
+export declare const enum KebabStringEnum {
+  VariantOne = 'variant-one',
+  VariantTwo = 'variant-two'
+}
+
+export declare function kebabStringEnumToIndex(value: KebabStringEnum): number
+
 /** default enum values are continuos i32s start from 0 */
 export declare const enum Kind {
   /** Barks */
@@ -524,14 +732,36 @@ export interface LocalDates {
   end?: Date
 }
 
+export declare function logWithTracing(message: string): void
+
 export declare function mapOption(val?: number | undefined | null): number | null
 
+export interface Measurable {
+  /** The area in square units */
+  area(): number
+  describe(): string
+}
+
+export type Message =
+| { kind: 'Text', content: string, replyTo?: number }
+| { kind: 'Ping' }
+
+export declare function messageLen(message: Message): number
+
+export const MIN_SUPPORTED_VERSION: `${number}.${number}.${number}`
+
+export declare function mutateBufferSlice(input: Buffer): void
+
 export declare function mutateExternal(external: ExternalObject<number>, newVal: number): void
 
 export declare function mutateOptionalExternal(external: ExternalObject<number> | undefined | null, newVal: number): void
 
 export declare function mutateTypedArray(input: Float32Array): void
 
+export declare function namedDelaySum(a: number, b: number): Promise<number>
+
+export declare function nodeVersionAtLeast(major: number, minor: number, patch: number): boolean
+
 export interface NotUseNullableStruct {
   requiredNumberField: number
   requiredStringField: string
@@ -549,6 +779,8 @@ export interface ObjectFieldClassInstance {
 
 export declare function objectGetNamedPropertyShouldPerformTypecheck(obj: { foo: number; bar: string; }): void
 
+export declare function objectIntegrityLevel(obj: object): { frozen: boolean, sealed: boolean }
+
 export interface ObjectOnlyFromJs {
   count: number
   callback: (err: Error | null, arg: number) => any
@@ -559,6 +791,8 @@ export interface ObjectOnlyToJs {
   dependencies: any
 }
 
+export declare function openSession(user: string): Session
+
 export declare function optionEnd(callback: (arg0: string, arg1?: string | undefined | null) => void): void
 
 export declare function optionOnly(callback: (arg0?: string | undefined | null) => void): void
@@ -580,12 +814,33 @@ export interface PackageJson {
   devDependencies?: Record<string, any>
 }
 
+export interface PackageManifest {
+  readonly name: string
+  version: string
+  readonly description?: string
+}
+
 export declare function panic(): void
 
 export declare function panicInAsync(): Promise<void>
 
+export declare function parseVersion(version: `${number}.${number}.${number}`): [number, number, number]
+
+export declare function peakSerialDelayConcurrency(): number
+
+export declare function pipeStream(input: NodeJS.ReadableStream, output: NodeJS.WritableStream): Promise<number>
+
 export declare function plusOne(this: Width): number
 
+export declare function pooledBuffersAvailable(): number
+
+export interface ProcessInfo {
+  cwd: string
+  platform: string
+  argv: Array<string>
+  env: Record<string, string>
+}
+
 export declare function promiseInEither(input: number | Promise<number>): Promise<boolean>
 
 /** napi = { version = 2, features = ["serde-json"] } */
@@ -595,6 +850,8 @@ export declare function readFileAsync(path: string): Promise<Buffer>
 
 export declare function readPackageJson(): PackageJson
 
+export declare function readStreamToString(input: NodeJS.ReadableStream): Promise<string>
+
 export declare function receiveAllOptionalObject(obj?: AllOptionalObject | undefined | null): void
 
 export declare function receiveClassOrNumber(either: number | JsClassForEither): number
@@ -613,6 +870,10 @@ export declare function receiveString(s: string): string
 
 export declare function referenceAsCallback(callback: (arg0: number, arg1: number) => number, arg0: number, arg1: number): number
 
+export declare function releasedResources(): number
+
+export declare function resetPanicHandler(): void
+
 export declare function returnEither(input: number): string | number
 
 export declare function returnEitherClass(input: number): number | JsClassForEither
@@ -629,22 +890,71 @@ export declare function returnUndefinedIfInvalid(input: boolean): boolean
 
 export declare function returnUndefinedIfInvalidPromise(input: Promise<boolean>): Promise<boolean>
 
+export declare function returnUndefinedIfNull(val: number | null): number | undefined
+
 export declare function roundtripStr(s: string): string
 
+export declare function runCachedScript(body: string): unknown
+
+/** Queue a closure with the stashed handle then drop it, `false` if the `Env` of the handle has been torn down */
+export declare function runOnStashedMainThreadHandle(): boolean
+
 export declare function runScript(script: string): unknown
 
+export declare function runScriptWithFilename(script: string, filename: string): unknown
+
+export declare function savingsWalletRate(wallet: SavingsWallet): number
+
+export declare function scaleShape(shape: Shape, factor: number): Shape
+
+export declare function scheduleCallbacks(callback: (arg: string) => void): void
+
+export declare function sealObject(obj: object): object
+
+export declare function sendToMainThread(values: Array<number>): void
+
+export declare function serialDelay(value: number): Promise<number>
+
+export declare function setJsLoggerLevel(level: string): void
+
 export declare function setSymbolInObj(symbol: symbol): object
 
+/** Shapes are passed as objects discriminated by `type` */
+export type Shape =
+| { type: 'Circle', radius: number }
+| { type: 'Rectangle', width: number, height: number }
+| { type: 'polygon', field0: Array<number> }
+| { type: 'Empty' }
+
+export declare function shapeArea(shape: Shape): number
+
 export interface Shared {
   value: number
 }
 
+/** `true` if aborted before `ms` elapsed */
+export declare function sleepOrAbort(token: AbortSignal, ms: number): Promise<boolean>
+
+export declare function spawnBlockingSum(a: number, b: number): Promise<number>
+
+export declare function startBackgroundWorker(): void
+
+export declare function stashMainThreadHandle(): void
+
+export declare function stashTimeout(): void
+
 export declare const enum Status {
   Pristine = 'Pristine',
   Loading = 'Loading',
   Ready = 'Ready'
 }
 
+/** Exported as `StoreU32` and `StoreString` */
+export type Store<T> =
+[T] extends [number] ? StoreU32 :
+[T] extends [string] ? StoreString :
+never
+
 export interface StrictObject {
   name: string
 }
@@ -655,18 +965,37 @@ export declare const enum StringEnum {
   VariantThree = 'variantthree'
 }
 
+export declare function structuredCloneObject(value: object): object
+
 export declare function sumBtreeMapping(nums: Record<string, number>): number
 
 export declare function sumIndexMapping(nums: Record<string, number>): number
 
+export declare function sumJsMap(map: Map<any, any>): number
+
 export declare function sumMapping(nums: Record<string, number>): number
 
 export declare function sumNums(nums: Array<number>): number
 
+export declare function sumSet(nums: Set<number>): number
+
+export declare function sumTypedArray(input: Float64Array): number
+
+export declare function sumWithHandleScopes(nums: Array<number>): number
+
+export interface SymbolDescription {
+  description?: string
+  key?: string
+}
+
+export declare function takeMainThreadValues(): Array<number>
+
 export declare function testSerdeBigNumberPrecision(number: string): any
 
 export declare function testSerdeBufferBytes(obj: object): bigint
 
+export declare function testSerdeBytesMode(obj: unknown, asArray: boolean): unknown
+
 export declare function testSerdeRoundtrip(data: any): any
 
 export declare function threadsafeFunctionClosureCapture(func: (arg: string) => void): void
@@ -679,16 +1008,28 @@ export declare function threadsafeFunctionThrowError(cb: (err: Error | null, arg
 
 export declare function throwAsyncError(): Promise<void>
 
+export declare function throwCustomClassError(): void
+
 export declare function throwError(): void
 
+export declare function throwErrorFromChain(input: string): number
+
+export declare function throwErrorWithCause(): void
+
 export declare function throwSyntaxError(error: string, code?: string | undefined | null): void
 
+export declare function throwTypeErrorWithErrno(): void
+
 export declare function toJsObj(): object
 
 export declare function tsfnAsyncCall(func: (arg0: number, arg1: number, arg2: number) => string): Promise<void>
 
+export declare function tsfnCallAsyncTyped(func: (err: Error | null, arg: number) => number | Promise<number>): Promise<number>
+
 export declare function tsfnCallWithCallback(tsfn: (err: Error | null, ) => string): void
 
+export declare function tsfnQueueFull(func: (arg: number) => void): Array<string>
+
 export declare function tsfnReturnPromise(func: (err: Error | null, arg: number) => Promise<number>): Promise<number>
 
 export declare function tsfnReturnPromiseTimeout(func: (err: Error | null, arg: number) => Promise<number>): Promise<number>
@@ -762,12 +1103,33 @@ export declare function validateUint8ClampedSlice(input: Uint8ClampedArray): num
 
 export declare function validateUndefined(i: undefined): boolean
 
+export declare function waitForAbort(token: AbortSignal): Promise<boolean>
+
+export declare function walletBalance(wallet: Wallet): number
+
 export declare function withAbortController(a: number, b: number, signal: AbortSignal): Promise<number>
 
+export declare function withCancellationToken(signal: AbortSignal): Promise<number>
+
 export declare function withoutAbortController(a: number, b: number): Promise<number>
 
 export declare function xxh64Alias(input: Buffer): bigint
 
+export declare namespace path {
+  export class PathSegments {
+    constructor(path: string)
+    push(segment: string): void
+    get base(): string | null
+    get depth(): number
+  }
+  /** Join `segments` with `/` */
+  export function joinSegments(segments: Array<string>): string
+  export namespace posix {
+    export function isAbsolute(path: string): boolean
+    export const SEPARATOR: string
+  }
+}
+
 export declare namespace xxh2 {
   export function xxh2Plus(a: number, b: number): number
   export function xxh3Xxh64Alias(input: Buffer): bigint
@@ -1,5 +1,6 @@
 #[cfg(not(target_family = "wasm"))]
 use futures::prelude::*;
+use futures::{io, stream, AsyncReadExt, AsyncWriteExt};
 use napi::bindgen_prelude::*;
 #[cfg(not(target_family = "wasm"))]
 use napi::tokio::fs;
@@ -36,3 +37,36 @@ async fn async_multi_two(arg: u32) -> Result<u32> {
 async fn panic_in_async() {
   panic!("panic in async function");
 }
+
+#[napi]
+fn async_range(from: u32, to: u32) -> AsyncIterator<u32> {
+  AsyncIterator::new(stream::iter((from..to).map(Ok)))
+}
+
+#[napi]
+fn async_range_with_error(to: u32) -> AsyncIterator<u32> {
+  AsyncIterator::new(stream::iter((0..=to).map(move |n| {
+    if n == to {
+      Err(Error::new(
+        Status::GenericFailure,
+        format!("reached {}", to),
+      ))
+    } else {
+      Ok(n)
+    }
+  })))
+}
+
+#[napi]
+async fn read_stream_to_string(mut input: NodeReadable) -> Result<String> {
+  let mut content = String::new();
+  input.read_to_string(&mut content).await?;
+  Ok(content)
+}
+
+#[napi]
+async fn pipe_stream(input: NodeReadable, mut output: NodeWritable) -> Result<u32> {
+  let copied = io::copy(input, &mut output).await?;
+  output.close().await?;
+  Ok(copied as u32)
+}
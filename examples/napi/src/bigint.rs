@@ -32,3 +32,13 @@ pub fn bigint_from_i64() -> BigInt {
 pub fn bigint_from_i128() -> BigInt {
   BigInt::from(-100i128)
 }
+
+#[napi]
+pub fn bigint_from_negative_i128() -> i128 {
+  -(u64::MAX as i128) - 2
+}
+
+#[napi]
+pub fn bigint_to_i128(bi: BigInt) -> Result<String> {
+  i128::try_from(bi).map(|v| v.to_string())
+}
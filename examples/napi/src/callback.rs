@@ -27,6 +27,11 @@ fn option_only<T: Fn(Option<String>) -> Result<()>>(callback: T) {
   callback(None).unwrap();
 }
 
+#[napi]
+fn call_impl_fn(callback: impl Fn(u32, String) -> Result<String>) -> Result<String> {
+  callback(42, "napi".to_owned())
+}
+
 /// napi = { version = 2, features = ["serde-json"] }
 #[napi]
 fn read_file<T: Fn(Result<()>, Option<String>) -> Result<()>>(callback: T) {
@@ -1,6 +1,8 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
 use napi::{
   bindgen_prelude::{Buffer, ClassInstance, ObjectFinalize, This, Uint8Array, Unknown},
-  Env, Property, Result,
+  Env, ExternalMemoryGuard, Property, Result,
 };
 
 use crate::r#enum::Kind;
@@ -365,6 +367,13 @@ pub fn receive_object_with_class_field(
   Ok(object.bird)
 }
 
+#[napi]
+pub fn construct_animal(env: Env, name: String) -> Result<ClassInstance<Animal>> {
+  let mut animal = env.construct::<Animal, _>((Kind::Dog, name))?;
+  animal.name.push_str(" Jr.");
+  Ok(animal)
+}
+
 #[napi(constructor)]
 pub struct NotWritableClass {
   #[napi(writable = false)]
@@ -408,6 +417,60 @@ impl ObjectFinalize for CustomFinalize {
   }
 }
 
+static RELEASED_RESOURCES: AtomicU32 = AtomicU32::new(0);
+
+#[napi(custom_finalize)]
+pub struct NativeResource {
+  pub name: String,
+}
+
+#[napi]
+impl NativeResource {
+  #[napi(constructor)]
+  pub fn new(mut env: Env, name: String) -> Result<Self> {
+    env.adjust_external_memory(name.len() as i64)?;
+    Ok(Self { name })
+  }
+
+  #[napi(finalize)]
+  fn release(&mut self, mut env: Env) -> Result<()> {
+    env.adjust_external_memory(-(self.name.len() as i64))?;
+    RELEASED_RESOURCES.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+  }
+}
+
+#[napi]
+pub fn released_resources() -> u32 {
+  RELEASED_RESOURCES.load(Ordering::Relaxed)
+}
+
+#[napi]
+pub struct TrackedBuffer {
+  data: Vec<u8>,
+  memory: ExternalMemoryGuard,
+}
+
+#[napi]
+impl TrackedBuffer {
+  #[napi(constructor)]
+  pub fn new(env: Env, size: u32) -> Result<Self> {
+    let data = vec![0; size as usize];
+    let memory = env.track_external_memory(data.capacity())?;
+    Ok(Self { data, memory })
+  }
+
+  #[napi(getter)]
+  pub fn length(&self) -> u32 {
+    self.data.len() as u32
+  }
+
+  #[napi(getter)]
+  pub fn tracked_size(&self) -> i64 {
+    self.memory.size()
+  }
+}
+
 #[napi(constructor)]
 pub struct Width {
   pub value: i32,
@@ -459,3 +522,264 @@ impl CatchOnConstructor2 {
     panic!("CatchOnConstructor2 panic");
   }
 }
+
+/// Exported as `StoreU32` and `StoreString`
+#[napi(concrete = "Store<u32>", concrete = "Store<String>")]
+pub struct Store<T> {
+  items: Vec<T>,
+}
+
+#[napi]
+impl<T: Clone> Store<T> {
+  #[napi(constructor)]
+  pub fn new(items: Vec<T>) -> Self {
+    Self { items }
+  }
+
+  #[napi]
+  pub fn push(&mut self, item: T) {
+    self.items.push(item);
+  }
+
+  #[napi]
+  pub fn get(&self, index: u32) -> Option<T> {
+    self.items.get(index as usize).cloned()
+  }
+
+  #[napi(getter)]
+  pub fn size(&self) -> u32 {
+    self.items.len() as u32
+  }
+}
+
+#[napi]
+impl Store<String> {
+  #[napi]
+  pub fn join(&self, separator: String) -> String {
+    self.items.join(&separator)
+  }
+}
+
+#[napi]
+pub fn first_in_store(store: &Store<String>) -> Option<String> {
+  store.items.first().cloned()
+}
+
+#[napi]
+pub struct Vehicle {
+  wheels: u32,
+}
+
+#[napi]
+impl Vehicle {
+  #[napi(constructor)]
+  pub fn new(wheels: u32) -> Self {
+    Self { wheels }
+  }
+
+  #[napi]
+  pub fn wheels(&self) -> u32 {
+    self.wheels
+  }
+}
+
+/// Inherits `wheels()` from `Vehicle`
+#[napi(extends = Vehicle)]
+#[repr(C)]
+pub struct Car {
+  vehicle: Vehicle,
+  brand: String,
+}
+
+#[napi]
+impl Car {
+  #[napi(constructor)]
+  pub fn new(brand: String) -> Self {
+    Self {
+      vehicle: Vehicle::new(4),
+      brand,
+    }
+  }
+
+  #[napi]
+  pub fn brand(&self) -> String {
+    self.brand.clone()
+  }
+}
+
+/// Instances are tagged, unwrapping anything else as a `Wallet` fails instead of reading foreign memory
+#[napi(type_tag = "7c8e3a52-91d4-4c3b-b0f6-5e2a6d9f1c04")]
+pub struct Wallet {
+  balance: u32,
+}
+
+#[napi]
+impl Wallet {
+  #[napi(constructor)]
+  pub fn new(balance: u32) -> Self {
+    Self { balance }
+  }
+
+  #[napi]
+  pub fn balance(&self) -> u32 {
+    self.balance
+  }
+
+  #[napi(to_string)]
+  pub fn describe(&self) -> String {
+    format!("Wallet {{ balance: {} }}", self.balance)
+  }
+}
+
+#[napi]
+pub fn wallet_balance(wallet: &Wallet) -> u32 {
+  wallet.balance
+}
+
+/// Tagged with its own tag derived from the tag of `Wallet`
+#[napi(extends = Wallet)]
+#[repr(C)]
+pub struct SavingsWallet {
+  wallet: Wallet,
+  rate: u32,
+}
+
+#[napi]
+impl SavingsWallet {
+  #[napi(constructor)]
+  pub fn new(balance: u32, rate: u32) -> Self {
+    Self {
+      wallet: Wallet::new(balance),
+      rate,
+    }
+  }
+}
+
+#[napi]
+pub fn savings_wallet_rate(wallet: &SavingsWallet) -> u32 {
+  wallet.rate
+}
+
+/// Closed at the end of the scope of a `using` declaration
+#[napi]
+pub struct Connection {
+  closed: bool,
+}
+
+#[napi]
+impl Connection {
+  #[napi(constructor)]
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> Self {
+    Self { closed: false }
+  }
+
+  #[napi(getter)]
+  pub fn closed(&self) -> bool {
+    self.closed
+  }
+
+  #[napi(disposable)]
+  pub fn close(&mut self) {
+    self.closed = true;
+  }
+}
+
+/// Flushed at the end of the scope of an `await using` declaration
+#[napi]
+pub struct BufferedWriter {
+  flushed: AtomicBool,
+}
+
+#[napi]
+impl BufferedWriter {
+  #[napi(constructor)]
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> Self {
+    Self {
+      flushed: AtomicBool::new(false),
+    }
+  }
+
+  #[napi(getter)]
+  pub fn flushed(&self) -> bool {
+    self.flushed.load(Ordering::Relaxed)
+  }
+
+  #[napi(disposable)]
+  pub async fn flush(&self) {
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    self.flushed.store(true, Ordering::Relaxed);
+  }
+}
+
+#[napi(constructor)]
+pub struct Temperature {
+  pub celsius: f64,
+}
+
+#[napi]
+impl Temperature {
+  /// Absolute zero in degrees Celsius
+  #[napi]
+  pub const ABSOLUTE_ZERO: f64 = -273.15;
+
+  #[napi(js_name = "BOILING_POINT")]
+  pub const BOILING_POINT_CELSIUS: f64 = 100.0;
+
+  #[napi(static)]
+  pub fn from_fahrenheit(fahrenheit: f64) -> Temperature {
+    Temperature {
+      celsius: (fahrenheit - 32.0) * 5.0 / 9.0,
+    }
+  }
+
+  #[napi(getter)]
+  pub fn fahrenheit(&self) -> f64 {
+    self.celsius * 9.0 / 5.0 + 32.0
+  }
+}
+
+pub trait Measurable {
+  fn area(&self) -> f64;
+
+  fn describe(&self) -> String;
+}
+
+#[napi(constructor)]
+pub struct Square {
+  pub side: f64,
+}
+
+#[napi]
+impl Measurable for Square {
+  /// The area in square units
+  #[napi]
+  fn area(&self) -> f64 {
+    self.side * self.side
+  }
+
+  #[napi]
+  fn describe(&self) -> String {
+    format!("square of side {}", self.side)
+  }
+}
+
+#[napi(constructor)]
+pub struct Disk {
+  pub radius: f64,
+}
+
+#[napi]
+impl Measurable for Disk {
+  /// The area in square units
+  #[napi]
+  fn area(&self) -> f64 {
+    std::f64::consts::PI * self.radius * self.radius
+  }
+
+  #[napi]
+  fn describe(&self) -> String {
+    format!("disk of radius {}", self.radius)
+  }
+}
@@ -36,3 +36,24 @@ impl ClassWithFactory {
     self
   }
 }
+
+#[napi(constructor = false)]
+pub struct Session {
+  #[napi(readonly)]
+  pub user: String,
+}
+
+#[napi]
+impl Session {
+  #[napi(factory)]
+  pub fn guest() -> Self {
+    Self {
+      user: "guest".to_owned(),
+    }
+  }
+}
+
+#[napi]
+pub fn open_session(user: String) -> Session {
+  Session { user }
+}
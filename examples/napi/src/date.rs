@@ -8,6 +8,17 @@ fn date_to_number(input: Date) -> Result<f64> {
   input.value_of()
 }
 
+#[napi]
+fn js_date_to_chrono_millis(input: Date) -> Result<i64> {
+  chrono::DateTime::<Utc>::try_from(input).map(|date| date.timestamp_millis())
+}
+
+#[napi]
+fn js_date_from_chrono(env: Env, millis: i64) -> Result<Date> {
+  let date = Utc.timestamp_millis_opt(millis).unwrap();
+  env.create_date_from_chrono(&date)
+}
+
 #[napi]
 fn chrono_utc_date_to_millis(input: chrono::DateTime<Utc>) -> i64 {
   input.timestamp_millis()
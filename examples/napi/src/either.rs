@@ -17,6 +17,17 @@ fn return_either(input: u32) -> Either<String, u32> {
   }
 }
 
+#[napi(
+  ts_overload = "(input: string): string",
+  ts_overload = "(input: number): number"
+)]
+fn double_either(input: Either<String, f64>) -> Either<String, f64> {
+  match input {
+    Either::A(s) => Either::A(s.repeat(2)),
+    Either::B(n) => Either::B(n * 2.0),
+  }
+}
+
 #[napi]
 fn either3(input: Either3<String, u32, bool>) -> u32 {
   match input {
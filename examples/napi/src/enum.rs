@@ -53,6 +53,17 @@ pub enum SkippedEnums {
   Tree,
 }
 
+#[napi(string_enum = "kebab-case")]
+pub enum KebabStringEnum {
+  VariantOne,
+  VariantTwo,
+}
+
+#[napi(strict)]
+fn kebab_string_enum_to_index(value: KebabStringEnum) -> u32 {
+  value as u32
+}
+
 #[napi(string_enum)]
 pub enum CustomStringEnum {
   #[napi(value = "my-custom-value")]
@@ -60,3 +71,59 @@ pub enum CustomStringEnum {
   Bar,
   Baz,
 }
+
+/// Shapes are passed as objects discriminated by `type`
+#[napi]
+pub enum Shape {
+  Circle {
+    radius: f64,
+  },
+  Rectangle {
+    width: f64,
+    height: f64,
+  },
+  #[napi(value = "polygon")]
+  Polygon(Vec<f64>),
+  Empty,
+}
+
+#[napi]
+fn shape_area(shape: Shape) -> f64 {
+  match shape {
+    Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+    Shape::Rectangle { width, height } => width * height,
+    Shape::Polygon(_) | Shape::Empty => 0.0,
+  }
+}
+
+#[napi]
+fn scale_shape(shape: Shape, factor: f64) -> Shape {
+  match shape {
+    Shape::Circle { radius } => Shape::Circle {
+      radius: radius * factor,
+    },
+    Shape::Rectangle { width, height } => Shape::Rectangle {
+      width: width * factor,
+      height: height * factor,
+    },
+    Shape::Polygon(points) => Shape::Polygon(points.into_iter().map(|p| p * factor).collect()),
+    Shape::Empty => Shape::Empty,
+  }
+}
+
+#[napi(discriminant = "kind")]
+pub enum Message {
+  Text {
+    content: String,
+    reply_to: Option<u32>,
+  },
+  Ping,
+}
+
+#[napi]
+fn message_len(message: Message) -> u32 {
+  match message {
+    Message::Text { content, .. } => content.len() as u32,
+    Message::Ping => 0,
+  }
+}
@@ -1,10 +1,22 @@
-use napi::bindgen_prelude::*;
+use std::{collections::HashMap, sync::mpsc, thread};
+
+use napi::{bindgen_prelude::*, JsNumber};
 
 #[napi]
 pub fn run_script(env: Env, script: String) -> Result<Unknown> {
   env.run_script(script)
 }
 
+#[napi]
+pub fn run_script_with_filename(env: Env, script: String, filename: String) -> Result<Unknown> {
+  env.run_script_with_filename(script, filename)
+}
+
+#[napi]
+pub fn run_cached_script(env: Env, body: String) -> Result<Unknown> {
+  env.run_script_cached(&body, Some("cached-script.js"))
+}
+
 #[napi]
 pub fn get_module_file_name(env: Env) -> Result<String> {
   env.get_module_file_name()
@@ -14,3 +26,70 @@ pub fn get_module_file_name(env: Env) -> Result<String> {
 pub fn throw_syntax_error(env: Env, error: String, code: Option<String>) {
   env.throw_syntax_error(error, code);
 }
+
+struct CallCounter(u32);
+
+#[napi]
+pub fn count_calls_in_env(env: Env) -> Result<u32> {
+  let counter = env.get_or_init_instance_data(|| CallCounter(0))?;
+  counter.0 += 1;
+  Ok(counter.0)
+}
+
+#[napi]
+pub fn start_background_worker(mut env: Env) -> Result<()> {
+  let (stop, stopped) = mpsc::channel::<()>();
+  // runs until the cleanup hook drops `stop`
+  let worker = thread::spawn(move || while stopped.recv().is_ok() {});
+  env.add_env_cleanup_hook((stop, worker), |(stop, worker)| {
+    drop(stop);
+    let _ = worker.join();
+  })?;
+  Ok(())
+}
+
+#[napi]
+pub fn get_runtime_napi_version(env: Env) -> Result<u32> {
+  env.get_napi_version()
+}
+
+#[napi]
+pub fn node_version_at_least(env: Env, major: u32, minor: u32, patch: u32) -> Result<bool> {
+  Ok(env.get_node_version()?.at_least(major, minor, patch))
+}
+
+#[napi]
+pub fn sum_with_handle_scopes(env: Env, nums: Vec<u32>) -> Result<JsNumber> {
+  env.with_handle_scope(|scope| {
+    let mut sum = 0;
+    for chunk in nums.chunks(100) {
+      // the numbers of a chunk are released when its scope closes
+      sum += env.with_handle_scope(|_| {
+        chunk
+          .iter()
+          .map(|n| env.create_uint32(*n)?.get_uint32())
+          .sum::<Result<u32>>()
+      })?;
+    }
+    scope.escape(env.create_uint32(sum)?)
+  })
+}
+
+#[napi(object)]
+pub struct ProcessInfo {
+  pub cwd: String,
+  pub platform: String,
+  pub argv: Vec<String>,
+  pub env: HashMap<String, String>,
+}
+
+#[napi]
+pub fn get_process_info(env: Env) -> Result<ProcessInfo> {
+  let process = env.node_process()?;
+  Ok(ProcessInfo {
+    cwd: process.cwd()?,
+    platform: process.platform()?,
+    argv: process.argv()?,
+    env: process.env()?,
+  })
+}
@@ -10,6 +10,16 @@ pub fn panic() {
   panic!("Don't panic");
 }
 
+#[napi]
+pub fn install_panic_handler() {
+  napi::set_panic_handler(|panic| panic.to_error().with_property("code", "ERR_RUST_PANIC"));
+}
+
+#[napi]
+pub fn reset_panic_handler() {
+  napi::reset_panic_handler();
+}
+
 #[napi]
 pub fn receive_string(s: String) -> String {
   s
@@ -38,3 +48,61 @@ pub fn custom_status_code() -> Result<(), CustomError> {
 pub async fn throw_async_error() -> Result<()> {
   Err(Error::new(Status::InvalidArg, "Async Error".to_owned()))
 }
+
+#[napi]
+pub fn throw_type_error_with_errno() -> Result<()> {
+  Err(
+    Error::new(Status::InvalidArg, "Invalid file descriptor")
+      .with_kind(ErrorKind::TypeError)
+      .with_property("errno", -9)
+      .with_property("syscall", "read"),
+  )
+}
+
+#[napi]
+pub struct CustomErrorClass {
+  pub message: String,
+}
+
+#[napi]
+impl CustomErrorClass {
+  #[napi(constructor)]
+  pub fn new(message: String) -> Self {
+    Self { message }
+  }
+}
+
+#[napi]
+pub fn throw_custom_class_error() -> Result<()> {
+  Err(Error::from_reason("Custom class error").with_kind(ErrorKind::Class("CustomErrorClass")))
+}
+
+#[napi]
+pub fn throw_error_with_cause() -> Result<()> {
+  Err(
+    Error::from_reason("Failed to load config")
+      .with_cause(Error::new(Status::InvalidArg, "Missing field `name`")),
+  )
+}
+
+#[derive(Debug)]
+struct LoadError(std::num::ParseIntError);
+
+impl std::fmt::Display for LoadError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Failed to parse port")
+  }
+}
+
+impl std::error::Error for LoadError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.0)
+  }
+}
+
+#[napi]
+pub fn throw_error_from_chain(input: String) -> Result<u32> {
+  input
+    .parse::<u32>()
+    .map_err(|err| Error::from_error_chain(&LoadError(err)))
+}
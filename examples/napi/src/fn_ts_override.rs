@@ -25,3 +25,14 @@ fn override_individual_arg_on_function_with_cb_arg<
 ) -> Result<Object> {
   callback(format!("World({})", not_overridden), None)
 }
+
+#[napi(ts_type = "`${number}.${number}.${number}`")]
+pub const MIN_SUPPORTED_VERSION: &str = "1.2.3";
+
+#[napi(ts_return_type = "[number, number, number]")]
+fn parse_version(#[napi(ts_type = "`${number}.${number}.${number}`")] version: String) -> Vec<u32> {
+  version
+    .split('.')
+    .map(|part| part.parse().unwrap_or(0))
+    .collect()
+}
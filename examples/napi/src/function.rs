@@ -1,9 +1,11 @@
 #![allow(deprecated)]
 
+use std::sync::Mutex;
+
 use napi::{
   bindgen_prelude::{ClassInstance, Function, FunctionRef},
   threadsafe_function::ThreadsafeFunctionCallMode,
-  Env, Error, JsObject, Result, Status,
+  Env, Error, JsObject, Result, ScheduledCallback, Status,
 };
 
 use crate::class::Animal;
@@ -127,3 +129,40 @@ pub fn build_threadsafe_function_from_function_callee_handle(
 
   Ok(())
 }
+
+#[napi]
+pub fn schedule_callbacks(env: Env, callback: Function<String, ()>) -> Result<()> {
+  for (name, delay, cancel) in [
+    ("timeout", Some(10.0), false),
+    // cleared when cancelled, it would keep the process alive for a minute otherwise
+    ("cancelled timeout", Some(60_000.0), true),
+    ("microtask", None, false),
+    ("cancelled microtask", None, true),
+  ] {
+    let callback = callback.create_ref()?;
+    let run = move |env: Env| callback.borrow_back(&env)?.call(name.to_owned());
+    let scheduled = match delay {
+      Some(delay) => env.set_timeout(run, delay)?,
+      None => env.queue_microtask(run)?,
+    };
+    if cancel {
+      scheduled.cancel();
+    }
+  }
+  Ok(())
+}
+
+static STASHED_TIMEOUT: Mutex<Option<ScheduledCallback>> = Mutex::new(None);
+
+#[napi]
+pub fn stash_timeout(env: Env) -> Result<()> {
+  *STASHED_TIMEOUT.lock().unwrap() = Some(env.set_timeout(|_env| Ok(()), 60_000.0)?);
+  Ok(())
+}
+
+/// Cancel the stashed timeout, `false` if there was none
+#[napi]
+pub fn cancel_stashed_timeout() -> bool {
+  let scheduled = STASHED_TIMEOUT.lock().unwrap().take();
+  scheduled.map(|scheduled| scheduled.cancel()).is_some()
+}
@@ -109,3 +109,21 @@ impl Generator for Fib3 {
     Some(self.current)
   }
 }
+
+#[napi(iterator, constructor)]
+pub struct Countdown {
+  pub remaining: u32,
+}
+
+#[napi]
+impl Iterator for Countdown {
+  type Item = u32;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    self.remaining -= 1;
+    Some(self.remaining)
+  }
+}
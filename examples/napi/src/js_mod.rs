@@ -81,3 +81,51 @@ use napi::bindgen_prelude::Buffer;
 pub fn xxh64_alias(input: Buffer) -> u64 {
   xxh3::xxh64(input)
 }
+
+#[napi(namespace = "path")]
+/// Join `segments` with `/`
+pub fn join_segments(segments: Vec<String>) -> String {
+  segments.join("/")
+}
+
+#[napi(namespace = "path")]
+pub struct PathSegments {
+  segments: Vec<String>,
+}
+
+#[napi]
+impl PathSegments {
+  #[napi(constructor)]
+  pub fn new(path: String) -> Self {
+    PathSegments {
+      segments: path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.to_owned())
+        .collect(),
+    }
+  }
+
+  #[napi]
+  pub fn push(&mut self, segment: String) {
+    self.segments.push(segment);
+  }
+
+  #[napi(getter)]
+  pub fn base(&self) -> Option<String> {
+    self.segments.last().cloned()
+  }
+
+  #[napi(getter)]
+  pub fn depth(&self) -> u32 {
+    self.segments.len() as u32
+  }
+}
+
+#[napi(namespace = "path::posix")]
+pub const SEPARATOR: &str = "/";
+
+#[napi(namespace = "path::posix")]
+pub fn is_absolute(path: String) -> bool {
+  path.starts_with(SEPARATOR)
+}
@@ -67,4 +67,5 @@ mod string;
 mod symbol;
 mod task;
 mod threadsafe_function;
+mod tracing_logger;
 mod typed_array;
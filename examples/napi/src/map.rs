@@ -1,6 +1,7 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use indexmap::IndexMap;
+use napi::{bindgen_prelude::*, JsMap, JsSet};
 
 #[napi]
 fn get_mapping() -> HashMap<String, u32> {
@@ -45,3 +46,40 @@ fn sum_index_mapping(nums: IndexMap<String, u32>) -> u32 {
 fn indexmap_passthrough(fixture: IndexMap<String, u32>) -> IndexMap<String, u32> {
   fixture
 }
+
+#[napi]
+fn get_js_map(env: Env) -> Result<JsMap> {
+  let mut map = HashMap::new();
+  map.insert("a".to_string(), 101);
+  map.insert("b".to_string(), 102);
+  JsMap::from_hash_map(&env, map)
+}
+
+#[napi]
+fn sum_js_map(map: JsMap) -> Result<u32> {
+  let map: HashMap<String, u32> = map.to_hash_map()?;
+  Ok(map.into_values().sum())
+}
+
+#[napi]
+fn js_map_remove(mut map: JsMap, key: String) -> Result<u32> {
+  map.delete(key)?;
+  map.size()
+}
+
+#[napi]
+fn get_set() -> HashSet<String> {
+  let mut set = HashSet::new();
+  set.insert("a".to_string());
+  set
+}
+
+#[napi]
+fn sum_set(nums: HashSet<u32>) -> u32 {
+  nums.into_iter().sum()
+}
+
+#[napi]
+fn js_set_has(set: JsSet, value: String) -> Result<bool> {
+  set.has(value)
+}
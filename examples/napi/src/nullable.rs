@@ -13,6 +13,22 @@ fn return_null() -> Null {
 #[napi]
 fn return_undefined() -> Undefined {}
 
+#[napi]
+fn increment_nullable(val: Nullable<u32>) -> Nullable<u32> {
+  match val {
+    Nullable::Value(v) => Nullable::Value(v + 1),
+    Nullable::Null => Nullable::Null,
+  }
+}
+
+#[napi]
+fn return_undefined_if_null(val: Nullable<u32>) -> Either<u32, Undefined> {
+  match val {
+    Nullable::Value(v) => Either::A(v),
+    Nullable::Null => Either::B(()),
+  }
+}
+
 #[napi(object, use_nullable = true)]
 struct UseNullableStruct {
   pub required_number_field: u32,
@@ -10,3 +10,8 @@ fn fibonacci(n: u32) -> u32 {
     _ => fibonacci(n - 1) + fibonacci(n - 2),
   }
 }
+
+#[napi]
+fn clamp(value: f64, #[napi(default = "0.0")] min: f64, #[napi(default = "1.0")] max: f64) -> f64 {
+  value.max(min).min(max)
+}
@@ -1,6 +1,9 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
 use napi::{
-  bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, JsGlobal, JsNull, JsObject,
-  JsUndefined,
+  bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, JsGlobal, JsNull, JsObject, JsProxy,
+  JsUndefined, WellKnownSymbol,
 };
 
 #[napi]
@@ -21,6 +24,11 @@ fn get_global(env: Env) -> Result<JsGlobal> {
   env.get_global()
 }
 
+#[napi]
+fn structured_clone_object(env: Env, value: JsObject) -> Result<JsObject> {
+  env.get_global()?.structured_clone(value)
+}
+
 #[napi]
 fn get_undefined(env: Env) -> Result<JsUndefined> {
   env.get_undefined()
@@ -100,6 +108,94 @@ pub fn create_obj_with_property(env: Env) -> Result<JsObject> {
   Ok(obj)
 }
 
+#[napi(ts_return_type = "{ readonly frozen: number, hidden: string }")]
+pub fn create_obj_with_property_attributes(env: Env) -> Result<JsObject> {
+  let mut obj = env.create_object()?;
+  let frozen = env.create_uint32(1)?;
+  let hidden = env.create_string("hidden")?;
+  let tag = env.create_string("NapiRsObject")?;
+  obj.define_properties(&[
+    Property::new("frozen")?.with_value(&frozen).writable(false),
+    Property::new("hidden")?
+      .with_value(&hidden)
+      .enumerable(false),
+    Property::default()
+      .key_symbol(WellKnownSymbol::ToStringTag)
+      .with_value(&tag)
+      .enumerable(false),
+  ])?;
+  Ok(obj)
+}
+
+#[napi]
+pub fn create_frozen_config(env: Env) -> Result<Object> {
+  let mut config = env.create_object()?;
+  config.set_named_property("name", "napi-rs")?;
+  config.freeze()?;
+  Ok(config)
+}
+
+#[napi]
+pub fn seal_object(mut obj: Object) -> Result<Object> {
+  obj.seal()?;
+  Ok(obj)
+}
+
+#[napi(ts_return_type = "{ frozen: boolean, sealed: boolean }")]
+pub fn object_integrity_level(env: Env, obj: Object) -> Result<Object> {
+  let mut result = env.create_object()?;
+  result.set_named_property("frozen", obj.is_frozen()?)?;
+  result.set_named_property("sealed", obj.is_sealed()?)?;
+  Ok(result)
+}
+
+/// The values are only computed when they are read, until they are overwritten
+#[napi(ts_return_type = "Record<string, string>")]
+pub fn create_lazy_config(env: Env, keys: Vec<String>) -> Result<JsObject> {
+  let entries: Rc<RefCell<IndexMap<String, Option<String>>>> = Rc::new(RefCell::new(
+    keys.into_iter().map(|key| (key, None)).collect(),
+  ));
+  let (get_entries, set_entries, has_entries, delete_entries, own_keys_entries) = (
+    entries.clone(),
+    entries.clone(),
+    entries.clone(),
+    entries.clone(),
+    entries,
+  );
+  env.create_proxy(
+    JsProxy::new()
+      .get(move |_, key| {
+        let Either::A(key) = key else {
+          return Ok(None);
+        };
+        Ok(
+          get_entries
+            .borrow()
+            .get(&key)
+            .map(|value| value.clone().unwrap_or_else(|| format!("default {}", key))),
+        )
+      })
+      .set(move |_, key, value| {
+        let Either::A(key) = key else {
+          return Ok(false);
+        };
+        let value = value.coerce_to_string()?.into_utf8()?.into_owned()?;
+        set_entries.borrow_mut().insert(key, Some(value));
+        Ok(true)
+      })
+      .has(move |_, key| {
+        Ok(matches!(key, Either::A(key) if has_entries.borrow().contains_key(&key)))
+      })
+      .delete_property(move |_, key| {
+        if let Either::A(key) = key {
+          delete_entries.borrow_mut().shift_remove(&key);
+        }
+        Ok(true)
+      })
+      .own_keys(move |_| Ok(own_keys_entries.borrow().keys().cloned().collect())),
+  )
+}
+
 #[napi]
 fn getter_from_obj() -> u32 {
   42
@@ -145,3 +241,69 @@ fn return_object_only_to_js() -> ObjectOnlyToJs {
     dependencies: serde_json::json!({ "@napi-rs/cli": "^3.0.0", "rollup": "^4.0.0" }),
   }
 }
+
+#[napi(object)]
+pub struct FormatPriceOptions {
+  pub amount: f64,
+  pub currency: String,
+  pub decimals: Option<u32>,
+  pub symbol_first: Option<bool>,
+  pub thousands_separator: Option<String>,
+  pub decimal_separator: Option<String>,
+}
+
+#[napi]
+pub fn format_price(
+  FormatPriceOptions {
+    amount,
+    currency,
+    decimals,
+    symbol_first,
+    thousands_separator,
+    decimal_separator,
+  }: FormatPriceOptions,
+) -> String {
+  let decimals = decimals.unwrap_or(2) as usize;
+  let formatted = format!("{:.*}", decimals, amount.abs());
+  let (integer, fraction) = formatted.split_at(formatted.find('.').unwrap_or(formatted.len()));
+  let thousands_separator = thousands_separator.unwrap_or_else(|| ",".to_owned());
+  let mut grouped = String::new();
+  for (i, digit) in integer.chars().enumerate() {
+    if i > 0 && (integer.len() - i) % 3 == 0 {
+      grouped.push_str(&thousands_separator);
+    }
+    grouped.push(digit);
+  }
+  if let Some(fraction) = fraction.strip_prefix('.') {
+    grouped.push_str(decimal_separator.as_deref().unwrap_or("."));
+    grouped.push_str(fraction);
+  }
+  let sign = if amount < 0.0 { "-" } else { "" };
+  if symbol_first.unwrap_or(false) {
+    format!("{}{}{}", sign, currency, grouped)
+  } else {
+    format!("{}{} {}", sign, grouped, currency)
+  }
+}
+
+#[napi(object)]
+pub struct PackageManifest {
+  #[napi(readonly)]
+  pub name: String,
+  pub version: String,
+  #[napi(readonly)]
+  pub description: Option<String>,
+  /// Only used on the Rust side
+  #[napi(skip_typescript)]
+  pub checksum: Option<String>,
+}
+
+#[napi]
+pub fn create_package_manifest(name: String, description: Option<String>) -> PackageManifest {
+  PackageManifest {
+    name,
+    version: "0.0.0".to_owned(),
+    description,
+    checksum: None,
+  }
+}
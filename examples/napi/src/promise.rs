@@ -1,7 +1,31 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, Env, JsPromise};
 
 #[napi]
 pub async fn async_plus_100(p: Promise<u32>) -> Result<u32> {
   let v = p.await?;
   Ok(v + 100)
 }
+
+#[napi(
+  ts_args_type = "combinator: 'all' | 'race' | 'allSettled', promises: Array<Promise<unknown>>"
+)]
+pub fn combine_promises(
+  env: Env,
+  combinator: String,
+  mut promises: Vec<JsPromise>,
+) -> Result<JsPromise> {
+  let count = promises.len() as u32;
+  promises.push(JsPromise::from_future(&env, async move {
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    Ok(count)
+  })?);
+  match combinator.as_str() {
+    "all" => JsPromise::all(&env, promises),
+    "race" => JsPromise::race(&env, promises),
+    "allSettled" => JsPromise::all_settled(&env, promises),
+    _ => Err(Error::new(
+      Status::InvalidArg,
+      format!("Unknown combinator {}", combinator),
+    )),
+  }
+}
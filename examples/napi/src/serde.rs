@@ -48,3 +48,14 @@ fn test_serde_buffer_bytes(obj: Object, env: Env) -> napi::Result<usize> {
   let obj: BytesObject = env.from_js_value(obj)?;
   Ok(obj.code.len())
 }
+
+#[napi]
+fn test_serde_bytes_mode(env: Env, obj: Unknown, as_array: bool) -> Result<Unknown> {
+  let obj: BytesObject = napi::serde::from_js_value(obj)?;
+  let bytes_mode = if as_array {
+    napi::serde::BytesMode::Array
+  } else {
+    napi::serde::BytesMode::Buffer
+  };
+  napi::serde::to_js_value_with_bytes_mode(&env, &obj, bytes_mode)
+}
@@ -1,4 +1,4 @@
-use napi::{bindgen_prelude::*, JsObject, JsSymbol};
+use napi::{bindgen_prelude::*, JsObject, JsSymbol, WellKnownSymbol};
 
 #[napi]
 pub fn set_symbol_in_obj(env: Env, symbol: JsSymbol) -> Result<JsObject> {
@@ -16,3 +16,27 @@ pub fn create_symbol() -> Symbol {
 pub fn create_symbol_for(desc: String) -> Symbol {
   Symbol::for_desc(desc)
 }
+
+#[napi]
+pub fn create_registered_symbol(env: Env, key: String) -> Result<JsSymbol> {
+  JsSymbol::for_key(&env, &key)
+}
+
+#[napi]
+pub fn get_async_iterator_symbol(env: Env) -> Result<JsSymbol> {
+  JsSymbol::well_known(&env, WellKnownSymbol::AsyncIterator)
+}
+
+#[napi(object)]
+pub struct SymbolDescription {
+  pub description: Option<String>,
+  pub key: Option<String>,
+}
+
+#[napi]
+pub fn describe_symbol(symbol: JsSymbol) -> Result<SymbolDescription> {
+  Ok(SymbolDescription {
+    description: symbol.description()?,
+    key: symbol.key_for()?,
+  })
+}
@@ -1,6 +1,8 @@
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::thread::sleep;
 
 use napi::bindgen_prelude::*;
+use napi::AsyncWorkQueue;
 
 struct DelaySum(u32, u32);
 
@@ -93,3 +95,98 @@ impl Task for AsyncTaskReadFile {
 pub fn async_task_read_file(path: String) -> AsyncTask<AsyncTaskReadFile> {
   AsyncTask::new(AsyncTaskReadFile { path })
 }
+
+struct CancellableCount {
+  token: CancellationToken,
+}
+
+#[napi]
+impl Task for CancellableCount {
+  type Output = u32;
+  type JsValue = u32;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    for _ in 0..100 {
+      // `napi_cancel_async_work` can't stop a running task, so check the token instead
+      self.token.check()?;
+      sleep(std::time::Duration::from_millis(10));
+    }
+    Ok(100)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+fn with_cancellation_token(signal: AbortSignal) -> AsyncTask<CancellableCount> {
+  let token = signal.cancellation_token();
+  AsyncTask::with_signal(CancellableCount { token }, signal)
+}
+
+#[napi]
+async fn wait_for_abort(token: CancellationToken) -> bool {
+  token.cancelled().await;
+  token.is_cancelled()
+}
+
+/// `true` if aborted before `ms` elapsed
+#[napi]
+async fn sleep_or_abort(token: CancellationToken, ms: u32) -> bool {
+  tokio::select! {
+    _ = token.cancelled() => true,
+    _ = tokio::time::sleep(std::time::Duration::from_millis(ms as u64)) => false,
+  }
+}
+
+#[napi(ts_return_type = "Promise<number>")]
+fn spawn_blocking_sum(env: Env, a: u32, b: u32) -> Result<napi::JsObject> {
+  let promise = env.spawn_blocking(move || {
+    sleep(std::time::Duration::from_millis(10));
+    Ok(a + b)
+  })?;
+  Ok(promise.promise_object())
+}
+
+#[napi]
+fn named_delay_sum(a: u32, b: u32) -> AsyncTask<DelaySum> {
+  AsyncTask::new(DelaySum(a, b)).with_resource_name("DelaySum")
+}
+
+static RUNNING_QUEUED_TASKS: AtomicU32 = AtomicU32::new(0);
+static PEAK_QUEUED_TASKS: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+  static SERIAL_QUEUE: AsyncWorkQueue = AsyncWorkQueue::new(1);
+}
+
+struct QueuedDelay(u32);
+
+#[napi]
+impl Task for QueuedDelay {
+  type Output = u32;
+  type JsValue = u32;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    let running = RUNNING_QUEUED_TASKS.fetch_add(1, Ordering::SeqCst) + 1;
+    PEAK_QUEUED_TASKS.fetch_max(running, Ordering::SeqCst);
+    sleep(std::time::Duration::from_millis(20));
+    RUNNING_QUEUED_TASKS.fetch_sub(1, Ordering::SeqCst);
+    Ok(self.0)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+#[napi]
+fn serial_delay(value: u32) -> AsyncTask<QueuedDelay> {
+  SERIAL_QUEUE.with(|queue| AsyncTask::new(QueuedDelay(value)).with_queue(queue))
+}
+
+#[napi]
+fn peak_serial_delay_concurrency() -> u32 {
+  PEAK_QUEUED_TASKS.load(Ordering::SeqCst)
+}
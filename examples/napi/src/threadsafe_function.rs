@@ -1,8 +1,9 @@
-use std::{thread, time::Duration};
+use std::{cell::RefCell, sync::Mutex, thread, time::Duration};
 
 use napi::{
   bindgen_prelude::*,
   threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode, UnknownReturnValue},
+  MainThreadHandle,
 };
 
 #[napi]
@@ -155,7 +156,81 @@ pub async fn tsfn_return_promise_timeout(
   }
 }
 
+#[napi(ts_args_type = "func: (err: Error | null, arg: number) => number | Promise<number>")]
+pub async fn tsfn_call_async_typed(func: ThreadsafeFunction<u32, u32>) -> Result<u32> {
+  let sync_value = func.call_async(Ok(1)).await?;
+  let async_value = func.call_async(Ok(sync_value)).await?;
+  Ok(async_value + 2)
+}
+
 #[napi]
 pub async fn tsfn_throw_from_js(tsfn: ThreadsafeFunction<u32, Promise<u32>>) -> napi::Result<u32> {
   tsfn.call_async(Ok(42)).await?.await
 }
+
+#[napi]
+pub fn tsfn_queue_full(func: Function<u32, ()>) -> napi::Result<Vec<String>> {
+  let tsfn = func
+    .build_threadsafe_function::<u32>()
+    .max_queue_size::<1>()
+    .build()?;
+  // The JavaScript thread is blocked by this function, so the queue can't be drained
+  Ok(
+    (0..2)
+      .map(
+        |i| match tsfn.try_call(i, ThreadsafeFunctionCallMode::NonBlocking) {
+          Ok(()) => "Ok".to_owned(),
+          Err(err) => err.status.to_string(),
+        },
+      )
+      .collect(),
+  )
+}
+
+#[napi]
+pub fn call_on_next_tick(env: Env, callback: Function<u32, ()>) -> Result<()> {
+  let callback = callback.create_ref()?;
+  env.run_on_main_thread(move |env| callback.borrow_back(&env)?.call(42))
+}
+
+thread_local! {
+  static MAIN_THREAD_VALUES: RefCell<Vec<u32>> = Default::default();
+}
+
+#[napi]
+pub fn send_to_main_thread(env: Env, values: Vec<u32>) -> Result<()> {
+  let handle = env.main_thread_handle()?;
+  thread::spawn(move || {
+    for value in values {
+      handle
+        .run(move |_env| {
+          MAIN_THREAD_VALUES.with(|values| values.borrow_mut().push(value));
+          Ok(())
+        })
+        .expect("Queue closure onto the JavaScript thread failed");
+    }
+  });
+  Ok(())
+}
+
+#[napi]
+pub fn take_main_thread_values() -> Vec<u32> {
+  MAIN_THREAD_VALUES.with(|values| values.take())
+}
+
+static STASHED_MAIN_THREAD_HANDLE: Mutex<Option<MainThreadHandle>> = Mutex::new(None);
+
+#[napi]
+pub fn stash_main_thread_handle(env: Env) -> Result<()> {
+  *STASHED_MAIN_THREAD_HANDLE.lock().unwrap() = Some(env.main_thread_handle()?);
+  Ok(())
+}
+
+/// Queue a closure with the stashed handle then drop it, `false` if the `Env` of the handle has been torn down
+#[napi]
+pub fn run_on_stashed_main_thread_handle() -> bool {
+  let handle = STASHED_MAIN_THREAD_HANDLE.lock().unwrap().take();
+  handle
+    .map(|handle| handle.run(|_env| Ok(())).is_ok())
+    .unwrap_or(false)
+}
@@ -0,0 +1,42 @@
+use std::sync::Mutex;
+
+use napi::{
+  bindgen_prelude::*,
+  tracing_layer::{JsLoggerLayer, JsLoggerLevel, LogRecord},
+};
+use tracing_subscriber::prelude::*;
+
+static JS_LOGGER_LEVEL: Mutex<Option<JsLoggerLevel>> = Mutex::new(None);
+
+#[napi(
+  ts_args_type = "logger: (record: { level: string, target: string, message: string, fields: Record<string, string> }) => void, level: string"
+)]
+pub fn init_js_logger(logger: Function<LogRecord, Unknown>, level: String) -> Result<()> {
+  let layer = JsLoggerLayer::new(logger, &level)?;
+  let layer_level = layer.level();
+  tracing_subscriber::registry()
+    .with(layer)
+    .try_init()
+    .map_err(|err| Error::new(Status::GenericFailure, err.to_string()))?;
+  *JS_LOGGER_LEVEL.lock().unwrap() = Some(layer_level);
+  Ok(())
+}
+
+#[napi]
+pub fn set_js_logger_level(level: String) -> Result<()> {
+  match JS_LOGGER_LEVEL.lock().unwrap().as_ref() {
+    Some(logger_level) => logger_level.set(&level),
+    None => Err(Error::new(
+      Status::GenericFailure,
+      "The js logger is not initialized",
+    )),
+  }
+}
+
+#[napi]
+pub fn log_with_tracing(message: String) {
+  std::thread::spawn(move || {
+    tracing::debug!("{} from debug", message);
+    tracing::info!(answer = 42, "{}", message);
+  });
+}
@@ -1,4 +1,6 @@
-use napi::{bindgen_prelude::*, JsArrayBuffer};
+use std::sync::atomic::Ordering;
+
+use napi::{bindgen_prelude::*, JsArrayBuffer, JsSharedArrayBuffer, SharedMemory};
 
 #[napi]
 fn get_buffer() -> Buffer {
@@ -17,6 +19,35 @@ fn get_empty_buffer() -> Buffer {
   vec![].into()
 }
 
+#[napi]
+fn get_copied_buffer() -> Buffer {
+  Buffer::from(b"copied".to_vec()).force_copy()
+}
+
+thread_local! {
+  static BUFFER_POOL: BufferPool = BufferPool::new(1024, 4);
+}
+
+#[napi]
+fn create_pooled_buffer(len: u32, fill: u8) -> Buffer {
+  BUFFER_POOL.with(|pool| {
+    let mut data = pool.take();
+    data.resize(len as usize, fill);
+    pool.buffer(data)
+  })
+}
+
+/// A pooled `Buffer` dropped in Rust, without being sent to JavaScript, returns its memory to the pool as well
+#[napi]
+fn drop_pooled_buffer() {
+  BUFFER_POOL.with(|pool| drop(pool.buffer(Vec::with_capacity(1024))))
+}
+
+#[napi]
+fn pooled_buffers_available() -> u32 {
+  BUFFER_POOL.with(|pool| pool.available() as u32)
+}
+
 #[napi]
 fn convert_u32_array(input: Uint32Array) -> Vec<u32> {
   input.to_vec()
@@ -119,6 +150,46 @@ fn accept_uint8_clamped_slice_and_buffer_slice(a: BufferSlice, b: Uint8ClampedSl
   a.len() + b.len()
 }
 
+#[napi]
+fn mutate_buffer_slice(mut input: BufferSlice) {
+  for b in input.as_mut_slice() {
+    *b = b.wrapping_add(1);
+  }
+}
+
+#[napi]
+fn sum_typed_array(input: TypedArray<f64>) -> f64 {
+  input.iter().sum()
+}
+
+#[napi]
+fn double_typed_array(input: TypedArray<i32>) -> TypedArray<i32> {
+  input.iter().map(|v| v * 2).collect::<Vec<_>>().into()
+}
+
+#[napi]
+fn create_typed_array_from_vec() -> TypedArray<i64> {
+  vec![1, -2, 3].into()
+}
+
+#[napi]
+fn create_shared_arraybuffer(env: Env, length: u32) -> Result<JsSharedArrayBuffer> {
+  env.create_shared_arraybuffer(length as usize)
+}
+
+#[napi]
+fn fill_shared_memory_in_thread(memory: SharedMemory, value: u8) -> u32 {
+  let memory = std::thread::spawn(move || {
+    for byte in memory.as_atomic_slice() {
+      byte.store(value, Ordering::Relaxed);
+    }
+    memory
+  })
+  .join()
+  .unwrap();
+  memory.len() as u32
+}
+
 struct AsyncBuffer {
   buf: Buffer,
 }
@@ -0,0 +1,20 @@
+//! This is testing that `#[napi(getter)]` can't take arguments
+
+use napi_derive::napi;
+
+#[napi]
+pub struct Counter {
+  value: u32,
+}
+
+#[napi]
+impl Counter {
+  #[napi(getter)]
+  pub fn value(&self, offset: u32) -> u32 {
+    self.value + offset
+  }
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -0,0 +1,15 @@
+//! This is testing that `#[napi(extends)]` requires the base class as the first field of a `#[repr(C)]` struct
+
+use napi_derive::napi;
+
+#[napi]
+pub struct Base {}
+
+#[napi(extends = Base)]
+pub struct Derived {
+  base: Base,
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -0,0 +1,16 @@
+//! This is testing that `#[napi(finalize)]` requires `#[napi(custom_finalize)]` on the struct
+
+use napi_derive::napi;
+
+#[napi]
+pub struct Resource {}
+
+#[napi]
+impl Resource {
+  #[napi(finalize)]
+  fn release(&mut self) {}
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
@@ -1,5 +1,8 @@
 //! Include the test files here so they can be formatted properly with `cargo fmt`
 
+pub mod accessor_args;
+pub mod extends_without_repr_c;
+pub mod finalize_without_custom_finalize;
 pub mod fn_outside_impl_factory;
 pub mod ts_arg_type_1;
 pub mod ts_arg_type_2;
@@ -9,4 +9,7 @@ fn run_build_error_tests() {
   let t = trybuild::TestCases::new();
   t.compile_fail("tests/build_error_tests/ts_arg_type_*.rs");
   t.compile_fail("tests/build_error_tests/fn_outside_impl_factory.rs");
+  t.compile_fail("tests/build_error_tests/accessor_args.rs");
+  t.compile_fail("tests/build_error_tests/extends_without_repr_c.rs");
+  t.compile_fail("tests/build_error_tests/finalize_without_custom_finalize.rs");
 }